@@ -6,7 +6,7 @@
 use frame_support::{pallet_prelude::*, transactional};
 use frame_system::pallet_prelude::*;
 use orml_traits::MultiCurrencyExtended;
-use sp_arithmetic::{traits::SaturatedConversion, Perbill};
+use sp_arithmetic::{traits::SaturatedConversion, PerThing, Perbill};
 use sp_core::{H160, U256};
 use sp_std::prelude::*;
 
@@ -33,7 +33,7 @@ pub mod pallet {
 	use sp_runtime::traits::AccountIdConversion;
 	use sp_runtime::ModuleError;
 
-	use core_primitives::NFTTrait;
+	use core_primitives::{NFTTrait, PriceProvider};
 
 	use primitives::{Attributes, ClassId, NftMetadata, TokenId};
 
@@ -60,6 +60,8 @@ pub mod pallet {
 		/// The sovereign pallet
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+		/// Price provider used to value bridged tokens and minimum fees in native token
+		type PriceProvider: PriceProvider;
 	}
 
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -99,6 +101,8 @@ pub mod pallet {
 		RemoveResourceTokenId(ResourceId, FungibleTokenId),
 		/// [chainId, min_fee, fee_scale]
 		FeeUpdated(ChainId, BalanceOf<T>, u32),
+		/// [chainId, min_fee, fee_per_mille]
+		ValueFeeUpdated(ChainId, BalanceOf<T>, u32),
 		/// FungibleTransfer is for relaying fungibles (dest_id, resource_id, amount,
 		/// recipient, metadata)
 		FungibleTransfer(ChainId, ResourceId, U256, Vec<u8>, Vec<u8>),
@@ -145,6 +149,13 @@ pub mod pallet {
 	#[pallet::getter(fn bridge_fee)]
 	pub type BridgeFee<T: Config> = StorageMap<_, Twox64Concat, ChainId, (BalanceOf<T>, u32), ValueQuery>;
 
+	/// Fee of chainId charged on the value of bridged tokens while prices are available, the
+	/// minimum fee in the reference currency of the price provider and the fee per mille of the
+	/// bridged value in native token
+	#[pallet::storage]
+	#[pallet::getter(fn bridge_value_fee)]
+	pub type BridgeValueFee<T: Config> = StorageMap<_, Twox64Concat, ChainId, (BalanceOf<T>, u32), OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn currency_ids)]
 	pub type CurrencyIds<T: Config> =
@@ -177,6 +188,24 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Set the fee charged on the value of bridged tokens, `min_fee` is in the reference
+		/// currency of the price provider
+		#[pallet::weight(Weight::from_parts(195_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn oracle_change_value_fee(
+			origin: OriginFor<T>,
+			min_fee: BalanceOf<T>,
+			fee_per_mille: u32,
+			dest_id: ChainId,
+		) -> DispatchResult {
+			let bridge_origin = ensure_signed(origin)?;
+			ensure!(Self::is_bridge_origin(&bridge_origin), Error::<T>::NoPermission);
+
+			ensure!(fee_per_mille <= 1000u32, Error::<T>::InvalidFeeOption);
+			BridgeValueFee::<T>::insert(dest_id, (min_fee, fee_per_mille));
+			Self::deposit_event(Event::ValueFeeUpdated(dest_id, min_fee, fee_per_mille));
+			Ok(())
+		}
+
 		/// Transfers some amount of the native token to some recipient on a (whitelisted)
 		/// destination chain.
 		#[pallet::weight(Weight::from_parts(195_000, 0)  + T::DbWeight::get().writes(1))]
@@ -192,9 +221,11 @@ pub mod pallet {
 
 			let bridge_id = T::PalletId::get().into_account_truncating();
 			ensure!(BridgeFee::<T>::contains_key(&chain_id), Error::<T>::FeeOptionsMissing);
-			let (min_fee, _fee_scale) = Self::bridge_fee(chain_id);
-
-			let fee_estimated = currency_id.1 * amount;
+			let (min_fee, fee_estimated) =
+				Self::bridge_value_fee_of(chain_id, currency_id.0, amount).unwrap_or_else(|| {
+					let (min_fee, _fee_scale) = Self::bridge_fee(chain_id);
+					(min_fee, currency_id.1 * amount)
+				});
 			let fee = if fee_estimated > min_fee {
 				fee_estimated
 			} else {
@@ -337,6 +368,7 @@ pub mod pallet {
 
 			ensure!(BridgeFee::<T>::contains_key(&chain_id), Error::<T>::FeeOptionsMissing);
 			let (min_fee, _fee_scale) = Self::bridge_fee(chain_id);
+			let min_fee = Self::bridge_value_min_fee(chain_id).unwrap_or(min_fee);
 
 			T::Currency::transfer(&source, &bridge_id, min_fee.into(), ExistenceRequirement::AllowDeath)?;
 
@@ -451,4 +483,26 @@ impl<T: Config> Pallet<T> {
 		let bridge_origin = Self::bridge_origin(who);
 		bridge_origin == Some(())
 	}
+
+	/// Minimum value fee of chainId in native token, `None` without a value fee or a native price
+	fn bridge_value_min_fee(chain_id: ChainId) -> Option<BalanceOf<T>> {
+		let (min_fee, _fee_per_mille) = Self::bridge_value_fee(chain_id)?;
+		T::PriceProvider::from_reference(T::NativeCurrencyId::get(), min_fee.saturated_into())
+			.map(|fee| fee.saturated_into())
+	}
+
+	/// Minimum fee and fee on the value of bridged tokens in native token, `None` without a value
+	/// fee or the prices of both tokens
+	fn bridge_value_fee_of(
+		chain_id: ChainId,
+		currency_id: FungibleTokenId,
+		amount: BalanceOf<T>,
+	) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+		let (_min_fee, fee_per_mille) = Self::bridge_value_fee(chain_id)?;
+		let min_fee = Self::bridge_value_min_fee(chain_id)?;
+		let native_value: BalanceOf<T> =
+			T::PriceProvider::convert(currency_id, T::NativeCurrencyId::get(), amount.saturated_into())?
+				.saturated_into();
+		Some((min_fee, Perbill::from_perthousand(fee_per_mille) * native_value))
+	}
 }
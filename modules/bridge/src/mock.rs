@@ -9,11 +9,11 @@ use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::traits::AccountIdConversion;
-use sp_runtime::{traits::IdentityLookup, ModuleError, Perbill};
+use sp_runtime::{traits::IdentityLookup, FixedPointNumber, ModuleError, Perbill};
 
-use core_primitives::{Attributes, CollectionType, NFTTrait, NftClassData, NftMetadata, TokenType};
+use core_primitives::{Attributes, CollectionType, NFTTrait, NftClassData, NftMetadata, PriceProvider, TokenType};
 use primitives::{
-	continuum::MapTrait, Amount, ClassId, EstateId, FungibleTokenId, GroupCollectionId, MapSpotId, TokenId,
+	continuum::MapTrait, Amount, ClassId, EstateId, FungibleTokenId, GroupCollectionId, MapSpotId, Price, TokenId,
 	UndeployedLandBlockId,
 };
 use sp_runtime::BuildStorage;
@@ -151,6 +151,19 @@ parameter_types! {
 	pub const BridgeSovereignPalletId: PalletId = PalletId(*b"bit/brgd");
 }
 
+/// Prices in native token, the mining resource is worth 2 native tokens
+pub struct MockPriceProvider;
+
+impl PriceProvider for MockPriceProvider {
+	fn get_price(currency_id: FungibleTokenId) -> Option<Price> {
+		match currency_id {
+			FungibleTokenId::NativeToken(0) => Some(Price::saturating_from_integer(1)),
+			FungibleTokenId::MiningResource(0) => Some(Price::saturating_from_integer(2)),
+			_ => None,
+		}
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgeOrigin = EnsureRoot<AccountId>;
@@ -159,6 +172,7 @@ impl Config for Runtime {
 	type NFTHandler = MockNFTHandler;
 	type NativeCurrencyId = NativeCurrencyId;
 	type PalletId = BridgeSovereignPalletId;
+	type PriceProvider = MockPriceProvider;
 }
 
 pub type AdaptedBasicCurrency = currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
@@ -484,4 +498,11 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 	) -> sp_runtime::DispatchResult {
 		Ok(())
 	}
+
+	fn get_royalty_payouts(
+		_class_id: &Self::ClassId,
+		_sale_price: Balance,
+	) -> Result<Vec<(AccountId, Balance)>, DispatchError> {
+		Ok(vec![])
+	}
 }
@@ -5,7 +5,7 @@ use std::str::FromStr;
 use frame_support::assert_ok;
 use sp_core::H160;
 
-use mock::{Balances, BridgeModule, ExtBuilder, RuntimeOrigin};
+use mock::{Balances, BridgeModule, ExtBuilder, RuntimeOrigin, Tokens};
 
 use crate::mock::{ALICE, BOB};
 
@@ -59,6 +59,52 @@ fn bridge_out_fungible_work() {
 	})
 }
 
+#[test]
+fn bridge_out_fungible_with_price_should_charge_fee_on_value() {
+	ExtBuilder::default().build().execute_with(|| {
+		let resource_id = H160::from_str("0x0000000000000000000200000000000000000001")
+			.ok()
+			.unwrap();
+		let mining_resource = FungibleTokenId::MiningResource(0);
+		assert_ok!(BridgeModule::add_bridge_origin(RuntimeOrigin::root(), ALICE));
+		assert_ok!(BridgeModule::oracle_change_fee(RuntimeOrigin::signed(ALICE), 50, 0, 0));
+		assert_ok!(BridgeModule::register_new_token_id(
+			RuntimeOrigin::root(),
+			resource_id,
+			mining_resource,
+			Perbill::from_percent(3)
+		));
+
+		// Without a value fee the fee ratio of the token is charged on the amount
+		assert_ok!(BridgeModule::bridge_out_fungible(
+			RuntimeOrigin::signed(ALICE),
+			5000,
+			vec![0],
+			resource_id,
+			0
+		));
+		assert_eq!(Balances::free_balance(ALICE), 100000 - 150);
+
+		assert_ok!(BridgeModule::oracle_change_value_fee(
+			RuntimeOrigin::signed(ALICE),
+			100,
+			20,
+			0
+		));
+		assert_eq!(BridgeModule::bridge_fee(0), (50, 0));
+		assert_ok!(BridgeModule::bridge_out_fungible(
+			RuntimeOrigin::signed(ALICE),
+			5000,
+			vec![0],
+			resource_id,
+			0
+		));
+		// 5000 mining resource are worth 10000 native token, the fee is 2% of it
+		assert_eq!(Balances::free_balance(ALICE), 100000 - 150 - 200);
+		assert_eq!(Tokens::accounts(ALICE, mining_resource).free, 0);
+	})
+}
+
 #[test]
 fn bridge_in_fungible() {
 	ExtBuilder::default().build().execute_with(|| {
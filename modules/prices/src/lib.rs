@@ -0,0 +1,112 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Prices Module
+//!
+//! Prices of fungible tokens in a reference currency, fed by the oracle members through
+//! `orml_oracle`. Each member feeds its own price per token; the provider ignores the values
+//! older than the maximum price age and takes the median of the remaining ones, so a single
+//! member can neither move the price nor keep a stale price alive.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::traits::{Get, Time};
+use sp_runtime::{
+	traits::{AtLeast32Bit, CheckedAdd, CheckedDiv, One},
+	FixedPointNumber,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+use core_primitives::PriceProvider;
+use primitives::{FungibleTokenId, Price};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// Prices fed by each oracle member with the time they were fed
+pub trait RawPriceSource {
+	type Moment;
+
+	fn raw_prices(currency_id: FungibleTokenId) -> Vec<(Price, Self::Moment)>;
+}
+
+/// Raw prices of an `orml_oracle` instance keyed by currency
+pub struct OracleRawPrices<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T, I> RawPriceSource for OracleRawPrices<T, I>
+where
+	T: orml_oracle::Config<I, OracleKey = FungibleTokenId, OracleValue = Price>,
+	I: 'static,
+{
+	type Moment = <<T as orml_oracle::Config<I>>::Time as Time>::Moment;
+
+	fn raw_prices(currency_id: FungibleTokenId) -> Vec<(Price, Self::Moment)> {
+		orml_oracle::Pallet::<T, I>::read_raw_values(&currency_id)
+			.into_iter()
+			.map(|fed| (fed.value, fed.timestamp))
+			.collect()
+	}
+}
+
+/// Median of the fresh prices fed by the oracle members.
+///
+/// - `ReferenceCurrencyId` is always priced at one.
+/// - Prices fed more than `MaxPriceAge` ago are ignored.
+/// - No price is given with fewer than `MinimumFeeds` fresh prices.
+pub struct MedianPriceProvider<Source, Now, ReferenceCurrencyId, MaxPriceAge, MinimumFeeds>(
+	PhantomData<(Source, Now, ReferenceCurrencyId, MaxPriceAge, MinimumFeeds)>,
+);
+
+impl<Source, Now, ReferenceCurrencyId, MaxPriceAge, MinimumFeeds> PriceProvider
+	for MedianPriceProvider<Source, Now, ReferenceCurrencyId, MaxPriceAge, MinimumFeeds>
+where
+	Source: RawPriceSource,
+	Source::Moment: AtLeast32Bit + Copy,
+	Now: Time<Moment = Source::Moment>,
+	ReferenceCurrencyId: Get<FungibleTokenId>,
+	MaxPriceAge: Get<Source::Moment>,
+	MinimumFeeds: Get<u32>,
+{
+	fn get_price(currency_id: FungibleTokenId) -> Option<Price> {
+		if currency_id == ReferenceCurrencyId::get() {
+			return Some(Price::one());
+		}
+
+		let now = Now::now();
+		let mut prices: Vec<Price> = Source::raw_prices(currency_id)
+			.into_iter()
+			.filter(|(_, fed_at)| now.saturating_sub(*fed_at) <= MaxPriceAge::get())
+			.map(|(price, _)| price)
+			.collect();
+		if prices.is_empty() || (prices.len() as u32) < MinimumFeeds::get() {
+			return None;
+		}
+
+		prices.sort();
+		let middle = prices.len() / 2;
+		if prices.len() % 2 == 0 {
+			prices[middle - 1]
+				.checked_add(&prices[middle])?
+				.checked_div(&Price::saturating_from_integer(2))
+		} else {
+			Some(prices[middle])
+		}
+	}
+}
@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use frame_support::parameter_types;
+
+use super::*;
+
+pub const NATIVE: FungibleTokenId = FungibleTokenId::NativeToken(0);
+pub const KSM: FungibleTokenId = FungibleTokenId::NativeToken(1);
+pub const KUSD: FungibleTokenId = FungibleTokenId::Stable(0);
+
+parameter_types! {
+	pub static FedPrices: Vec<(FungibleTokenId, Price, u64)> = vec![];
+	pub static CurrentTime: u64 = 1_000;
+	pub const ReferenceCurrencyId: FungibleTokenId = NATIVE;
+	pub const MaxPriceAge: u64 = 100;
+	pub const MinimumFeeds: u32 = 2;
+}
+
+pub struct MockRawPrices;
+
+impl RawPriceSource for MockRawPrices {
+	type Moment = u64;
+
+	fn raw_prices(currency_id: FungibleTokenId) -> Vec<(Price, u64)> {
+		FedPrices::get()
+			.into_iter()
+			.filter(|(fed_currency_id, _, _)| *fed_currency_id == currency_id)
+			.map(|(_, price, fed_at)| (price, fed_at))
+			.collect()
+	}
+}
+
+pub struct MockTime;
+
+impl Time for MockTime {
+	type Moment = u64;
+
+	fn now() -> u64 {
+		CurrentTime::get()
+	}
+}
+
+pub type Prices = MedianPriceProvider<MockRawPrices, MockTime, ReferenceCurrencyId, MaxPriceAge, MinimumFeeds>;
+
+/// Feeds `price` for `currency_id` at `fed_at`, one entry per member
+pub fn feed(currency_id: FungibleTokenId, price: Price, fed_at: u64) {
+	let mut fed_prices = FedPrices::get();
+	fed_prices.push((currency_id, price, fed_at));
+	FedPrices::set(fed_prices);
+}
@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+use mock::*;
+
+use super::*;
+
+fn price(n: u128, d: u128) -> Price {
+	Price::saturating_from_rational(n, d)
+}
+
+#[test]
+fn reference_currency_is_priced_at_one() {
+	assert_eq!(Prices::get_price(NATIVE), Some(Price::one()));
+	assert_eq!(Prices::from_reference(NATIVE, 100), Some(100));
+}
+
+#[test]
+fn median_of_odd_feeds_should_work() {
+	feed(KSM, price(30, 1), 1_000);
+	feed(KSM, price(10, 1), 990);
+	feed(KSM, price(20, 1), 950);
+
+	assert_eq!(Prices::get_price(KSM), Some(price(20, 1)));
+}
+
+#[test]
+fn median_of_even_feeds_should_work() {
+	feed(KSM, price(10, 1), 1_000);
+	feed(KSM, price(40, 1), 1_000);
+	feed(KSM, price(20, 1), 1_000);
+	feed(KSM, price(1_000, 1), 1_000);
+
+	// A single member can not move the price
+	assert_eq!(Prices::get_price(KSM), Some(price(30, 1)));
+}
+
+#[test]
+fn stale_feeds_are_ignored() {
+	feed(KSM, price(10, 1), 1_000);
+	feed(KSM, price(20, 1), 900);
+	feed(KSM, price(1_000, 1), 899);
+
+	assert_eq!(Prices::get_price(KSM), Some(price(15, 1)));
+
+	// Not enough fresh feeds
+	CurrentTime::set(1_001);
+	assert_eq!(Prices::get_price(KSM), None);
+}
+
+#[test]
+fn unknown_currency_has_no_price() {
+	feed(KSM, price(10, 1), 1_000);
+
+	// A single feed is not enough
+	assert_eq!(Prices::get_price(KSM), None);
+	assert_eq!(Prices::get_price(KUSD), None);
+	assert_eq!(Prices::convert(KSM, NATIVE, 100), None);
+	assert_eq!(Prices::from_reference(KUSD, 100), None);
+}
+
+#[test]
+fn convert_between_currencies_should_work() {
+	feed(KSM, price(40, 1), 1_000);
+	feed(KSM, price(40, 1), 1_000);
+	feed(KUSD, price(2, 1), 1_000);
+	feed(KUSD, price(2, 1), 1_000);
+
+	assert_eq!(Prices::get_relative_price(KSM, KUSD), Some(price(20, 1)));
+	assert_eq!(Prices::get_relative_price(KSM, KSM), Some(Price::one()));
+	assert_eq!(Prices::convert(KSM, KUSD, 3), Some(60));
+	assert_eq!(Prices::convert(KUSD, NATIVE, 3), Some(6));
+	// 100 native are worth 2.5 KSM
+	assert_eq!(Prices::from_reference(KSM, 100), Some(2));
+}
@@ -37,7 +37,10 @@ use xcm::VersionedMultiLocation;
 
 use core_primitives::CurrencyIdManagement;
 pub use pallet::*;
-use primitives::{AssetIds, AssetMetadata, BuyWeightRate, ForeignAssetIdMapping, FungibleTokenId, Ratio, TokenId};
+use primitives::{
+	AssetIds, AssetMetadata, BuyWeightRate, ForeignAssetIdMapping, FungibleTokenId, FungibleTokenRegistry, Ratio,
+	TokenId,
+};
 
 mod mock;
 mod tests;
@@ -323,6 +326,27 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> FungibleTokenRegistry<BalanceOf<T>> for Pallet<T> {
+	fn register_fungible_token(metadata: &AssetMetadata<BalanceOf<T>>) -> Result<FungibleTokenId, DispatchError> {
+		// Local fungible tokens share the `FungibleToken` id space with foreign assets
+		let currency_id = FungibleTokenId::FungibleToken(Self::get_next_foreign_asset_id()?);
+		let asset_id = AssetIds::NativeAssetId(currency_id);
+		AssetMetadatas::<T>::try_mutate(asset_id.clone(), |maybe_asset_metadatas| -> DispatchResult {
+			ensure!(maybe_asset_metadatas.is_none(), Error::<T>::AssetIdExisted);
+
+			*maybe_asset_metadatas = Some(metadata.clone());
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T>::AssetRegistered {
+			asset_id,
+			metadata: metadata.clone(),
+		});
+
+		Ok(currency_id)
+	}
+}
+
 pub struct ForeignAssetMapping<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> ForeignAssetIdMapping<TokenId, MultiLocation, AssetMetadata<BalanceOf<T>>> for ForeignAssetMapping<T> {
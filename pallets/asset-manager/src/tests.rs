@@ -141,6 +141,42 @@ fn register_foreign_asset_fail() {
 	});
 }
 
+#[test]
+fn register_fungible_token_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = AssetMetadata {
+			name: b"Social Token".to_vec(),
+			symbol: b"SOCIAL".to_vec(),
+			decimals: 18,
+			minimal_balance: 1,
+		};
+		assert_eq!(
+			<AssetManager as FungibleTokenRegistry<_>>::register_fungible_token(&metadata),
+			Ok(FungibleTokenId::FungibleToken(0))
+		);
+		System::assert_last_event(RuntimeEvent::AssetManager(crate::Event::AssetRegistered {
+			asset_id: AssetIds::NativeAssetId(FungibleTokenId::FungibleToken(0)),
+			metadata: metadata.clone(),
+		}));
+		assert_eq!(
+			AssetMetadatas::<Runtime>::get(AssetIds::NativeAssetId(FungibleTokenId::FungibleToken(0))),
+			Some(metadata.clone())
+		);
+
+		// Foreign assets registered afterwards must not reuse the token id
+		let location: MultiLocation = VersionedMultiLocation::V3(MultiLocation::parent()).try_into().unwrap();
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			Box::new(location.clone().into()),
+			Box::new(metadata)
+		));
+		assert_eq!(
+			LocationToFungibleTokenIds::<Runtime>::get(location),
+			Some(FungibleTokenId::FungibleToken(1))
+		);
+	});
+}
+
 #[test]
 fn versioned_multi_location_convert_work() {
 	ExtBuilder::default().build().execute_with(|| {
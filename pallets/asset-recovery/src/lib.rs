@@ -0,0 +1,448 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Social recovery of land, estates and NFTs.
+//!
+//! An account sets up guardians, a threshold and a delay. When its key is lost, a rescuer opens
+//! a recovery request for it, the guardians vouch for the rescuer and, once the threshold is
+//! reached and the delay has passed since the request, the rescuer claims the recovery. The claim
+//! moves the NFTs, stackable NFT balances, land units, estates, undeployed land blocks,
+//! metaverses and the claimed EVM address of the lost account to the rescuer, at most
+//! `MaxRecoveredAssets` per call. The rescuer repeats the claim until every asset is moved.
+//!
+//! Guardians only vouch: the assets can only be moved to the rescuer named in the request, and
+//! fungible balances of the lost account are not touched. While the delay runs, the lost account
+//! can still close any request it does not recognise.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	pallet_prelude::*,
+	traits::{BalanceStatus, Currency, ReservableCurrency},
+	transactional, BoundedVec,
+};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+use sp_std::prelude::*;
+
+use core_primitives::AccountAssetsMigration;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Guardians configuration of an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RecoveryConfig<BlockNumber, Balance, Guardians> {
+	/// Accounts allowed to vouch for a rescuer
+	pub guardians: Guardians,
+	/// Number of guardians needed to recover the account
+	pub threshold: u32,
+	/// Blocks between opening a recovery request and claiming it
+	pub delay_period: BlockNumber,
+	/// Deposit reserved from the configured account
+	pub deposit: Balance,
+}
+
+/// Open recovery request of a rescuer
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ActiveRecovery<BlockNumber, Balance, Guardians> {
+	/// Block the request was opened at
+	pub created: BlockNumber,
+	/// Deposit reserved from the rescuer
+	pub deposit: Balance,
+	/// Guardians who vouched for the rescuer
+	pub approvals: Guardians,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Currency used to reserve the recovery deposits
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Deposit reserved when setting up guardians and when opening a recovery request
+		#[pallet::constant]
+		type RecoveryDeposit: Get<BalanceOf<Self>>;
+		/// Maximum number of guardians of an account
+		#[pallet::constant]
+		type MaxGuardians: Get<u32>;
+		/// Minimum delay between opening a recovery request and claiming it
+		#[pallet::constant]
+		type MinimumRecoveryDelay: Get<BlockNumberFor<Self>>;
+		/// Maximum number of assets moved by a single claim call
+		#[pallet::constant]
+		type MaxRecoveredAssets: Get<u32>;
+		/// Moves the land, estates, NFTs, metaverses and EVM address of the recovered account
+		type AssetsMigration: AccountAssetsMigration<Self::AccountId>;
+		/// Weight implementation for asset recovery extrinsics
+		type WeightInfo: WeightInfo;
+	}
+
+	pub type GuardiansOf<T> = BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxGuardians>;
+
+	pub type RecoveryConfigOf<T> = RecoveryConfig<BlockNumberFor<T>, BalanceOf<T>, GuardiansOf<T>>;
+
+	pub type ActiveRecoveryOf<T> = ActiveRecovery<BlockNumberFor<T>, BalanceOf<T>, GuardiansOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_config)]
+	/// Guardians configuration of each recoverable account.
+	pub type RecoveryConfigs<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, RecoveryConfigOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn active_recovery)]
+	/// Open recovery requests by lost account and rescuer.
+	pub type ActiveRecoveries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, ActiveRecoveryOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claiming_rescuer)]
+	/// Rescuer claiming a lost account whose assets are partially moved.
+	pub type ClaimingRescuers<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Guardians of an account are set
+		GuardiansSet {
+			account: T::AccountId,
+			threshold: u32,
+			delay_period: BlockNumberFor<T>,
+		},
+		/// Guardians of an account are removed
+		GuardiansRemoved { account: T::AccountId },
+		/// Rescuer opened a recovery request for a lost account
+		RecoveryInitiated { lost: T::AccountId, rescuer: T::AccountId },
+		/// Guardian vouched for a rescuer
+		RecoveryVouched {
+			lost: T::AccountId,
+			rescuer: T::AccountId,
+			guardian: T::AccountId,
+		},
+		/// Part of the assets of a lost account are moved to the rescuer, the claim must be
+		/// repeated to move the rest
+		RecoveryPartiallyClaimed {
+			lost: T::AccountId,
+			rescuer: T::AccountId,
+			migrated_assets: u32,
+		},
+		/// The last assets of a lost account are moved to the rescuer
+		RecoveryClaimed {
+			lost: T::AccountId,
+			rescuer: T::AccountId,
+			migrated_assets: u32,
+		},
+		/// Lost account closed a recovery request
+		RecoveryClosed { lost: T::AccountId, rescuer: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account already has guardians
+		AlreadyConfigured,
+		/// Account has no guardians
+		NotConfigured,
+		/// Guardians list is empty or exceeds the maximum
+		InvalidGuardians,
+		/// Guardian is listed twice or is the account itself
+		InvalidGuardian,
+		/// Threshold is zero or above the number of guardians
+		InvalidThreshold,
+		/// Delay period is below the minimum
+		DelayPeriodTooShort,
+		/// Guardians can not be removed while a recovery is open
+		RecoveryInProgress,
+		/// Rescuer already opened a recovery for this account
+		AlreadyStarted,
+		/// Recovery request does not exist
+		RecoveryNotFound,
+		/// Account can not recover itself
+		CannotRecoverSelf,
+		/// Caller is not a guardian of the lost account
+		NotGuardian,
+		/// Guardian already vouched for this rescuer
+		AlreadyVouched,
+		/// Not enough guardians vouched for the rescuer
+		ThresholdNotReached,
+		/// Delay period of the recovery has not passed
+		DelayPeriodNotPassed,
+		/// Another rescuer is already moving the assets of the lost account
+		RecoveryBeingClaimed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the guardians able to recover the assets of the caller.
+		///
+		/// The dispatch origin for this call must be _Signed_. `RecoveryDeposit` is reserved
+		/// until the guardians are removed.
+		/// - `guardians`: accounts allowed to vouch for a rescuer
+		/// - `threshold`: number of guardians needed to recover the account
+		/// - `delay_period`: blocks between opening a recovery request and claiming it
+		///
+		/// Emits `GuardiansSet` if successful.
+		#[pallet::weight(T::WeightInfo::set_guardians())]
+		#[transactional]
+		pub fn set_guardians(
+			origin: OriginFor<T>,
+			guardians: Vec<T::AccountId>,
+			threshold: u32,
+			delay_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!RecoveryConfigs::<T>::contains_key(&who), Error::<T>::AlreadyConfigured);
+			ensure!(!guardians.is_empty(), Error::<T>::InvalidGuardians);
+			ensure!(
+				threshold > 0 && threshold as usize <= guardians.len(),
+				Error::<T>::InvalidThreshold
+			);
+			ensure!(
+				delay_period >= T::MinimumRecoveryDelay::get(),
+				Error::<T>::DelayPeriodTooShort
+			);
+
+			let mut sorted_guardians = guardians.clone();
+			sorted_guardians.sort();
+			sorted_guardians.dedup();
+			ensure!(
+				sorted_guardians.len() == guardians.len() && !guardians.contains(&who),
+				Error::<T>::InvalidGuardian
+			);
+			let guardians: GuardiansOf<T> = guardians.try_into().map_err(|_| Error::<T>::InvalidGuardians)?;
+
+			let deposit = T::RecoveryDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			RecoveryConfigs::<T>::insert(
+				&who,
+				RecoveryConfig {
+					guardians,
+					threshold,
+					delay_period,
+					deposit,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::GuardiansSet {
+				account: who,
+				threshold,
+				delay_period,
+			});
+
+			Ok(())
+		}
+
+		/// Remove the guardians of the caller and release the deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_. Open recovery requests must be
+		/// closed first.
+		///
+		/// Emits `GuardiansRemoved` if successful.
+		#[pallet::weight(T::WeightInfo::remove_guardians())]
+		#[transactional]
+		pub fn remove_guardians(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				ActiveRecoveries::<T>::iter_prefix(&who).next().is_none(),
+				Error::<T>::RecoveryInProgress
+			);
+			let recovery_config = RecoveryConfigs::<T>::take(&who).ok_or(Error::<T>::NotConfigured)?;
+			T::Currency::unreserve(&who, recovery_config.deposit);
+
+			Self::deposit_event(Event::<T>::GuardiansRemoved { account: who });
+
+			Ok(())
+		}
+
+		/// Open a recovery request moving the assets of a lost account to the caller.
+		///
+		/// The dispatch origin for this call must be _Signed_. `RecoveryDeposit` is reserved
+		/// until the recovery is claimed or closed.
+		/// - `lost`: the account to recover
+		///
+		/// Emits `RecoveryInitiated` if successful.
+		#[pallet::weight(T::WeightInfo::initiate_recovery())]
+		#[transactional]
+		pub fn initiate_recovery(origin: OriginFor<T>, lost: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(who != lost, Error::<T>::CannotRecoverSelf);
+			ensure!(RecoveryConfigs::<T>::contains_key(&lost), Error::<T>::NotConfigured);
+			ensure!(
+				!ActiveRecoveries::<T>::contains_key(&lost, &who),
+				Error::<T>::AlreadyStarted
+			);
+
+			let deposit = T::RecoveryDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			ActiveRecoveries::<T>::insert(
+				&lost,
+				&who,
+				ActiveRecovery {
+					created: <frame_system::Pallet<T>>::block_number(),
+					deposit,
+					approvals: Default::default(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::RecoveryInitiated { lost, rescuer: who });
+
+			Ok(())
+		}
+
+		/// Vouch for a rescuer of a lost account.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a guardian of `lost`.
+		/// - `lost`: the account to recover
+		/// - `rescuer`: the account which opened the recovery request
+		///
+		/// Emits `RecoveryVouched` if successful.
+		#[pallet::weight(T::WeightInfo::vouch_recovery())]
+		#[transactional]
+		pub fn vouch_recovery(origin: OriginFor<T>, lost: T::AccountId, rescuer: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let recovery_config = RecoveryConfigs::<T>::get(&lost).ok_or(Error::<T>::NotConfigured)?;
+			ensure!(recovery_config.guardians.contains(&who), Error::<T>::NotGuardian);
+
+			ActiveRecoveries::<T>::try_mutate(&lost, &rescuer, |active_recovery| -> DispatchResult {
+				let active_recovery = active_recovery.as_mut().ok_or(Error::<T>::RecoveryNotFound)?;
+				ensure!(!active_recovery.approvals.contains(&who), Error::<T>::AlreadyVouched);
+				active_recovery
+					.approvals
+					.try_push(who.clone())
+					.map_err(|_| Error::<T>::InvalidGuardians)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::RecoveryVouched {
+				lost,
+				rescuer,
+				guardian: who,
+			});
+
+			Ok(())
+		}
+
+		/// Claim a vouched recovery and move the assets of the lost account to the caller.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the rescuer. The threshold must
+		/// be reached and the delay period passed. At most `MaxRecoveredAssets` assets are moved
+		/// per call, the request stays open until a call finds fewer assets left to move. Any
+		/// asset which can not be moved, such as a locked NFT or an NFT listed in an auction,
+		/// fails the call.
+		/// - `lost`: the account to recover
+		///
+		/// Emits `RecoveryPartiallyClaimed` or `RecoveryClaimed` if successful.
+		#[pallet::weight(T::WeightInfo::claim_recovery(T::MaxRecoveredAssets::get()))]
+		#[transactional]
+		pub fn claim_recovery(origin: OriginFor<T>, lost: T::AccountId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let recovery_config = RecoveryConfigs::<T>::get(&lost).ok_or(Error::<T>::NotConfigured)?;
+			let active_recovery = ActiveRecoveries::<T>::get(&lost, &who).ok_or(Error::<T>::RecoveryNotFound)?;
+			ensure!(
+				active_recovery.approvals.len() as u32 >= recovery_config.threshold,
+				Error::<T>::ThresholdNotReached
+			);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number()
+					>= active_recovery.created.saturating_add(recovery_config.delay_period),
+				Error::<T>::DelayPeriodNotPassed
+			);
+			if let Some(rescuer) = ClaimingRescuers::<T>::get(&lost) {
+				ensure!(rescuer == who, Error::<T>::RecoveryBeingClaimed);
+			}
+
+			let max_assets = T::MaxRecoveredAssets::get();
+			let migrated_assets = T::AssetsMigration::migrate_account_assets(&lost, &who, max_assets)?;
+			if migrated_assets >= max_assets {
+				ClaimingRescuers::<T>::insert(&lost, &who);
+				Self::deposit_event(Event::<T>::RecoveryPartiallyClaimed {
+					lost,
+					rescuer: who,
+					migrated_assets,
+				});
+				return Ok(Some(T::WeightInfo::claim_recovery(migrated_assets)).into());
+			}
+
+			// The lost account is recovered, every request for it is settled
+			ClaimingRescuers::<T>::remove(&lost);
+			for (rescuer, recovery) in ActiveRecoveries::<T>::drain_prefix(&lost) {
+				T::Currency::unreserve(&rescuer, recovery.deposit);
+			}
+			RecoveryConfigs::<T>::remove(&lost);
+			T::Currency::unreserve(&lost, recovery_config.deposit);
+
+			Self::deposit_event(Event::<T>::RecoveryClaimed {
+				lost,
+				rescuer: who,
+				migrated_assets,
+			});
+
+			Ok(Some(T::WeightInfo::claim_recovery(migrated_assets)).into())
+		}
+
+		/// Close a recovery request opened for the caller.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account to recover. The
+		/// deposit of the rescuer is moved to the caller. A request whose assets are partially
+		/// moved can not be closed.
+		/// - `rescuer`: the account which opened the recovery request
+		///
+		/// Emits `RecoveryClosed` if successful.
+		#[pallet::weight(T::WeightInfo::close_recovery())]
+		#[transactional]
+		pub fn close_recovery(origin: OriginFor<T>, rescuer: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				ClaimingRescuers::<T>::get(&who).as_ref() != Some(&rescuer),
+				Error::<T>::RecoveryBeingClaimed
+			);
+			let active_recovery = ActiveRecoveries::<T>::take(&who, &rescuer).ok_or(Error::<T>::RecoveryNotFound)?;
+			T::Currency::repatriate_reserved(&rescuer, &who, active_recovery.deposit, BalanceStatus::Free)?;
+
+			Self::deposit_event(Event::<T>::RecoveryClosed { lost: who, rescuer });
+
+			Ok(())
+		}
+	}
+}
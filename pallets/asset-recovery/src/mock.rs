@@ -0,0 +1,205 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use frame_support::storage::unhashed;
+use frame_support::{construct_runtime, parameter_types};
+use sp_core::H256;
+use sp_runtime::traits::IdentityLookup;
+use sp_runtime::{BuildStorage, DispatchError};
+
+use crate as asset_recovery;
+
+use super::*;
+
+pub type AccountId = u128;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const DAVE: AccountId = 4;
+pub const EVE: AccountId = 5;
+
+pub const RECOVERY_DEPOSIT: Balance = 10;
+pub const DELAY_PERIOD: BlockNumber = 10;
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type WeightInfo = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = frame_support::traits::ConstU32<0>;
+	type MaxFreezes = frame_support::traits::ConstU32<0>;
+}
+
+/// Counts the assets owned by each account, an account can be marked as having a listed asset
+pub struct MockAssetsMigration;
+
+impl MockAssetsMigration {
+	fn assets_key(who: &AccountId) -> Vec<u8> {
+		(b"assets", who).encode()
+	}
+
+	fn listed_key(who: &AccountId) -> Vec<u8> {
+		(b"listed", who).encode()
+	}
+
+	pub fn assets(who: &AccountId) -> u32 {
+		unhashed::get_or_default(&Self::assets_key(who))
+	}
+
+	pub fn set_assets(who: &AccountId, assets: u32) {
+		unhashed::put(&Self::assets_key(who), &assets);
+	}
+
+	pub fn set_listed(who: &AccountId) {
+		unhashed::put(&Self::listed_key(who), &true);
+	}
+}
+
+impl AccountAssetsMigration<AccountId> for MockAssetsMigration {
+	fn migrate_account_assets(from: &AccountId, to: &AccountId, limit: u32) -> Result<u32, DispatchError> {
+		if unhashed::get_or_default::<bool>(&Self::listed_key(from)) {
+			return Err(DispatchError::Other("AssetAlreadyInAuction"));
+		}
+		let assets = Self::assets(from).min(limit);
+		Self::set_assets(from, Self::assets(from) - assets);
+		Self::set_assets(to, Self::assets(to) + assets);
+		Ok(assets)
+	}
+}
+
+parameter_types! {
+	pub const RecoveryDeposit: Balance = RECOVERY_DEPOSIT;
+	pub const MaxGuardians: u32 = 3;
+	pub const MinimumRecoveryDelay: BlockNumber = 5;
+	pub const MaxRecoveredAssets: u32 = 10;
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RecoveryDeposit = RecoveryDeposit;
+	type MaxGuardians = MaxGuardians;
+	type MinimumRecoveryDelay = MinimumRecoveryDelay;
+	type MaxRecoveredAssets = MaxRecoveredAssets;
+	type AssetsMigration = MockAssetsMigration;
+	type WeightInfo = ();
+}
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		AssetRecovery: asset_recovery::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+pub type AssetRecoveryModule = Pallet<Runtime>;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(ALICE, 100), (BOB, 100), (CHARLIE, 100), (DAVE, 100), (EVE, 100)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			MockAssetsMigration::set_assets(&ALICE, 3);
+		});
+		ext
+	}
+}
+
+pub fn last_event() -> RuntimeEvent {
+	frame_system::Pallet::<Runtime>::events()
+		.pop()
+		.expect("Event expected")
+		.event
+}
+
+pub fn run_to_block(n: BlockNumber) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+	}
+}
@@ -0,0 +1,336 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+use mock::{RuntimeEvent, *};
+
+use super::*;
+
+fn set_alice_guardians() {
+	assert_ok!(AssetRecoveryModule::set_guardians(
+		RuntimeOrigin::signed(ALICE),
+		vec![BOB, CHARLIE, DAVE],
+		2,
+		DELAY_PERIOD
+	));
+}
+
+fn vouched_recovery_by_eve() {
+	set_alice_guardians();
+	assert_ok!(AssetRecoveryModule::initiate_recovery(
+		RuntimeOrigin::signed(EVE),
+		ALICE
+	));
+	assert_ok!(AssetRecoveryModule::vouch_recovery(
+		RuntimeOrigin::signed(BOB),
+		ALICE,
+		EVE
+	));
+	assert_ok!(AssetRecoveryModule::vouch_recovery(
+		RuntimeOrigin::signed(CHARLIE),
+		ALICE,
+		EVE
+	));
+}
+
+#[test]
+fn set_guardians_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_alice_guardians();
+
+		let recovery_config = AssetRecoveryModule::recovery_config(ALICE).unwrap();
+		assert_eq!(recovery_config.guardians.to_vec(), vec![BOB, CHARLIE, DAVE]);
+		assert_eq!(recovery_config.threshold, 2);
+		assert_eq!(Balances::reserved_balance(ALICE), RECOVERY_DEPOSIT);
+		let event = RuntimeEvent::AssetRecovery(crate::Event::GuardiansSet {
+			account: ALICE,
+			threshold: 2,
+			delay_period: DELAY_PERIOD,
+		});
+		assert_eq!(last_event(), event);
+
+		assert_ok!(AssetRecoveryModule::remove_guardians(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(AssetRecoveryModule::recovery_config(ALICE), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn set_guardians_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRecoveryModule::set_guardians(RuntimeOrigin::signed(ALICE), vec![], 1, DELAY_PERIOD),
+			Error::<Runtime>::InvalidGuardians
+		);
+		assert_noop!(
+			AssetRecoveryModule::set_guardians(
+				RuntimeOrigin::signed(ALICE),
+				vec![BOB, CHARLIE, DAVE, EVE],
+				2,
+				DELAY_PERIOD
+			),
+			Error::<Runtime>::InvalidGuardians
+		);
+		assert_noop!(
+			AssetRecoveryModule::set_guardians(RuntimeOrigin::signed(ALICE), vec![BOB, CHARLIE], 3, DELAY_PERIOD),
+			Error::<Runtime>::InvalidThreshold
+		);
+		assert_noop!(
+			AssetRecoveryModule::set_guardians(RuntimeOrigin::signed(ALICE), vec![BOB, CHARLIE], 0, DELAY_PERIOD),
+			Error::<Runtime>::InvalidThreshold
+		);
+		assert_noop!(
+			AssetRecoveryModule::set_guardians(RuntimeOrigin::signed(ALICE), vec![BOB, BOB], 1, DELAY_PERIOD),
+			Error::<Runtime>::InvalidGuardian
+		);
+		assert_noop!(
+			AssetRecoveryModule::set_guardians(RuntimeOrigin::signed(ALICE), vec![ALICE, BOB], 1, DELAY_PERIOD),
+			Error::<Runtime>::InvalidGuardian
+		);
+		assert_noop!(
+			AssetRecoveryModule::set_guardians(RuntimeOrigin::signed(ALICE), vec![BOB], 1, 1),
+			Error::<Runtime>::DelayPeriodTooShort
+		);
+		set_alice_guardians();
+		assert_noop!(
+			AssetRecoveryModule::set_guardians(RuntimeOrigin::signed(ALICE), vec![BOB], 1, DELAY_PERIOD),
+			Error::<Runtime>::AlreadyConfigured
+		);
+		// Guardians stay while a recovery is open
+		assert_ok!(AssetRecoveryModule::initiate_recovery(
+			RuntimeOrigin::signed(EVE),
+			ALICE
+		));
+		assert_noop!(
+			AssetRecoveryModule::remove_guardians(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::RecoveryInProgress
+		);
+	});
+}
+
+#[test]
+fn initiate_and_vouch_recovery_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRecoveryModule::initiate_recovery(RuntimeOrigin::signed(EVE), ALICE),
+			Error::<Runtime>::NotConfigured
+		);
+		set_alice_guardians();
+		assert_noop!(
+			AssetRecoveryModule::initiate_recovery(RuntimeOrigin::signed(ALICE), ALICE),
+			Error::<Runtime>::CannotRecoverSelf
+		);
+		assert_noop!(
+			AssetRecoveryModule::vouch_recovery(RuntimeOrigin::signed(BOB), ALICE, EVE),
+			Error::<Runtime>::RecoveryNotFound
+		);
+		assert_ok!(AssetRecoveryModule::initiate_recovery(
+			RuntimeOrigin::signed(EVE),
+			ALICE
+		));
+		assert_eq!(Balances::reserved_balance(EVE), RECOVERY_DEPOSIT);
+		assert_noop!(
+			AssetRecoveryModule::initiate_recovery(RuntimeOrigin::signed(EVE), ALICE),
+			Error::<Runtime>::AlreadyStarted
+		);
+		assert_noop!(
+			AssetRecoveryModule::vouch_recovery(RuntimeOrigin::signed(EVE), ALICE, EVE),
+			Error::<Runtime>::NotGuardian
+		);
+		assert_ok!(AssetRecoveryModule::vouch_recovery(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			EVE
+		));
+		assert_noop!(
+			AssetRecoveryModule::vouch_recovery(RuntimeOrigin::signed(BOB), ALICE, EVE),
+			Error::<Runtime>::AlreadyVouched
+		);
+	});
+}
+
+#[test]
+fn claim_recovery_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		vouched_recovery_by_eve();
+		// A competing request is settled by the claim
+		assert_ok!(AssetRecoveryModule::initiate_recovery(
+			RuntimeOrigin::signed(DAVE),
+			ALICE
+		));
+
+		run_to_block(1 + DELAY_PERIOD);
+		assert_ok!(AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(EVE), ALICE));
+
+		assert_eq!(MockAssetsMigration::assets(&ALICE), 0);
+		assert_eq!(MockAssetsMigration::assets(&EVE), 3);
+		assert_eq!(AssetRecoveryModule::recovery_config(ALICE), None);
+		assert_eq!(AssetRecoveryModule::active_recovery(ALICE, EVE), None);
+		assert_eq!(AssetRecoveryModule::active_recovery(ALICE, DAVE), None);
+		// Deposits are released and balances stay with their owners
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(EVE), 0);
+		assert_eq!(Balances::reserved_balance(DAVE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(Balances::free_balance(EVE), 100);
+		let event = RuntimeEvent::AssetRecovery(crate::Event::RecoveryClaimed {
+			lost: ALICE,
+			rescuer: EVE,
+			migrated_assets: 3,
+		});
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn claim_recovery_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_alice_guardians();
+		assert_ok!(AssetRecoveryModule::initiate_recovery(
+			RuntimeOrigin::signed(EVE),
+			ALICE
+		));
+		assert_ok!(AssetRecoveryModule::vouch_recovery(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			EVE
+		));
+		run_to_block(1 + DELAY_PERIOD);
+		assert_noop!(
+			AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(EVE), ALICE),
+			Error::<Runtime>::ThresholdNotReached
+		);
+		assert_noop!(
+			AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(DAVE), ALICE),
+			Error::<Runtime>::RecoveryNotFound
+		);
+	});
+
+	ExtBuilder::default().build().execute_with(|| {
+		vouched_recovery_by_eve();
+		run_to_block(DELAY_PERIOD);
+		assert_noop!(
+			AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(EVE), ALICE),
+			Error::<Runtime>::DelayPeriodNotPassed
+		);
+
+		// Nothing moves unless everything moves
+		run_to_block(1 + DELAY_PERIOD);
+		MockAssetsMigration::set_listed(&ALICE);
+		assert_noop!(
+			AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(EVE), ALICE),
+			DispatchError::Other("AssetAlreadyInAuction")
+		);
+		assert_eq!(MockAssetsMigration::assets(&ALICE), 3);
+	});
+}
+
+#[test]
+fn claim_recovery_should_move_assets_in_pages() {
+	ExtBuilder::default().build().execute_with(|| {
+		vouched_recovery_by_eve();
+		assert_ok!(AssetRecoveryModule::initiate_recovery(
+			RuntimeOrigin::signed(DAVE),
+			ALICE
+		));
+		assert_ok!(AssetRecoveryModule::vouch_recovery(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			DAVE
+		));
+		assert_ok!(AssetRecoveryModule::vouch_recovery(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			DAVE
+		));
+		MockAssetsMigration::set_assets(&ALICE, 20);
+		run_to_block(1 + DELAY_PERIOD);
+
+		assert_ok!(AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(EVE), ALICE));
+		assert_eq!(MockAssetsMigration::assets(&ALICE), 10);
+		assert_eq!(MockAssetsMigration::assets(&EVE), 10);
+		assert_eq!(AssetRecoveryModule::claiming_rescuer(ALICE), Some(EVE));
+		assert!(AssetRecoveryModule::active_recovery(ALICE, EVE).is_some());
+		let event = RuntimeEvent::AssetRecovery(crate::Event::RecoveryPartiallyClaimed {
+			lost: ALICE,
+			rescuer: EVE,
+			migrated_assets: 10,
+		});
+		assert_eq!(last_event(), event);
+
+		// The claim is left to the rescuer which started moving the assets
+		assert_noop!(
+			AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(DAVE), ALICE),
+			Error::<Runtime>::RecoveryBeingClaimed
+		);
+		assert_noop!(
+			AssetRecoveryModule::close_recovery(RuntimeOrigin::signed(ALICE), EVE),
+			Error::<Runtime>::RecoveryBeingClaimed
+		);
+
+		// A full page may leave nothing behind, the next claim settles the recovery
+		assert_ok!(AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(EVE), ALICE));
+		assert_eq!(MockAssetsMigration::assets(&ALICE), 0);
+		assert_eq!(AssetRecoveryModule::claiming_rescuer(ALICE), Some(EVE));
+		assert_ok!(AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(EVE), ALICE));
+		assert_eq!(MockAssetsMigration::assets(&EVE), 20);
+		assert_eq!(AssetRecoveryModule::claiming_rescuer(ALICE), None);
+		assert_eq!(AssetRecoveryModule::recovery_config(ALICE), None);
+		assert_eq!(AssetRecoveryModule::active_recovery(ALICE, EVE), None);
+		assert_eq!(AssetRecoveryModule::active_recovery(ALICE, DAVE), None);
+		assert_eq!(Balances::reserved_balance(EVE), 0);
+		assert_eq!(Balances::reserved_balance(DAVE), 0);
+		let event = RuntimeEvent::AssetRecovery(crate::Event::RecoveryClaimed {
+			lost: ALICE,
+			rescuer: EVE,
+			migrated_assets: 0,
+		});
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn close_recovery_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		vouched_recovery_by_eve();
+
+		// Alice still holds her key and stops the recovery
+		assert_ok!(AssetRecoveryModule::close_recovery(RuntimeOrigin::signed(ALICE), EVE));
+		assert_eq!(AssetRecoveryModule::active_recovery(ALICE, EVE), None);
+		assert_eq!(Balances::total_balance(&ALICE), 100 + RECOVERY_DEPOSIT);
+		assert_eq!(Balances::total_balance(&EVE), 100 - RECOVERY_DEPOSIT);
+		let event = RuntimeEvent::AssetRecovery(crate::Event::RecoveryClosed {
+			lost: ALICE,
+			rescuer: EVE,
+		});
+		assert_eq!(last_event(), event);
+
+		run_to_block(1 + DELAY_PERIOD);
+		assert_noop!(
+			AssetRecoveryModule::claim_recovery(RuntimeOrigin::signed(EVE), ALICE),
+			Error::<Runtime>::RecoveryNotFound
+		);
+		assert_noop!(
+			AssetRecoveryModule::close_recovery(RuntimeOrigin::signed(ALICE), EVE),
+			Error::<Runtime>::RecoveryNotFound
+		);
+	});
+}
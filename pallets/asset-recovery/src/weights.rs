@@ -0,0 +1,130 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for asset recovery, estimated from storage access until benchmarks are added
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for asset recovery.
+pub trait WeightInfo {
+	fn set_guardians() -> Weight;
+	fn remove_guardians() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn vouch_recovery() -> Weight;
+	fn claim_recovery(n: u32) -> Weight;
+	fn close_recovery() -> Weight;
+}
+
+/// Weights for asset recovery using the for collator node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: AssetRecovery RecoveryConfigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_guardians() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetRecovery ActiveRecoveries (r:1 w:0)
+	// Storage: AssetRecovery RecoveryConfigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_guardians() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetRecovery RecoveryConfigs (r:1 w:0)
+	// Storage: AssetRecovery ActiveRecoveries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetRecovery RecoveryConfigs (r:1 w:0)
+	// Storage: AssetRecovery ActiveRecoveries (r:1 w:1)
+	fn vouch_recovery() -> Weight {
+		Weight::from_parts(25_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: AssetRecovery RecoveryConfigs (r:1 w:1)
+	// Storage: AssetRecovery ActiveRecoveries (r:2 w:1)
+	// Storage: AssetRecovery ClaimingRescuers (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: OrmlNFT TokensByOwner (r:n w:2n)
+	// Storage: Nft StackableNftHoldings (r:n w:2n)
+	// Storage: OrmlNFT Tokens (r:n w:n)
+	// Storage: Auction ItemsInAuction (r:n w:0)
+	fn claim_recovery(n: u32) -> Weight {
+		Weight::from_parts(60_000_000, 8000)
+			.saturating_add(Weight::from_parts(25_000_000, 3000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	// Storage: AssetRecovery ActiveRecoveries (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn close_recovery() -> Weight {
+		Weight::from_parts(30_000_000, 6000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_guardians() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn remove_guardians() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn vouch_recovery() -> Weight {
+		Weight::from_parts(25_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn claim_recovery(n: u32) -> Weight {
+		Weight::from_parts(60_000_000, 8000)
+			.saturating_add(Weight::from_parts(25_000_000, 3000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	fn close_recovery() -> Weight {
+		Weight::from_parts(30_000_000, 6000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+}
@@ -25,24 +25,28 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::traits::{Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency};
-use frame_support::{ensure, pallet_prelude::*, transactional};
+use frame_support::traits::{
+	BalanceStatus, Currency, EnsureOriginWithArg, ExistenceRequirement, LockableCurrency, ReservableCurrency,
+};
+use frame_support::{ensure, pallet_prelude::*, storage::with_storage_layer, transactional};
 use frame_system::{self as system, ensure_signed, pallet_prelude::BlockNumberFor};
 use sp_core::sp_std::convert::TryInto;
+use sp_io::hashing::blake2_256;
 use sp_runtime::SaturatedConversion;
 use sp_runtime::{
 	traits::{One, Saturating, Zero},
 	DispatchError, DispatchResult, Perbill,
 };
-use sp_std::vec::Vec;
+use sp_std::{boxed::Box, vec::Vec};
 
 use auction_manager::{
-	Auction, AuctionHandler, AuctionInfo, AuctionItem, AuctionItemV2, AuctionType, Change, OnNewBidResult,
+	Auction, AuctionHandler, AuctionInfo, AuctionItem, AuctionItemV2, AuctionType, Change, DropEligibility, DropInfo,
+	DropPhase, OnNewBidResult, SealedBid, SealedBidAuctionInfo, SealedBidSettlement, SignedOrder, SwapProposal,
 };
-use core_primitives::UndeployedLandBlocksTrait;
+use core_primitives::{CredentialTrait, PriceProvider, UndeployedLandBlocksTrait};
 pub use pallet::*;
 
-use primitives::{continuum::MapTrait, estate::Estate, AuctionId, ItemId, NftOffer};
+use primitives::{continuum::MapTrait, estate::Estate, AuctionId, DropId, ItemId, NftOffer, SwapId};
 pub use weights::WeightInfo;
 
 //#[cfg(feature = "runtime-benchmarks")]
@@ -55,6 +59,9 @@ mod tests;
 
 pub mod weights;
 
+/// Tag prefixed to the payload of signed orders
+pub const SIGNED_ORDER_TAG: &[u8] = b"bitcountry/auction/signed-order";
+
 pub struct AuctionLogicHandler;
 
 pub mod migration_v2 {
@@ -106,7 +113,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::OriginFor;
 	use orml_traits::{MultiCurrency, MultiReservableCurrency};
 	use primitives::{Balance, ClassId, FungibleTokenId, MetaverseId, TokenId};
-	use sp_runtime::traits::CheckedAdd;
+	use sp_runtime::traits::{CheckedAdd, CheckedMul, IdentifyAccount, Verify};
 	use sp_runtime::ArithmeticError;
 
 	use super::*;
@@ -118,6 +125,19 @@ pub mod pallet {
 
 	pub(super) type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	/// A type alias for the off-chain signed order of an item
+	pub(super) type SignedOrderOf<T> = SignedOrder<BalanceOf<T>, BlockNumberFor<T>>;
+	/// A type alias for the peer-to-peer swap proposal of items and fungible amounts
+	pub(super) type SwapProposalOf<T> = SwapProposal<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+		<T as Config>::MaxSwapItems,
+	>;
+	/// A type alias for the items of one side of a swap
+	pub(super) type SwapItemsOf<T> = BoundedVec<ItemId<BalanceOf<T>>, <T as Config>::MaxSwapItems>;
+	/// A type alias for the fungible amounts of one side of a swap
+	pub(super) type SwapTokensOf<T> = BoundedVec<(FungibleTokenId, BalanceOf<T>), <T as Config>::MaxSwapItems>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -147,6 +167,9 @@ pub mod pallet {
 		/// Metaverse info trait for getting information from metaverse
 		type MetaverseInfoSource: MetaverseTrait<Self::AccountId>;
 
+		/// Origin of a metaverse acting on its own behalf, e.g. a locally enacted proposal
+		type MetaverseGovernanceOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, MetaverseId>;
+
 		/// Minimum auction duration when new listing created.
 		#[pallet::constant]
 		type MinimumAuctionDuration: Get<BlockNumberFor<Self>>;
@@ -181,10 +204,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type OfferDuration: Get<BlockNumberFor<Self>>;
 
-		/// Minimum listing price
+		/// Minimum listing price, expressed in the reference currency of the price provider
 		#[pallet::constant]
 		type MinimumListingPrice: Get<BalanceOf<Self>>;
 
+		/// Price provider converting the minimum listing price into the listing currency
+		type PriceProvider: PriceProvider;
+
 		/// Anti-snipe duration
 		#[pallet::constant]
 		type AntiSnipeDuration: Get<BlockNumberFor<Self>>;
@@ -193,6 +219,62 @@ pub mod pallet {
 		/// The fee will be unreserved after the storage is freed.
 		#[pallet::constant]
 		type StorageDepositFee: Get<BalanceOf<Self>>;
+
+		/// Number of blocks sealed bids can be revealed after the bidding phase
+		#[pallet::constant]
+		type SealedBidRevealPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Max number of sealed bids can be committed to a single auction
+		#[pallet::constant]
+		type MaxSealedBidsPerAuction: Get<u32>;
+
+		/// Max number of phases in a drop
+		#[pallet::constant]
+		type MaxDropPhases: Get<u32>;
+
+		/// Max number of accounts allowed in a drop phase
+		#[pallet::constant]
+		type MaxDropAllowlistSize: Get<u32>;
+
+		/// Max number of purchase and holder token records of closed drops removed per block
+		#[pallet::constant]
+		type MaxDropRecordRemovals: Get<u32>;
+
+		/// Off-Chain signature type.
+		///
+		/// Can verify whether an `Self::OffchainPublic` created a signature.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Off-Chain public key.
+		///
+		/// Must identify as an on-chain `Self::AccountId`.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Metaverse credentials used to gate drop phases
+		type Credentials: CredentialTrait<Self::AccountId>;
+
+		/// Max number of blocks a swap proposal can stay open
+		#[pallet::constant]
+		type MaxSwapDuration: Get<BlockNumberFor<Self>>;
+
+		/// Max number of items or fungible amounts on each side of a swap
+		#[pallet::constant]
+		type MaxSwapItems: Get<u32>;
+
+		/// Max number of royalty fees collected when a swap is accepted, one per item given and
+		/// fungible amount received for it
+		#[pallet::constant]
+		type MaxSwapRoyaltyFees: Get<u32>;
+
+		/// Deposit reserved from the proposer while a swap is open.
+		/// The deposit will be unreserved when the swap is accepted, cancelled or expired.
+		#[pallet::constant]
+		type SwapDeposit: Get<BalanceOf<Self>>;
+
+		/// Number of nonces from the current signed order nonce of an account that its signed
+		/// orders can use
+		#[pallet::constant]
+		type SignedOrderNonceWindow: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -242,6 +324,104 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn sealed_bid_auctions)]
+	/// Store the reveal phase and settlement of sealed-bid auctions
+	pub(super) type SealedBidAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, SealedBidAuctionInfo<BalanceOf<T>, BlockNumberFor<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sealed_bids)]
+	/// Index committed sealed bids by auction and bidder
+	pub(super) type SealedBids<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionId,
+		Blake2_128Concat,
+		T::AccountId,
+		SealedBid<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sealed_bid_count)]
+	/// Number of sealed bids committed to an auction, at most `MaxSealedBidsPerAuction`
+	pub(super) type SealedBidCount<T: Config> = StorageMap<_, Twox64Concat, AuctionId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_finalization)]
+	/// Ended auctions waiting to be finalized, with the block they ended at
+	pub(super) type PendingFinalization<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Ended auctions in the order they were queued for finalization, by queue index
+	pub(super) type PendingFinalizationQueue<T: Config> = StorageMap<_, Twox64Concat, u64, AuctionId, OptionQuery>;
+
+	#[pallet::storage]
+	/// Queue index of the oldest auction waiting to be finalized
+	pub(super) type PendingFinalizationHead<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// Queue index the next ended auction is queued at
+	pub(super) type PendingFinalizationTail<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_drop_id)]
+	/// Track the next drop ID.
+	pub(super) type NextDropId<T: Config> = StorageValue<_, DropId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn drops)]
+	/// Store the scheduled drops of stackable NFTs
+	pub(super) type Drops<T: Config> =
+		StorageMap<_, Twox64Concat, DropId, DropInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn drop_purchases)]
+	/// Index the units purchased by drop, phase and account
+	pub(super) type DropPurchases<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DropId, Blake2_128Concat, (u32, T::AccountId), BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn drop_holder_token)]
+	/// Index the account which used a holder token in a drop phase, by drop, phase and token
+	pub(super) type DropHolderTokens<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DropId, Twox64Concat, (u32, TokenId), T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn closed_drop)]
+	/// Closed drops whose purchase records are still being removed
+	pub(super) type ClosedDrops<T: Config> = StorageMap<_, Twox64Concat, DropId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn signed_order_nonce)]
+	/// Lowest nonce signed orders of an account can use, orders with a lower nonce or a nonce
+	/// outside of `SignedOrderNonceWindow` are invalid
+	pub(super) type SignedOrderNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn invalidated_signed_orders)]
+	/// Index nonces of signed orders fulfilled or cancelled above the current nonce of the signer
+	pub(super) type InvalidatedSignedOrders<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u64, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_swap_id)]
+	/// Track the next swap ID.
+	pub(super) type NextSwapId<T: Config> = StorageValue<_, SwapId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn swaps)]
+	/// Store the open peer-to-peer swap proposals
+	pub(super) type Swaps<T: Config> = StorageMap<_, Twox64Concat, SwapId, SwapProposalOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn swap_end_time)]
+	/// Index swap proposals by expiry block.
+	pub(super) type SwapEndTime<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, SwapId, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -279,6 +459,35 @@ pub mod pallet {
 		NftOfferWithdrawn(ClassId, TokenId, T::AccountId),
 		/// Auction extended. [auction_id, end_block]
 		AuctionExtended(AuctionId, BlockNumberFor<T>),
+		/// A sealed bid is committed. [auction_id, bidder, deposit]
+		SealedBidCommitted(AuctionId, T::AccountId, BalanceOf<T>),
+		/// A sealed bid is revealed. [auction_id, bidder, bidding_amount]
+		SealedBidRevealed(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Deposit of an unrevealed sealed bid is slashed. [auction_id, bidder, deposit]
+		SealedBidDepositSlashed(AuctionId, T::AccountId, BalanceOf<T>),
+		/// New drop created. [drop_id, creator, class_id, token_id, amount]
+		DropCreated(DropId, T::AccountId, ClassId, TokenId, BalanceOf<T>),
+		/// Units purchased from a drop. [drop_id, buyer, amount, total_price]
+		DropPurchased(DropId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Drop closed and remaining units returned to the creator. [drop_id, remaining_amount]
+		DropClosed(DropId, BalanceOf<T>),
+		/// Signed order fulfilled. [signer, buyer, order_hash, price]
+		SignedOrderFulfilled(T::AccountId, T::AccountId, [u8; 32], BalanceOf<T>),
+		/// Signed order cancelled. [signer, nonce]
+		SignedOrderCancelled(T::AccountId, u64),
+		/// Signed order nonce increased, invalidating all previous orders. [signer, new_nonce]
+		SignedOrderNonceIncreased(T::AccountId, u64),
+		/// New swap proposed. [swap_id, proposer, counterparty]
+		SwapProposed(SwapId, T::AccountId, Option<T::AccountId>),
+		/// Swap accepted and settled. [swap_id, proposer, acceptor]
+		SwapAccepted(SwapId, T::AccountId, T::AccountId),
+		/// Swap cancelled by the proposer. [swap_id]
+		SwapCancelled(SwapId),
+		/// Swap expired and its offered items unlocked. [swap_id]
+		SwapExpired(SwapId),
+		/// Swap expired but its offered items could not be unlocked, the proposer can still cancel
+		/// it. [swap_id, error]
+		SwapExpiryFailed(SwapId, DispatchError),
 	}
 
 	/// Errors inform users that something went wrong.
@@ -356,6 +565,68 @@ pub mod pallet {
 		ListingPriceIsBelowMinimum,
 		/// Only metaverse owner can participate
 		MetaverseOwnerOnly,
+		/// Sealed bids can no longer be committed
+		SealedBidCommitPhaseIsOver,
+		/// Sealed bids can not be revealed yet
+		SealedBidRevealPhaseHasNotStarted,
+		/// The account has already committed a sealed bid
+		SealedBidAlreadyCommitted,
+		/// The sealed bid does not exist
+		SealedBidDoesNotExist,
+		/// The sealed bid has already been revealed
+		SealedBidAlreadyRevealed,
+		/// Revealed bid does not match the commitment
+		SealedBidRevealDoesNotMatch,
+		/// There is too many sealed bids committed to the auction
+		ExceedSealedBidLimit,
+		/// Auction has ended and is waiting to be finalized
+		AuctionIsPendingFinalization,
+		/// Listing start time is in the past
+		InvalidListingStartTime,
+		/// Drop phases are invalid
+		InvalidDropPhases,
+		/// Drop does not exist
+		DropDoesNotExist,
+		/// Drop has not started
+		DropHasNotStarted,
+		/// Drop has ended
+		DropHasEnded,
+		/// Drop has not ended
+		DropHasNotEnded,
+		/// Account is not eligible for the current drop phase
+		NotEligibleForDropPhase,
+		/// Holder token was already used by another account in the current drop phase
+		DropHolderTokenAlreadyUsed,
+		/// Purchase exceeds the account limit of the drop phase
+		DropPurchaseLimitExceeded,
+		/// Drop does not have enough units left
+		InsufficientDropInventory,
+		/// The provided signature is incorrect
+		WrongSignature,
+		/// Signature expired
+		SignatureExpired,
+		/// Signed order has been fulfilled, cancelled or its nonce is outdated
+		SignedOrderIsInvalid,
+		/// Signed orders only support NFTs and stackable NFTs
+		SignedOrderItemNotSupported,
+		/// Signer does not own the item of the signed order
+		SignerIsNotItemOwner,
+		/// Swap proposal is invalid
+		InvalidSwapProposal,
+		/// Swap does not exist
+		SwapDoesNotExist,
+		/// Swap is expired
+		SwapIsExpired,
+		/// No permission to accept the swap
+		NoPermissionToAcceptSwap,
+		/// No permission to cancel the swap
+		NoPermissionToCancelSwap,
+		/// Swap expiry is beyond the max swap duration
+		SwapExpiryExceedsMaxDuration,
+		/// Exceed maximum number of royalty fees collected by a swap
+		ExceedMaximumSwapRoyaltyFees,
+		/// Social tokens can only be used on the local marketplace of their metaverse
+		InvalidListingCurrency,
 	}
 
 	#[pallet::call]
@@ -430,6 +701,8 @@ pub mod pallet {
 		/// - `value`: value of the listing
 		/// - `listing_level`: if listing is on local or global marketplace
 		/// - `end_time`: the listing end time.
+		/// - `currency_id`: the currency of the listing, local listings can use the social token of
+		///   their metaverse
 		///
 		/// Emits `NewAuctionItem` if successful.
 		#[pallet::weight(T::WeightInfo::create_new_auction())]
@@ -444,13 +717,8 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 			// Only support NFT on marketplace
-			ensure!(
-				(matches!(item_id, ItemId::NFT(_, _)) && matches!(listing_level, ListingLevel::Local(_)))
-					|| (matches!(item_id, ItemId::Bundle(_)) && matches!(listing_level, ListingLevel::Local(_)))
-					|| (matches!(item_id, ItemId::UndeployedLandBlock(_))
-						&& matches!(listing_level, ListingLevel::Global)),
-				Error::<T>::NoPermissionToCreateAuction
-			);
+			Self::ensure_valid_listing_level(&item_id, &listing_level)?;
+			Self::ensure_valid_listing_currency(&listing_level, currency_id)?;
 
 			let start_time: BlockNumberFor<T> = <system::Pallet<T>>::block_number();
 
@@ -490,6 +758,8 @@ pub mod pallet {
 		/// - `value`: value of the listing
 		/// - `listing_level`: if listing is on local or global marketplace
 		/// - `end_time`: the listing end time.
+		/// - `currency_id`: the currency of the listing, local listings can use the social token of
+		///   their metaverse
 		///
 		/// Emits `NewAuctionItem` if successful.
 		#[pallet::weight(T::WeightInfo::create_new_buy_now())]
@@ -503,13 +773,8 @@ pub mod pallet {
 			currency_id: FungibleTokenId,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
-			ensure!(
-				(matches!(item_id, ItemId::NFT(_, _)) && matches!(listing_level, ListingLevel::Local(_)))
-					|| (matches!(item_id, ItemId::Bundle(_)) && matches!(listing_level, ListingLevel::Local(_)))
-					|| (matches!(item_id, ItemId::UndeployedLandBlock(_))
-						&& matches!(listing_level, ListingLevel::Global)),
-				Error::<T>::NoPermissionToCreateAuction
-			);
+			Self::ensure_valid_listing_level(&item_id, &listing_level)?;
+			Self::ensure_valid_listing_currency(&listing_level, currency_id)?;
 
 			let start_time: BlockNumberFor<T> = <system::Pallet<T>>::block_number();
 			let remaining_time: BlockNumberFor<T> =
@@ -550,8 +815,8 @@ pub mod pallet {
 
 		/// Metaverse owner can authorize collection that sell in their local marketplace
 		///
-		/// The dispatch origin for this call must be _Signed_. Only owner of metaverse can make
-		/// this call
+		/// The dispatch origin for this call must be _Signed_ or the metaverse origin. Only owner
+		/// of metaverse or the metaverse itself can make this call
 		/// - `class_id`: the nft collection that want to authorize
 		/// - `metaverse_id`: the metaverse id that user want to authorize
 		///
@@ -563,11 +828,13 @@ pub mod pallet {
 			class_id: ClassId,
 			metaverse_id: MetaverseId,
 		) -> DispatchResultWithPostInfo {
-			let from = ensure_signed(origin)?;
-			ensure!(
-				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
-				Error::<T>::NoPermissionToAuthoriseCollection
-			);
+			if let Err(origin) = T::MetaverseGovernanceOrigin::try_origin(origin, &metaverse_id) {
+				let from = ensure_signed(origin)?;
+				ensure!(
+					T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
+					Error::<T>::NoPermissionToAuthoriseCollection
+				);
+			}
 
 			ensure!(
 				!MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
@@ -583,8 +850,8 @@ pub mod pallet {
 
 		/// Metaverse owner can remove authorized collection that sell in their local marketplace
 		///
-		/// The dispatch origin for this call must be _Signed_. Only owner of metaverse can make
-		/// this call
+		/// The dispatch origin for this call must be _Signed_ or the metaverse origin. Only owner
+		/// of metaverse or the metaverse itself can make this call
 		/// - `class_id`: the nft collection that want to authorize
 		/// - `metaverse_id`: the metaverse id that user want to authorize
 		///
@@ -596,11 +863,13 @@ pub mod pallet {
 			class_id: ClassId,
 			metaverse_id: MetaverseId,
 		) -> DispatchResultWithPostInfo {
-			let from = ensure_signed(origin)?;
-			ensure!(
-				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
-				Error::<T>::NoPermissionToAuthoriseCollection
-			);
+			if let Err(origin) = T::MetaverseGovernanceOrigin::try_origin(origin, &metaverse_id) {
+				let from = ensure_signed(origin)?;
+				ensure!(
+					T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
+					Error::<T>::NoPermissionToAuthoriseCollection
+				);
+			}
 
 			ensure!(
 				MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
@@ -628,7 +897,16 @@ pub mod pallet {
 			let from = ensure_signed(origin)?;
 
 			ensure!(Auctions::<T>::contains_key(auction_id), Error::<T>::AuctionDoesNotExist);
+			ensure!(
+				!PendingFinalization::<T>::contains_key(auction_id),
+				Error::<T>::AuctionIsPendingFinalization
+			);
 			let auction_item = AuctionItems::<T>::get(auction_id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+			// Sealed bids can not be returned once committed
+			ensure!(
+				SealedBids::<T>::iter_prefix(auction_id).next().is_none(),
+				Error::<T>::AuctionAlreadyStartedOrBid
+			);
 
 			match auction_item.clone().item_id {
 				ItemId::NFT(class_id, token_id) => {
@@ -669,6 +947,20 @@ pub mod pallet {
 
 					Ok(().into())
 				}
+				ItemId::MixedBundle(items) => {
+					ensure!(auction_item.recipient == from, Error::<T>::AuctionAlreadyStartedOrBid);
+
+					// Return all items of the bundle
+					Self::unlock_bundle_items(&from, &items)?;
+
+					Self::remove_auction(auction_id, auction_item.item_id);
+					T::Currency::unreserve(&auction_item.recipient, T::NetworkFeeReserve::get());
+
+					Self::deposit_event(Event::<T>::AuctionCancelled(auction_id));
+					Self::deposit_event(Event::<T>::AuctionFinalizedNoBid(auction_id));
+
+					Ok(().into())
+				}
 				_ => Err(Error::<T>::NoPermissionToCancelAuction.into()),
 			}
 		}
@@ -715,7 +1007,7 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		/// Accept offer for an NFT asset
+		/// Accept offer for an NFT asset, the royalty fee of the NFT class is paid from the offer
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		/// Only NFT owner can make this call.
@@ -748,6 +1040,7 @@ pub mod pallet {
 
 			T::Currency::unreserve(&offeror, offer.amount);
 			<T as Config>::Currency::transfer(&offeror, &owner, offer.amount, ExistenceRequirement::KeepAlive)?;
+			Self::collect_royalty_fee(&offer.amount, &owner, &asset, FungibleTokenId::NativeToken(0))?;
 			T::NFTHandler::transfer_nft(&owner, &offeror, &asset)?;
 			Offers::<T>::remove(asset, offeror.clone());
 
@@ -775,125 +1068,691 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		/// Manually finalize ended auction.
+		/// Manually finalize ended auction, including auctions still waiting in the finalization
+		/// queue.
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		/// - `auction_id`: the ID of the auction that will be finalized.
 		///
 		/// Emits `AuctionFinalized` or `AuctionFinalizedNoBid` if successful.
-		#[pallet::weight(T::WeightInfo::on_finalize())]
+		#[pallet::weight(Self::max_auction_finalization_weight())]
+		#[transactional]
 		pub fn finalize_auction(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
 
 			let auction = <Auctions<T>>::get(&auction_id).ok_or(Error::<T>::AuctionDoesNotExist)?;
-			ensure!(
-				auction.end.ok_or(Error::<T>::AuctionIsNotExpired)? < <system::Pallet<T>>::block_number(),
-				Error::<T>::AuctionIsNotExpired
-			);
+			if <PendingFinalization<T>>::take(&auction_id).is_none() {
+				ensure!(
+					auction.end.ok_or(Error::<T>::AuctionIsNotExpired)? < <system::Pallet<T>>::block_number(),
+					Error::<T>::AuctionIsNotExpired
+				);
+			}
 
+			let weight = Self::auction_finalization_weight(auction_id);
 			T::Handler::on_auction_ended(auction_id, auction.bid);
 
-			Ok(().into())
+			Ok(Some(weight).into())
 		}
-	}
-
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		/// Hooks that call every new block is initialized.
-		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
-			let mut total_item = 0;
-			for (auction_id, _) in <AuctionEndTime<T>>::drain_prefix(&now) {
-				total_item += 1;
-				if let Some(auction) = <Auctions<T>>::get(&auction_id) {
-					T::Handler::on_auction_ended(auction_id, auction.bid);
-				};
-			}
 
-			T::WeightInfo::on_finalize().saturating_mul(total_item)
-		}
+		/// User create new sealed-bid auction listing if they are metaverse owner of their local
+		/// marketplace or NFT collection has authorized to list
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `item_id`: the enum of what item type want to list
+		/// - `value`: reserve price of the listing
+		/// - `end_time`: the block bids stop being committed. Bids are revealed from this block
+		///   until the reveal period is over.
+		/// - `listing_level`: if listing is on local or global marketplace
+		/// - `settlement`: if the winner pays its own bid or the second highest bid
+		///
+		/// Emits `NewAuctionItem` if successful.
+		#[pallet::weight(T::WeightInfo::create_new_sealed_bid_auction())]
+		#[transactional]
+		pub fn create_new_sealed_bid_auction(
+			origin: OriginFor<T>,
+			item_id: ItemId<BalanceOf<T>>,
+			value: BalanceOf<T>,
+			end_time: BlockNumberFor<T>,
+			listing_level: ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
+			settlement: SealedBidSettlement,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::ensure_valid_listing_level(&item_id, &listing_level)?;
+			Self::ensure_valid_listing_currency(&listing_level, currency_id)?;
 
-		// fn on_runtime_upgrade() -> Weight {
-		// 	Self::upgrade_auction_item_data_v3();
-		// 	Weight::from_(0u64)
-		// }
-	}
+			let start_time: BlockNumberFor<T> = <system::Pallet<T>>::block_number();
+			let remaining_time: BlockNumberFor<T> =
+				end_time.checked_sub(&start_time).ok_or(ArithmeticError::Overflow)?;
 
-	impl<T: Config> Auction<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
-		type Balance = BalanceOf<T>;
+			// Ensure bidding phase duration is valid
+			ensure!(
+				remaining_time >= T::MinimumAuctionDuration::get(),
+				Error::<T>::AuctionEndIsLessThanMinimumDuration
+			);
 
-		/// Internal update auction extension
-		fn update_auction(
-			id: AuctionId,
-			info: AuctionInfo<T::AccountId, Self::Balance, BlockNumberFor<T>>,
-		) -> DispatchResult {
-			let auction = <Auctions<T>>::get(id).ok_or(Error::<T>::AuctionDoesNotExist)?;
-			if let Some(old_end) = auction.end {
-				<AuctionEndTime<T>>::remove(&old_end, id);
-			}
-			if let Some(new_end) = info.end {
-				<AuctionEndTime<T>>::insert(&new_end, id, ());
+			let mut listing_fee: Perbill = Perbill::from_percent(0u32);
+			if let ListingLevel::Local(metaverse_id) = listing_level {
+				listing_fee = T::MetaverseInfoSource::get_metaverse_marketplace_listing_fee(metaverse_id)?;
 			}
-			<Auctions<T>>::insert(id, info);
-			Ok(())
+
+			Self::create_auction(
+				AuctionType::SealedBid(settlement),
+				item_id,
+				Some(end_time),
+				from.clone(),
+				value.clone(),
+				start_time,
+				listing_level.clone(),
+				listing_fee,
+				currency_id,
+			)?;
+			Ok(().into())
 		}
 
-		/// Internal create new auction item struct extension. This function will be executed inside
-		/// create_auction
-		fn new_auction(
-			_recipient: T::AccountId,
-			_initial_amount: Self::Balance,
-			start: BlockNumberFor<T>,
-			end: Option<BlockNumberFor<T>>,
-		) -> Result<AuctionId, DispatchError> {
-			let auction: AuctionInfo<T::AccountId, Self::Balance, BlockNumberFor<T>> =
-				AuctionInfo { bid: None, start, end };
+		/// User commit a sealed bid for any available sealed-bid auction.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `id`: auction id that user wants to bid
+		/// - `commitment`: blake2 256 hash of the SCALE encoded auction id, bidder, bid price and
+		///   salt
+		/// - `deposit`: the amount reserved to cover the bid price. Deposit of bids which are not
+		///   revealed in time is slashed to the treasury.
+		///
+		/// Emits `SealedBidCommitted` if successful.
+		#[pallet::weight(T::WeightInfo::commit_bid())]
+		#[transactional]
+		pub fn commit_bid(
+			origin: OriginFor<T>,
+			id: AuctionId,
+			commitment: [u8; 32],
+			deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
 
-			let auction_id: AuctionId = AuctionsIndex::<T>::try_mutate(|n| -> Result<AuctionId, DispatchError> {
-				let id = *n;
-				ensure!(id != AuctionId::max_value(), Error::<T>::NoAvailableAuctionId);
-				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
-				Ok(id)
-			})?;
+			let auction_item: AuctionItem<T::AccountId, BlockNumberFor<T>, BalanceOf<T>> =
+				Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionDoesNotExist)?;
 
-			<Auctions<T>>::insert(auction_id, auction);
+			ensure!(
+				!auction_item.item_id.is_map_spot(),
+				Error::<T>::AuctionTypeIsNotSupported
+			);
 
-			if let Some(end_block) = end {
-				<AuctionEndTime<T>>::insert(&end_block, auction_id, ());
-			}
+			T::Currency::transfer(
+				&from,
+				&T::MetaverseInfoSource::get_network_treasury(),
+				T::StorageDepositFee::get(),
+				ExistenceRequirement::KeepAlive,
+			)?;
 
-			Ok(auction_id)
+			Self::sealed_bid_commit_handler(from, id, commitment, deposit)?;
+
+			Ok(().into())
 		}
 
-		/// Internal create auction extension
-		fn create_auction(
-			auction_type: AuctionType,
-			item_id: ItemId<Self::Balance>,
-			_end: Option<BlockNumberFor<T>>,
-			recipient: T::AccountId,
-			initial_amount: Self::Balance,
-			_start: BlockNumberFor<T>,
-			listing_level: ListingLevel<T::AccountId>,
-			listing_fee: Perbill,
-			currency_id: FungibleTokenId,
-		) -> Result<AuctionId, DispatchError> {
+		/// User reveal a committed sealed bid.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `id`: auction id that user has committed a bid to
+		/// - `value`: the bid price, must not exceed the committed deposit
+		/// - `salt`: the salt used when computing the commitment
+		///
+		/// Emits `SealedBidRevealed` if successful.
+		#[pallet::weight(T::WeightInfo::reveal_bid())]
+		#[transactional]
+		pub fn reveal_bid(
+			origin: OriginFor<T>,
+			id: AuctionId,
+			value: BalanceOf<T>,
+			salt: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let auction_item: AuctionItem<T::AccountId, BlockNumberFor<T>, BalanceOf<T>> =
+				Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionDoesNotExist)?;
+
 			ensure!(
-				initial_amount.clone() >= T::MinimumListingPrice::get(),
-				Error::<T>::ListingPriceIsBelowMinimum
+				!auction_item.item_id.is_map_spot(),
+				Error::<T>::AuctionTypeIsNotSupported
 			);
 
-			//ensure!(
-			//	Self::items_in_auction(item_id.clone()) == None,
-			//	Error::<T>::ItemAlreadyInAuction
-			//);
+			Self::sealed_bid_reveal_handler(from, id, value, salt)?;
 
-			let start_time = <system::Pallet<T>>::block_number();
+			Ok(().into())
+		}
 
-			let mut end_time = start_time + T::AuctionTimeToClose::get();
-			if let Some(_end_block) = _end {
+		/// User create new auction or buy-now's listing which opens at a future block
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `auction_type`: the type of listing, auction or buy now
+		/// - `item_id`: the enum of what item type want to list
+		/// - `value`: value of the listing
+		/// - `start_time`: the block the listing opens at
+		/// - `end_time`: the listing end time.
+		/// - `listing_level`: if listing is on local or global marketplace
+		///
+		/// Emits `NewAuctionItem` if successful.
+		#[pallet::weight(T::WeightInfo::create_new_buy_now())]
+		#[transactional]
+		pub fn create_scheduled_listing(
+			origin: OriginFor<T>,
+			auction_type: AuctionType,
+			item_id: ItemId<BalanceOf<T>>,
+			value: BalanceOf<T>,
+			start_time: BlockNumberFor<T>,
+			end_time: BlockNumberFor<T>,
+			listing_level: ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				matches!(auction_type, AuctionType::Auction | AuctionType::BuyNow),
+				Error::<T>::AuctionTypeIsNotSupported
+			);
+			Self::ensure_valid_listing_level(&item_id, &listing_level)?;
+			Self::ensure_valid_listing_currency(&listing_level, currency_id)?;
+
+			ensure!(
+				start_time >= <system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidListingStartTime
+			);
+			let remaining_time: BlockNumberFor<T> =
+				end_time.checked_sub(&start_time).ok_or(ArithmeticError::Overflow)?;
+
+			// Ensure auction duration is valid
+			ensure!(
+				remaining_time >= T::MinimumAuctionDuration::get(),
+				Error::<T>::AuctionEndIsLessThanMinimumDuration
+			);
+
+			let mut listing_fee: Perbill = Perbill::from_percent(0u32);
+			if let ListingLevel::Local(metaverse_id) = listing_level {
+				listing_fee = T::MetaverseInfoSource::get_metaverse_marketplace_listing_fee(metaverse_id)?;
+			}
+
+			if auction_type == AuctionType::BuyNow {
+				T::Currency::transfer(
+					&from,
+					&T::MetaverseInfoSource::get_network_treasury(),
+					T::StorageDepositFee::get(),
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+
+			Self::create_auction(
+				auction_type,
+				item_id,
+				Some(end_time),
+				from.clone(),
+				value.clone(),
+				start_time,
+				listing_level.clone(),
+				listing_fee,
+				currency_id,
+			)?;
+			Ok(().into())
+		}
+
+		/// User create a drop selling units of a stackable NFT in phases
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `asset`: the stackable NFT sold in the drop
+		/// - `amount`: the number of units sold in the drop
+		/// - `phases`: the phases of the drop, ordered by start block. Each phase runs until the
+		///   next phase starts and has its own price, account limit and eligibility
+		/// - `end_time`: the drop end time.
+		/// - `listing_level`: the local marketplace of the drop
+		///
+		/// Emits `DropCreated` if successful.
+		#[pallet::weight(T::WeightInfo::create_new_buy_now())]
+		#[transactional]
+		pub fn create_drop(
+			origin: OriginFor<T>,
+			asset: (ClassId, TokenId),
+			amount: BalanceOf<T>,
+			phases: Vec<DropPhase<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>>,
+			end_time: BlockNumberFor<T>,
+			listing_level: ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			let metaverse_id = match listing_level {
+				ListingLevel::Local(metaverse_id) => metaverse_id,
+				_ => return Err(Error::<T>::NoPermissionToCreateAuction.into()),
+			};
+
+			ensure!(
+				T::NFTHandler::is_stackable(asset)?,
+				Error::<T>::AuctionTypeIsNotSupported
+			);
+			ensure!(
+				!amount.is_zero() && T::NFTHandler::get_free_stackable_nft_balance(&from, &asset) >= amount,
+				Error::<T>::NoPermissionToCreateAuction
+			);
+			Self::ensure_authorised_collection(&from, &asset.0, &listing_level)?;
+			Self::ensure_valid_drop_phases(&phases, end_time, currency_id)?;
+
+			let listing_fee = T::MetaverseInfoSource::get_metaverse_marketplace_listing_fee(metaverse_id)?;
+
+			T::Currency::transfer(
+				&from,
+				&T::MetaverseInfoSource::get_network_treasury(),
+				T::StorageDepositFee::get(),
+				ExistenceRequirement::KeepAlive,
+			)?;
+			T::NFTHandler::reserve_stackable_nft_balance(&from, &asset, amount)?;
+			// Reserve network deposit fee
+			<T as Config>::Currency::reserve(&from, T::NetworkFeeReserve::get())?;
+
+			let drop_id = NextDropId::<T>::try_mutate(|id| -> Result<DropId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
+				Ok(current_id)
+			})?;
+
+			Drops::<T>::insert(
+				drop_id,
+				DropInfo {
+					creator: from.clone(),
+					asset,
+					remaining: amount,
+					end: end_time,
+					phases,
+					listing_level,
+					currency_id,
+					listing_fee,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::DropCreated(drop_id, from, asset.0, asset.1, amount));
+			Ok(().into())
+		}
+
+		/// User purchase units of a stackable NFT from the current phase of a drop
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `drop_id`: the drop to purchase from
+		/// - `amount`: the number of units to purchase
+		/// - `holder_token`: an NFT of the required class owned by the buyer, needed when the
+		///   current phase is restricted to class holders
+		///
+		/// Emits `DropPurchased` if successful.
+		#[pallet::weight(T::WeightInfo::buy_now())]
+		#[transactional]
+		pub fn purchase_from_drop(
+			origin: OriginFor<T>,
+			drop_id: DropId,
+			amount: BalanceOf<T>,
+			holder_token: Option<TokenId>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			Self::do_purchase_from_drop(from, drop_id, amount, holder_token)?;
+			Ok(().into())
+		}
+
+		/// Close a drop and return the remaining units to the creator
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// The creator can close the drop at any time, any account can close an ended drop.
+		/// - `drop_id`: the drop to close
+		///
+		/// Emits `DropClosed` if successful.
+		#[pallet::weight(T::WeightInfo::cancel_listing())]
+		#[transactional]
+		pub fn close_drop(origin: OriginFor<T>, drop_id: DropId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let drop_info = Self::drops(drop_id).ok_or(Error::<T>::DropDoesNotExist)?;
+			ensure!(
+				drop_info.creator == from || <system::Pallet<T>>::block_number() >= drop_info.end,
+				Error::<T>::DropHasNotEnded
+			);
+
+			Self::remove_drop(drop_id, &drop_info);
+			Ok(().into())
+		}
+
+		/// Fulfil an order signed off-chain by the owner of the item
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `order`: the signed order that consists of the item, its price and currency, until
+		///   what block number the order is valid and the nonce of the signer.
+		/// - `signature`: the signature of the `order` payload, see `signed_order_payload`.
+		/// - `signer`: the `order` object's signer. Should be the owner of the item.
+		///
+		/// Emits `SignedOrderFulfilled` if successful.
+		#[pallet::weight(T::WeightInfo::buy_now().saturating_add(Pallet::<T>::signed_order_invalidation_weight()))]
+		#[transactional]
+		pub fn fulfil_signed_order(
+			origin: OriginFor<T>,
+			order: Box<SignedOrderOf<T>>,
+			signature: T::OffchainSignature,
+			signer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::validate_signature(&Self::signed_order_payload(&order), &signature, &signer)?;
+
+			Self::do_fulfil_signed_order(from, *order, signer)?;
+			Ok(().into())
+		}
+
+		/// Cancel the signed orders of the caller signed with a nonce
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `nonce`: the nonce of the orders
+		///
+		/// Emits `SignedOrderCancelled` if successful.
+		#[pallet::weight(Pallet::<T>::signed_order_invalidation_weight())]
+		pub fn cancel_signed_order(origin: OriginFor<T>, nonce: u64) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_signed_order_nonce_usable(&from, nonce),
+				Error::<T>::SignedOrderIsInvalid
+			);
+			Self::invalidate_signed_order_nonce(&from, nonce)?;
+
+			Self::deposit_event(Event::<T>::SignedOrderCancelled(from, nonce));
+			Ok(().into())
+		}
+
+		/// Increase the signed order nonce of the caller past `SignedOrderNonceWindow`, cancelling
+		/// all outstanding orders
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Emits `SignedOrderNonceIncreased` if successful.
+		#[pallet::weight(Pallet::<T>::signed_order_invalidation_weight())]
+		pub fn increase_signed_order_nonce(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let current_nonce = Self::signed_order_nonce(&from);
+			let nonce = current_nonce
+				.checked_add(T::SignedOrderNonceWindow::get() as u64)
+				.ok_or(ArithmeticError::Overflow)?;
+			for invalidated_nonce in current_nonce..nonce {
+				InvalidatedSignedOrders::<T>::remove(&from, invalidated_nonce);
+			}
+			SignedOrderNonces::<T>::insert(&from, nonce);
+
+			Self::deposit_event(Event::<T>::SignedOrderNonceIncreased(from, nonce));
+			Ok(().into())
+		}
+
+		/// Propose a peer-to-peer swap of items and fungible amounts
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `offered_items`: the items given by the caller, locked until the swap is accepted,
+		///   cancelled or expired
+		/// - `offered_tokens`: the fungible amounts given by the caller, reserved until then
+		/// - `requested_items`: the items asked from the counterparty
+		/// - `requested_tokens`: the fungible amounts asked from the counterparty
+		/// - `counterparty`: the only account allowed to accept the swap, any account if none
+		/// - `expiry`: the block the proposal expires at, at most `MaxSwapDuration` blocks ahead
+		///
+		/// Emits `SwapProposed` if successful.
+		#[pallet::weight(T::WeightInfo::propose_swap((offered_items.len() + offered_tokens.len()) as u32))]
+		#[transactional]
+		pub fn propose_swap(
+			origin: OriginFor<T>,
+			offered_items: SwapItemsOf<T>,
+			offered_tokens: SwapTokensOf<T>,
+			requested_items: SwapItemsOf<T>,
+			requested_tokens: SwapTokensOf<T>,
+			counterparty: Option<T::AccountId>,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			let proposal = SwapProposal {
+				proposer: from.clone(),
+				counterparty: counterparty.clone(),
+				offered_items,
+				offered_tokens,
+				requested_items,
+				requested_tokens,
+				deposit: T::SwapDeposit::get(),
+				expiry,
+			};
+			Self::ensure_valid_swap_proposal(&proposal)?;
+
+			Self::lock_bundle_items(
+				&from,
+				&Self::swap_bundle(&proposal.offered_items),
+				&ListingLevel::Global,
+			)?;
+			Self::reserve_swap_tokens(&from, &proposal.offered_tokens)?;
+			<T as Config>::Currency::reserve(&from, proposal.deposit)?;
+
+			let swap_id = NextSwapId::<T>::try_mutate(|id| -> Result<SwapId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
+				Ok(current_id)
+			})?;
+
+			SwapEndTime::<T>::insert(expiry, swap_id, ());
+			Swaps::<T>::insert(swap_id, proposal);
+
+			Self::deposit_event(Event::<T>::SwapProposed(swap_id, from, counterparty));
+			Ok(().into())
+		}
+
+		/// Accept a swap, exchanging the items and fungible amounts of both sides atomically. The
+		/// royalty fees of the items each side gives are collected from the fungible amounts it
+		/// receives.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `swap_id`: the swap to accept
+		///
+		/// Emits `SwapAccepted` if successful.
+		#[pallet::weight(T::WeightInfo::accept_swap(
+			T::MaxSwapItems::get().saturating_mul(4),
+			T::MaxSwapRoyaltyFees::get()
+		))]
+		#[transactional]
+		pub fn accept_swap(origin: OriginFor<T>, swap_id: SwapId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let proposal = Self::swaps(swap_id).ok_or(Error::<T>::SwapDoesNotExist)?;
+			ensure!(
+				proposal.proposer != from && proposal.counterparty.as_ref().map_or(true, |who| *who == from),
+				Error::<T>::NoPermissionToAcceptSwap
+			);
+			ensure!(
+				<system::Pallet<T>>::block_number() < proposal.expiry,
+				Error::<T>::SwapIsExpired
+			);
+
+			let royalty_fees = Self::do_settle_swap(&from, &proposal)?;
+
+			<T as Config>::Currency::unreserve(&proposal.proposer, proposal.deposit);
+			SwapEndTime::<T>::remove(proposal.expiry, swap_id);
+			Swaps::<T>::remove(swap_id);
+
+			let swap_size = Self::swap_size(&proposal);
+			Self::deposit_event(Event::<T>::SwapAccepted(swap_id, proposal.proposer, from));
+			Ok(Some(T::WeightInfo::accept_swap(swap_size, royalty_fees)).into())
+		}
+
+		/// Cancel a swap and unlock its offered items and fungible amounts
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the proposer can cancel the swap, including an expired swap whose offered items
+		/// could not be unlocked.
+		/// - `swap_id`: the swap to cancel
+		///
+		/// Emits `SwapCancelled` if successful.
+		#[pallet::weight(T::WeightInfo::cancel_swap(T::MaxSwapItems::get().saturating_mul(2)))]
+		#[transactional]
+		pub fn cancel_swap(origin: OriginFor<T>, swap_id: SwapId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let proposal = Self::swaps(swap_id).ok_or(Error::<T>::SwapDoesNotExist)?;
+			ensure!(proposal.proposer == from, Error::<T>::NoPermissionToCancelSwap);
+
+			Self::remove_swap(swap_id, &proposal)?;
+
+			Self::deposit_event(Event::<T>::SwapCancelled(swap_id));
+			Ok(Some(T::WeightInfo::cancel_swap(Self::offered_swap_size(&proposal))).into())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Hooks that call every new block is initialized. Ended auctions are queued and at most
+		/// `MaxFinality` of them are finalized, the rest are carried to the next blocks. Expired
+		/// swaps, at most `MaxFinality` per block, are removed and their offered items unlocked.
+		/// Records of closed drops are removed, at most `MaxDropRecordRemovals` per block.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut total_queued_item = 0;
+			let mut tail = <PendingFinalizationTail<T>>::get();
+			for (auction_id, _) in <AuctionEndTime<T>>::drain_prefix(&now) {
+				total_queued_item += 1;
+				<PendingFinalization<T>>::insert(auction_id, now);
+				<PendingFinalizationQueue<T>>::insert(tail, auction_id);
+				tail = tail.saturating_add(1);
+			}
+			if total_queued_item > 0 {
+				<PendingFinalizationTail<T>>::put(tail);
+			}
+
+			let mut expired_swap_weight = Weight::zero();
+			for (swap_id, _) in <SwapEndTime<T>>::drain_prefix(&now) {
+				expired_swap_weight = expired_swap_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+				if let Some(proposal) = Self::swaps(swap_id) {
+					// A failed removal is rolled back and the swap stays open until it is cancelled
+					match with_storage_layer(|| Self::remove_swap(swap_id, &proposal)) {
+						Ok(()) => Self::deposit_event(Event::<T>::SwapExpired(swap_id)),
+						Err(error) => Self::deposit_event(Event::<T>::SwapExpiryFailed(swap_id, error)),
+					}
+					expired_swap_weight = expired_swap_weight
+						.saturating_add(T::WeightInfo::cancel_swap(Self::offered_swap_size(&proposal)));
+				}
+			}
+
+			let finalization_weight = Self::finalize_pending_auctions(T::MaxFinality::get());
+
+			let drop_records_weight = Self::remove_closed_drop_records(T::MaxDropRecordRemovals::get());
+
+			T::DbWeight::get()
+				.reads_writes(total_queued_item + 1, total_queued_item * 2 + 1)
+				.saturating_add(expired_swap_weight)
+				.saturating_add(drop_records_weight)
+				.saturating_add(finalization_weight)
+		}
+
+		/// Hooks that finalize the queued auctions with the remaining block weight.
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			// Budget the worst case of each auction, the actual weight is returned
+			let finalize_weight = Self::max_auction_finalization_weight();
+			let mut consumed_weight = T::DbWeight::get().reads_writes(2, 1);
+			let mut max_items = 0u32;
+			while max_items < T::MaxFinality::get()
+				&& consumed_weight
+					.saturating_add(finalize_weight)
+					.all_lte(remaining_weight)
+			{
+				consumed_weight = consumed_weight.saturating_add(finalize_weight);
+				max_items += 1;
+			}
+
+			Self::finalize_pending_auctions(max_items)
+		}
+
+		// fn on_runtime_upgrade() -> Weight {
+		// 	Self::upgrade_auction_item_data_v3();
+		// 	Weight::from_(0u64)
+		// }
+	}
+
+	impl<T: Config> Auction<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+		type Balance = BalanceOf<T>;
+
+		/// Internal update auction extension
+		fn update_auction(
+			id: AuctionId,
+			info: AuctionInfo<T::AccountId, Self::Balance, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let auction = <Auctions<T>>::get(id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+			if let Some(old_end) = auction.end {
+				<AuctionEndTime<T>>::remove(&old_end, id);
+			}
+			if let Some(new_end) = info.end {
+				<AuctionEndTime<T>>::insert(&new_end, id, ());
+			}
+			<Auctions<T>>::insert(id, info);
+			Ok(())
+		}
+
+		/// Internal create new auction item struct extension. This function will be executed inside
+		/// create_auction
+		fn new_auction(
+			_recipient: T::AccountId,
+			_initial_amount: Self::Balance,
+			start: BlockNumberFor<T>,
+			end: Option<BlockNumberFor<T>>,
+		) -> Result<AuctionId, DispatchError> {
+			let auction: AuctionInfo<T::AccountId, Self::Balance, BlockNumberFor<T>> =
+				AuctionInfo { bid: None, start, end };
+
+			let auction_id: AuctionId = AuctionsIndex::<T>::try_mutate(|n| -> Result<AuctionId, DispatchError> {
+				let id = *n;
+				ensure!(id != AuctionId::max_value(), Error::<T>::NoAvailableAuctionId);
+				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
+				Ok(id)
+			})?;
+
+			<Auctions<T>>::insert(auction_id, auction);
+
+			if let Some(end_block) = end {
+				<AuctionEndTime<T>>::insert(&end_block, auction_id, ());
+			}
+
+			Ok(auction_id)
+		}
+
+		/// Internal create auction extension
+		fn create_auction(
+			auction_type: AuctionType,
+			item_id: ItemId<Self::Balance>,
+			_end: Option<BlockNumberFor<T>>,
+			recipient: T::AccountId,
+			initial_amount: Self::Balance,
+			start: BlockNumberFor<T>,
+			listing_level: ListingLevel<T::AccountId>,
+			listing_fee: Perbill,
+			currency_id: FungibleTokenId,
+		) -> Result<AuctionId, DispatchError> {
+			ensure!(
+				initial_amount.clone() >= Self::minimum_listing_price(currency_id),
+				Error::<T>::ListingPriceIsBelowMinimum
+			);
+
+			//ensure!(
+			//	Self::items_in_auction(item_id.clone()) == None,
+			//	Error::<T>::ItemAlreadyInAuction
+			//);
+
+			// Listings can be scheduled to start at a future block
+			let start_time = start.max(<system::Pallet<T>>::block_number());
+
+			let mut end_time = start_time + T::AuctionTimeToClose::get();
+			if let Some(_end_block) = _end {
 				end_time = _end_block
 			}
 
-			match item_id.clone() {
+			// Sealed bids are committed until the given end and revealed until the auction ends
+			let mut sealed_bid_auction = None;
+			if let AuctionType::SealedBid(settlement) = auction_type {
+				sealed_bid_auction = Some(SealedBidAuctionInfo {
+					settlement,
+					reveal_start: end_time,
+					second_price: None,
+				});
+				end_time = end_time.saturating_add(T::SealedBidRevealPeriod::get());
+			}
+
+			let new_auction: Result<AuctionId, DispatchError> = match item_id.clone() {
 				ItemId::NFT(class_id, token_id) => {
 					ensure!(
 						Self::items_in_auction(item_id.clone()) == None,
@@ -1165,8 +2024,66 @@ pub mod pallet {
 					<ItemsInAuction<T>>::insert(item_id, true);
 					Ok(auction_id)
 				}
+				ItemId::MixedBundle(items) => {
+					ensure!(
+						Self::items_in_auction(item_id.clone()) == None,
+						Error::<T>::ItemAlreadyInAuction
+					);
+					ensure!(!items.is_empty(), Error::<T>::AssetDoesNotExist);
+					ensure!(
+						(items.len() as u32) <= T::MaxBundleItem::get(),
+						Error::<T>::ExceedBundleLimit
+					);
+
+					// Make sure total item bundle is not exceed max finality
+					ensure!(
+						Self::check_valid_finality(&end_time, items.len() as u32),
+						Error::<T>::ExceedFinalityLimit
+					);
+
+					// Lock all items of the bundle
+					Self::lock_bundle_items(&recipient, &items, &listing_level)?;
+
+					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+
+					// Reserve network deposit fee
+					<T as Config>::Currency::reserve(&recipient, T::NetworkFeeReserve::get())?;
+
+					let new_auction_item = AuctionItem {
+						item_id: item_id.clone(),
+						recipient: recipient.clone(),
+						initial_amount,
+						amount: initial_amount,
+						start_time,
+						end_time,
+						auction_type,
+						listing_level: listing_level.clone(),
+						currency_id,
+						listing_fee,
+					};
+
+					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+
+					Self::deposit_event(Event::NewAuctionItem(
+						auction_id,
+						recipient,
+						listing_level,
+						initial_amount,
+						initial_amount,
+						end_time,
+					));
+					<ItemsInAuction<T>>::insert(item_id, true);
+					Ok(auction_id)
+				}
 				_ => Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+			};
+
+			let auction_id = new_auction?;
+			if let Some(sealed_bid_auction) = sealed_bid_auction {
+				<SealedBidAuctions<T>>::insert(auction_id, sealed_bid_auction);
 			}
+
+			Ok(auction_id)
 		}
 
 		/// Internal remove auction extension
@@ -1177,6 +2094,8 @@ pub mod pallet {
 					<Auctions<T>>::remove(&id);
 					<ItemsInAuction<T>>::remove(item_id);
 					<AuctionItems<T>>::remove(&id);
+					<SealedBidAuctions<T>>::remove(&id);
+					<PendingFinalization<T>>::remove(&id);
 				}
 			}
 		}
@@ -1190,8 +2109,12 @@ pub mod pallet {
 				Error::<T>::InvalidAuctionType
 			);
 			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
+			ensure!(
+				!<PendingFinalization<T>>::contains_key(id),
+				Error::<T>::AuctionIsPendingFinalization
+			);
 
-			if matches!(auction_item.item_id, ItemId::UndeployedLandBlock(_)) {
+			if Self::contains_undeployed_land_block(&auction_item.item_id) {
 				ensure!(
 					T::MetaverseInfoSource::is_metaverse_owner(&from),
 					Error::<T>::MetaverseOwnerOnly
@@ -1354,20 +2277,30 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Collect royalty fee for auction
+		/// Collect royalty fee for auction. Royalty payouts which would leave a recipient below the
+		/// existential deposit go to the class fund instead.
 		fn collect_royalty_fee(
 			high_bid_price: &Self::Balance,
 			high_bidder: &T::AccountId,
 			asset_id: &(ClassId, TokenId),
 			social_currency_id: FungibleTokenId,
 		) -> DispatchResult {
-			// Get royalty fee
-			let nft_details = T::NFTHandler::get_nft_detail((asset_id.0, asset_id.1))?;
-			let royalty_fee: Self::Balance = nft_details.royalty_fee * *high_bid_price;
-			let class_fund = T::NFTHandler::get_class_fund(&asset_id.0);
+			let mut payouts = T::NFTHandler::get_royalty_payouts(&asset_id.0, *high_bid_price)?;
+			// The last payout is the class fund share of the royalty
+			let (class_fund, mut class_fund_royalty_fee) = match payouts.pop() {
+				Some(class_fund_payout) => class_fund_payout,
+				None => return Ok(()),
+			};
 
-			// Transfer loyalty fee from winner to class fund pot
-			Self::fee_transfer_handler(&high_bidder, &class_fund, social_currency_id, royalty_fee)?;
+			// Transfer royalty fee from winner to royalty recipients and class fund pot
+			for (recipient, royalty_fee) in payouts {
+				if Self::can_receive_fee(&recipient, social_currency_id, royalty_fee) {
+					Self::fee_transfer_handler(&high_bidder, &recipient, social_currency_id, royalty_fee)?;
+				} else {
+					class_fund_royalty_fee = class_fund_royalty_fee.saturating_add(royalty_fee);
+				}
+			}
+			Self::fee_transfer_handler(&high_bidder, &class_fund, social_currency_id, class_fund_royalty_fee)?;
 
 			Ok(())
 		}
@@ -1383,6 +2316,10 @@ pub mod pallet {
 			);
 
 			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
+			ensure!(
+				!<PendingFinalization<T>>::contains_key(auction_id),
+				Error::<T>::AuctionIsPendingFinalization
+			);
 
 			let block_number = <system::Pallet<T>>::block_number();
 			ensure!(block_number >= auction.start, Error::<T>::AuctionHasNotStarted);
@@ -1405,7 +2342,7 @@ pub mod pallet {
 				);
 			}
 
-			if matches!(auction_item.item_id, ItemId::UndeployedLandBlock(_)) {
+			if Self::contains_undeployed_land_block(&auction_item.item_id) {
 				ensure!(
 					T::MetaverseInfoSource::is_metaverse_owner(&from),
 					Error::<T>::MetaverseOwnerOnly
@@ -1510,6 +2447,26 @@ pub mod pallet {
 
 							Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
 						}
+						ItemId::MixedBundle(items) => {
+							// Collect listing fee once
+							Self::collect_listing_fee(
+								&value,
+								&auction_item.recipient,
+								auction_item.currency_id,
+								auction_item.listing_level.clone(),
+								auction_item.listing_fee,
+							)?;
+
+							Self::transfer_bundle_items(
+								&auction_item.recipient,
+								&from,
+								&items,
+								value,
+								auction_item.currency_id,
+							)?;
+
+							Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
+						}
 						ItemId::UndeployedLandBlock(undeployed_land_block_id) => {
 							let undeployed_land_block = T::EstateHandler::transfer_undeployed_land_block(
 								&auction_item.recipient,
@@ -1567,6 +2524,128 @@ pub mod pallet {
 			}
 			Ok(())
 		}
+
+		/// Internal sealed bid commit handler
+		fn sealed_bid_commit_handler(
+			from: T::AccountId,
+			id: AuctionId,
+			commitment: [u8; 32],
+			deposit: Self::Balance,
+		) -> DispatchResult {
+			let auction_item = Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionDoesNotExist)?;
+			let sealed_bid_auction = Self::sealed_bid_auctions(id).ok_or(Error::<T>::InvalidAuctionType)?;
+			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
+			ensure!(
+				!<PendingFinalization<T>>::contains_key(id),
+				Error::<T>::AuctionIsPendingFinalization
+			);
+
+			if Self::contains_undeployed_land_block(&auction_item.item_id) {
+				ensure!(
+					T::MetaverseInfoSource::is_metaverse_owner(&from),
+					Error::<T>::MetaverseOwnerOnly
+				);
+			}
+
+			let auction = Self::auctions(id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+			let block_number = <system::Pallet<T>>::block_number();
+			ensure!(block_number >= auction.start, Error::<T>::AuctionHasNotStarted);
+			ensure!(
+				block_number < sealed_bid_auction.reveal_start,
+				Error::<T>::SealedBidCommitPhaseIsOver
+			);
+
+			ensure!(
+				!SealedBids::<T>::contains_key(id, &from),
+				Error::<T>::SealedBidAlreadyCommitted
+			);
+			ensure!(
+				Self::sealed_bid_count(id) < T::MaxSealedBidsPerAuction::get(),
+				Error::<T>::ExceedSealedBidLimit
+			);
+			// Deposit has to cover at least the reserve price
+			ensure!(deposit >= auction_item.initial_amount, Error::<T>::InvalidBidPrice);
+
+			if auction_item.currency_id == FungibleTokenId::NativeToken(0) {
+				<T as Config>::Currency::reserve(&from, deposit)?;
+			} else {
+				T::FungibleTokenCurrency::reserve(auction_item.currency_id, &from, deposit.saturated_into())?;
+			}
+
+			SealedBids::<T>::insert(
+				id,
+				from.clone(),
+				SealedBid {
+					commitment,
+					deposit,
+					revealed: None,
+				},
+			);
+			SealedBidCount::<T>::mutate(id, |count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::SealedBidCommitted(id, from, deposit));
+			Ok(())
+		}
+
+		/// Internal sealed bid reveal handler
+		fn sealed_bid_reveal_handler(
+			from: T::AccountId,
+			id: AuctionId,
+			value: Self::Balance,
+			salt: Vec<u8>,
+		) -> Result<bool, DispatchError> {
+			let auction_item = Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionDoesNotExist)?;
+
+			SealedBidAuctions::<T>::try_mutate(id, |maybe_sealed_bid_auction| -> Result<bool, DispatchError> {
+				let sealed_bid_auction = maybe_sealed_bid_auction
+					.as_mut()
+					.ok_or(Error::<T>::InvalidAuctionType)?;
+
+				let mut auction = Self::auctions(id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+				let block_number = <system::Pallet<T>>::block_number();
+				ensure!(
+					block_number >= sealed_bid_auction.reveal_start,
+					Error::<T>::SealedBidRevealPhaseHasNotStarted
+				);
+				let auction_end = auction.end.ok_or(Error::<T>::AuctionIsExpired)?;
+				ensure!(block_number < auction_end, Error::<T>::AuctionIsExpired);
+
+				SealedBids::<T>::try_mutate(id, &from, |maybe_sealed_bid| -> DispatchResult {
+					let sealed_bid = maybe_sealed_bid.as_mut().ok_or(Error::<T>::SealedBidDoesNotExist)?;
+					ensure!(sealed_bid.revealed.is_none(), Error::<T>::SealedBidAlreadyRevealed);
+					ensure!(
+						Self::sealed_bid_commitment(id, &from, value, &salt) == sealed_bid.commitment,
+						Error::<T>::SealedBidRevealDoesNotMatch
+					);
+					ensure!(
+						value >= auction_item.initial_amount && value <= sealed_bid.deposit,
+						Error::<T>::InvalidBidPrice
+					);
+
+					sealed_bid.revealed = Some(value);
+					Ok(())
+				})?;
+
+				// Earlier reveal wins on equal bids
+				let mut is_leading = false;
+				match auction.bid.clone() {
+					Some((_, leading_price)) if value <= leading_price => {
+						if sealed_bid_auction.second_price.map_or(true, |price| value > price) {
+							sealed_bid_auction.second_price = Some(value);
+						}
+					}
+					last_bid => {
+						sealed_bid_auction.second_price = last_bid.map(|(_, price)| price);
+						auction.bid = Some((from.clone(), value));
+						<Auctions<T>>::insert(id, auction);
+						is_leading = true;
+					}
+				}
+
+				Self::deposit_event(Event::SealedBidRevealed(id, from, value));
+				Ok(is_leading)
+			})
+		}
 	}
 
 	impl<T: Config> CheckAuctionItemHandler<BalanceOf<T>> for Pallet<T> {
@@ -1590,6 +2669,10 @@ pub mod pallet {
 
 		fn on_auction_ended(auction_id: AuctionId, winner: Option<(T::AccountId, BalanceOf<T>)>) {
 			if let Some(auction_item) = <AuctionItems<T>>::get(&auction_id) {
+				let mut winner = winner;
+				if let Some(sealed_bid_auction) = <SealedBidAuctions<T>>::take(&auction_id) {
+					winner = Self::settle_sealed_bids(auction_id, &auction_item, sealed_bid_auction, winner);
+				}
 				Self::remove_auction(auction_id.clone(), auction_item.item_id.clone());
 
 				// Unreserve network deposit fee
@@ -1609,6 +2692,20 @@ pub mod pallet {
 						);
 					}
 
+					// Mixed bundle is either settled as a whole or returned to the owner
+					if let ItemId::MixedBundle(items) = auction_item.item_id.clone() {
+						match Self::settle_mixed_bundle(&auction_item, &high_bidder, high_bid_price, &items) {
+							Ok(_) => {
+								Self::deposit_event(Event::AuctionFinalized(auction_id, high_bidder, high_bid_price));
+							}
+							Err(_) => {
+								let _ = Self::unlock_bundle_items(&auction_item.recipient, &items);
+								Self::deposit_event(Event::AuctionFinalizedNoBid(auction_id));
+							}
+						}
+						return;
+					}
+
 					// Handle balance transfer
 					let currency_transfer;
 					if auction_item.currency_id == FungibleTokenId::NativeToken(0) {
@@ -1631,10 +2728,102 @@ pub mod pallet {
 						// Collect network commission fee
 						Self::collect_network_fee(&high_bid_price, &auction_item.recipient, auction_item.currency_id);
 
-						// Transfer asset from asset owner to high bidder
-						// Check asset type and handle internal logic
-						match auction_item.item_id.clone() {
-							ItemId::NFT(class_id, token_id) => {
+						// Transfer asset from asset owner to high bidder
+						// Check asset type and handle internal logic
+						match auction_item.item_id.clone() {
+							ItemId::NFT(class_id, token_id) => {
+								Self::collect_listing_fee(
+									&high_bid_price,
+									&auction_item.recipient,
+									auction_item.currency_id,
+									auction_item.listing_level.clone(),
+									auction_item.listing_fee,
+								);
+
+								Self::collect_royalty_fee(
+									&high_bid_price,
+									&auction_item.recipient,
+									&(class_id, token_id),
+									auction_item.currency_id,
+								);
+
+								T::NFTHandler::set_lock_nft((class_id, token_id), false);
+								let asset_transfer = T::NFTHandler::transfer_nft(
+									&auction_item.recipient,
+									&high_bidder,
+									&(class_id, token_id),
+								);
+								if let Ok(_transferred) = asset_transfer {
+									Self::deposit_event(Event::AuctionFinalized(
+										auction_id,
+										high_bidder,
+										high_bid_price,
+									));
+								}
+							}
+							ItemId::Spot(spot_id, metaverse_id) => {
+								let continuum_spot = T::ContinuumHandler::transfer_spot(
+									spot_id,
+									auction_item.recipient.clone(),
+									(high_bidder.clone(), metaverse_id),
+								);
+
+								if let Ok(_continuum_spot) = continuum_spot {
+									Self::deposit_event(Event::AuctionFinalized(
+										auction_id,
+										high_bidder,
+										high_bid_price,
+									));
+								}
+							}
+							ItemId::Bundle(tokens) => {
+								// Collect listing fee once
+								Self::collect_listing_fee(
+									&high_bid_price,
+									&auction_item.recipient,
+									auction_item.currency_id,
+									auction_item.listing_level.clone(),
+									auction_item.listing_fee,
+								);
+
+								for token in tokens {
+									// Collect royalty fee of each nft sold in the bundle
+									Self::collect_royalty_fee(
+										&token.2,
+										&auction_item.recipient,
+										&(token.0, token.1),
+										auction_item.currency_id,
+									);
+									T::NFTHandler::set_lock_nft((token.0, token.1), false);
+									T::NFTHandler::transfer_nft(
+										&auction_item.recipient,
+										&high_bidder,
+										&(token.0, token.1),
+									);
+								}
+
+								Self::deposit_event(Event::AuctionFinalized(
+									auction_id,
+									high_bidder.clone(),
+									high_bid_price,
+								));
+							}
+							ItemId::UndeployedLandBlock(undeployed_land_block_id) => {
+								let undeployed_land_block = T::EstateHandler::transfer_undeployed_land_block(
+									&auction_item.recipient,
+									&high_bidder.clone(),
+									undeployed_land_block_id,
+								);
+
+								if let Ok(_) = undeployed_land_block {
+									Self::deposit_event(Event::AuctionFinalized(
+										auction_id,
+										high_bidder,
+										high_bid_price,
+									));
+								}
+							}
+							ItemId::StackableNFT(class_id, token_id, amount) => {
 								Self::collect_listing_fee(
 									&high_bid_price,
 									&auction_item.recipient,
@@ -1650,11 +2839,17 @@ pub mod pallet {
 									auction_item.currency_id,
 								);
 
-								T::NFTHandler::set_lock_nft((class_id, token_id), false);
-								let asset_transfer = T::NFTHandler::transfer_nft(
+								T::NFTHandler::unreserve_stackable_nft_balance(
+									&auction_item.recipient,
+									&(class_id, token_id),
+									amount,
+								);
+
+								let asset_transfer = T::NFTHandler::transfer_stackable_nft(
 									&auction_item.recipient,
 									&high_bidder,
 									&(class_id, token_id),
+									amount,
 								);
 								if let Ok(_transferred) = asset_transfer {
 									Self::deposit_event(Event::AuctionFinalized(
@@ -1664,132 +2859,669 @@ pub mod pallet {
 									));
 								}
 							}
-							ItemId::Spot(spot_id, metaverse_id) => {
-								let continuum_spot = T::ContinuumHandler::transfer_spot(
-									spot_id,
-									auction_item.recipient.clone(),
-									(high_bidder.clone(), metaverse_id),
-								);
+							_ => {} // Future implementation for Metaverse
+						}
+						<ItemsInAuction<T>>::remove(auction_item.item_id.clone());
+						<AuctionItems<T>>::remove(auction_id.clone());
+					}
+				} else {
+					if let ItemId::NFT(class_id, token_id) = auction_item.item_id.clone() {
+						T::NFTHandler::set_lock_nft((class_id, token_id), false);
+					}
 
-								if let Ok(_continuum_spot) = continuum_spot {
-									Self::deposit_event(Event::AuctionFinalized(
-										auction_id,
-										high_bidder,
-										high_bid_price,
-									));
-								}
-							}
-							ItemId::Bundle(tokens) => {
-								// Collect listing fee once
-								Self::collect_listing_fee(
-									&high_bid_price,
-									&auction_item.recipient,
-									auction_item.currency_id,
-									auction_item.listing_level.clone(),
-									auction_item.listing_fee,
-								);
+					if let ItemId::Bundle(tokens) = auction_item.item_id.clone() {
+						for token in tokens {
+							T::NFTHandler::set_lock_nft((token.0, token.1), false);
+						}
+					}
+
+					if let ItemId::MixedBundle(items) = auction_item.item_id.clone() {
+						let _ = Self::unlock_bundle_items(&auction_item.recipient, &items);
+					}
+
+					Self::deposit_event(Event::AuctionFinalizedNoBid(auction_id));
+				}
+			}
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn check_valid_finality(end: &BlockNumberFor<T>, quantity: u32) -> bool {
+			let existing_auctions_same_block: u32 = <AuctionEndTime<T>>::iter_prefix_values(end).count() as u32;
+			let total_auction_in_same_block = existing_auctions_same_block.saturating_add(quantity);
+
+			T::MaxFinality::get() >= total_auction_in_same_block
+		}
+
+		/// Ensure the item can be listed on the listing level, NFTs and bundles are listed on a
+		/// local marketplace and undeployed land blocks on the global marketplace
+		fn ensure_valid_listing_level(
+			item_id: &ItemId<BalanceOf<T>>,
+			listing_level: &ListingLevel<T::AccountId>,
+		) -> DispatchResult {
+			ensure!(
+				matches!(
+					(item_id, listing_level),
+					(
+						ItemId::NFT(_, _) | ItemId::Bundle(_) | ItemId::MixedBundle(_),
+						ListingLevel::Local(_)
+					) | (ItemId::UndeployedLandBlock(_), ListingLevel::Global)
+				),
+				Error::<T>::NoPermissionToCreateAuction
+			);
+			Ok(())
+		}
+
+		/// Ensure social tokens are only listed on the local marketplace of their metaverse, fees
+		/// of these listings are then collected in the social token as well
+		fn ensure_valid_listing_currency(
+			listing_level: &ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
+		) -> DispatchResult {
+			if let FungibleTokenId::FungibleToken(_) = currency_id {
+				let metaverse_token = match listing_level {
+					ListingLevel::Local(metaverse_id) => T::MetaverseInfoSource::get_metaverse_token(*metaverse_id),
+					_ => None,
+				};
+				ensure!(metaverse_token == Some(currency_id), Error::<T>::InvalidListingCurrency);
+			}
+			Ok(())
+		}
+
+		/// Ensure the phases of a drop are ordered, start in the future and before the drop end
+		fn ensure_valid_drop_phases(
+			phases: &Vec<DropPhase<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>>,
+			end_time: BlockNumberFor<T>,
+			currency_id: FungibleTokenId,
+		) -> DispatchResult {
+			ensure!(
+				!phases.is_empty() && phases.len() as u32 <= T::MaxDropPhases::get(),
+				Error::<T>::InvalidDropPhases
+			);
+
+			let mut phase_start = <system::Pallet<T>>::block_number();
+			for (index, phase) in phases.iter().enumerate() {
+				ensure!(
+					phase.start >= phase_start && (index == 0 || phase.start > phase_start),
+					Error::<T>::InvalidDropPhases
+				);
+				ensure!(!phase.per_account_limit.is_zero(), Error::<T>::InvalidDropPhases);
+				ensure!(
+					phase.price >= Self::minimum_listing_price(currency_id),
+					Error::<T>::ListingPriceIsBelowMinimum
+				);
+				match &phase.eligibility {
+					DropEligibility::Accounts(accounts) => {
+						ensure!(
+							!accounts.is_empty() && accounts.len() as u32 <= T::MaxDropAllowlistSize::get(),
+							Error::<T>::InvalidDropPhases
+						);
+					}
+					DropEligibility::CredentialHolders(class_id) => {
+						ensure!(
+							T::Credentials::get_credential_issuer(*class_id).is_some(),
+							Error::<T>::InvalidDropPhases
+						);
+					}
+					_ => {}
+				}
+				phase_start = phase.start;
+			}
+
+			// Ensure the last phase lasts at least the minimum duration
+			let remaining_time = end_time.checked_sub(&phase_start).ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				remaining_time >= T::MinimumAuctionDuration::get(),
+				Error::<T>::AuctionEndIsLessThanMinimumDuration
+			);
+
+			Ok(())
+		}
+
+		/// Internal purchase of units from a drop
+		fn do_purchase_from_drop(
+			from: T::AccountId,
+			drop_id: DropId,
+			amount: BalanceOf<T>,
+			holder_token: Option<TokenId>,
+		) -> DispatchResult {
+			let mut drop_info = Self::drops(drop_id).ok_or(Error::<T>::DropDoesNotExist)?;
+			ensure!(drop_info.creator != from, Error::<T>::CannotBidOnOwnAuction);
+
+			let block_number = <system::Pallet<T>>::block_number();
+			ensure!(block_number < drop_info.end, Error::<T>::DropHasEnded);
+
+			// The current phase is the last phase that has started
+			let (phase_index, phase) = drop_info
+				.phases
+				.iter()
+				.enumerate()
+				.filter(|(_, phase)| phase.start <= block_number)
+				.last()
+				.ok_or(Error::<T>::DropHasNotStarted)?;
+			let phase_index = phase_index as u32;
+
+			match &phase.eligibility {
+				DropEligibility::Public => {}
+				DropEligibility::ClassHolders(class_id) => {
+					let token_id = holder_token.ok_or(Error::<T>::NotEligibleForDropPhase)?;
+					ensure!(
+						T::NFTHandler::check_ownership(&from, &(*class_id, token_id))?,
+						Error::<T>::NotEligibleForDropPhase
+					);
+					// A holder token only makes its first buyer in the phase eligible
+					match Self::drop_holder_token(drop_id, (phase_index, token_id)) {
+						Some(buyer) => ensure!(buyer == from, Error::<T>::DropHolderTokenAlreadyUsed),
+						None => DropHolderTokens::<T>::insert(drop_id, (phase_index, token_id), from.clone()),
+					}
+				}
+				DropEligibility::Accounts(accounts) => {
+					ensure!(accounts.contains(&from), Error::<T>::NotEligibleForDropPhase);
+				}
+				DropEligibility::CredentialHolders(class_id) => {
+					ensure!(
+						T::Credentials::has_valid_credential(&from, *class_id),
+						Error::<T>::NotEligibleForDropPhase
+					);
+				}
+			}
+
+			ensure!(!amount.is_zero(), Error::<T>::InvalidBuyNowPrice);
+			ensure!(amount <= drop_info.remaining, Error::<T>::InsufficientDropInventory);
+			let purchased = Self::drop_purchases(drop_id, (phase_index, from.clone()))
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				purchased <= phase.per_account_limit,
+				Error::<T>::DropPurchaseLimitExceeded
+			);
+
+			let total_price = phase.price.checked_mul(&amount).ok_or(ArithmeticError::Overflow)?;
+
+			// Transfer balance from buyer to drop creator
+			if drop_info.currency_id == FungibleTokenId::NativeToken(0) {
+				<T as Config>::Currency::transfer(
+					&from,
+					&drop_info.creator,
+					total_price,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			} else {
+				T::FungibleTokenCurrency::transfer(
+					drop_info.currency_id,
+					&from,
+					&drop_info.creator,
+					total_price.saturated_into(),
+				)?;
+			}
+
+			Self::collect_network_fee(&total_price, &drop_info.creator, drop_info.currency_id)?;
+			Self::collect_listing_fee(
+				&total_price,
+				&drop_info.creator,
+				drop_info.currency_id,
+				drop_info.listing_level.clone(),
+				drop_info.listing_fee,
+			)?;
+			Self::collect_royalty_fee(
+				&total_price,
+				&drop_info.creator,
+				&drop_info.asset,
+				drop_info.currency_id,
+			)?;
+
+			T::NFTHandler::unreserve_stackable_nft_balance(&drop_info.creator, &drop_info.asset, amount)?;
+			T::NFTHandler::transfer_stackable_nft(&drop_info.creator, &from, &drop_info.asset, amount)?;
+
+			DropPurchases::<T>::insert(drop_id, (phase_index, from.clone()), purchased);
+			drop_info.remaining = drop_info.remaining.saturating_sub(amount);
+
+			Self::deposit_event(Event::<T>::DropPurchased(drop_id, from, amount, total_price));
+
+			if drop_info.remaining.is_zero() {
+				Self::remove_drop(drop_id, &drop_info);
+			} else {
+				Drops::<T>::insert(drop_id, drop_info);
+			}
+
+			Ok(())
+		}
+
+		/// Internal removal of drop, returning the remaining units to the creator
+		fn remove_drop(drop_id: DropId, drop_info: &DropInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>) {
+			if !drop_info.remaining.is_zero() {
+				let _ = T::NFTHandler::unreserve_stackable_nft_balance(
+					&drop_info.creator,
+					&drop_info.asset,
+					drop_info.remaining,
+				);
+			}
+			// Unreserve network deposit fee
+			<T as Config>::Currency::unreserve(&drop_info.creator, T::NetworkFeeReserve::get());
+
+			Drops::<T>::remove(drop_id);
+			// Purchase records are removed in the next blocks
+			ClosedDrops::<T>::insert(drop_id, ());
+
+			Self::deposit_event(Event::<T>::DropClosed(drop_id, drop_info.remaining));
+		}
+
+		/// Remove at most `limit` purchase and holder token records of closed drops
+		fn remove_closed_drop_records(limit: u32) -> Weight {
+			let mut removed: u32 = 0;
+			let mut reads: u64 = 1;
+			let closed_drops: Vec<DropId> = ClosedDrops::<T>::iter_keys().take(limit as usize).collect();
+			for drop_id in closed_drops {
+				let purchases = DropPurchases::<T>::clear_prefix(drop_id, limit.saturating_sub(removed), None);
+				removed = removed.saturating_add(purchases.unique);
+				let holder_tokens = DropHolderTokens::<T>::clear_prefix(drop_id, limit.saturating_sub(removed), None);
+				removed = removed.saturating_add(holder_tokens.unique);
+				reads = reads.saturating_add(purchases.loops as u64 + holder_tokens.loops as u64);
+				if purchases.maybe_cursor.is_none() && holder_tokens.maybe_cursor.is_none() {
+					ClosedDrops::<T>::remove(drop_id);
+					removed = removed.saturating_add(1);
+				}
+				if removed >= limit {
+					break;
+				}
+			}
+
+			T::DbWeight::get().reads_writes(reads, removed as u64)
+		}
+
+		/// Ensure a swap proposal exchanges supported items and non-zero amounts before its expiry,
+		/// and that the expiry block has room for another swap
+		fn ensure_valid_swap_proposal(proposal: &SwapProposalOf<T>) -> DispatchResult {
+			let block_number = <system::Pallet<T>>::block_number();
+			ensure!(proposal.expiry > block_number, Error::<T>::InvalidSwapProposal);
+			ensure!(
+				proposal.expiry <= block_number.saturating_add(T::MaxSwapDuration::get()),
+				Error::<T>::SwapExpiryExceedsMaxDuration
+			);
+			ensure!(
+				(<SwapEndTime<T>>::iter_prefix_values(proposal.expiry).count() as u32) < T::MaxFinality::get(),
+				Error::<T>::ExceedFinalityLimit
+			);
+			ensure!(
+				proposal.counterparty.as_ref() != Some(&proposal.proposer),
+				Error::<T>::InvalidSwapProposal
+			);
+
+			for (items, tokens) in [
+				(&proposal.offered_items, &proposal.offered_tokens),
+				(&proposal.requested_items, &proposal.requested_tokens),
+			] {
+				ensure!(
+					!(items.is_empty() && tokens.is_empty()),
+					Error::<T>::InvalidSwapProposal
+				);
+				ensure!(
+					tokens.iter().all(|(_, amount)| !amount.is_zero()),
+					Error::<T>::InvalidSwapProposal
+				);
+				for item in items {
+					match item {
+						ItemId::NFT(_, _)
+						| ItemId::Estate(_)
+						| ItemId::LandUnit(_, _)
+						| ItemId::UndeployedLandBlock(_) => {}
+						ItemId::StackableNFT(_, _, amount) => {
+							ensure!(!amount.is_zero(), Error::<T>::InvalidSwapProposal)
+						}
+						_ => return Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+					}
+				}
+			}
+			ensure!(
+				Self::swap_royalty_fees(proposal) <= T::MaxSwapRoyaltyFees::get(),
+				Error::<T>::ExceedMaximumSwapRoyaltyFees
+			);
+
+			Ok(())
+		}
+
+		/// Number of items and fungible amounts offered in a swap
+		fn offered_swap_size(proposal: &SwapProposalOf<T>) -> u32 {
+			proposal
+				.offered_items
+				.len()
+				.saturating_add(proposal.offered_tokens.len()) as u32
+		}
+
+		/// Number of items and fungible amounts exchanged by both sides of a swap
+		fn swap_size(proposal: &SwapProposalOf<T>) -> u32 {
+			Self::offered_swap_size(proposal).saturating_add(
+				proposal
+					.requested_items
+					.len()
+					.saturating_add(proposal.requested_tokens.len()) as u32,
+			)
+		}
+
+		/// Get the items of a swap as a mixed bundle, so they share the bundle locking and
+		/// transfer logic
+		fn swap_bundle(items: &[ItemId<BalanceOf<T>>]) -> Vec<(ItemId<BalanceOf<T>>, BalanceOf<T>)> {
+			items.iter().map(|item| (item.clone(), Zero::zero())).collect()
+		}
+
+		/// Reserve the fungible amounts offered in a swap
+		fn reserve_swap_tokens(who: &T::AccountId, tokens: &[(FungibleTokenId, BalanceOf<T>)]) -> DispatchResult {
+			for (currency_id, amount) in tokens {
+				if *currency_id == FungibleTokenId::NativeToken(0) {
+					<T as Config>::Currency::reserve(who, *amount)?;
+				} else {
+					T::FungibleTokenCurrency::reserve(*currency_id, who, (*amount).saturated_into())?;
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Unreserve the fungible amounts offered in a swap
+		fn unreserve_swap_tokens(who: &T::AccountId, tokens: &[(FungibleTokenId, BalanceOf<T>)]) {
+			for (currency_id, amount) in tokens {
+				if *currency_id == FungibleTokenId::NativeToken(0) {
+					<T as Config>::Currency::unreserve(who, *amount);
+				} else {
+					T::FungibleTokenCurrency::unreserve(*currency_id, who, (*amount).saturated_into());
+				}
+			}
+		}
+
+		/// Transfer the fungible amounts of one side of a swap
+		fn transfer_swap_tokens(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			tokens: &[(FungibleTokenId, BalanceOf<T>)],
+		) -> DispatchResult {
+			for (currency_id, amount) in tokens {
+				if *currency_id == FungibleTokenId::NativeToken(0) {
+					<T as Config>::Currency::transfer(from, to, *amount, ExistenceRequirement::AllowDeath)?;
+				} else {
+					T::FungibleTokenCurrency::transfer(*currency_id, from, to, (*amount).saturated_into())?;
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Exchange both sides of a swap, all or nothing. Requested items go through the same
+		/// ownership and lock checks as offered ones. Returns the number of collected royalty
+		/// fees.
+		#[transactional]
+		fn do_settle_swap(acceptor: &T::AccountId, proposal: &SwapProposalOf<T>) -> Result<u32, DispatchError> {
+			let royalty_fees = Self::swap_royalty_fees(proposal);
+			ensure!(
+				royalty_fees <= T::MaxSwapRoyaltyFees::get(),
+				Error::<T>::ExceedMaximumSwapRoyaltyFees
+			);
+
+			let requested_items = Self::swap_bundle(&proposal.requested_items);
+			Self::lock_bundle_items(acceptor, &requested_items, &ListingLevel::Global)?;
+			Self::transfer_bundle_items(
+				acceptor,
+				&proposal.proposer,
+				&requested_items,
+				Zero::zero(),
+				FungibleTokenId::NativeToken(0),
+			)?;
+			Self::transfer_swap_tokens(acceptor, &proposal.proposer, &proposal.requested_tokens)?;
+
+			Self::transfer_bundle_items(
+				&proposal.proposer,
+				acceptor,
+				&Self::swap_bundle(&proposal.offered_items),
+				Zero::zero(),
+				FungibleTokenId::NativeToken(0),
+			)?;
+			Self::unreserve_swap_tokens(&proposal.proposer, &proposal.offered_tokens);
+			Self::transfer_swap_tokens(&proposal.proposer, acceptor, &proposal.offered_tokens)?;
+
+			Self::collect_swap_royalty_fees(&proposal.proposer, &proposal.offered_items, &proposal.requested_tokens)?;
+			Self::collect_swap_royalty_fees(acceptor, &proposal.requested_items, &proposal.offered_tokens)?;
+
+			Ok(royalty_fees)
+		}
+
+		/// Number of royalty fees collected when a swap is accepted, one per item with a royalty
+		/// given and fungible amount received for it
+		fn swap_royalty_fees(proposal: &SwapProposalOf<T>) -> u32 {
+			let royalty_items = |items: &SwapItemsOf<T>| {
+				items
+					.iter()
+					.filter(|item| !matches!(item, ItemId::UndeployedLandBlock(_)))
+					.count()
+			};
+			royalty_items(&proposal.offered_items)
+				.saturating_mul(proposal.requested_tokens.len())
+				.saturating_add(royalty_items(&proposal.requested_items).saturating_mul(proposal.offered_tokens.len()))
+				as u32
+		}
+
+		/// Collect the royalty fees of the items one side of a swap gives from the fungible amounts
+		/// it receives. Each amount is shared equally by the items with a royalty.
+		fn collect_swap_royalty_fees(
+			seller: &T::AccountId,
+			items: &[ItemId<BalanceOf<T>>],
+			tokens: &[(FungibleTokenId, BalanceOf<T>)],
+		) -> DispatchResult {
+			let mut assets: Vec<(ClassId, TokenId)> = Vec::new();
+			for item in items {
+				match item {
+					ItemId::NFT(_, _) | ItemId::Estate(_) | ItemId::LandUnit(_, _) => {
+						assets.push(Self::bundle_item_token(item)?)
+					}
+					ItemId::StackableNFT(class_id, token_id, _) => assets.push((*class_id, *token_id)),
+					_ => {}
+				}
+			}
+			if assets.is_empty() {
+				return Ok(());
+			}
+
+			let item_share = Perbill::from_rational(1u32, assets.len() as u32);
+			for (currency_id, amount) in tokens {
+				let item_price = item_share * *amount;
+				for asset in assets.iter() {
+					Self::collect_royalty_fee(&item_price, seller, asset, *currency_id)?;
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Internal removal of an open swap, unlocking its offered items, fungible amounts and
+		/// deposit
+		fn remove_swap(swap_id: SwapId, proposal: &SwapProposalOf<T>) -> DispatchResult {
+			Self::unlock_bundle_items(&proposal.proposer, &Self::swap_bundle(&proposal.offered_items))?;
+			Self::unreserve_swap_tokens(&proposal.proposer, &proposal.offered_tokens);
+			<T as Config>::Currency::unreserve(&proposal.proposer, proposal.deposit);
+
+			SwapEndTime::<T>::remove(proposal.expiry, swap_id);
+			Swaps::<T>::remove(swap_id);
+			Ok(())
+		}
+
+		/// Payload signed off-chain for a signed order, the order prefixed with the pallet tag and
+		/// the genesis hash so that it can not be replayed on another chain or pallet
+		pub fn signed_order_payload(order: &SignedOrderOf<T>) -> Vec<u8> {
+			let genesis_hash = <system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+			(SIGNED_ORDER_TAG, genesis_hash, order).encode()
+		}
+
+		/// Compute the hash of a signed order
+		pub fn signed_order_hash(order: &SignedOrderOf<T>) -> [u8; 32] {
+			blake2_256(&Self::signed_order_payload(order))
+		}
+
+		/// Whether signed orders of `signer` with `nonce` can be fulfilled or cancelled
+		fn is_signed_order_nonce_usable(signer: &T::AccountId, nonce: u64) -> bool {
+			let current_nonce = Self::signed_order_nonce(signer);
+			nonce >= current_nonce
+				&& nonce - current_nonce < T::SignedOrderNonceWindow::get() as u64
+				&& !InvalidatedSignedOrders::<T>::contains_key(signer, nonce)
+		}
+
+		/// Invalidate signed orders of `signer` with `nonce`. The current nonce moves past the
+		/// invalidated nonces it reaches, so at most `SignedOrderNonceWindow` nonces are stored.
+		fn invalidate_signed_order_nonce(signer: &T::AccountId, nonce: u64) -> DispatchResult {
+			let current_nonce = Self::signed_order_nonce(signer);
+			if nonce != current_nonce {
+				InvalidatedSignedOrders::<T>::insert(signer, nonce, ());
+				return Ok(());
+			}
+
+			let mut next_nonce = current_nonce.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			while InvalidatedSignedOrders::<T>::take(signer, next_nonce).is_some() {
+				next_nonce = next_nonce.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			}
+			SignedOrderNonces::<T>::insert(signer, next_nonce);
+			Ok(())
+		}
 
-								for token in tokens {
-									// Collect royalty fee of each nft sold in the bundle
-									Self::collect_royalty_fee(
-										&token.2,
-										&auction_item.recipient,
-										&(token.0, token.1),
-										auction_item.currency_id,
-									);
-									T::NFTHandler::set_lock_nft((token.0, token.1), false);
-									T::NFTHandler::transfer_nft(
-										&auction_item.recipient,
-										&high_bidder,
-										&(token.0, token.1),
-									);
-								}
+		/// Weight of invalidating signed order nonces, reading and removing up to
+		/// `SignedOrderNonceWindow` invalidated nonces
+		fn signed_order_invalidation_weight() -> Weight {
+			let window = T::SignedOrderNonceWindow::get() as u64;
+			T::DbWeight::get().reads_writes(window.saturating_add(2), window.saturating_add(1))
+		}
 
-								Self::deposit_event(Event::AuctionFinalized(
-									auction_id,
-									high_bidder.clone(),
-									high_bid_price,
-								));
-							}
-							ItemId::UndeployedLandBlock(undeployed_land_block_id) => {
-								let undeployed_land_block = T::EstateHandler::transfer_undeployed_land_block(
-									&auction_item.recipient,
-									&high_bidder.clone(),
-									undeployed_land_block_id,
-								);
+		/// Verify the off-chain signature of `data`, the signature of the data wrapped in
+		/// `<Bytes>` tags is accepted as well
+		pub fn validate_signature(
+			data: &Vec<u8>,
+			signature: &T::OffchainSignature,
+			signer: &T::AccountId,
+		) -> DispatchResult {
+			if signature.verify(&**data, &signer) {
+				return Ok(());
+			}
 
-								if let Ok(_) = undeployed_land_block {
-									Self::deposit_event(Event::AuctionFinalized(
-										auction_id,
-										high_bidder,
-										high_bid_price,
-									));
-								}
-							}
-							ItemId::StackableNFT(class_id, token_id, amount) => {
-								Self::collect_listing_fee(
-									&high_bid_price,
-									&auction_item.recipient,
-									auction_item.currency_id,
-									auction_item.listing_level.clone(),
-									auction_item.listing_fee,
-								);
+			let prefix = b"<Bytes>";
+			let suffix = b"</Bytes>";
+			let mut wrapped: Vec<u8> = Vec::with_capacity(data.len() + prefix.len() + suffix.len());
+			wrapped.extend(prefix);
+			wrapped.extend(data);
+			wrapped.extend(suffix);
 
-								Self::collect_royalty_fee(
-									&high_bid_price,
-									&auction_item.recipient,
-									&(class_id, token_id),
-									auction_item.currency_id,
-								);
+			ensure!(signature.verify(&*wrapped, &signer), Error::<T>::WrongSignature);
 
-								T::NFTHandler::unreserve_stackable_nft_balance(
-									&auction_item.recipient,
-									&(class_id, token_id),
-									amount,
-								);
+			Ok(())
+		}
 
-								let asset_transfer = T::NFTHandler::transfer_stackable_nft(
-									&auction_item.recipient,
-									&high_bidder,
-									&(class_id, token_id),
-									amount,
-								);
-								if let Ok(_transferred) = asset_transfer {
-									Self::deposit_event(Event::AuctionFinalized(
-										auction_id,
-										high_bidder,
-										high_bid_price,
-									));
-								}
-							}
-							_ => {} // Future implementation for Metaverse
-						}
-						<ItemsInAuction<T>>::remove(auction_item.item_id.clone());
-						<AuctionItems<T>>::remove(auction_id.clone());
-					}
-				} else {
-					if let ItemId::NFT(class_id, token_id) = auction_item.item_id.clone() {
-						T::NFTHandler::set_lock_nft((class_id, token_id), false);
-					}
+		/// Settle a signed order, transferring the item from the signer to the buyer
+		fn do_fulfil_signed_order(from: T::AccountId, order: SignedOrderOf<T>, signer: T::AccountId) -> DispatchResult {
+			ensure!(from != signer, Error::<T>::CannotBidOnOwnAuction);
 
-					if let ItemId::Bundle(tokens) = auction_item.item_id.clone() {
-						for token in tokens {
-							T::NFTHandler::set_lock_nft((token.0, token.1), false);
+			let now = <system::Pallet<T>>::block_number();
+			ensure!(order.expired >= now, Error::<T>::SignatureExpired);
+
+			ensure!(
+				Self::is_signed_order_nonce_usable(&signer, order.nonce),
+				Error::<T>::SignedOrderIsInvalid
+			);
+
+			let asset = match order.item_id {
+				ItemId::NFT(class_id, token_id) => {
+					ensure!(
+						T::NFTHandler::check_ownership(&signer, &(class_id, token_id))?,
+						Error::<T>::SignerIsNotItemOwner
+					);
+					ensure!(
+						T::NFTHandler::is_transferable(&(class_id, token_id))?,
+						Error::<T>::NoPermissionToCreateAuction
+					);
+					(class_id, token_id)
+				}
+				ItemId::StackableNFT(class_id, token_id, amount) => {
+					ensure!(
+						T::NFTHandler::get_free_stackable_nft_balance(&signer, &(class_id, token_id)) >= amount,
+						Error::<T>::SignerIsNotItemOwner
+					);
+					(class_id, token_id)
+				}
+				_ => return Err(Error::<T>::SignedOrderItemNotSupported.into()),
+			};
+			ensure!(
+				Self::items_in_auction(order.item_id.clone()) == None,
+				Error::<T>::ItemAlreadyInAuction
+			);
+
+			// Transfer balance from buyer to signer
+			if order.currency_id == FungibleTokenId::NativeToken(0) {
+				<T as Config>::Currency::transfer(&from, &signer, order.price, ExistenceRequirement::KeepAlive)?;
+			} else {
+				T::FungibleTokenCurrency::transfer(order.currency_id, &from, &signer, order.price.saturated_into())?;
+			}
+
+			Self::collect_network_fee(&order.price, &signer, order.currency_id)?;
+			Self::collect_royalty_fee(&order.price, &signer, &asset, order.currency_id)?;
+
+			match order.item_id {
+				ItemId::StackableNFT(_, _, amount) => {
+					T::NFTHandler::transfer_stackable_nft(&signer, &from, &asset, amount)?;
+				}
+				_ => {
+					T::NFTHandler::transfer_nft(&signer, &from, &asset)?;
+				}
+			}
+
+			Self::invalidate_signed_order_nonce(&signer, order.nonce)?;
+
+			let order_hash = Self::signed_order_hash(&order);
+			Self::deposit_event(Event::<T>::SignedOrderFulfilled(signer, from, order_hash, order.price));
+			Ok(())
+		}
+
+		/// Finalize up to `max_items` queued auctions, oldest first, returning the consumed weight.
+		/// Entries of auctions already finalized manually or removed are skipped.
+		fn finalize_pending_auctions(max_items: u32) -> Weight {
+			let mut head = <PendingFinalizationHead<T>>::get();
+			let tail = <PendingFinalizationTail<T>>::get();
+			let mut total_processed_item = 0u32;
+			let mut weight = T::DbWeight::get().reads(2);
+			while head < tail && total_processed_item < max_items {
+				if let Some(auction_id) = <PendingFinalizationQueue<T>>::take(head) {
+					if <PendingFinalization<T>>::take(auction_id).is_some() {
+						if let Some(auction) = <Auctions<T>>::get(auction_id) {
+							weight = weight.saturating_add(Self::auction_finalization_weight(auction_id));
+							T::Handler::on_auction_ended(auction_id, auction.bid);
 						}
 					}
-
-					Self::deposit_event(Event::AuctionFinalizedNoBid(auction_id));
 				}
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+				head = head.saturating_add(1);
+				total_processed_item += 1;
+			}
+
+			if total_processed_item > 0 {
+				<PendingFinalizationHead<T>>::put(head);
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
 			}
+
+			weight
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn check_valid_finality(end: &BlockNumberFor<T>, quantity: u32) -> bool {
-			let existing_auctions_same_block: u32 = <AuctionEndTime<T>>::iter_prefix_values(end).count() as u32;
-			let total_auction_in_same_block = existing_auctions_same_block.saturating_add(quantity);
+		/// Weight of finalizing an auction, including the settlement of its sealed bids
+		fn auction_finalization_weight(auction_id: AuctionId) -> Weight {
+			T::WeightInfo::on_finalize()
+				.saturating_add(T::WeightInfo::settle_sealed_bids(Self::sealed_bid_count(auction_id)))
+		}
 
-			T::MaxFinality::get() >= total_auction_in_same_block
+		/// Worst case weight of finalizing an auction
+		fn max_auction_finalization_weight() -> Weight {
+			T::WeightInfo::on_finalize()
+				.saturating_add(T::WeightInfo::settle_sealed_bids(T::MaxSealedBidsPerAuction::get()))
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+		}
+
+		/// Minimum listing price in `currency_id`, falling back to the configured amount without a
+		/// price
+		fn minimum_listing_price(currency_id: FungibleTokenId) -> BalanceOf<T> {
+			let minimum_listing_price = T::MinimumListingPrice::get();
+			T::PriceProvider::from_reference(currency_id, minimum_listing_price.saturated_into())
+				.map(|price| price.saturated_into())
+				.unwrap_or(minimum_listing_price)
 		}
 
 		/// Collect listing fee for auction
@@ -1830,25 +3562,29 @@ pub mod pallet {
 			social_currency_id: FungibleTokenId,
 			amount: BalanceOf<T>,
 		) -> DispatchResult {
+			// Only transfer fee if amount plus balance greater than ED, never fail
+			if !Self::can_receive_fee(to, social_currency_id, amount) {
+				return Ok(());
+			}
 			if social_currency_id == FungibleTokenId::NativeToken(0) {
-				// Check if account free_balance + network fee less than ED
-				let amount_plus_free_balance = T::Currency::free_balance(to).saturating_add(amount);
-				// Only transfer fee if amount plus balance greater than ED, never fail
-				if amount_plus_free_balance >= T::Currency::minimum_balance() {
-					<T as Config>::Currency::transfer(from, to, amount, ExistenceRequirement::KeepAlive)?;
-				}
+				<T as Config>::Currency::transfer(from, to, amount, ExistenceRequirement::KeepAlive)?;
 			} else {
-				// Check if account free_balance + network fee less than ED
-				let amount_plus_free_balance = T::FungibleTokenCurrency::free_balance(social_currency_id.clone(), to)
-					.saturating_add(amount.saturated_into());
-				// Only transfer fee if amount plus balance greater than ED, never fail
-				if amount_plus_free_balance >= T::FungibleTokenCurrency::minimum_balance(social_currency_id.clone()) {
-					T::FungibleTokenCurrency::transfer(social_currency_id.clone(), from, to, amount.saturated_into())?;
-				}
+				T::FungibleTokenCurrency::transfer(social_currency_id.clone(), from, to, amount.saturated_into())?;
 			}
 			Ok(())
 		}
 
+		/// Check if account free_balance + fee is at least ED
+		fn can_receive_fee(to: &T::AccountId, social_currency_id: FungibleTokenId, amount: BalanceOf<T>) -> bool {
+			if social_currency_id == FungibleTokenId::NativeToken(0) {
+				T::Currency::free_balance(to).saturating_add(amount) >= T::Currency::minimum_balance()
+			} else {
+				T::FungibleTokenCurrency::free_balance(social_currency_id.clone(), to)
+					.saturating_add(amount.saturated_into())
+					>= T::FungibleTokenCurrency::minimum_balance(social_currency_id.clone())
+			}
+		}
+
 		pub fn upgrade_auction_item_data_v3() -> Weight {
 			log::info!("Start upgrading auction item data v3");
 			let mut num_auction_items = 0;
@@ -1937,6 +3673,302 @@ pub mod pallet {
 			})
 		}
 
+		/// Check if the listing contains undeployed land block which only metaverse owners can buy
+		fn contains_undeployed_land_block(item_id: &ItemId<BalanceOf<T>>) -> bool {
+			match item_id {
+				ItemId::UndeployedLandBlock(_) => true,
+				ItemId::MixedBundle(items) => items
+					.iter()
+					.any(|(item, _)| matches!(item, ItemId::UndeployedLandBlock(_))),
+				_ => false,
+			}
+		}
+
+		/// Get the NFT which represents an NFT, estate or land unit item of a mixed bundle
+		fn bundle_item_token(item_id: &ItemId<BalanceOf<T>>) -> Result<(ClassId, TokenId), DispatchError> {
+			match item_id {
+				ItemId::NFT(class_id, token_id) => Ok((*class_id, *token_id)),
+				ItemId::Estate(estate_id) => {
+					T::EstateHandler::get_estate_token(*estate_id).ok_or(Error::<T>::EstateDoesNotExist.into())
+				}
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					T::EstateHandler::get_land_unit_token(*metaverse_id, *coordinate)
+						.ok_or(Error::<T>::LandUnitDoesNotExist.into())
+				}
+				_ => Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+			}
+		}
+
+		/// Ensure NFT collection is authorised to be sold on the listing level
+		fn ensure_authorised_collection(
+			owner: &T::AccountId,
+			class_id: &ClassId,
+			listing_level: &ListingLevel<T::AccountId>,
+		) -> DispatchResult {
+			if let ListingLevel::Local(metaverse_id) = listing_level {
+				ensure!(
+					MetaverseCollection::<T>::contains_key(metaverse_id, class_id)
+						|| T::MetaverseInfoSource::check_ownership(owner, metaverse_id)
+						|| T::MetaverseInfoSource::check_if_metaverse_estate(*metaverse_id, class_id)?,
+					Error::<T>::NoPermissionToCreateAuction
+				);
+			}
+			Ok(())
+		}
+
+		/// Check ownership of every item of a mixed bundle and lock them while listed, all or
+		/// nothing
+		#[transactional]
+		fn lock_bundle_items(
+			owner: &T::AccountId,
+			items: &[(ItemId<BalanceOf<T>>, BalanceOf<T>)],
+			listing_level: &ListingLevel<T::AccountId>,
+		) -> DispatchResult {
+			for (item, _) in items {
+				match item {
+					ItemId::NFT(_, _) | ItemId::Estate(_) | ItemId::LandUnit(_, _) => {
+						let asset = Self::bundle_item_token(item)?;
+						ensure!(
+							T::NFTHandler::check_ownership(owner, &asset)?,
+							Error::<T>::NoPermissionToCreateAuction
+						);
+						ensure!(
+							Self::items_in_auction(ItemId::NFT(asset.0, asset.1)) == None,
+							Error::<T>::ItemAlreadyInAuction
+						);
+						ensure!(
+							T::NFTHandler::is_transferable(&asset)?,
+							Error::<T>::NoPermissionToCreateAuction
+						);
+
+						match item {
+							ItemId::NFT(class_id, _) => {
+								Self::ensure_authorised_collection(owner, class_id, listing_level)?
+							}
+							ItemId::Estate(estate_id) => ensure!(
+								!T::EstateHandler::is_estate_leased(*estate_id)?,
+								Error::<T>::NoPermissionToCreateAuction
+							),
+							_ => {}
+						}
+
+						T::NFTHandler::set_lock_nft(asset, true)?;
+						<ItemsInAuction<T>>::insert(ItemId::NFT(asset.0, asset.1), true);
+					}
+					ItemId::StackableNFT(class_id, token_id, amount) => {
+						ensure!(
+							T::NFTHandler::get_free_stackable_nft_balance(owner, &(*class_id, *token_id)) >= *amount,
+							Error::<T>::NoPermissionToCreateAuction
+						);
+						Self::ensure_authorised_collection(owner, class_id, listing_level)?;
+
+						T::NFTHandler::reserve_stackable_nft_balance(owner, &(*class_id, *token_id), *amount)?;
+					}
+					ItemId::UndeployedLandBlock(undeployed_land_block_id) => {
+						ensure!(
+							Self::items_in_auction(item.clone()) == None,
+							Error::<T>::ItemAlreadyInAuction
+						);
+						ensure!(
+							T::EstateHandler::check_undeployed_land_block(owner, *undeployed_land_block_id)?,
+							Error::<T>::UndeployedLandBlockDoesNotExistOrNotAvailable
+						);
+
+						<ItemsInAuction<T>>::insert(item.clone(), true);
+					}
+					_ => return Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Unlock every item of a mixed bundle
+		fn unlock_bundle_items(owner: &T::AccountId, items: &[(ItemId<BalanceOf<T>>, BalanceOf<T>)]) -> DispatchResult {
+			for (item, _) in items {
+				match item {
+					ItemId::NFT(_, _) | ItemId::Estate(_) | ItemId::LandUnit(_, _) => {
+						let asset = Self::bundle_item_token(item)?;
+						T::NFTHandler::set_lock_nft(asset, false)?;
+						<ItemsInAuction<T>>::remove(ItemId::NFT(asset.0, asset.1));
+					}
+					ItemId::StackableNFT(class_id, token_id, amount) => {
+						T::NFTHandler::unreserve_stackable_nft_balance(owner, &(*class_id, *token_id), *amount)?;
+					}
+					ItemId::UndeployedLandBlock(_) => {
+						<ItemsInAuction<T>>::remove(item.clone());
+					}
+					_ => {}
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Transfer every item of a mixed bundle and collect royalty fee of each NFT from its share
+		/// of the price
+		fn transfer_bundle_items(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			items: &[(ItemId<BalanceOf<T>>, BalanceOf<T>)],
+			price: BalanceOf<T>,
+			currency_id: FungibleTokenId,
+		) -> DispatchResult {
+			let total_share = items
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |total, (_, share)| total.saturating_add(*share));
+
+			for (item, share) in items {
+				let item_price: BalanceOf<T> = if total_share.is_zero() {
+					Zero::zero()
+				} else {
+					Perbill::from_rational(*share, total_share) * price
+				};
+
+				match item {
+					ItemId::NFT(_, _) | ItemId::Estate(_) | ItemId::LandUnit(_, _) => {
+						let asset = Self::bundle_item_token(item)?;
+						Self::collect_royalty_fee(&item_price, from, &asset, currency_id)?;
+						T::NFTHandler::set_lock_nft(asset, false)?;
+						<ItemsInAuction<T>>::remove(ItemId::NFT(asset.0, asset.1));
+						T::NFTHandler::transfer_nft(from, to, &asset)?;
+					}
+					ItemId::StackableNFT(class_id, token_id, amount) => {
+						let asset = (*class_id, *token_id);
+						Self::collect_royalty_fee(&item_price, from, &asset, currency_id)?;
+						T::NFTHandler::unreserve_stackable_nft_balance(from, &asset, *amount)?;
+						T::NFTHandler::transfer_stackable_nft(from, to, &asset, *amount)?;
+					}
+					ItemId::UndeployedLandBlock(undeployed_land_block_id) => {
+						<ItemsInAuction<T>>::remove(item.clone());
+						T::EstateHandler::transfer_undeployed_land_block(from, to, *undeployed_land_block_id)?;
+					}
+					_ => return Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Pay for and transfer a mixed bundle to the high bidder, all or nothing
+		#[transactional]
+		fn settle_mixed_bundle(
+			auction_item: &AuctionItem<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+			high_bidder: &T::AccountId,
+			high_bid_price: BalanceOf<T>,
+			items: &[(ItemId<BalanceOf<T>>, BalanceOf<T>)],
+		) -> DispatchResult {
+			if auction_item.currency_id == FungibleTokenId::NativeToken(0) {
+				<T as Config>::Currency::transfer(
+					high_bidder,
+					&auction_item.recipient,
+					high_bid_price,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			} else {
+				T::FungibleTokenCurrency::transfer(
+					auction_item.currency_id,
+					high_bidder,
+					&auction_item.recipient,
+					high_bid_price.saturated_into(),
+				)?;
+			}
+
+			// Collect network commission fee
+			Self::collect_network_fee(&high_bid_price, &auction_item.recipient, auction_item.currency_id)?;
+
+			// Collect listing fee once
+			Self::collect_listing_fee(
+				&high_bid_price,
+				&auction_item.recipient,
+				auction_item.currency_id,
+				auction_item.listing_level.clone(),
+				auction_item.listing_fee,
+			)?;
+
+			Self::transfer_bundle_items(
+				&auction_item.recipient,
+				high_bidder,
+				items,
+				high_bid_price,
+				auction_item.currency_id,
+			)
+		}
+
+		/// Compute the commitment of a sealed bid
+		pub fn sealed_bid_commitment(
+			auction_id: AuctionId,
+			bidder: &T::AccountId,
+			value: BalanceOf<T>,
+			salt: &[u8],
+		) -> [u8; 32] {
+			(auction_id, bidder, value, salt).using_encoded(blake2_256)
+		}
+
+		/// Refund deposits of revealed sealed bids, slash deposits of unrevealed ones to the
+		/// treasury and return the winner with the settled price. Only the settled price of the
+		/// winner stays reserved.
+		fn settle_sealed_bids(
+			auction_id: AuctionId,
+			auction_item: &AuctionItem<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+			sealed_bid_auction: SealedBidAuctionInfo<BalanceOf<T>, BlockNumberFor<T>>,
+			winner: Option<(T::AccountId, BalanceOf<T>)>,
+		) -> Option<(T::AccountId, BalanceOf<T>)> {
+			let treasury = T::MetaverseInfoSource::get_network_treasury();
+			let settled_winner = winner.map(|(high_bidder, high_bid_price)| {
+				let price = match sealed_bid_auction.settlement {
+					SealedBidSettlement::FirstPrice => high_bid_price,
+					SealedBidSettlement::SecondPrice => sealed_bid_auction
+						.second_price
+						.unwrap_or(auction_item.initial_amount)
+						.max(auction_item.initial_amount),
+				};
+				(high_bidder, price)
+			});
+
+			// Commits are bounded by `MaxSealedBidsPerAuction`, so all bids are settled at once
+			SealedBidCount::<T>::remove(auction_id);
+			for (bidder, sealed_bid) in
+				SealedBids::<T>::drain_prefix(auction_id).take(T::MaxSealedBidsPerAuction::get() as usize)
+			{
+				if sealed_bid.revealed.is_none() {
+					if auction_item.currency_id == FungibleTokenId::NativeToken(0) {
+						let _ = <T as Config>::Currency::repatriate_reserved(
+							&bidder,
+							&treasury,
+							sealed_bid.deposit,
+							BalanceStatus::Free,
+						);
+					} else {
+						let _ = T::FungibleTokenCurrency::repatriate_reserved(
+							auction_item.currency_id,
+							&bidder,
+							&treasury,
+							sealed_bid.deposit.saturated_into(),
+							BalanceStatus::Free,
+						);
+					}
+					Self::deposit_event(Event::SealedBidDepositSlashed(auction_id, bidder, sealed_bid.deposit));
+					continue;
+				}
+
+				let mut refund = sealed_bid.deposit;
+				if let Some((high_bidder, price)) = &settled_winner {
+					if high_bidder == &bidder {
+						refund = refund.saturating_sub(*price);
+					}
+				}
+
+				if auction_item.currency_id == FungibleTokenId::NativeToken(0) {
+					<T as Config>::Currency::unreserve(&bidder, refund);
+				} else {
+					T::FungibleTokenCurrency::unreserve(auction_item.currency_id, &bidder, refund.saturated_into());
+				}
+			}
+
+			settled_winner
+		}
+
 		fn extend_auction_end_time(id: AuctionId, new_end_block: BlockNumberFor<T>) -> DispatchResult {
 			<AuctionItems<T>>::try_mutate_exists(id, |auction_item| -> DispatchResult {
 				let auction_item = auction_item.as_mut().ok_or(Error::<T>::AuctionDoesNotExist)?;
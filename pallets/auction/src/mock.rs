@@ -1,7 +1,8 @@
 #![cfg(test)]
 
-use frame_support::traits::{Contains, EqualPrivilegeOnly, InstanceFilter, Nothing};
+use frame_support::traits::{AsEnsureOriginWithArg, Contains, EqualPrivilegeOnly, InstanceFilter, Nothing};
 use frame_support::{construct_runtime, pallet_prelude::Hooks, parameter_types, PalletId};
+use frame_system::pallet_prelude::BlockNumberFor;
 use frame_system::Call as SystemCall;
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
@@ -11,12 +12,14 @@ use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, IdentifyAccount, Veri
 use sp_runtime::{traits::IdentityLookup, MultiSignature, Perbill};
 
 use auction_manager::{CheckAuctionItemHandler, ListingLevel};
-use core_primitives::{MetaverseInfo, MetaverseMetadata, MetaverseTrait, NftAssetData, NftClassData};
+use core_primitives::{
+	CredentialTrait, MetaverseInfo, MetaverseMetadata, MetaverseTrait, NftAssetData, NftClassData, PriceProvider,
+};
 use primitives::{
-	continuum::MapTrait, estate::Estate, Amount, AuctionId, ClassId, EstateId, FungibleTokenId, MapSpotId,
-	UndeployedLandBlockId,
+	continuum::MapTrait, estate::Estate, Amount, AuctionId, ClassId, EstateId, FungibleTokenId, MapSpotId, Price,
+	TokenId, UndeployedLandBlockId,
 };
-use sp_runtime::BuildStorage;
+use sp_runtime::{traits::One, BuildStorage, FixedPointNumber};
 
 use crate as auction;
 
@@ -37,6 +40,7 @@ pub const CLASS_ID: u32 = 0;
 pub const COLLECTION_ID: u64 = 0;
 pub const ALICE_METAVERSE_ID: MetaverseId = 1;
 pub const BOB_METAVERSE_ID: MetaverseId = 2;
+pub const CREDENTIAL_CLASS_ID: ClassId = 20;
 
 pub const ESTATE_ID_EXIST: EstateId = 0;
 pub const ESTATE_ID_EXIST_1: EstateId = 1;
@@ -44,6 +48,8 @@ pub const ESTATE_ID_NOT_EXIST: EstateId = 99;
 pub const LAND_UNIT_EXIST: (i32, i32) = (0, 0);
 pub const LAND_UNIT_EXIST_1: (i32, i32) = (1, 1);
 pub const LAND_UNIT_NOT_EXIST: (i32, i32) = (99, 99);
+pub const ESTATE_TOKEN: (ClassId, TokenId) = (CLASS_ID, 1);
+pub const LAND_UNIT_TOKEN: (ClassId, TokenId) = (CLASS_ID, 2);
 
 pub const UNDEPLOYED_LAND_BLOCK_ID_EXIST: UndeployedLandBlockId = 4;
 pub const UNDEPLOYED_LAND_BLOCK_ID_NOT_EXIST: UndeployedLandBlockId = 5;
@@ -75,7 +81,7 @@ impl frame_system::Config for Runtime {
 }
 
 parameter_types! {
-	pub const ExistentialDeposit: u64 = 1;
+	pub static ExistentialDeposit: Balance = 1;
 }
 
 impl pallet_balances::Config for Runtime {
@@ -174,6 +180,19 @@ impl Estate<AccountId32> for EstateHandler {
 	fn is_estate_leased(_estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(false)
 	}
+	fn get_estate_token(estate_id: EstateId) -> Option<(ClassId, TokenId)> {
+		match estate_id {
+			ESTATE_ID_EXIST => Some(ESTATE_TOKEN),
+			_ => None,
+		}
+	}
+
+	fn get_land_unit_token(_metaverse_id: MetaverseId, coordinate: (i32, i32)) -> Option<(ClassId, TokenId)> {
+		match coordinate {
+			LAND_UNIT_EXIST => Some(LAND_UNIT_TOKEN),
+			_ => None,
+		}
+	}
 }
 
 pub struct Handler;
@@ -240,6 +259,16 @@ parameter_types! {
 	pub const OfferDuration: BlockNumber = 10; // Default 10
 	pub const MinimumListingPrice: Balance = 1;
 	pub const AntiSnipeDuration: BlockNumber = 5; // Default 5
+	pub const SealedBidRevealPeriod: BlockNumber = 10;
+	pub const MaxSealedBidsPerAuction: u32 = 3;
+	pub const MaxDropPhases: u32 = 3;
+	pub const MaxDropAllowlistSize: u32 = 3;
+	pub const MaxDropRecordRemovals: u32 = 2;
+	pub const MaxSwapDuration: BlockNumber = 20;
+	pub const MaxSwapItems: u32 = 3;
+	pub const MaxSwapRoyaltyFees: u32 = 4;
+	pub const SwapDeposit: Balance = 2;
+	pub const SignedOrderNonceWindow: u32 = 3;
 }
 
 pub struct MetaverseInfoSource {}
@@ -263,8 +292,12 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 		None
 	}
 
-	fn get_metaverse_token(_metaverse_id: u64) -> Option<FungibleTokenId> {
-		None
+	fn get_metaverse_token(metaverse_id: u64) -> Option<FungibleTokenId> {
+		if metaverse_id == ALICE_METAVERSE_ID {
+			Some(FungibleTokenId::FungibleToken(1))
+		} else {
+			None
+		}
 	}
 
 	fn update_metaverse_token(_metaverse_id: u64, _currency_id: FungibleTokenId) -> Result<(), DispatchError> {
@@ -310,6 +343,35 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	}
 }
 
+/// Credentials of the Alice metaverse, only held by Bob
+pub struct MockCredentials;
+
+impl CredentialTrait<AccountId> for MockCredentials {
+	fn get_credential_issuer(class_id: ClassId) -> Option<MetaverseId> {
+		match class_id {
+			CREDENTIAL_CLASS_ID => Some(ALICE_METAVERSE_ID),
+			_ => None,
+		}
+	}
+
+	fn has_valid_credential(who: &AccountId, class_id: ClassId) -> bool {
+		*who == AccountId32::new([2; 32]) && class_id == CREDENTIAL_CLASS_ID
+	}
+}
+
+/// Prices in native token, the social token is worth 1/50 native token
+pub struct MockPriceProvider;
+
+impl PriceProvider for MockPriceProvider {
+	fn get_price(currency_id: FungibleTokenId) -> Option<Price> {
+		match currency_id {
+			FungibleTokenId::NativeToken(0) => Some(Price::one()),
+			FungibleTokenId::FungibleToken(1) => Some(Price::saturating_from_rational(1, 50)),
+			_ => None,
+		}
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AuctionTimeToClose = AuctionTimeToClose;
@@ -318,6 +380,7 @@ impl Config for Runtime {
 	type ContinuumHandler = Continuumm;
 	type FungibleTokenCurrency = Tokens;
 	type MetaverseInfoSource = MetaverseInfoSource;
+	type MetaverseGovernanceOrigin = AsEnsureOriginWithArg<EnsureRoot<AccountId>>;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = EstateHandler;
 	type MaxFinality = MaxFinality;
@@ -328,8 +391,22 @@ impl Config for Runtime {
 	type WeightInfo = ();
 	type OfferDuration = OfferDuration;
 	type MinimumListingPrice = MinimumListingPrice;
+	type PriceProvider = MockPriceProvider;
 	type AntiSnipeDuration = AntiSnipeDuration;
 	type StorageDepositFee = StorageDepositFee;
+	type SealedBidRevealPeriod = SealedBidRevealPeriod;
+	type MaxSealedBidsPerAuction = MaxSealedBidsPerAuction;
+	type MaxDropPhases = MaxDropPhases;
+	type MaxDropAllowlistSize = MaxDropAllowlistSize;
+	type MaxDropRecordRemovals = MaxDropRecordRemovals;
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	type Credentials = MockCredentials;
+	type MaxSwapDuration = MaxSwapDuration;
+	type MaxSwapItems = MaxSwapItems;
+	type MaxSwapRoyaltyFees = MaxSwapRoyaltyFees;
+	type SwapDeposit = SwapDeposit;
+	type SignedOrderNonceWindow = SignedOrderNonceWindow;
 }
 
 pub type AdaptedBasicCurrency = currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
@@ -369,10 +446,26 @@ parameter_types! {
 	pub NftPalletId: PalletId = PalletId(*b"bit/bNFT");
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 2000;
+	pub MaxRoyaltyRecipients: u32 = 3;
+	pub MaxEquipmentDepth: u32 = 3;
+	pub MaxEquippedItems: u32 = 10;
+	pub MaxCraftingIngredients: u32 = 5;
+	pub MaxAttributeHistory: u32 = 3;
+	pub MaxTokenAttributes: u32 = 4;
 	pub MaxMetadata: u32 = 10;
 	pub StorageDepositFee: Balance = 1;
 }
 
+pub struct MockRandomness;
+impl frame_support::traits::Randomness<H256, BlockNumberFor<Runtime>> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumberFor<Runtime>) {
+		(
+			<BlakeTwo256 as sp_runtime::traits::Hash>::hash(subject),
+			frame_system::Pallet::<Runtime>::block_number(),
+		)
+	}
+}
+
 impl pallet_nft::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -390,6 +483,14 @@ impl pallet_nft::Config for Runtime {
 	type StorageDepositFee = StorageDepositFee;
 	type OffchainSignature = Signature;
 	type OffchainPublic = AccountPublic;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxEquipmentDepth = MaxEquipmentDepth;
+	type MaxEquippedItems = MaxEquippedItems;
+	type MaxCraftingIngredients = MaxCraftingIngredients;
+	type Randomness = MockRandomness;
+	type MaxAttributeHistory = MaxAttributeHistory;
+	type MaxTokenAttributes = MaxTokenAttributes;
+	type ExecutableCallFilter = ProxyType;
 }
 
 parameter_types! {
@@ -506,6 +607,7 @@ impl ExtBuilder {
 			balances: vec![
 				([1; 32].into(), FungibleTokenId::MiningResource(0), 10000),
 				([2; 32].into(), FungibleTokenId::MiningResource(0), 5000),
+				([2; 32].into(), FungibleTokenId::FungibleToken(1), 5000),
 			],
 		}
 		.assimilate_storage(&mut t)
@@ -596,6 +698,24 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn sealed_bid_commit_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_commitment: [u8; 32],
+		_deposit: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn sealed_bid_reveal_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_value: Self::Balance,
+		_salt: Vec<u8>,
+	) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
 	fn local_auction_bid_handler(
 		_now: u64,
 		_id: u64,
@@ -1,7 +1,8 @@
 #![cfg(test)]
 
-use frame_support::{assert_noop, assert_ok};
-use sp_core::crypto::AccountId32;
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight, BoundedVec};
+use sp_core::{crypto::AccountId32, Pair};
+use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
 use sp_std::collections::btree_map::BTreeMap;
 
 use auction_manager::ListingLevel;
@@ -35,6 +36,10 @@ fn init_test_nft(owner: RuntimeOrigin) {
 	assert_ok!(NFTModule::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
 }
 
+fn bounded<T>(items: Vec<T>) -> BoundedVec<T, MaxSwapItems> {
+	items.try_into().unwrap()
+}
+
 fn init_test_stackable_nft(owner: RuntimeOrigin) {
 	//Create group collection before class
 	assert_ok!(NFTModule::create_group(RuntimeOrigin::root(), vec![1], vec![1]));
@@ -1599,6 +1604,124 @@ fn on_finalize_with_listing_fee_should_work() {
 	});
 }
 
+#[test]
+// Local auction paid in the metaverse social token works
+fn on_finalize_local_auction_with_social_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		let bidder = RuntimeOrigin::signed(BOB);
+		let social_token = FungibleTokenId::FungibleToken(1);
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			ALICE,
+			100,
+			0,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			Perbill::from_percent(10u32),
+			social_token
+		));
+		assert_ok!(AuctionModule::bid(bidder, 0, 100));
+		assert_eq!(Tokens::accounts(BOB, social_token).reserved, 100);
+		run_to_block(102);
+		assert_eq!(NFTModule::check_ownership(&BOB, &(0, 0)), Ok(true));
+		assert_eq!(Tokens::accounts(BOB, social_token).free, 4900);
+		// Alice receives 100 - 10 (listing fee) - 1 (1% network fee) - 1 (1% royalty fee)
+		assert_eq!(Tokens::accounts(ALICE, social_token).free, 88);
+		// Listing fee and network fee are paid in the social token
+		let treasury: AccountId = MetaverseInfoSource::get_metaverse_treasury(ALICE_METAVERSE_ID);
+		assert_eq!(Tokens::accounts(treasury, social_token).free, 11);
+		let event = mock::RuntimeEvent::AuctionModule(crate::Event::AuctionFinalized(0, BOB, 100));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn create_auction_in_social_token_should_use_minimum_listing_price_in_reference_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		let social_token = FungibleTokenId::FungibleToken(1);
+		init_test_nft(owner.clone());
+		// The minimum listing price of 1 native token is worth 50 social tokens
+		assert_noop!(
+			AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::NFT(0, 0),
+				None,
+				ALICE,
+				49,
+				0,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				Perbill::from_percent(10u32),
+				social_token
+			),
+			Error::<Runtime>::ListingPriceIsBelowMinimum
+		);
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			ALICE,
+			50,
+			0,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			Perbill::from_percent(10u32),
+			social_token
+		));
+	});
+}
+
+#[test]
+// Local listings can be priced in the social token of their metaverse only
+fn create_new_auction_in_social_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		let social_token = FungibleTokenId::FungibleToken(1);
+		init_test_nft(owner.clone());
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				owner.clone(),
+				ItemId::NFT(0, 0),
+				1000,
+				102,
+				ListingLevel::Local(BOB_METAVERSE_ID),
+				social_token,
+			),
+			Error::<Runtime>::InvalidListingCurrency
+		);
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				owner.clone(),
+				ItemId::UndeployedLandBlock(UNDEPLOYED_LAND_BLOCK_ID_EXIST),
+				1000,
+				102,
+				ListingLevel::Global,
+				social_token,
+			),
+			Error::<Runtime>::InvalidListingCurrency
+		);
+		assert_ok!(AuctionModule::create_new_auction(
+			owner,
+			ItemId::NFT(0, 0),
+			1000,
+			102,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			social_token,
+		));
+
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 1000));
+		assert_eq!(Tokens::accounts(BOB, social_token).reserved, 1000);
+		run_to_block(103);
+		assert_eq!(NFTModule::check_ownership(&BOB, &(0, 0)), Ok(true));
+		assert_eq!(Tokens::accounts(BOB, social_token).free, 4000);
+		// 1% listing fee and 1% network fee are collected in the social token
+		let treasury: AccountId = MetaverseInfoSource::get_metaverse_treasury(ALICE_METAVERSE_ID);
+		assert_eq!(Tokens::accounts(treasury, social_token).free, 20);
+	});
+}
+
 #[test]
 fn auction_bundle_should_update_new_price_according_new_bid() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -2004,16 +2127,17 @@ fn accept_offer_should_fail() {
 fn accept_offer_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		init_test_nft(RuntimeOrigin::signed(ALICE));
-		assert_ok!(AuctionModule::make_offer(RuntimeOrigin::signed(BOB), (0, 0), 150));
-		assert_eq!(Balances::free_balance(BOB), 350);
+		assert_ok!(AuctionModule::make_offer(RuntimeOrigin::signed(BOB), (0, 0), 200));
+		assert_eq!(Balances::free_balance(BOB), 300);
 		assert_ok!(AuctionModule::accept_offer(RuntimeOrigin::signed(ALICE), (0, 0), BOB));
 
 		let event = mock::RuntimeEvent::AuctionModule(crate::Event::NftOfferAccepted(0, 0, BOB));
 		assert_eq!(last_event(), event);
 
 		assert_eq!(Offers::<Runtime>::get((0, 0), BOB), None);
-		assert_eq!(Balances::free_balance(BOB), 350);
-		assert_eq!(Balances::free_balance(ALICE), 100146);
+		assert_eq!(Balances::free_balance(BOB), 300);
+		// The 1% royalty fee of the class is paid from the offer
+		assert_eq!(Balances::free_balance(ALICE), 100194);
 	});
 }
 
@@ -2088,6 +2212,110 @@ fn finalize_auction_should_fail() {
 	});
 }
 
+#[test]
+fn auction_finalization_should_be_bounded_by_max_finality() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(NFTModule::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 4));
+
+		for token_id in 0..5 {
+			if token_id == 3 {
+				run_to_block(2);
+			}
+			assert_ok!(AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::NFT(0, token_id),
+				None,
+				BOB,
+				100,
+				0,
+				ListingLevel::Global,
+				Perbill::from_percent(0u32),
+				FungibleTokenId::NativeToken(0)
+			));
+		}
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(ALICE), 3, 100));
+
+		// Move the auctions created later to the same end block
+		for auction_id in 3..5 {
+			let mut auction = AuctionModule::auctions(auction_id).unwrap();
+			auction.end = Some(101);
+			assert_ok!(<AuctionModule as Auction<AccountId, BlockNumber>>::update_auction(
+				auction_id, auction
+			));
+		}
+
+		run_to_block(101);
+
+		// Only MaxFinality auctions are finalized, the others are queued
+		let pending_auctions: Vec<AuctionId> = PendingFinalization::<Runtime>::iter_keys().collect();
+		assert_eq!(pending_auctions.len(), 2);
+		assert_eq!((0..5).filter(|id| AuctionModule::auctions(id).is_some()).count(), 2);
+
+		let pending_auction = pending_auctions[0];
+		assert_noop!(
+			AuctionModule::bid(RuntimeOrigin::signed(ALICE), pending_auction, 200),
+			Error::<Runtime>::AuctionIsPendingFinalization
+		);
+		assert_noop!(
+			AuctionModule::cancel_listing(RuntimeOrigin::signed(BOB), pending_auction),
+			Error::<Runtime>::AuctionIsPendingFinalization
+		);
+
+		// Anyone can finalize a queued auction
+		assert_ok!(AuctionModule::finalize_auction(
+			RuntimeOrigin::signed(ALICE),
+			pending_auction
+		));
+		assert_eq!(AuctionModule::auctions(pending_auction), None);
+		assert_eq!(AuctionModule::pending_finalization(pending_auction), None);
+
+		// The remaining auction is finalized with the idle block weight
+		AuctionModule::on_idle(101, Weight::MAX);
+		assert_eq!(PendingFinalization::<Runtime>::iter_keys().count(), 0);
+		assert_eq!((0..5).filter(|id| AuctionModule::auctions(id).is_some()).count(), 0);
+		assert_eq!(NFTModule::check_ownership(&ALICE, &(0, 3)), Ok(true));
+	});
+}
+
+#[test]
+fn queued_auctions_should_be_finalized_oldest_first() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(NFTModule::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 6));
+
+		// Four auctions end at block 101 and three at block 102
+		for token_id in 0..7 {
+			if token_id == 4 {
+				run_to_block(2);
+			}
+			assert_ok!(AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::NFT(0, token_id),
+				None,
+				BOB,
+				100,
+				0,
+				ListingLevel::Global,
+				Perbill::from_percent(0u32),
+				FungibleTokenId::NativeToken(0)
+			));
+		}
+
+		run_to_block(101);
+		assert_eq!(PendingFinalization::<Runtime>::iter_keys().count(), 1);
+
+		// The auction left from block 101 is finalized before the ones ended at block 102
+		run_to_block(102);
+		let pending_auctions: Vec<AuctionId> = PendingFinalization::<Runtime>::iter_keys().collect();
+		assert_eq!(pending_auctions.len(), 1);
+		assert_eq!(AuctionModule::pending_finalization(pending_auctions[0]), Some(102));
+		assert_eq!((0..4).filter(|id| AuctionModule::auctions(id).is_some()).count(), 0);
+	});
+}
+
 #[test]
 fn cancel_listing_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -2160,3 +2388,1313 @@ fn cancel_listing_should_fail() {
 		);
 	});
 }
+
+#[test]
+fn authorise_metaverse_collection_with_metaverse_origin_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::authorise_metaverse_collection(RuntimeOrigin::signed(BOB), 0, ALICE_METAVERSE_ID),
+			Error::<Runtime>::NoPermissionToAuthoriseCollection
+		);
+		assert_ok!(AuctionModule::authorise_metaverse_collection(
+			RuntimeOrigin::root(),
+			0,
+			ALICE_METAVERSE_ID
+		));
+		assert_eq!(
+			AuctionModule::authorised_metaverse_collection(ALICE_METAVERSE_ID, 0),
+			Some(())
+		);
+		assert_ok!(AuctionModule::remove_authorise_metaverse_collection(
+			RuntimeOrigin::root(),
+			0,
+			ALICE_METAVERSE_ID
+		));
+		assert_eq!(
+			AuctionModule::authorised_metaverse_collection(ALICE_METAVERSE_ID, 0),
+			None
+		);
+	});
+}
+
+#[test]
+// Vickrey auction settles with the second highest revealed bid
+fn sealed_bid_second_price_auction_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::SealedBid(SealedBidSettlement::SecondPrice),
+			ItemId::NFT(0, 0),
+			Some(21),
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+		// Bids are revealed until the end of the reveal period
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(31));
+		assert_eq!(AuctionModule::sealed_bid_auctions(0).unwrap().reveal_start, 21);
+
+		let alice_commitment = AuctionModule::sealed_bid_commitment(0, &ALICE, 300, &[1]);
+		let charlie_commitment = AuctionModule::sealed_bid_commitment(0, &NO_METAVERSE_OWNER, 200, &[2]);
+		assert_ok!(AuctionModule::commit_bid(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			alice_commitment,
+			400
+		));
+		assert_ok!(AuctionModule::commit_bid(
+			RuntimeOrigin::signed(NO_METAVERSE_OWNER),
+			0,
+			charlie_commitment,
+			200
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 400);
+		assert_eq!(Balances::reserved_balance(NO_METAVERSE_OWNER), 200);
+		// No bid is known before the reveal phase
+		assert_eq!(AuctionModule::auctions(0).unwrap().bid, None);
+
+		assert_noop!(
+			AuctionModule::reveal_bid(RuntimeOrigin::signed(ALICE), 0, 300, vec![1]),
+			Error::<Runtime>::SealedBidRevealPhaseHasNotStarted
+		);
+
+		run_to_block(21);
+		assert_ok!(AuctionModule::reveal_bid(RuntimeOrigin::signed(ALICE), 0, 300, vec![1]));
+		assert_ok!(AuctionModule::reveal_bid(
+			RuntimeOrigin::signed(NO_METAVERSE_OWNER),
+			0,
+			200,
+			vec![2]
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::SealedBidRevealed(0, NO_METAVERSE_OWNER, 200))
+		);
+		assert_eq!(AuctionModule::auctions(0).unwrap().bid, Some((ALICE, 300)));
+		assert_eq!(AuctionModule::sealed_bid_auctions(0).unwrap().second_price, Some(200));
+
+		let alice_free_balance = Balances::free_balance(ALICE);
+		let charlie_free_balance = Balances::free_balance(NO_METAVERSE_OWNER);
+		run_to_block(32);
+
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::sealed_bid_auctions(0), None);
+		assert_eq!(SealedBids::<Runtime>::iter_prefix(0).count(), 0);
+		assert_eq!(NFTModule::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		// Winner pays the second highest bid and the rest of the deposit is refunded
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), alice_free_balance + 400 - 200);
+		assert_eq!(Balances::reserved_balance(NO_METAVERSE_OWNER), 0);
+		assert_eq!(Balances::free_balance(NO_METAVERSE_OWNER), charlie_free_balance + 200);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 200))
+		);
+	});
+}
+
+#[test]
+// Unrevealed sealed bid deposit is slashed to the treasury
+fn sealed_bid_first_price_auction_should_slash_unrevealed_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::SealedBid(SealedBidSettlement::FirstPrice),
+			ItemId::NFT(0, 0),
+			Some(21),
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+
+		let alice_commitment = AuctionModule::sealed_bid_commitment(0, &ALICE, 250, &[1]);
+		let charlie_commitment = AuctionModule::sealed_bid_commitment(0, &NO_METAVERSE_OWNER, 300, &[2]);
+		assert_ok!(AuctionModule::commit_bid(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			alice_commitment,
+			300
+		));
+		assert_ok!(AuctionModule::commit_bid(
+			RuntimeOrigin::signed(NO_METAVERSE_OWNER),
+			0,
+			charlie_commitment,
+			300
+		));
+
+		run_to_block(25);
+		assert_ok!(AuctionModule::reveal_bid(RuntimeOrigin::signed(ALICE), 0, 250, vec![1]));
+
+		let charlie_free_balance = Balances::free_balance(NO_METAVERSE_OWNER);
+		run_to_block(32);
+
+		assert_eq!(NFTModule::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(NO_METAVERSE_OWNER), 0);
+		assert_eq!(Balances::free_balance(NO_METAVERSE_OWNER), charlie_free_balance);
+		System::assert_has_event(RuntimeEvent::AuctionModule(crate::Event::SealedBidDepositSlashed(
+			0,
+			NO_METAVERSE_OWNER,
+			300,
+		)));
+		// Winner pays its own bid
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 250))
+		);
+	});
+}
+
+#[test]
+fn sealed_bid_spot_auction_should_settle_highest_revealed_bid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::SealedBid(SealedBidSettlement::FirstPrice),
+			ItemId::Spot((0, 0), ALICE_METAVERSE_ID),
+			Some(21),
+			ALICE,
+			100,
+			0,
+			ListingLevel::Global,
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+
+		let bob_commitment = AuctionModule::sealed_bid_commitment(0, &BOB, 250, &[1]);
+		let charlie_commitment = AuctionModule::sealed_bid_commitment(0, &NO_METAVERSE_OWNER, 200, &[2]);
+		assert_ok!(
+			<AuctionModule as Auction<AccountId, BlockNumber>>::sealed_bid_commit_handler(BOB, 0, bob_commitment, 300)
+		);
+		assert_ok!(
+			<AuctionModule as Auction<AccountId, BlockNumber>>::sealed_bid_commit_handler(
+				NO_METAVERSE_OWNER,
+				0,
+				charlie_commitment,
+				300
+			)
+		);
+		assert_eq!(AuctionModule::sealed_bid_count(0), 2);
+
+		run_to_block(25);
+		assert_ok!(<AuctionModule as Auction<AccountId, BlockNumber>>::sealed_bid_reveal_handler(BOB, 0, 250, vec![1]));
+		assert_ok!(
+			<AuctionModule as Auction<AccountId, BlockNumber>>::sealed_bid_reveal_handler(
+				NO_METAVERSE_OWNER,
+				0,
+				200,
+				vec![2]
+			)
+		);
+
+		run_to_block(32);
+
+		// Winner pays its own bid, losing bidder gets its whole deposit back
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 250);
+		assert_eq!(Balances::reserved_balance(NO_METAVERSE_OWNER), 0);
+		assert_eq!(Balances::free_balance(NO_METAVERSE_OWNER), 500);
+		assert_eq!(AuctionModule::sealed_bid_count(0), 0);
+		assert_eq!(SealedBids::<Runtime>::iter_prefix(0).count(), 0);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::AuctionFinalized(0, BOB, 250))
+		);
+	});
+}
+
+#[test]
+fn sealed_bid_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::SealedBid(SealedBidSettlement::FirstPrice),
+			ItemId::NFT(0, 0),
+			Some(21),
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+
+		// Open bids are not accepted
+		assert_noop!(
+			AuctionModule::bid(RuntimeOrigin::signed(ALICE), 0, 200),
+			Error::<Runtime>::InvalidAuctionType
+		);
+
+		let commitment = AuctionModule::sealed_bid_commitment(0, &ALICE, 500, &[1]);
+		assert_noop!(
+			AuctionModule::commit_bid(owner.clone(), 0, commitment, 500),
+			Error::<Runtime>::CannotBidOnOwnAuction
+		);
+		// Deposit has to cover the reserve price
+		assert_noop!(
+			AuctionModule::commit_bid(RuntimeOrigin::signed(ALICE), 0, commitment, 50),
+			Error::<Runtime>::InvalidBidPrice
+		);
+		assert_ok!(AuctionModule::commit_bid(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			commitment,
+			400
+		));
+		assert_noop!(
+			AuctionModule::commit_bid(RuntimeOrigin::signed(ALICE), 0, commitment, 400),
+			Error::<Runtime>::SealedBidAlreadyCommitted
+		);
+		// Committed listing can not be cancelled
+		assert_noop!(
+			AuctionModule::cancel_listing(owner.clone(), 0),
+			Error::<Runtime>::AuctionAlreadyStartedOrBid
+		);
+
+		run_to_block(21);
+		assert_noop!(
+			AuctionModule::commit_bid(RuntimeOrigin::signed(NO_METAVERSE_OWNER), 0, commitment, 400),
+			Error::<Runtime>::SealedBidCommitPhaseIsOver
+		);
+		assert_noop!(
+			AuctionModule::reveal_bid(RuntimeOrigin::signed(ALICE), 0, 500, vec![2]),
+			Error::<Runtime>::SealedBidRevealDoesNotMatch
+		);
+		// Bid can not exceed the deposit
+		assert_noop!(
+			AuctionModule::reveal_bid(RuntimeOrigin::signed(ALICE), 0, 500, vec![1]),
+			Error::<Runtime>::InvalidBidPrice
+		);
+		assert_noop!(
+			AuctionModule::reveal_bid(RuntimeOrigin::signed(NO_METAVERSE_OWNER), 0, 500, vec![1]),
+			Error::<Runtime>::SealedBidDoesNotExist
+		);
+
+		run_to_block(31);
+		assert_noop!(
+			AuctionModule::reveal_bid(RuntimeOrigin::signed(ALICE), 0, 500, vec![1]),
+			Error::<Runtime>::AuctionDoesNotExist
+		);
+	});
+}
+
+#[test]
+// Auction finalize with mixed bundle of nft, estate and undeployed land block works
+fn on_finalize_with_mixed_bundle_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		let bidder = RuntimeOrigin::signed(BOB);
+		init_test_nft(owner.clone());
+		init_test_nft(owner.clone());
+
+		let items = vec![
+			(ItemId::NFT(0, 0), 100),
+			(ItemId::Estate(ESTATE_ID_EXIST), 200),
+			(ItemId::UndeployedLandBlock(UNDEPLOYED_LAND_BLOCK_ID_EXIST), 100),
+		];
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::MixedBundle(items.clone()),
+			None,
+			ALICE,
+			200,
+			0,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+		// every item of the bundle is locked
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::MixedBundle(items.clone())),
+			Some(true)
+		);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true));
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::NFT(ESTATE_TOKEN.0, ESTATE_TOKEN.1)),
+			Some(true)
+		);
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::UndeployedLandBlock(UNDEPLOYED_LAND_BLOCK_ID_EXIST)),
+			Some(true)
+		);
+		assert_eq!(NFTModule::is_transferable(&(0, 0)), Ok(false));
+		assert_eq!(NFTModule::is_transferable(&ESTATE_TOKEN), Ok(false));
+
+		assert_ok!(AuctionModule::bid(bidder, 0, 400));
+		let alice_balance = Balances::free_balance(ALICE);
+		run_to_block(102);
+		assert_eq!(AuctionModule::auctions(0), None);
+		// check account received assets
+		assert_eq!(NFTModule::check_ownership(&BOB, &(0, 0)), Ok(true));
+		assert_eq!(NFTModule::check_ownership(&BOB, &ESTATE_TOKEN), Ok(true));
+		assert_eq!(NFTModule::is_transferable(&(0, 0)), Ok(true));
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::UndeployedLandBlock(UNDEPLOYED_LAND_BLOCK_ID_EXIST)),
+			None
+		);
+		// Cost breakdown 400 - 4 (1% network fee) - 1 (royalty of nft) - 2 (royalty of estate)
+		// plus 1 unit network reserve fee returned
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 394);
+		let event = mock::RuntimeEvent::AuctionModule(crate::Event::AuctionFinalized(0, BOB, 400));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+// Cancel listing of mixed bundle unlocks every item
+fn cancel_listing_with_mixed_bundle_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		init_test_nft(owner.clone());
+		init_test_nft(owner.clone());
+		init_test_nft(owner.clone());
+
+		let items = vec![
+			(ItemId::NFT(0, 0), 50),
+			(ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID), 50),
+		];
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::MixedBundle(items.clone()),
+			None,
+			ALICE,
+			100,
+			0,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_eq!(NFTModule::is_transferable(&LAND_UNIT_TOKEN), Ok(false));
+
+		assert_ok!(AuctionModule::cancel_listing(owner.clone(), 0));
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::MixedBundle(items)), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::NFT(LAND_UNIT_TOKEN.0, LAND_UNIT_TOKEN.1)),
+			None
+		);
+		assert_eq!(NFTModule::is_transferable(&(0, 0)), Ok(true));
+		assert_eq!(NFTModule::is_transferable(&LAND_UNIT_TOKEN), Ok(true));
+	});
+}
+
+#[test]
+// Creating mixed bundle listing with invalid items does not work
+fn create_mixed_bundle_auction_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		init_test_nft(owner.clone());
+		init_test_nft(owner.clone());
+
+		assert_noop!(
+			AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::MixedBundle(vec![]),
+				None,
+				ALICE,
+				100,
+				0,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				Perbill::from_percent(0u32),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::AssetDoesNotExist
+		);
+
+		assert_noop!(
+			AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::MixedBundle(vec![(ItemId::NFT(0, 0), 20); 6]),
+				None,
+				ALICE,
+				100,
+				0,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				Perbill::from_percent(0u32),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ExceedBundleLimit
+		);
+
+		assert_noop!(
+			AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::MixedBundle(vec![
+					(ItemId::NFT(0, 0), 50),
+					(ItemId::Spot((0, 0), ALICE_METAVERSE_ID), 50)
+				]),
+				None,
+				ALICE,
+				100,
+				0,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				Perbill::from_percent(0u32),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::AuctionTypeIsNotSupported
+		);
+
+		assert_noop!(
+			AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::MixedBundle(vec![(ItemId::NFT(0, 0), 50), (ItemId::Estate(ESTATE_ID_NOT_EXIST), 50)]),
+				None,
+				ALICE,
+				100,
+				0,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				Perbill::from_percent(0u32),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::EstateDoesNotExist
+		);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			ALICE,
+			100,
+			0,
+			ListingLevel::Global,
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+
+		assert_noop!(
+			AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::MixedBundle(vec![(ItemId::NFT(0, 1), 50), (ItemId::NFT(0, 0), 50)]),
+				None,
+				ALICE,
+				100,
+				0,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				Perbill::from_percent(0u32),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemAlreadyInAuction
+		);
+	});
+}
+
+#[test]
+fn create_scheduled_listing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(RuntimeOrigin::signed(ALICE));
+
+		assert_noop!(
+			AuctionModule::create_scheduled_listing(
+				RuntimeOrigin::signed(ALICE),
+				AuctionType::BuyNow,
+				ItemId::NFT(0, 0),
+				100,
+				0,
+				20,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::InvalidListingStartTime
+		);
+		assert_noop!(
+			AuctionModule::create_scheduled_listing(
+				RuntimeOrigin::signed(ALICE),
+				AuctionType::BuyNow,
+				ItemId::NFT(0, 0),
+				100,
+				15,
+				20,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::AuctionEndIsLessThanMinimumDuration
+		);
+
+		assert_ok!(AuctionModule::create_scheduled_listing(
+			RuntimeOrigin::signed(ALICE),
+			AuctionType::BuyNow,
+			ItemId::NFT(0, 0),
+			100,
+			5,
+			20,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_eq!(AuctionModule::get_auction_item(0).unwrap().start_time, 5);
+
+		assert_noop!(
+			AuctionModule::buy_now(RuntimeOrigin::signed(BOB), 0, 100),
+			Error::<Runtime>::AuctionHasNotStarted
+		);
+
+		run_to_block(5);
+		assert_ok!(AuctionModule::buy_now(RuntimeOrigin::signed(BOB), 0, 100));
+		assert_eq!(NFTModule::check_ownership(&BOB, &(0, 0)), Ok(true));
+	});
+}
+
+#[test]
+fn drop_with_allowlist_and_public_phases_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let charlie: AccountId = AccountId32::new([3; 32]);
+		init_test_stackable_nft(RuntimeOrigin::signed(ALICE));
+
+		let allowlist_phase = DropPhase {
+			start: 5,
+			price: 10,
+			per_account_limit: 2,
+			eligibility: DropEligibility::Accounts(vec![BOB]),
+		};
+		let public_phase = DropPhase {
+			start: 10,
+			price: 20,
+			per_account_limit: 5,
+			eligibility: DropEligibility::Public,
+		};
+
+		assert_noop!(
+			AuctionModule::create_drop(
+				RuntimeOrigin::signed(ALICE),
+				(0, 0),
+				10,
+				vec![public_phase.clone(), allowlist_phase.clone()],
+				30,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::InvalidDropPhases
+		);
+		assert_noop!(
+			AuctionModule::create_drop(
+				RuntimeOrigin::signed(ALICE),
+				(0, 0),
+				10,
+				vec![allowlist_phase.clone(), public_phase.clone()],
+				30,
+				ListingLevel::Local(BOB_METAVERSE_ID),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+
+		assert_ok!(AuctionModule::create_drop(
+			RuntimeOrigin::signed(ALICE),
+			(0, 0),
+			10,
+			vec![allowlist_phase, public_phase],
+			30,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::DropCreated(0, ALICE, 0, 0, 10))
+		);
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&ALICE, &(0, 0)), 90u128);
+
+		assert_noop!(
+			AuctionModule::purchase_from_drop(RuntimeOrigin::signed(BOB), 0, 1, None),
+			Error::<Runtime>::DropHasNotStarted
+		);
+
+		// Allowlist phase
+		run_to_block(5);
+		assert_noop!(
+			AuctionModule::purchase_from_drop(RuntimeOrigin::signed(charlie.clone()), 0, 1, None),
+			Error::<Runtime>::NotEligibleForDropPhase
+		);
+		assert_noop!(
+			AuctionModule::purchase_from_drop(RuntimeOrigin::signed(BOB), 0, 3, None),
+			Error::<Runtime>::DropPurchaseLimitExceeded
+		);
+		assert_ok!(AuctionModule::purchase_from_drop(
+			RuntimeOrigin::signed(BOB),
+			0,
+			2,
+			None
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::DropPurchased(0, BOB, 2, 20))
+		);
+		assert_eq!(Balances::free_balance(BOB), 480);
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&BOB, &(0, 0)), 2u128);
+
+		// Public phase
+		run_to_block(10);
+		assert_ok!(AuctionModule::purchase_from_drop(
+			RuntimeOrigin::signed(charlie.clone()),
+			0,
+			5,
+			None
+		));
+		assert_noop!(
+			AuctionModule::purchase_from_drop(RuntimeOrigin::signed(BOB), 0, 4, None),
+			Error::<Runtime>::InsufficientDropInventory
+		);
+		assert_eq!(AuctionModule::drops(0).unwrap().remaining, 3);
+
+		assert_noop!(
+			AuctionModule::close_drop(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::DropHasNotEnded
+		);
+		assert_ok!(AuctionModule::close_drop(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::DropClosed(0, 3))
+		);
+		assert_eq!(AuctionModule::drops(0), None);
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&ALICE, &(0, 0)), 93u128);
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&charlie, &(0, 0)), 5u128);
+	});
+}
+
+fn signed_order_seller() -> (sp_core::sr25519::Pair, AccountId) {
+	let seller_pair = sp_core::sr25519::Pair::from_string("//Alice", None).unwrap();
+	let seller = MultiSigner::Sr25519(seller_pair.public()).into_account();
+	assert_ok!(Balances::transfer(RuntimeOrigin::signed(ALICE), seller.clone(), 1000));
+	(seller_pair, seller)
+}
+
+#[test]
+fn fulfil_signed_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (seller_pair, seller) = signed_order_seller();
+		init_test_nft(RuntimeOrigin::signed(seller.clone()));
+
+		let order = SignedOrder {
+			item_id: ItemId::NFT(0, 0),
+			price: 100,
+			currency_id: FungibleTokenId::NativeToken(0),
+			expired: 10,
+			nonce: 0,
+		};
+		let signature = MultiSignature::Sr25519(seller_pair.sign(&AuctionModule::signed_order_payload(&order)));
+		let order_hash = AuctionModule::signed_order_hash(&order);
+
+		assert_noop!(
+			AuctionModule::fulfil_signed_order(
+				RuntimeOrigin::signed(BOB),
+				Box::new(order.clone()),
+				signature.clone(),
+				ALICE
+			),
+			Error::<Runtime>::WrongSignature
+		);
+
+		assert_ok!(AuctionModule::fulfil_signed_order(
+			RuntimeOrigin::signed(BOB),
+			Box::new(order.clone()),
+			signature.clone(),
+			seller.clone()
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::SignedOrderFulfilled(seller.clone(), BOB, order_hash, 100))
+		);
+		assert_eq!(NFTModule::check_ownership(&BOB, &(0, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(BOB), 400);
+
+		// The order can not be replayed once the item is back to the signer
+		assert_ok!(NFTModule::transfer(RuntimeOrigin::signed(BOB), seller.clone(), (0, 0)));
+		assert_noop!(
+			AuctionModule::fulfil_signed_order(
+				RuntimeOrigin::signed(BOB),
+				Box::new(order.clone()),
+				signature.clone(),
+				seller.clone()
+			),
+			Error::<Runtime>::SignedOrderIsInvalid
+		);
+
+		let expiring_order = SignedOrder { expired: 5, ..order };
+		let signature =
+			MultiSignature::Sr25519(seller_pair.sign(&AuctionModule::signed_order_payload(&expiring_order)));
+		run_to_block(6);
+		assert_noop!(
+			AuctionModule::fulfil_signed_order(RuntimeOrigin::signed(BOB), Box::new(expiring_order), signature, seller),
+			Error::<Runtime>::SignatureExpired
+		);
+	});
+}
+
+#[test]
+fn cancel_signed_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (seller_pair, seller) = signed_order_seller();
+		init_test_stackable_nft(RuntimeOrigin::signed(seller.clone()));
+
+		let order = SignedOrder {
+			item_id: ItemId::StackableNFT(0, 0, 10),
+			price: 100,
+			currency_id: FungibleTokenId::NativeToken(0),
+			expired: 10,
+			nonce: 0,
+		};
+		let signature = MultiSignature::Sr25519(seller_pair.sign(&AuctionModule::signed_order_payload(&order)));
+
+		// Orders signed for another chain are rejected
+		let mut foreign_payload = AuctionModule::signed_order_payload(&order);
+		foreign_payload[SIGNED_ORDER_TAG.len() + 1] ^= 1;
+		assert_noop!(
+			AuctionModule::fulfil_signed_order(
+				RuntimeOrigin::signed(BOB),
+				Box::new(order.clone()),
+				MultiSignature::Sr25519(seller_pair.sign(&foreign_payload)),
+				seller.clone()
+			),
+			Error::<Runtime>::WrongSignature
+		);
+
+		assert_ok!(AuctionModule::cancel_signed_order(
+			RuntimeOrigin::signed(seller.clone()),
+			0
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::AuctionModule(crate::Event::SignedOrderCancelled(seller.clone(), 0))
+		);
+		assert_eq!(AuctionModule::signed_order_nonce(&seller), 1);
+		assert_noop!(
+			AuctionModule::cancel_signed_order(RuntimeOrigin::signed(seller.clone()), 0),
+			Error::<Runtime>::SignedOrderIsInvalid
+		);
+		assert_noop!(
+			AuctionModule::fulfil_signed_order(
+				RuntimeOrigin::signed(BOB),
+				Box::new(order.clone()),
+				signature,
+				seller.clone()
+			),
+			Error::<Runtime>::SignedOrderIsInvalid
+		);
+
+		// Nonces ahead of the current nonce are invalidated individually
+		assert_ok!(AuctionModule::cancel_signed_order(
+			RuntimeOrigin::signed(seller.clone()),
+			2
+		));
+		assert_eq!(AuctionModule::signed_order_nonce(&seller), 1);
+		assert_eq!(AuctionModule::invalidated_signed_orders(&seller, 2), Some(()));
+		assert_noop!(
+			AuctionModule::cancel_signed_order(RuntimeOrigin::signed(seller.clone()), 4),
+			Error::<Runtime>::SignedOrderIsInvalid
+		);
+
+		// Increasing the nonce invalidates all outstanding orders
+		let order = SignedOrder {
+			price: 50,
+			nonce: 1,
+			..order
+		};
+		let signature = MultiSignature::Sr25519(seller_pair.sign(&AuctionModule::signed_order_payload(&order)));
+		assert_ok!(AuctionModule::increase_signed_order_nonce(RuntimeOrigin::signed(
+			seller.clone()
+		)));
+		assert_eq!(AuctionModule::signed_order_nonce(&seller), 4);
+		assert_eq!(AuctionModule::invalidated_signed_orders(&seller, 2), None);
+		assert_noop!(
+			AuctionModule::fulfil_signed_order(
+				RuntimeOrigin::signed(BOB),
+				Box::new(order.clone()),
+				signature,
+				seller.clone()
+			),
+			Error::<Runtime>::SignedOrderIsInvalid
+		);
+
+		let order = SignedOrder { nonce: 4, ..order };
+		let signature = MultiSignature::Sr25519(seller_pair.sign(&AuctionModule::signed_order_payload(&order)));
+		assert_ok!(AuctionModule::fulfil_signed_order(
+			RuntimeOrigin::signed(BOB),
+			Box::new(order),
+			signature,
+			seller.clone()
+		));
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&BOB, &(0, 0)), 10u128);
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&seller, &(0, 0)), 90u128);
+		assert_eq!(AuctionModule::signed_order_nonce(&seller), 5);
+	});
+}
+
+#[test]
+fn buy_now_should_pay_royalty_recipients() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(RuntimeOrigin::signed(BOB));
+		assert_ok!(NFTModule::force_update_royalty_fee(
+			RuntimeOrigin::root(),
+			CLASS_ID,
+			Perbill::from_percent(10u32)
+		));
+		assert_ok!(NFTModule::set_class_royalty_recipients(
+			RuntimeOrigin::signed(BOB),
+			CLASS_ID,
+			vec![(NO_METAVERSE_OWNER, Perbill::from_percent(5u32))]
+		));
+		let class_fund = NFTModule::get_class_fund(&CLASS_ID);
+		let class_fund_balance = Balances::free_balance(&class_fund);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			200,
+			0,
+			ListingLevel::Global,
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::buy_now(RuntimeOrigin::signed(ALICE), 0, 200));
+
+		// Royalty fee of 10% is split between the recipient and the class fund
+		assert_eq!(Balances::free_balance(NO_METAVERSE_OWNER), 510);
+		assert_eq!(Balances::free_balance(&class_fund), class_fund_balance + 10);
+	});
+}
+
+#[test]
+fn accept_offer_should_pay_royalty_recipients() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(RuntimeOrigin::signed(BOB));
+		assert_ok!(NFTModule::force_update_royalty_fee(
+			RuntimeOrigin::root(),
+			CLASS_ID,
+			Perbill::from_percent(10u32)
+		));
+		assert_ok!(NFTModule::set_class_royalty_recipients(
+			RuntimeOrigin::signed(BOB),
+			CLASS_ID,
+			vec![(NO_METAVERSE_OWNER, Perbill::from_percent(5u32))]
+		));
+		let class_fund = NFTModule::get_class_fund(&CLASS_ID);
+		let class_fund_balance = Balances::free_balance(&class_fund);
+		let bob_balance = Balances::free_balance(BOB);
+
+		assert_ok!(AuctionModule::make_offer(RuntimeOrigin::signed(ALICE), (0, 0), 200));
+		assert_ok!(AuctionModule::accept_offer(RuntimeOrigin::signed(BOB), (0, 0), ALICE));
+
+		// Royalty fee of 10% is split between the recipient and the class fund
+		assert_eq!(Balances::free_balance(NO_METAVERSE_OWNER), 510);
+		assert_eq!(Balances::free_balance(&class_fund), class_fund_balance + 10);
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 180);
+	});
+}
+
+#[test]
+fn royalty_below_existential_deposit_should_go_to_class_fund() {
+	ExtBuilder::default().build().execute_with(|| {
+		let fresh_recipient: AccountId = AccountId32::new([9; 32]);
+		init_test_nft(RuntimeOrigin::signed(BOB));
+		assert_ok!(NFTModule::force_update_royalty_fee(
+			RuntimeOrigin::root(),
+			CLASS_ID,
+			Perbill::from_percent(10u32)
+		));
+		assert_ok!(NFTModule::set_class_royalty_recipients(
+			RuntimeOrigin::signed(BOB),
+			CLASS_ID,
+			vec![(fresh_recipient.clone(), Perbill::from_percent(1u32))]
+		));
+		ExistentialDeposit::set(5);
+		let class_fund = NFTModule::get_class_fund(&CLASS_ID);
+		let class_fund_balance = Balances::free_balance(&class_fund);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			200,
+			0,
+			ListingLevel::Global,
+			Perbill::from_percent(0u32),
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::buy_now(RuntimeOrigin::signed(ALICE), 0, 200));
+
+		// The royalty of 2 cannot create the recipient account and goes to the class fund
+		assert_eq!(Balances::free_balance(&fresh_recipient), 0);
+		assert_eq!(Balances::free_balance(&class_fund), class_fund_balance + 20);
+	});
+}
+
+#[test]
+fn drop_with_credential_holders_phase_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let charlie: AccountId = AccountId32::new([3; 32]);
+		init_test_stackable_nft(RuntimeOrigin::signed(ALICE));
+
+		let credential_phase = |class_id| DropPhase {
+			start: 5,
+			price: 10,
+			per_account_limit: 2,
+			eligibility: DropEligibility::CredentialHolders(class_id),
+		};
+		assert_noop!(
+			AuctionModule::create_drop(
+				RuntimeOrigin::signed(ALICE),
+				(0, 0),
+				10,
+				vec![credential_phase(CLASS_ID)],
+				30,
+				ListingLevel::Local(ALICE_METAVERSE_ID),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::InvalidDropPhases
+		);
+		assert_ok!(AuctionModule::create_drop(
+			RuntimeOrigin::signed(ALICE),
+			(0, 0),
+			10,
+			vec![credential_phase(CREDENTIAL_CLASS_ID)],
+			30,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			FungibleTokenId::NativeToken(0)
+		));
+
+		run_to_block(5);
+		assert_noop!(
+			AuctionModule::purchase_from_drop(RuntimeOrigin::signed(charlie), 0, 1, None),
+			Error::<Runtime>::NotEligibleForDropPhase
+		);
+		assert_ok!(AuctionModule::purchase_from_drop(
+			RuntimeOrigin::signed(BOB),
+			0,
+			2,
+			None
+		));
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&BOB, &(0, 0)), 2u128);
+	})
+}
+
+#[test]
+fn drop_with_class_holders_phase_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let charlie: AccountId = AccountId32::new([3; 32]);
+		init_test_stackable_nft(RuntimeOrigin::signed(ALICE));
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(BOB),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(1u32),
+			None
+		));
+		assert_ok!(NFTModule::mint(
+			RuntimeOrigin::signed(BOB),
+			1,
+			vec![1],
+			test_attributes(1),
+			1
+		));
+
+		assert_ok!(AuctionModule::create_drop(
+			RuntimeOrigin::signed(ALICE),
+			(0, 0),
+			10,
+			vec![DropPhase {
+				start: 5,
+				price: 10,
+				per_account_limit: 2,
+				eligibility: DropEligibility::ClassHolders(1),
+			}],
+			30,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			FungibleTokenId::NativeToken(0)
+		));
+
+		run_to_block(5);
+		assert_noop!(
+			AuctionModule::purchase_from_drop(RuntimeOrigin::signed(BOB), 0, 1, None),
+			Error::<Runtime>::NotEligibleForDropPhase
+		);
+		// The holder token keeps serving its first buyer up to the account limit
+		assert_ok!(AuctionModule::purchase_from_drop(
+			RuntimeOrigin::signed(BOB),
+			0,
+			1,
+			Some(0)
+		));
+		assert_ok!(AuctionModule::purchase_from_drop(
+			RuntimeOrigin::signed(BOB),
+			0,
+			1,
+			Some(0)
+		));
+		assert_eq!(AuctionModule::drop_holder_token(0, (0, 0)), Some(BOB));
+
+		// Passing the holder token on does not make the new owner eligible
+		assert_ok!(NFTModule::transfer(RuntimeOrigin::signed(BOB), charlie.clone(), (1, 0)));
+		assert_noop!(
+			AuctionModule::purchase_from_drop(RuntimeOrigin::signed(charlie), 0, 1, Some(0)),
+			Error::<Runtime>::DropHolderTokenAlreadyUsed
+		);
+
+		// Records of a closed drop are removed in the next blocks
+		assert_ok!(AuctionModule::close_drop(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(AuctionModule::closed_drop(0), Some(()));
+		assert_eq!(AuctionModule::drop_purchases(0, (0, BOB)), 2);
+		run_to_block(6);
+		assert_eq!(AuctionModule::closed_drop(0), None);
+		assert_eq!(AuctionModule::drop_purchases(0, (0, BOB)), 0);
+		assert_eq!(AuctionModule::drop_holder_token(0, (0, 0)), None);
+	})
+}
+
+#[test]
+// Accepting a swap exchanges both sides atomically
+fn accept_swap_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		init_test_nft(owner.clone());
+
+		let offered_items = bounded(vec![
+			ItemId::NFT(0, 0),
+			ItemId::UndeployedLandBlock(UNDEPLOYED_LAND_BLOCK_ID_EXIST),
+		]);
+		assert_noop!(
+			AuctionModule::propose_swap(
+				owner.clone(),
+				offered_items.clone(),
+				bounded(vec![]),
+				bounded(vec![]),
+				bounded(vec![]),
+				Some(BOB),
+				10
+			),
+			Error::<Runtime>::InvalidSwapProposal
+		);
+		assert_ok!(AuctionModule::propose_swap(
+			owner.clone(),
+			offered_items,
+			bounded(vec![]),
+			bounded(vec![]),
+			bounded(vec![(FungibleTokenId::NativeToken(0), 100)]),
+			Some(BOB),
+			10
+		));
+		let event = mock::RuntimeEvent::AuctionModule(crate::Event::SwapProposed(0, ALICE, Some(BOB)));
+		assert_eq!(last_event(), event);
+		// offered items are locked while the swap is open
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true));
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::UndeployedLandBlock(UNDEPLOYED_LAND_BLOCK_ID_EXIST)),
+			Some(true)
+		);
+		assert_eq!(NFTModule::is_transferable(&(0, 0)), Ok(false));
+
+		assert_noop!(
+			AuctionModule::accept_swap(RuntimeOrigin::signed(NO_METAVERSE_OWNER), 0),
+			Error::<Runtime>::NoPermissionToAcceptSwap
+		);
+
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(AuctionModule::accept_swap(RuntimeOrigin::signed(BOB), 0));
+		let event = mock::RuntimeEvent::AuctionModule(crate::Event::SwapAccepted(0, ALICE, BOB));
+		assert_eq!(last_event(), event);
+
+		assert!(AuctionModule::swaps(0).is_none());
+		assert_eq!(NFTModule::check_ownership(&BOB, &(0, 0)), Ok(true));
+		assert_eq!(NFTModule::is_transferable(&(0, 0)), Ok(true));
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::UndeployedLandBlock(UNDEPLOYED_LAND_BLOCK_ID_EXIST)),
+			None
+		);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 100);
+	});
+}
+
+#[test]
+// Cancelled or expired swaps unlock the offered items and amounts
+fn cancel_and_expire_swap_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		init_test_stackable_nft(owner.clone());
+
+		let reserved_balance = Balances::reserved_balance(ALICE);
+		assert_ok!(AuctionModule::propose_swap(
+			owner.clone(),
+			bounded(vec![ItemId::StackableNFT(0, 0, 40)]),
+			bounded(vec![(FungibleTokenId::NativeToken(0), 50)]),
+			bounded(vec![]),
+			bounded(vec![(FungibleTokenId::NativeToken(0), 10)]),
+			None,
+			5
+		));
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&ALICE, &(0, 0)), 60u128);
+		// offered amount and swap deposit are reserved
+		assert_eq!(Balances::reserved_balance(ALICE), reserved_balance + 50 + 2);
+
+		assert_noop!(
+			AuctionModule::cancel_swap(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::NoPermissionToCancelSwap
+		);
+		assert_ok!(AuctionModule::cancel_swap(owner.clone(), 0));
+		let event = mock::RuntimeEvent::AuctionModule(crate::Event::SwapCancelled(0));
+		assert_eq!(last_event(), event);
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&ALICE, &(0, 0)), 100u128);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved_balance);
+
+		assert_ok!(AuctionModule::propose_swap(
+			owner.clone(),
+			bounded(vec![ItemId::StackableNFT(0, 0, 40)]),
+			bounded(vec![]),
+			bounded(vec![]),
+			bounded(vec![(FungibleTokenId::NativeToken(0), 10)]),
+			None,
+			5
+		));
+		run_to_block(5);
+		let event = mock::RuntimeEvent::AuctionModule(crate::Event::SwapExpired(1));
+		assert_eq!(last_event(), event);
+		assert!(AuctionModule::swaps(1).is_none());
+		assert_eq!(NFTModule::get_free_stackable_nft_balance(&ALICE, &(0, 0)), 100u128);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved_balance);
+		assert_noop!(
+			AuctionModule::accept_swap(RuntimeOrigin::signed(BOB), 1),
+			Error::<Runtime>::SwapDoesNotExist
+		);
+	});
+}
+
+#[test]
+// The royalty fee of a swapped item is collected from the amount received for it
+fn accept_swap_should_pay_royalty_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(RuntimeOrigin::signed(BOB));
+		assert_ok!(NFTModule::force_update_royalty_fee(
+			RuntimeOrigin::root(),
+			CLASS_ID,
+			Perbill::from_percent(10u32)
+		));
+		assert_ok!(NFTModule::set_class_royalty_recipients(
+			RuntimeOrigin::signed(BOB),
+			CLASS_ID,
+			vec![(NO_METAVERSE_OWNER, Perbill::from_percent(5u32))]
+		));
+		let class_fund = NFTModule::get_class_fund(&CLASS_ID);
+		let class_fund_balance = Balances::free_balance(&class_fund);
+		let bob_balance = Balances::free_balance(BOB);
+
+		assert_ok!(AuctionModule::propose_swap(
+			RuntimeOrigin::signed(BOB),
+			bounded(vec![ItemId::NFT(0, 0)]),
+			bounded(vec![]),
+			bounded(vec![]),
+			bounded(vec![(FungibleTokenId::NativeToken(0), 200)]),
+			Some(ALICE),
+			10
+		));
+		assert_ok!(AuctionModule::accept_swap(RuntimeOrigin::signed(ALICE), 0));
+
+		// Royalty fee of 10% is split between the recipient and the class fund
+		assert_eq!(NFTModule::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(NO_METAVERSE_OWNER), 510);
+		assert_eq!(Balances::free_balance(&class_fund), class_fund_balance + 10);
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 180);
+	});
+}
+
+#[test]
+// A swap whose offered items can not be unlocked at expiry stays open until it is cancelled
+fn swap_expiry_failure_should_keep_swap_cancellable() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		init_test_nft(owner.clone());
+
+		let reserved_balance = Balances::reserved_balance(ALICE);
+		assert_ok!(AuctionModule::propose_swap(
+			owner.clone(),
+			bounded(vec![ItemId::NFT(0, 0)]),
+			bounded(vec![]),
+			bounded(vec![]),
+			bounded(vec![(FungibleTokenId::NativeToken(0), 10)]),
+			None,
+			5
+		));
+
+		let token = orml_nft::Tokens::<Runtime>::take(0, 0).unwrap();
+		run_to_block(5);
+		let event = mock::RuntimeEvent::AuctionModule(crate::Event::SwapExpiryFailed(
+			0,
+			pallet_nft::Error::<Runtime>::AssetInfoNotFound.into(),
+		));
+		assert_eq!(last_event(), event);
+		assert!(AuctionModule::swaps(0).is_some());
+		assert_eq!(Balances::reserved_balance(ALICE), reserved_balance + 2);
+		assert_noop!(
+			AuctionModule::accept_swap(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::SwapIsExpired
+		);
+
+		orml_nft::Tokens::<Runtime>::insert(0, 0, token);
+		assert_ok!(AuctionModule::cancel_swap(owner, 0));
+		let event = mock::RuntimeEvent::AuctionModule(crate::Event::SwapCancelled(0));
+		assert_eq!(last_event(), event);
+		assert!(AuctionModule::swaps(0).is_none());
+		assert_eq!(NFTModule::is_transferable(&(0, 0)), Ok(true));
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved_balance);
+	});
+}
+
+#[test]
+// Swap expiry is bounded by the max swap duration and the finality limit of the block
+fn propose_swap_should_fail_with_invalid_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(ALICE);
+		let tokens = bounded(vec![(FungibleTokenId::NativeToken(0), 1)]);
+		let expiry = System::block_number() + MaxSwapDuration::get();
+
+		assert_noop!(
+			AuctionModule::propose_swap(
+				owner.clone(),
+				bounded(vec![]),
+				tokens.clone(),
+				bounded(vec![]),
+				tokens.clone(),
+				None,
+				expiry + 1
+			),
+			Error::<Runtime>::SwapExpiryExceedsMaxDuration
+		);
+
+		for _ in 0..MaxFinality::get() {
+			assert_ok!(AuctionModule::propose_swap(
+				owner.clone(),
+				bounded(vec![]),
+				tokens.clone(),
+				bounded(vec![]),
+				tokens.clone(),
+				None,
+				expiry
+			));
+		}
+		assert_noop!(
+			AuctionModule::propose_swap(
+				owner.clone(),
+				bounded(vec![]),
+				tokens.clone(),
+				bounded(vec![]),
+				tokens,
+				None,
+				expiry
+			),
+			Error::<Runtime>::ExceedFinalityLimit
+		);
+	});
+}
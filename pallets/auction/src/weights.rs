@@ -47,7 +47,7 @@ use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions needed for auction.
-pub trait WeightInfo {	fn create_new_auction() -> Weight;	fn create_new_buy_now() -> Weight;	fn bid() -> Weight;	fn buy_now() -> Weight;	fn cancel_listing() -> Weight;	fn authorise_metaverse_collection() -> Weight;	fn remove_authorise_metaverse_collection() -> Weight;	fn make_offer() -> Weight;	fn withdraw_offer() -> Weight;	fn accept_offer() -> Weight;	fn on_finalize() -> Weight;}
+pub trait WeightInfo {	fn create_new_auction() -> Weight;	fn create_new_buy_now() -> Weight;	fn bid() -> Weight;	fn buy_now() -> Weight;	fn cancel_listing() -> Weight;	fn authorise_metaverse_collection() -> Weight;	fn remove_authorise_metaverse_collection() -> Weight;	fn make_offer() -> Weight;	fn withdraw_offer() -> Weight;	fn accept_offer() -> Weight;	fn on_finalize() -> Weight;	fn propose_swap(n: u32) -> Weight;	fn accept_swap(n: u32, r: u32) -> Weight;	fn cancel_swap(n: u32) -> Weight;	fn create_new_sealed_bid_auction() -> Weight;	fn commit_bid() -> Weight;	fn reveal_bid() -> Weight;	fn settle_sealed_bids(b: u32) -> Weight;}
 
 /// Weights for auction using the for collator node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -246,8 +246,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	// Storage: Me
 	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
 	// Storage: Auction Offers (r:1 w:1)
 	// Proof Skipped: Auction Offers (max_values: None, max_size: None, mode: Measured)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Nft ClassRoyaltyRecipients (r:1 w:0)
+	// Proof Skipped: Nft ClassRoyaltyRecipients (max_values: None, max_size: None, mode: Measured)
 	// Storage: Nft LockedCollection (r:1 w:0)
 	// Proof Skipped: Nft LockedCollection (max_values: None, max_size: None, mode: Measured)
 	// Storage: OrmlNFT StackableCollection (r:1 w:0)
@@ -256,12 +258,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	// Storage: Me
 	// Proof Skipped: OrmlNFT TokensByOwner (max_values: None, max_size: None, mode: Measured)
 	fn accept_offer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2326`
-		//  Estimated: `31537`
-		// Minimum execution time: 68_875 nanoseconds.
-		Weight::from_parts(72_454_000, 31537)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(6))
+		//  Measured:  `2638`
+		//  Estimated: `37745`
+		// Minimum execution time: 77_412 nanoseconds.
+		Weight::from_parts(81_630_000, 37745)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	fn on_finalize() -> Weight {
 		// Proof Size summary in bytes:
@@ -270,6 +272,131 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	// Storage: Me
 		// Minimum execution time: 5_673 nanoseconds.
 		Weight::from_parts(7_467_000, 0)
 	}
+	// Storage: Auction SwapEndTime (r:1 w:1)
+	// Storage: Auction NextSwapId (r:1 w:1)
+	// Storage: Auction Swaps (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: OrmlNFT Tokens (r:n w:n)
+	// Storage: OrmlNFT Classes (r:n w:0)
+	// Storage: Auction ItemsInAuction (r:n w:n)
+	fn propose_swap(n: u32) -> Weight {
+		Weight::from_parts(45_000_000, 9000)
+			.saturating_add(Weight::from_parts(20_000_000, 3000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: Auction Swaps (r:1 w:1)
+	// Storage: Auction SwapEndTime (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: OrmlNFT Tokens (r:n w:n)
+	// Storage: OrmlNFT Classes (r:n w:0)
+	// Storage: OrmlNFT TokensByOwner (r:n w:2n)
+	// Storage: Auction ItemsInAuction (r:n w:n)
+	// Storage: OrmlNFT Classes (r:r w:0)
+	// Storage: Nft ClassRoyaltyRecipients (r:r w:0)
+	// Storage: System Account (r:2r w:3r)
+	fn accept_swap(n: u32, r: u32) -> Weight {
+		Weight::from_parts(60_000_000, 12000)
+			.saturating_add(Weight::from_parts(35_000_000, 5000).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(45_000_000, 8000).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+	}
+	// Storage: Auction Swaps (r:1 w:1)
+	// Storage: Auction SwapEndTime (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: OrmlNFT Tokens (r:n w:n)
+	// Storage: Auction ItemsInAuction (r:0 w:n)
+	fn cancel_swap(n: u32) -> Weight {
+		Weight::from_parts(35_000_000, 6000)
+			.saturating_add(Weight::from_parts(15_000_000, 2500).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: Metaverse Metaverses (r:1 w:0)
+	// Proof Skipped: Metaverse Metaverses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction ItemsInAuction (r:1 w:1)
+	// Proof Skipped: Auction ItemsInAuction (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction MetaverseCollection (r:1 w:0)
+	// Proof Skipped: Auction MetaverseCollection (max_values: None, max_size: None, mode: Measured)
+	// Storage: Metaverse MetaverseOwner (r:1 w:0)
+	// Proof Skipped: Metaverse MetaverseOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction AuctionEndTime (r:1 w:1)
+	// Proof Skipped: Auction AuctionEndTime (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Auction AuctionsIndex (r:1 w:1)
+	// Proof Skipped: Auction AuctionsIndex (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Auction AuctionItems (r:0 w:1)
+	// Proof Skipped: Auction AuctionItems (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction Auctions (r:0 w:1)
+	// Proof Skipped: Auction Auctions (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction SealedBidAuctions (r:0 w:1)
+	// Proof Skipped: Auction SealedBidAuctions (max_values: None, max_size: None, mode: Measured)
+	fn create_new_sealed_bid_auction() -> Weight {
+		Weight::from_parts(63_214_000, 57373)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: Auction AuctionItems (r:1 w:0)
+	// Proof Skipped: Auction AuctionItems (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Auction SealedBidAuctions (r:1 w:0)
+	// Proof Skipped: Auction SealedBidAuctions (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction PendingFinalization (r:1 w:0)
+	// Proof Skipped: Auction PendingFinalization (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction Auctions (r:1 w:0)
+	// Proof Skipped: Auction Auctions (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction SealedBids (r:1 w:1)
+	// Proof Skipped: Auction SealedBids (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction SealedBidCount (r:1 w:1)
+	// Proof Skipped: Auction SealedBidCount (max_values: None, max_size: None, mode: Measured)
+	fn commit_bid() -> Weight {
+		Weight::from_parts(52_310_000, 21630)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Auction AuctionItems (r:1 w:0)
+	// Proof Skipped: Auction AuctionItems (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction SealedBidAuctions (r:1 w:1)
+	// Proof Skipped: Auction SealedBidAuctions (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction Auctions (r:1 w:1)
+	// Proof Skipped: Auction Auctions (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction SealedBids (r:1 w:1)
+	// Proof Skipped: Auction SealedBids (max_values: None, max_size: None, mode: Measured)
+	fn reveal_bid() -> Weight {
+		Weight::from_parts(41_872_000, 17458)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Auction SealedBidCount (r:0 w:1)
+	// Proof Skipped: Auction SealedBidCount (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction SealedBids (r:1 w:1)
+	// Proof Skipped: Auction SealedBids (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// The range of component `b` is `[0, 100]`.
+	fn settle_sealed_bids(b: u32) -> Weight {
+		Weight::from_parts(4_318_000, 1485)
+			.saturating_add(Weight::from_parts(21_540_000, 2603).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
 }
 
 // For backwards compatibility and tests
@@ -319,11 +446,61 @@ impl WeightInfo for () {	fn create_new_auction() -> Weight {
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 	fn accept_offer() -> Weight {
-		Weight::from_parts(72_454_000, 31537)
-			.saturating_add(RocksDbWeight::get().reads(7))
-			.saturating_add(RocksDbWeight::get().writes(6))
+		Weight::from_parts(81_630_000, 37745)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(7))
 	}
 	fn on_finalize() -> Weight {
 		Weight::from_parts(7_467_000, 0)
 	}
+	fn propose_swap(n: u32) -> Weight {
+		Weight::from_parts(45_000_000, 9000)
+			.saturating_add(Weight::from_parts(20_000_000, 3000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	fn accept_swap(n: u32, r: u32) -> Weight {
+		Weight::from_parts(60_000_000, 12000)
+			.saturating_add(Weight::from_parts(35_000_000, 5000).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(45_000_000, 8000).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(r.into())))
+	}
+	fn cancel_swap(n: u32) -> Weight {
+		Weight::from_parts(35_000_000, 6000)
+			.saturating_add(Weight::from_parts(15_000_000, 2500).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	fn create_new_sealed_bid_auction() -> Weight {
+		Weight::from_parts(63_214_000, 57373)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	fn commit_bid() -> Weight {
+		Weight::from_parts(52_310_000, 21630)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn reveal_bid() -> Weight {
+		Weight::from_parts(41_872_000, 17458)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn settle_sealed_bids(b: u32) -> Weight {
+		Weight::from_parts(4_318_000, 1485)
+			.saturating_add(Weight::from_parts(21_540_000, 2603).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
 }
@@ -486,6 +486,75 @@ pub mod pallet {
 			Self::deposit_event(Event::NewMaxBoundSet(new_bound));
 			Ok(().into())
 		}
+
+		/// Commit sealed bid for continuum slot
+		///
+		/// The commitment is computed as in the auction pallet with the salt bound to the spot and
+		/// the bidding metaverse by `map_spot_bid_salt`, so a revealed bid can not be moved to
+		/// another metaverse.
+		#[pallet::weight(T::WeightInfo::bid_map_spot())]
+		#[transactional]
+		pub fn commit_map_spot_bid(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			commitment: [u8; 32],
+			deposit: BalanceOf<T>,
+			metaverse_id: MetaverseId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_metaverse_can_bid_for_spot(&sender, metaverse_id)?;
+
+			let auction_item = T::AuctionHandler::auction_item(auction_id).ok_or(Error::<T>::InvalidSpotAuction)?;
+			ensure!(auction_item.item_id.is_map_spot(), Error::<T>::InvalidSpotAuction);
+
+			T::AuctionHandler::sealed_bid_commit_handler(sender.clone(), auction_id, commitment, deposit)?;
+
+			// Charge storage fee to the bidder
+			<T as Config>::Currency::transfer(
+				&sender,
+				&Self::account_id(),
+				T::StorageDepositFee::get(),
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Ok(())
+		}
+
+		/// Reveal committed sealed bid for continuum slot
+		#[pallet::weight(T::WeightInfo::bid_map_spot())]
+		#[transactional]
+		pub fn reveal_map_spot_bid(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			value: BalanceOf<T>,
+			salt: Vec<u8>,
+			metaverse_id: MetaverseId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_metaverse_can_bid_for_spot(&sender, metaverse_id)?;
+
+			let auction_item = T::AuctionHandler::auction_item(auction_id).ok_or(Error::<T>::InvalidSpotAuction)?;
+			ensure!(auction_item.item_id.is_map_spot(), Error::<T>::InvalidSpotAuction);
+			let spot_detail = auction_item
+				.item_id
+				.get_map_spot_detail()
+				.ok_or(Error::<T>::InvalidSpotAuction)?;
+
+			let is_leading = T::AuctionHandler::sealed_bid_reveal_handler(
+				sender,
+				auction_id,
+				value,
+				Self::map_spot_bid_salt(*spot_detail.0, metaverse_id, &salt),
+			)?;
+			if is_leading {
+				// Spot goes to the metaverse of the leading bid
+				T::AuctionHandler::update_auction_item(auction_id, ItemId::Spot(*spot_detail.0, metaverse_id))?;
+				MetaverseLeadingBid::<T>::remove_prefix(spot_detail.0, None);
+				MetaverseLeadingBid::<T>::insert(spot_detail.0, metaverse_id, ());
+			}
+
+			Ok(())
+		}
 	}
 }
 
@@ -493,12 +562,43 @@ impl<T: Config> Pallet<T> {
 	pub fn account_id() -> T::AccountId {
 		T::ContinuumTreasury::get().into_account_truncating()
 	}
+
+	/// Salt of the commitment of a sealed map spot bid, binding the bid to the spot and the
+	/// bidding metaverse
+	pub fn map_spot_bid_salt(spot_id: MapSpotId, metaverse_id: MetaverseId, salt: &[u8]) -> Vec<u8> {
+		(spot_id, metaverse_id, salt).encode()
+	}
 	// noinspection ALL
 	fn check_spot_ownership(spot_id: &MapSpotId, owner: &T::AccountId) -> Result<bool, DispatchError> {
 		let spot_info = MapSpots::<T>::get(spot_id).ok_or(Error::<T>::MapSpotNotFound)?;
 		Ok(spot_info.owner == *owner)
 	}
 
+	// Check if metaverse is allowed to bid for a spot
+	fn ensure_metaverse_can_bid_for_spot(who: &T::AccountId, metaverse_id: MetaverseId) -> DispatchResult {
+		ensure!(
+			T::MetaverseInfoSource::check_ownership(who, &metaverse_id),
+			Error::<T>::NotMetaverseOwner
+		);
+
+		ensure!(
+			!MetaverseMap::<T>::contains_key(&metaverse_id),
+			Error::<T>::MetaverseAlreadyGotSpot
+		);
+
+		ensure!(
+			T::MetaverseInfoSource::check_if_metaverse_has_any_land(metaverse_id.clone())?,
+			Error::<T>::MetaverseHasNotDeployedAnyLand
+		);
+
+		ensure!(
+			!Self::check_if_metaverse_has_leading_bid(&metaverse_id),
+			Error::<T>::MetaverseHasBidLeading
+		);
+
+		Ok(())
+	}
+
 	// Check if metaverse has any leading bid
 	fn check_if_metaverse_has_leading_bid(metaverse_id: &MetaverseId) -> bool {
 		let mut has_leading = false;
@@ -20,8 +20,9 @@
 use frame_support::pallet_prelude::{DispatchResult, Hooks};
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
 use frame_system::EnsureSignedBy;
-use sp_core::H256;
+use sp_core::{hashing::blake2_256, H256};
 use sp_runtime::{traits::IdentityLookup, Perbill};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 
 use auction_manager::{Auction, AuctionInfo, AuctionItem, CheckAuctionItemHandler, ListingLevel};
 use core_primitives::{MetaverseInfo, MetaverseMetadata, MetaverseTrait};
@@ -111,6 +112,15 @@ impl pallet_balances::Config for Runtime {
 
 pub struct MockAuctionManager;
 
+thread_local! {
+	static SEALED_BID_COMMITMENTS: RefCell<BTreeMap<(AuctionId, AccountId), [u8; 32]>> = RefCell::new(BTreeMap::new());
+}
+
+/// Commitment of a sealed bid, computed as in the auction pallet
+pub fn sealed_bid_commitment(id: AuctionId, from: AccountId, value: Balance, salt: &[u8]) -> [u8; 32] {
+	(id, from, value, salt).using_encoded(blake2_256)
+}
+
 impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 	type Balance = Balance;
 
@@ -192,6 +202,29 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn sealed_bid_commit_handler(
+		from: AccountId,
+		id: AuctionId,
+		commitment: [u8; 32],
+		_deposit: Self::Balance,
+	) -> DispatchResult {
+		SEALED_BID_COMMITMENTS.with(|commitments| commitments.borrow_mut().insert((id, from), commitment));
+		Ok(())
+	}
+
+	fn sealed_bid_reveal_handler(
+		from: AccountId,
+		id: AuctionId,
+		value: Self::Balance,
+		salt: Vec<u8>,
+	) -> Result<bool, DispatchError> {
+		let commitment = SEALED_BID_COMMITMENTS.with(|commitments| commitments.borrow().get(&(id, from)).copied());
+		if commitment != Some(sealed_bid_commitment(id, from, value, &salt)) {
+			return Err(DispatchError::Other("SealedBidRevealDoesNotMatch"));
+		}
+		Ok(true)
+	}
+
 	fn local_auction_bid_handler(
 		_now: u64,
 		_id: u64,
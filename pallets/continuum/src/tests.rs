@@ -20,6 +20,7 @@
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::traits::BadOrigin;
 
+use auction_manager::SealedBidSettlement;
 use core_primitives::TokenType;
 
 use mock::*;
@@ -528,3 +529,120 @@ fn bid_auction_continuum_should_replace_another_leading_bid() {
 		)
 	})
 }
+
+#[test]
+fn sealed_bid_continuum_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let root = RuntimeOrigin::root();
+
+		assert_ok!(ContinuumModule::issue_map_slot(
+			root.clone(),
+			CONTINUUM_MAP_COORDINATE,
+			TokenType::Transferable
+		));
+
+		assert_ok!(ContinuumModule::create_new_auction(
+			root.clone(),
+			CONTINUUM_MAP_COORDINATE,
+			AuctionType::SealedBid(SealedBidSettlement::SecondPrice),
+			100,
+			10
+		));
+
+		let salt = ContinuumModule::map_spot_bid_salt(CONTINUUM_MAP_COORDINATE, ALICE_METAVERSE_ID, &[1]);
+		assert_ok!(ContinuumModule::commit_map_spot_bid(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			sealed_bid_commitment(1, ALICE, 150, &salt),
+			200,
+			ALICE_METAVERSE_ID
+		));
+		// Storage fee is charged on commit
+		assert_eq!(Balances::free_balance(ALICE), 99999);
+		// Sealed bid does not lead until it is revealed
+		assert!(!MetaverseLeadingBid::<Runtime>::contains_key(
+			CONTINUUM_MAP_COORDINATE,
+			ALICE_METAVERSE_ID
+		));
+
+		assert_ok!(ContinuumModule::reveal_map_spot_bid(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			150,
+			vec![1],
+			ALICE_METAVERSE_ID
+		));
+
+		assert!(MetaverseLeadingBid::<Runtime>::contains_key(
+			CONTINUUM_MAP_COORDINATE,
+			ALICE_METAVERSE_ID
+		));
+	})
+}
+
+#[test]
+fn sealed_bid_continuum_should_fail_if_revealed_for_another_metaverse() {
+	ExtBuilder::default().build().execute_with(|| {
+		let root = RuntimeOrigin::root();
+
+		assert_ok!(ContinuumModule::issue_map_slot(
+			root.clone(),
+			CONTINUUM_MAP_COORDINATE,
+			TokenType::Transferable
+		));
+
+		assert_ok!(ContinuumModule::create_new_auction(
+			root.clone(),
+			CONTINUUM_MAP_COORDINATE,
+			AuctionType::SealedBid(SealedBidSettlement::FirstPrice),
+			100,
+			10
+		));
+
+		// The commitment is bound to the metaverse the bid was made for
+		let salt = ContinuumModule::map_spot_bid_salt(CONTINUUM_MAP_COORDINATE, CHARLIE_METAVERSE_ID, &[1]);
+		assert_ok!(ContinuumModule::commit_map_spot_bid(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			sealed_bid_commitment(1, ALICE, 150, &salt),
+			200,
+			ALICE_METAVERSE_ID
+		));
+		assert_noop!(
+			ContinuumModule::reveal_map_spot_bid(RuntimeOrigin::signed(ALICE), 1, 150, vec![1], ALICE_METAVERSE_ID),
+			DispatchError::Other("SealedBidRevealDoesNotMatch")
+		);
+	})
+}
+
+#[test]
+fn sealed_bid_continuum_should_fail_if_already_got_spot() {
+	ExtBuilder::default().build().execute_with(|| {
+		let root = RuntimeOrigin::root();
+
+		MetaverseMap::<Runtime>::insert(ALICE_METAVERSE_ID, (0, 1));
+
+		assert_ok!(ContinuumModule::issue_map_slot(
+			root.clone(),
+			CONTINUUM_MAP_COORDINATE,
+			TokenType::Transferable
+		));
+
+		assert_ok!(ContinuumModule::create_new_auction(
+			root.clone(),
+			CONTINUUM_MAP_COORDINATE,
+			AuctionType::SealedBid(SealedBidSettlement::FirstPrice),
+			100,
+			10
+		));
+
+		assert_noop!(
+			ContinuumModule::commit_map_spot_bid(RuntimeOrigin::signed(ALICE), 1, [1; 32], 200, ALICE_METAVERSE_ID),
+			Error::<Runtime>::MetaverseAlreadyGotSpot
+		);
+		assert_noop!(
+			ContinuumModule::reveal_map_spot_bid(RuntimeOrigin::signed(ALICE), 1, 150, vec![1], ALICE_METAVERSE_ID),
+			Error::<Runtime>::MetaverseAlreadyGotSpot
+		);
+	})
+}
@@ -0,0 +1,858 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional, PalletId};
+use frame_system::ensure_signed;
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrencyExtended;
+use scale_info::TypeInfo;
+use sp_core::U256;
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, DispatchError, FixedPointNumber, Perbill, RuntimeDebug,
+};
+use sp_std::{vec, vec::Vec};
+
+use auction_manager::{DEXPriceProvider, SwapManager};
+pub use pallet::*;
+use primitives::{Balance, FungibleTokenId, Ratio, TokenId};
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+/// Trading pair of two currencies, always stored in ascending order
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, PartialOrd, Ord, TypeInfo)]
+pub struct TradingPair(FungibleTokenId, FungibleTokenId);
+
+impl TradingPair {
+	pub fn from_currency_ids(currency_id_a: FungibleTokenId, currency_id_b: FungibleTokenId) -> Option<Self> {
+		if currency_id_a == currency_id_b
+			|| matches!(currency_id_a, FungibleTokenId::DEXShare(_, _))
+			|| matches!(currency_id_b, FungibleTokenId::DEXShare(_, _))
+		{
+			return None;
+		}
+
+		if currency_id_a < currency_id_b {
+			Some(TradingPair(currency_id_a, currency_id_b))
+		} else {
+			Some(TradingPair(currency_id_b, currency_id_a))
+		}
+	}
+
+	pub fn first(&self) -> FungibleTokenId {
+		self.0
+	}
+
+	pub fn second(&self) -> FungibleTokenId {
+		self.1
+	}
+
+	/// The LP token of the trading pair
+	pub fn dex_share_currency_id(&self) -> FungibleTokenId {
+		FungibleTokenId::DEXShare(Self::token_id(&self.0), Self::token_id(&self.1))
+	}
+
+	fn token_id(currency_id: &FungibleTokenId) -> TokenId {
+		match currency_id {
+			FungibleTokenId::NativeToken(token_id)
+			| FungibleTokenId::FungibleToken(token_id)
+			| FungibleTokenId::MiningResource(token_id)
+			| FungibleTokenId::Stable(token_id) => *token_id,
+			FungibleTokenId::DEXShare(_, _) => Zero::zero(),
+		}
+	}
+}
+
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum TradingPairStatus {
+	/// Trading pair is disabled, liquidity can only be removed
+	#[default]
+	Disabled,
+	/// Trading pair is enabled for providing liquidity and swapping
+	Enabled,
+}
+
+/// Accumulated price of both currencies of a trading pair, multiplied by the number of blocks each
+/// price was valid for
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PriceCumulative<BlockNumber> {
+	/// Accumulated price of the first currency quoted in the second currency
+	pub price_0_cumulative: U256,
+	/// Accumulated price of the second currency quoted in the first currency
+	pub price_1_cumulative: U256,
+	/// Block number of the last accumulation
+	pub last_updated: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Multi currencies type that handles pool currencies and mints DEXShare tokens
+		type MultiCurrency: MultiCurrencyExtended<Self::AccountId, CurrencyId = FungibleTokenId, Balance = Balance>;
+
+		/// Swap fee charged on the supplied amount of every hop, stays in the pool
+		#[pallet::constant]
+		type SwapFee: Get<Perbill>;
+
+		/// Maximum number of currencies in a swap path
+		#[pallet::constant]
+		type TradingPathLimit: Get<u32>;
+
+		/// Minimum number of blocks covered by the time weighted average price
+		#[pallet::constant]
+		type TwapPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Pallet account holding the pool liquidity
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin represented Governance
+		type GovernanceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Weight implementation for dex extrinsics
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Status of trading pairs
+	#[pallet::storage]
+	#[pallet::getter(fn trading_pair_statuses)]
+	pub type TradingPairStatuses<T: Config> = StorageMap<_, Twox64Concat, TradingPair, TradingPairStatus, ValueQuery>;
+
+	/// Trading pair of each DEXShare currency
+	#[pallet::storage]
+	#[pallet::getter(fn dex_share_trading_pair)]
+	pub type DEXShareTradingPair<T: Config> = StorageMap<_, Twox64Concat, FungibleTokenId, TradingPair, OptionQuery>;
+
+	/// Reserves of both currencies of the trading pair, in trading pair order
+	#[pallet::storage]
+	#[pallet::getter(fn liquidity_pool)]
+	pub type LiquidityPool<T: Config> = StorageMap<_, Twox64Concat, TradingPair, (Balance, Balance), ValueQuery>;
+
+	/// Latest accumulated prices of the trading pair
+	#[pallet::storage]
+	#[pallet::getter(fn price_cumulative)]
+	pub type PriceCumulatives<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, PriceCumulative<BlockNumberFor<T>>, ValueQuery>;
+
+	/// Previous and latest accumulated prices snapshots the time weighted average price is
+	/// measured from, taken at least `TwapPeriod` apart
+	#[pallet::storage]
+	#[pallet::getter(fn twap_observations)]
+	pub type TwapObservations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		TradingPair,
+		(PriceCumulative<BlockNumberFor<T>>, PriceCumulative<BlockNumberFor<T>>),
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Trading pair enabled [trading_pair]
+		TradingPairEnabled(TradingPair),
+		/// Trading pair disabled [trading_pair]
+		TradingPairDisabled(TradingPair),
+		/// Liquidity added [who, currency_a, amount_a, currency_b, amount_b, share_increment]
+		LiquidityAdded(
+			T::AccountId,
+			FungibleTokenId,
+			Balance,
+			FungibleTokenId,
+			Balance,
+			Balance,
+		),
+		/// Liquidity removed [who, currency_a, amount_a, currency_b, amount_b, share_decrement]
+		LiquidityRemoved(
+			T::AccountId,
+			FungibleTokenId,
+			Balance,
+			FungibleTokenId,
+			Balance,
+			Balance,
+		),
+		/// Currencies swapped along the path [who, path, amounts]
+		Swapped(T::AccountId, Vec<FungibleTokenId>, Vec<Balance>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Currencies can not form a trading pair
+		InvalidTradingPair,
+		/// Trading pair is already enabled
+		TradingPairAlreadyEnabled,
+		/// Trading pair is not enabled
+		TradingPairNotEnabled,
+		/// DEXShare currency of the trading pair is already used by another trading pair
+		DEXShareCurrencyAlreadyInUse,
+		/// Liquidity amount is zero or does not match the pool
+		InvalidLiquidityIncrement,
+		/// Share increment is lower than the acceptable minimum
+		UnacceptableShareIncrement,
+		/// Withdrawn liquidity is lower than the acceptable minimum
+		UnacceptableLiquidityWithdrawn,
+		/// Share amount is zero or exceeds the account balance
+		InvalidShareAmount,
+		/// Pool does not have enough liquidity
+		InsufficientLiquidity,
+		/// Swap path length is invalid
+		InvalidTradingPathLength,
+		/// Target amount is lower than the acceptable minimum
+		InsufficientTargetAmount,
+		/// Supply amount exceeds the acceptable maximum
+		ExcessiveSupplyAmount,
+		/// Swap amount is zero
+		ZeroSwapAmount,
+		/// Swap path goes through the same trading pair more than once
+		RepeatedTradingPair,
+		/// Swap would decrease the product of the pool reserves
+		InvariantCheckFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enable trading pair for providing liquidity and swapping
+		///
+		/// The dispatch origin for this call must be _GovernanceOrigin_.
+		/// - `currency_id_a`: currency of the trading pair
+		/// - `currency_id_b`: the other currency of the trading pair
+		///
+		/// Emits `TradingPairEnabled` if successful.
+		#[pallet::weight(T::WeightInfo::enable_trading_pair())]
+		pub fn enable_trading_pair(
+			origin: OriginFor<T>,
+			currency_id_a: FungibleTokenId,
+			currency_id_b: FungibleTokenId,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidTradingPair)?;
+			ensure!(
+				Self::trading_pair_statuses(trading_pair) != TradingPairStatus::Enabled,
+				Error::<T>::TradingPairAlreadyEnabled
+			);
+
+			Self::do_enable_trading_pair(trading_pair)
+		}
+
+		/// Disable trading pair, existing liquidity can still be removed
+		///
+		/// The dispatch origin for this call must be _GovernanceOrigin_.
+		/// - `currency_id_a`: currency of the trading pair
+		/// - `currency_id_b`: the other currency of the trading pair
+		///
+		/// Emits `TradingPairDisabled` if successful.
+		#[pallet::weight(T::WeightInfo::disable_trading_pair())]
+		pub fn disable_trading_pair(
+			origin: OriginFor<T>,
+			currency_id_a: FungibleTokenId,
+			currency_id_b: FungibleTokenId,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidTradingPair)?;
+			ensure!(
+				Self::trading_pair_statuses(trading_pair) == TradingPairStatus::Enabled,
+				Error::<T>::TradingPairNotEnabled
+			);
+
+			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Disabled);
+
+			Self::deposit_event(Event::<T>::TradingPairDisabled(trading_pair));
+
+			Ok(())
+		}
+
+		/// Add liquidity to the pool of an enabled trading pair and receive DEXShare tokens
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `currency_id_a`: currency of the trading pair
+		/// - `currency_id_b`: the other currency of the trading pair
+		/// - `max_amount_a`: maximum amount of `currency_id_a` to add
+		/// - `max_amount_b`: maximum amount of `currency_id_b` to add
+		/// - `min_share_increment`: minimum acceptable amount of DEXShare tokens received
+		///
+		/// Emits `LiquidityAdded` if successful.
+		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			currency_id_a: FungibleTokenId,
+			currency_id_b: FungibleTokenId,
+			#[pallet::compact] max_amount_a: Balance,
+			#[pallet::compact] max_amount_b: Balance,
+			#[pallet::compact] min_share_increment: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_add_liquidity(
+				&who,
+				currency_id_a,
+				currency_id_b,
+				max_amount_a,
+				max_amount_b,
+				min_share_increment,
+			)
+		}
+
+		/// Burn DEXShare tokens and withdraw the share of the pool liquidity
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `currency_id_a`: currency of the trading pair
+		/// - `currency_id_b`: the other currency of the trading pair
+		/// - `remove_share`: amount of DEXShare tokens to burn
+		/// - `min_withdrawn_a`: minimum acceptable amount of `currency_id_a` withdrawn
+		/// - `min_withdrawn_b`: minimum acceptable amount of `currency_id_b` withdrawn
+		///
+		/// Emits `LiquidityRemoved` if successful.
+		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			currency_id_a: FungibleTokenId,
+			currency_id_b: FungibleTokenId,
+			#[pallet::compact] remove_share: Balance,
+			#[pallet::compact] min_withdrawn_a: Balance,
+			#[pallet::compact] min_withdrawn_b: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidTradingPair)?;
+			let dex_share_currency_id = trading_pair.dex_share_currency_id();
+			ensure!(
+				!remove_share.is_zero() && T::MultiCurrency::free_balance(dex_share_currency_id, &who) >= remove_share,
+				Error::<T>::InvalidShareAmount
+			);
+
+			let total_shares = T::MultiCurrency::total_issuance(dex_share_currency_id);
+			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+			let withdrawn_0 = Self::proportion_of(pool_0, remove_share, total_shares)?;
+			let withdrawn_1 = Self::proportion_of(pool_1, remove_share, total_shares)?;
+			let (withdrawn_a, withdrawn_b) = if currency_id_a == trading_pair.first() {
+				(withdrawn_0, withdrawn_1)
+			} else {
+				(withdrawn_1, withdrawn_0)
+			};
+			ensure!(
+				withdrawn_a >= min_withdrawn_a && withdrawn_b >= min_withdrawn_b,
+				Error::<T>::UnacceptableLiquidityWithdrawn
+			);
+
+			let pool_account = Self::account_id();
+			T::MultiCurrency::withdraw(dex_share_currency_id, &who, remove_share)?;
+			T::MultiCurrency::transfer(trading_pair.first(), &pool_account, &who, withdrawn_0)?;
+			T::MultiCurrency::transfer(trading_pair.second(), &pool_account, &who, withdrawn_1)?;
+
+			Self::update_price_cumulative(&trading_pair, pool_0, pool_1);
+			LiquidityPool::<T>::insert(
+				trading_pair,
+				(pool_0.saturating_sub(withdrawn_0), pool_1.saturating_sub(withdrawn_1)),
+			);
+
+			Self::deposit_event(Event::<T>::LiquidityRemoved(
+				who,
+				currency_id_a,
+				withdrawn_a,
+				currency_id_b,
+				withdrawn_b,
+				remove_share,
+			));
+
+			Ok(())
+		}
+
+		/// Swap exact amount of the first currency of the path for the last currency of the path
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `path`: currencies to swap through, every adjacent pair must be an enabled trading
+		///   pair
+		/// - `supply_amount`: exact amount of the first currency to supply
+		/// - `min_target_amount`: minimum acceptable amount of the last currency received
+		///
+		/// Emits `Swapped` if successful.
+		#[pallet::weight(T::WeightInfo::swap_exact_in(path.len() as u32))]
+		#[transactional]
+		pub fn swap_exact_in(
+			origin: OriginFor<T>,
+			path: Vec<FungibleTokenId>,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amounts = Self::get_target_amounts(&path, supply_amount)?;
+			let target_amount = amounts.last().copied().unwrap_or_default();
+			ensure!(target_amount >= min_target_amount, Error::<T>::InsufficientTargetAmount);
+
+			Self::swap_by_path(&who, &path, &amounts)
+		}
+
+		/// Swap the first currency of the path for exact amount of the last currency of the path
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `path`: currencies to swap through, every adjacent pair must be an enabled trading
+		///   pair
+		/// - `target_amount`: exact amount of the last currency to receive
+		/// - `max_supply_amount`: maximum acceptable amount of the first currency supplied
+		///
+		/// Emits `Swapped` if successful.
+		#[pallet::weight(T::WeightInfo::swap_exact_out(path.len() as u32))]
+		#[transactional]
+		pub fn swap_exact_out(
+			origin: OriginFor<T>,
+			path: Vec<FungibleTokenId>,
+			#[pallet::compact] target_amount: Balance,
+			#[pallet::compact] max_supply_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amounts = Self::get_supply_amounts(&path, target_amount)?;
+			let supply_amount = amounts.first().copied().unwrap_or_default();
+			ensure!(supply_amount <= max_supply_amount, Error::<T>::ExcessiveSupplyAmount);
+
+			Self::swap_by_path(&who, &path, &amounts)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Get pool reserves of both currencies in the requested order
+	pub fn get_liquidity(currency_id_a: FungibleTokenId, currency_id_b: FungibleTokenId) -> (Balance, Balance) {
+		match TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
+			Some(trading_pair) => {
+				let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+				if currency_id_a == trading_pair.first() {
+					(pool_0, pool_1)
+				} else {
+					(pool_1, pool_0)
+				}
+			}
+			None => (Zero::zero(), Zero::zero()),
+		}
+	}
+
+	fn do_enable_trading_pair(trading_pair: TradingPair) -> DispatchResult {
+		// DEXShare currency only keeps the token ids, make sure it is not shared by another pair
+		let dex_share_currency_id = trading_pair.dex_share_currency_id();
+		if let Some(existing_trading_pair) = Self::dex_share_trading_pair(dex_share_currency_id) {
+			ensure!(
+				existing_trading_pair == trading_pair,
+				Error::<T>::DEXShareCurrencyAlreadyInUse
+			);
+		}
+
+		TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+		DEXShareTradingPair::<T>::insert(dex_share_currency_id, trading_pair);
+
+		Self::deposit_event(Event::<T>::TradingPairEnabled(trading_pair));
+
+		Ok(())
+	}
+
+	#[transactional]
+	fn do_add_liquidity(
+		who: &T::AccountId,
+		currency_id_a: FungibleTokenId,
+		currency_id_b: FungibleTokenId,
+		max_amount_a: Balance,
+		max_amount_b: Balance,
+		min_share_increment: Balance,
+	) -> DispatchResult {
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidTradingPair)?;
+		ensure!(
+			Self::trading_pair_statuses(trading_pair) == TradingPairStatus::Enabled,
+			Error::<T>::TradingPairNotEnabled
+		);
+		ensure!(
+			!max_amount_a.is_zero() && !max_amount_b.is_zero(),
+			Error::<T>::InvalidLiquidityIncrement
+		);
+
+		let dex_share_currency_id = trading_pair.dex_share_currency_id();
+		let total_shares = T::MultiCurrency::total_issuance(dex_share_currency_id);
+		let (pool_a, pool_b) = Self::get_liquidity(currency_id_a, currency_id_b);
+
+		let (amount_a, amount_b, share_increment) = if total_shares.is_zero() || pool_a.is_zero() || pool_b.is_zero() {
+			// First liquidity sets the price, shares are the geometric mean of both amounts
+			let share_increment: Balance = U256::from(max_amount_a)
+				.saturating_mul(U256::from(max_amount_b))
+				.integer_sqrt()
+				.as_u128();
+			(max_amount_a, max_amount_b, share_increment)
+		} else {
+			// Add liquidity at the current pool price
+			let optimal_amount_b = Self::proportion_of(pool_b, max_amount_a, pool_a)?;
+			let (amount_a, amount_b) = if optimal_amount_b <= max_amount_b {
+				(max_amount_a, optimal_amount_b)
+			} else {
+				(Self::proportion_of(pool_a, max_amount_b, pool_b)?, max_amount_b)
+			};
+			let share_increment = Self::proportion_of(total_shares, amount_a, pool_a)?.min(Self::proportion_of(
+				total_shares,
+				amount_b,
+				pool_b,
+			)?);
+			(amount_a, amount_b, share_increment)
+		};
+
+		ensure!(
+			!amount_a.is_zero() && !amount_b.is_zero() && !share_increment.is_zero(),
+			Error::<T>::InvalidLiquidityIncrement
+		);
+		ensure!(
+			share_increment >= min_share_increment,
+			Error::<T>::UnacceptableShareIncrement
+		);
+
+		let pool_account = Self::account_id();
+		T::MultiCurrency::transfer(currency_id_a, who, &pool_account, amount_a)?;
+		T::MultiCurrency::transfer(currency_id_b, who, &pool_account, amount_b)?;
+		T::MultiCurrency::deposit(dex_share_currency_id, who, share_increment)?;
+
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+		let (amount_0, amount_1) = if currency_id_a == trading_pair.first() {
+			(amount_a, amount_b)
+		} else {
+			(amount_b, amount_a)
+		};
+		Self::update_price_cumulative(&trading_pair, pool_0, pool_1);
+		LiquidityPool::<T>::insert(
+			trading_pair,
+			(
+				pool_0.checked_add(amount_0).ok_or(ArithmeticError::Overflow)?,
+				pool_1.checked_add(amount_1).ok_or(ArithmeticError::Overflow)?,
+			),
+		);
+
+		Self::deposit_event(Event::<T>::LiquidityAdded(
+			who.clone(),
+			currency_id_a,
+			amount_a,
+			currency_id_b,
+			amount_b,
+			share_increment,
+		));
+
+		Ok(())
+	}
+
+	/// Calculate `amount * numerator / denominator` without intermediate overflow
+	fn proportion_of(amount: Balance, numerator: Balance, denominator: Balance) -> Result<Balance, DispatchError> {
+		ensure!(!denominator.is_zero(), Error::<T>::InsufficientLiquidity);
+		let result = U256::from(amount)
+			.saturating_mul(U256::from(numerator))
+			.checked_div(U256::from(denominator))
+			.ok_or(ArithmeticError::DivisionByZero)?;
+		TryInto::<Balance>::try_into(result).map_err(|_| ArithmeticError::Overflow.into())
+	}
+
+	/// Get pool reserves of an enabled trading pair in the swap direction
+	fn get_swap_liquidity(
+		supply_currency_id: FungibleTokenId,
+		target_currency_id: FungibleTokenId,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let trading_pair = TradingPair::from_currency_ids(supply_currency_id, target_currency_id)
+			.ok_or(Error::<T>::InvalidTradingPair)?;
+		ensure!(
+			Self::trading_pair_statuses(trading_pair) == TradingPairStatus::Enabled,
+			Error::<T>::TradingPairNotEnabled
+		);
+
+		let (supply_pool, target_pool) = Self::get_liquidity(supply_currency_id, target_currency_id);
+		ensure!(
+			!supply_pool.is_zero() && !target_pool.is_zero(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		Ok((supply_pool, target_pool))
+	}
+
+	/// Get the target amount received for the supply amount after swap fee
+	pub fn get_target_amount(supply_pool: Balance, target_pool: Balance, supply_amount: Balance) -> Balance {
+		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
+			return Zero::zero();
+		}
+
+		let supply_amount_after_fee = supply_amount.saturating_sub(T::SwapFee::get().mul_ceil(supply_amount));
+		let numerator = U256::from(supply_amount_after_fee).saturating_mul(U256::from(target_pool));
+		let denominator = U256::from(supply_pool).saturating_add(U256::from(supply_amount_after_fee));
+
+		numerator
+			.checked_div(denominator)
+			.and_then(|target_amount| TryInto::<Balance>::try_into(target_amount).ok())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Get the supply amount needed for the target amount including swap fee
+	pub fn get_supply_amount(supply_pool: Balance, target_pool: Balance, target_amount: Balance) -> Balance {
+		if target_amount.is_zero() || supply_pool.is_zero() || target_amount >= target_pool {
+			return Zero::zero();
+		}
+
+		let fee_denominator = U256::from(Perbill::ACCURACY);
+		let fee_multiplier = fee_denominator.saturating_sub(U256::from(T::SwapFee::get().deconstruct()));
+		let numerator = U256::from(supply_pool)
+			.saturating_mul(U256::from(target_amount))
+			.saturating_mul(fee_denominator);
+		let denominator = U256::from(target_pool.saturating_sub(target_amount)).saturating_mul(fee_multiplier);
+
+		numerator
+			.checked_div(denominator)
+			.and_then(|supply_amount| supply_amount.checked_add(U256::one()))
+			.and_then(|supply_amount| TryInto::<Balance>::try_into(supply_amount).ok())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Ensure the path length is within the limit and every hop uses a different trading pair, as
+	/// the amounts of every hop are priced against the reserves before the swap
+	fn ensure_valid_path(path: &[FungibleTokenId]) -> DispatchResult {
+		let path_length = path.len() as u32;
+		ensure!(
+			(2..=T::TradingPathLimit::get()).contains(&path_length),
+			Error::<T>::InvalidTradingPathLength
+		);
+
+		let mut trading_pairs: Vec<TradingPair> = Vec::with_capacity(path.len() - 1);
+		for hop in path.windows(2) {
+			let trading_pair = TradingPair::from_currency_ids(hop[0], hop[1]).ok_or(Error::<T>::InvalidTradingPair)?;
+			ensure!(!trading_pairs.contains(&trading_pair), Error::<T>::RepeatedTradingPair);
+			trading_pairs.push(trading_pair);
+		}
+
+		Ok(())
+	}
+
+	/// Get the amounts of every currency of the path when supplying exact amount
+	pub fn get_target_amounts(path: &[FungibleTokenId], supply_amount: Balance) -> Result<Vec<Balance>, DispatchError> {
+		Self::ensure_valid_path(path)?;
+		ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSwapAmount);
+
+		let mut amounts: Vec<Balance> = vec![supply_amount];
+		for hop in path.windows(2) {
+			let (supply_pool, target_pool) = Self::get_swap_liquidity(hop[0], hop[1])?;
+			let supply_amount = amounts.last().copied().unwrap_or_default();
+			let target_amount = Self::get_target_amount(supply_pool, target_pool, supply_amount);
+			ensure!(!target_amount.is_zero(), Error::<T>::ZeroSwapAmount);
+			amounts.push(target_amount);
+		}
+
+		Ok(amounts)
+	}
+
+	/// Get the amounts of every currency of the path when receiving exact amount
+	pub fn get_supply_amounts(path: &[FungibleTokenId], target_amount: Balance) -> Result<Vec<Balance>, DispatchError> {
+		Self::ensure_valid_path(path)?;
+		ensure!(!target_amount.is_zero(), Error::<T>::ZeroSwapAmount);
+
+		let mut amounts: Vec<Balance> = vec![Zero::zero(); path.len()];
+		amounts[path.len() - 1] = target_amount;
+		for index in (0..path.len() - 1).rev() {
+			let (supply_pool, target_pool) = Self::get_swap_liquidity(path[index], path[index + 1])?;
+			ensure!(amounts[index + 1] < target_pool, Error::<T>::InsufficientLiquidity);
+			let supply_amount = Self::get_supply_amount(supply_pool, target_pool, amounts[index + 1]);
+			ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSwapAmount);
+			amounts[index] = supply_amount;
+		}
+
+		Ok(amounts)
+	}
+
+	/// Move currencies through every pool of the path with the pre-calculated amounts
+	fn swap_by_path(who: &T::AccountId, path: &[FungibleTokenId], amounts: &[Balance]) -> DispatchResult {
+		let pool_account = Self::account_id();
+		let supply_amount = amounts.first().copied().unwrap_or_default();
+		let target_amount = amounts.last().copied().unwrap_or_default();
+
+		T::MultiCurrency::transfer(path[0], who, &pool_account, supply_amount)?;
+
+		for (index, hop) in path.windows(2).enumerate() {
+			let trading_pair = TradingPair::from_currency_ids(hop[0], hop[1]).ok_or(Error::<T>::InvalidTradingPair)?;
+			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+			Self::update_price_cumulative(&trading_pair, pool_0, pool_1);
+
+			let (hop_supply_amount, hop_target_amount) = (amounts[index], amounts[index + 1]);
+			let new_pool = if hop[0] == trading_pair.first() {
+				(
+					pool_0.checked_add(hop_supply_amount).ok_or(ArithmeticError::Overflow)?,
+					pool_1
+						.checked_sub(hop_target_amount)
+						.ok_or(Error::<T>::InsufficientLiquidity)?,
+				)
+			} else {
+				(
+					pool_0
+						.checked_sub(hop_target_amount)
+						.ok_or(Error::<T>::InsufficientLiquidity)?,
+					pool_1.checked_add(hop_supply_amount).ok_or(ArithmeticError::Overflow)?,
+				)
+			};
+			ensure!(
+				U256::from(new_pool.0).saturating_mul(U256::from(new_pool.1))
+					>= U256::from(pool_0).saturating_mul(U256::from(pool_1)),
+				Error::<T>::InvariantCheckFailed
+			);
+			LiquidityPool::<T>::insert(trading_pair, new_pool);
+		}
+
+		T::MultiCurrency::transfer(path[path.len() - 1], &pool_account, who, target_amount)?;
+
+		Self::deposit_event(Event::<T>::Swapped(who.clone(), path.to_vec(), amounts.to_vec()));
+
+		Ok(())
+	}
+
+	/// Price of the first currency quoted in the second currency and vice versa
+	fn pool_prices(pool_0: Balance, pool_1: Balance) -> Option<(Ratio, Ratio)> {
+		Some((
+			Ratio::checked_from_rational(pool_1, pool_0)?,
+			Ratio::checked_from_rational(pool_0, pool_1)?,
+		))
+	}
+
+	/// Accumulated prices of the trading pair extrapolated to the current block
+	fn current_price_cumulative(
+		trading_pair: &TradingPair,
+		pool_0: Balance,
+		pool_1: Balance,
+	) -> PriceCumulative<BlockNumberFor<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut cumulative = Self::price_cumulative(trading_pair);
+		let elapsed: u128 = now.saturating_sub(cumulative.last_updated).unique_saturated_into();
+
+		if !elapsed.is_zero() {
+			if let Some((price_0, price_1)) = Self::pool_prices(pool_0, pool_1) {
+				cumulative.price_0_cumulative = cumulative
+					.price_0_cumulative
+					.saturating_add(U256::from(price_0.into_inner()).saturating_mul(U256::from(elapsed)));
+				cumulative.price_1_cumulative = cumulative
+					.price_1_cumulative
+					.saturating_add(U256::from(price_1.into_inner()).saturating_mul(U256::from(elapsed)));
+			}
+		}
+		cumulative.last_updated = now;
+
+		cumulative
+	}
+
+	/// Accumulate prices of the pool reserves before they change
+	fn update_price_cumulative(trading_pair: &TradingPair, pool_0: Balance, pool_1: Balance) {
+		let cumulative = Self::current_price_cumulative(trading_pair, pool_0, pool_1);
+
+		// Empty pool has no price, start measuring from the first liquidity
+		let (_, latest_observation) = Self::twap_observations(trading_pair);
+		if pool_0.is_zero() || pool_1.is_zero() {
+			TwapObservations::<T>::insert(trading_pair, (cumulative.clone(), cumulative.clone()));
+		} else if cumulative.last_updated.saturating_sub(latest_observation.last_updated) >= T::TwapPeriod::get() {
+			TwapObservations::<T>::insert(trading_pair, (latest_observation, cumulative.clone()));
+		}
+
+		PriceCumulatives::<T>::insert(trading_pair, cumulative);
+	}
+}
+
+impl<T: Config> SwapManager<T::AccountId, FungibleTokenId, Balance> for Pallet<T> {
+	fn add_liquidity(
+		who: &T::AccountId,
+		token_id_a: FungibleTokenId,
+		token_id_b: FungibleTokenId,
+		max_amount_a: Balance,
+		max_amount_b: Balance,
+	) -> DispatchResult {
+		Self::do_add_liquidity(who, token_id_a, token_id_b, max_amount_a, max_amount_b, Zero::zero())
+	}
+
+	fn enable_trading_pair(token_id_a: FungibleTokenId, token_id_b: FungibleTokenId) -> DispatchResult {
+		let trading_pair =
+			TradingPair::from_currency_ids(token_id_a, token_id_b).ok_or(Error::<T>::InvalidTradingPair)?;
+		if Self::trading_pair_statuses(trading_pair) == TradingPairStatus::Enabled {
+			return Ok(());
+		}
+
+		Self::do_enable_trading_pair(trading_pair)
+	}
+}
+
+impl<T: Config> DEXPriceProvider<FungibleTokenId> for Pallet<T> {
+	fn get_spot_price(base: FungibleTokenId, quote: FungibleTokenId) -> Option<Ratio> {
+		let (pool_base, pool_quote) = Self::get_liquidity(base, quote);
+		Ratio::checked_from_rational(pool_quote, pool_base).filter(|price| !price.is_zero())
+	}
+
+	fn get_twap_price(base: FungibleTokenId, quote: FungibleTokenId) -> Option<Ratio> {
+		let trading_pair = TradingPair::from_currency_ids(base, quote)?;
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+		if pool_0.is_zero() || pool_1.is_zero() {
+			return None;
+		}
+
+		let cumulative = Self::current_price_cumulative(&trading_pair, pool_0, pool_1);
+
+		// Measure from the latest observation once it covers a full period, otherwise from the
+		// previous one. There is no average price until the first period has elapsed.
+		let (previous_observation, latest_observation) = Self::twap_observations(trading_pair);
+		let observation =
+			if cumulative.last_updated.saturating_sub(latest_observation.last_updated) >= T::TwapPeriod::get() {
+				latest_observation
+			} else {
+				previous_observation
+			};
+		let period = cumulative.last_updated.saturating_sub(observation.last_updated);
+		if period < T::TwapPeriod::get() {
+			return None;
+		}
+		let elapsed: u128 = period.unique_saturated_into();
+
+		let (price_cumulative, observed_price_cumulative) = if base == trading_pair.first() {
+			(cumulative.price_0_cumulative, observation.price_0_cumulative)
+		} else {
+			(cumulative.price_1_cumulative, observation.price_1_cumulative)
+		};
+		let average_price: u128 = price_cumulative
+			.saturating_sub(observed_price_cumulative)
+			.checked_div(U256::from(elapsed))?
+			.try_into()
+			.ok()?;
+
+		Some(Ratio::from_inner(average_price))
+	}
+}
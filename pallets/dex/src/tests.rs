@@ -0,0 +1,513 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::BadOrigin;
+
+use mock::{RuntimeEvent, *};
+
+use super::*;
+
+fn ksm_social_pair() -> TradingPair {
+	TradingPair::from_currency_ids(KSM, SOCIAL).unwrap()
+}
+
+fn nuum_social_pair() -> TradingPair {
+	TradingPair::from_currency_ids(NUUM, SOCIAL).unwrap()
+}
+
+fn init_ksm_social_pool() {
+	assert_ok!(DexModule::enable_trading_pair(
+		RuntimeOrigin::signed(ALICE),
+		KSM,
+		SOCIAL
+	));
+	assert_ok!(DexModule::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		KSM,
+		SOCIAL,
+		10_000,
+		40_000,
+		0
+	));
+}
+
+#[test]
+fn enable_trading_pair_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ALICE),
+			SOCIAL,
+			KSM
+		));
+		assert_eq!(
+			DexModule::trading_pair_statuses(ksm_social_pair()),
+			TradingPairStatus::Enabled
+		);
+		assert_eq!(
+			DexModule::dex_share_trading_pair(FungibleTokenId::DEXShare(1, 1)),
+			Some(ksm_social_pair())
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::TradingPairEnabled(ksm_social_pair()))
+		);
+
+		assert_ok!(DexModule::disable_trading_pair(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			SOCIAL
+		));
+		assert_eq!(
+			DexModule::trading_pair_statuses(ksm_social_pair()),
+			TradingPairStatus::Disabled
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::TradingPairDisabled(ksm_social_pair()))
+		);
+
+		// Trading pair can be enabled again with the same DEXShare currency
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			SOCIAL
+		));
+	});
+}
+
+#[test]
+fn enable_trading_pair_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(BOB), KSM, SOCIAL),
+			BadOrigin
+		);
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), KSM, KSM),
+			Error::<Runtime>::InvalidTradingPair
+		);
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), KSM, FungibleTokenId::DEXShare(1, 1)),
+			Error::<Runtime>::InvalidTradingPair
+		);
+		assert_noop!(
+			DexModule::disable_trading_pair(RuntimeOrigin::signed(ALICE), KSM, SOCIAL),
+			Error::<Runtime>::TradingPairNotEnabled
+		);
+
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			SOCIAL
+		));
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), KSM, SOCIAL),
+			Error::<Runtime>::TradingPairAlreadyEnabled
+		);
+		// KSM and MINING trading pair share the same DEXShare(1, 1) currency
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), KSM, MINING),
+			Error::<Runtime>::DEXShareCurrencyAlreadyInUse
+		);
+	});
+}
+
+#[test]
+fn swap_manager_enable_trading_pair_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(<DexModule as SwapManager<_, _, _>>::enable_trading_pair(SOCIAL, KSM));
+		assert_eq!(
+			DexModule::trading_pair_statuses(ksm_social_pair()),
+			TradingPairStatus::Enabled
+		);
+		// Enabling an enabled trading pair is a no-op
+		assert_ok!(<DexModule as SwapManager<_, _, _>>::enable_trading_pair(KSM, SOCIAL));
+		assert_noop!(
+			<DexModule as SwapManager<_, _, _>>::enable_trading_pair(KSM, MINING),
+			Error::<Runtime>::DEXShareCurrencyAlreadyInUse
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			SOCIAL
+		));
+		assert_ok!(<DexModule as SwapManager<_, _, _>>::add_liquidity(
+			&ALICE, KSM, SOCIAL, 10_000, 40_000
+		));
+
+		// First liquidity receives square root of the product of both amounts
+		assert_eq!(DexModule::liquidity_pool(ksm_social_pair()), (10_000, 40_000));
+		assert_eq!(
+			Currencies::free_balance(FungibleTokenId::DEXShare(1, 1), &ALICE),
+			20_000
+		);
+		assert_eq!(Currencies::free_balance(KSM, &DexModule::account_id()), 10_000);
+		assert_eq!(Currencies::free_balance(SOCIAL, &DexModule::account_id()), 40_000);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::LiquidityAdded(ALICE, KSM, 10_000, SOCIAL, 40_000, 20_000))
+		);
+
+		// Next liquidity is added at the pool price
+		assert_ok!(DexModule::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			SOCIAL,
+			KSM,
+			8_000,
+			5_000,
+			4_000
+		));
+		assert_eq!(DexModule::liquidity_pool(ksm_social_pair()), (12_000, 48_000));
+		assert_eq!(Currencies::free_balance(FungibleTokenId::DEXShare(1, 1), &BOB), 4_000);
+		assert_eq!(Currencies::free_balance(KSM, &BOB), 998_000);
+		assert_eq!(Currencies::free_balance(SOCIAL, &BOB), 992_000);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::LiquidityAdded(BOB, SOCIAL, 8_000, KSM, 2_000, 4_000))
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexModule::add_liquidity(RuntimeOrigin::signed(ALICE), KSM, SOCIAL, 10_000, 40_000, 0),
+			Error::<Runtime>::TradingPairNotEnabled
+		);
+
+		init_ksm_social_pool();
+		assert_noop!(
+			DexModule::add_liquidity(RuntimeOrigin::signed(BOB), KSM, SOCIAL, 0, 4_000, 0),
+			Error::<Runtime>::InvalidLiquidityIncrement
+		);
+		assert_noop!(
+			DexModule::add_liquidity(RuntimeOrigin::signed(BOB), KSM, SOCIAL, 1_000, 4_000, 2_001),
+			Error::<Runtime>::UnacceptableShareIncrement
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_ksm_social_pool();
+
+		assert_ok!(DexModule::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			SOCIAL,
+			KSM,
+			5_000,
+			10_000,
+			2_500
+		));
+		assert_eq!(DexModule::liquidity_pool(ksm_social_pair()), (7_500, 30_000));
+		assert_eq!(
+			Currencies::free_balance(FungibleTokenId::DEXShare(1, 1), &ALICE),
+			15_000
+		);
+		assert_eq!(Currencies::free_balance(KSM, &ALICE), 992_500);
+		assert_eq!(Currencies::free_balance(SOCIAL, &ALICE), 970_000);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::LiquidityRemoved(ALICE, SOCIAL, 10_000, KSM, 2_500, 5_000))
+		);
+
+		// Liquidity can be removed after trading pair is disabled
+		assert_ok!(DexModule::disable_trading_pair(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			SOCIAL
+		));
+		assert_ok!(DexModule::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			SOCIAL,
+			15_000,
+			0,
+			0
+		));
+		assert_eq!(DexModule::liquidity_pool(ksm_social_pair()), (0, 0));
+		assert_eq!(Currencies::total_issuance(FungibleTokenId::DEXShare(1, 1)), 0);
+		assert_eq!(Currencies::free_balance(KSM, &ALICE), 1_000_000);
+		assert_eq!(Currencies::free_balance(SOCIAL, &ALICE), 1_000_000);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_ksm_social_pool();
+
+		assert_noop!(
+			DexModule::remove_liquidity(RuntimeOrigin::signed(ALICE), KSM, SOCIAL, 0, 0, 0),
+			Error::<Runtime>::InvalidShareAmount
+		);
+		assert_noop!(
+			DexModule::remove_liquidity(RuntimeOrigin::signed(ALICE), KSM, SOCIAL, 20_001, 0, 0),
+			Error::<Runtime>::InvalidShareAmount
+		);
+		assert_noop!(
+			DexModule::remove_liquidity(RuntimeOrigin::signed(BOB), KSM, SOCIAL, 1, 0, 0),
+			Error::<Runtime>::InvalidShareAmount
+		);
+		assert_noop!(
+			DexModule::remove_liquidity(RuntimeOrigin::signed(ALICE), KSM, SOCIAL, 5_000, 2_501, 0),
+			Error::<Runtime>::UnacceptableLiquidityWithdrawn
+		);
+	});
+}
+
+#[test]
+fn swap_exact_in_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_ksm_social_pool();
+
+		// 1000 KSM - 3 (0.3% swap fee) = 997 KSM swapped for 997 * 40000 / 10997 SOCIAL
+		assert_eq!(
+			DexModule::get_target_amounts(&[KSM, SOCIAL], 1_000),
+			Ok(vec![1_000, 3_626])
+		);
+		assert_ok!(DexModule::swap_exact_in(
+			RuntimeOrigin::signed(BOB),
+			vec![KSM, SOCIAL],
+			1_000,
+			3_626
+		));
+		assert_eq!(DexModule::liquidity_pool(ksm_social_pair()), (11_000, 36_374));
+		assert_eq!(Currencies::free_balance(KSM, &BOB), 999_000);
+		assert_eq!(Currencies::free_balance(SOCIAL, &BOB), 1_003_626);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::Swapped(BOB, vec![KSM, SOCIAL], vec![1_000, 3_626]))
+		);
+	});
+}
+
+#[test]
+fn swap_exact_out_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_ksm_social_pool();
+
+		assert_noop!(
+			DexModule::swap_exact_out(RuntimeOrigin::signed(BOB), vec![KSM, SOCIAL], 4_000, 1_114),
+			Error::<Runtime>::ExcessiveSupplyAmount
+		);
+		assert_ok!(DexModule::swap_exact_out(
+			RuntimeOrigin::signed(BOB),
+			vec![KSM, SOCIAL],
+			4_000,
+			1_115
+		));
+		assert_eq!(DexModule::liquidity_pool(ksm_social_pair()), (11_115, 36_000));
+		assert_eq!(Currencies::free_balance(KSM, &BOB), 998_885);
+		assert_eq!(Currencies::free_balance(SOCIAL, &BOB), 1_004_000);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::Swapped(BOB, vec![KSM, SOCIAL], vec![1_115, 4_000]))
+		);
+	});
+}
+
+#[test]
+fn swap_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_ksm_social_pool();
+
+		assert_noop!(
+			DexModule::swap_exact_in(RuntimeOrigin::signed(BOB), vec![KSM], 1_000, 0),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+		assert_noop!(
+			DexModule::swap_exact_in(RuntimeOrigin::signed(BOB), vec![KSM, SOCIAL, KSM, SOCIAL], 1_000, 0),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+		assert_noop!(
+			DexModule::swap_exact_in(RuntimeOrigin::signed(BOB), vec![KSM, NUUM], 1_000, 0),
+			Error::<Runtime>::TradingPairNotEnabled
+		);
+		assert_noop!(
+			DexModule::swap_exact_in(RuntimeOrigin::signed(BOB), vec![KSM, SOCIAL, KSM], 1_000, 0),
+			Error::<Runtime>::RepeatedTradingPair
+		);
+		assert_noop!(
+			DexModule::swap_exact_out(RuntimeOrigin::signed(BOB), vec![SOCIAL, KSM, SOCIAL], 1_000, 1_000_000),
+			Error::<Runtime>::RepeatedTradingPair
+		);
+		assert_noop!(
+			DexModule::swap_exact_in(RuntimeOrigin::signed(BOB), vec![KSM, SOCIAL], 0, 0),
+			Error::<Runtime>::ZeroSwapAmount
+		);
+		assert_noop!(
+			DexModule::swap_exact_in(RuntimeOrigin::signed(BOB), vec![KSM, SOCIAL], 1_000, 3_627),
+			Error::<Runtime>::InsufficientTargetAmount
+		);
+		assert_noop!(
+			DexModule::swap_exact_out(RuntimeOrigin::signed(BOB), vec![KSM, SOCIAL], 40_000, 1_000_000),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+
+		assert_ok!(DexModule::disable_trading_pair(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			SOCIAL
+		));
+		assert_noop!(
+			DexModule::swap_exact_in(RuntimeOrigin::signed(BOB), vec![KSM, SOCIAL], 1_000, 0),
+			Error::<Runtime>::TradingPairNotEnabled
+		);
+	});
+}
+
+#[test]
+fn multi_hop_swap_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_ksm_social_pool();
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ALICE),
+			NUUM,
+			SOCIAL
+		));
+		assert_ok!(DexModule::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			SOCIAL,
+			NUUM,
+			40_000,
+			20_000,
+			0
+		));
+
+		// KSM is swapped to SOCIAL which is swapped to NUUM
+		assert_ok!(DexModule::swap_exact_in(
+			RuntimeOrigin::signed(BOB),
+			vec![KSM, SOCIAL, NUUM],
+			1_000,
+			1_657
+		));
+		assert_eq!(DexModule::liquidity_pool(ksm_social_pair()), (11_000, 36_374));
+		assert_eq!(DexModule::liquidity_pool(nuum_social_pair()), (18_343, 43_626));
+		assert_eq!(Currencies::free_balance(KSM, &BOB), 999_000);
+		assert_eq!(Currencies::free_balance(SOCIAL, &BOB), 1_000_000);
+		assert_eq!(Currencies::free_balance(NUUM, &BOB), 1_001_657);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::Swapped(
+				BOB,
+				vec![KSM, SOCIAL, NUUM],
+				vec![1_000, 3_626, 1_657]
+			))
+		);
+
+		// Swap back exact NUUM amount through the same path in reverse
+		let amounts = DexModule::get_supply_amounts(&[NUUM, SOCIAL, KSM], 500).unwrap();
+		assert_ok!(DexModule::swap_exact_out(
+			RuntimeOrigin::signed(BOB),
+			vec![NUUM, SOCIAL, KSM],
+			500,
+			amounts[0]
+		));
+		assert_eq!(Currencies::free_balance(KSM, &BOB), 999_500);
+		assert_eq!(Currencies::free_balance(NUUM, &BOB), 1_001_657 - amounts[0]);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Dex(crate::Event::Swapped(BOB, vec![NUUM, SOCIAL, KSM], amounts))
+		);
+	});
+}
+
+#[test]
+fn twap_price_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(DexModule::get_twap_price(KSM, SOCIAL), None);
+
+		init_ksm_social_pool();
+		assert_eq!(
+			DexModule::get_spot_price(KSM, SOCIAL),
+			Some(Ratio::saturating_from_integer(4))
+		);
+		assert_eq!(
+			DexModule::get_spot_price(SOCIAL, KSM),
+			Some(Ratio::saturating_from_rational(1, 4))
+		);
+		// No average price until TwapPeriod has passed since the first liquidity
+		assert_eq!(DexModule::get_twap_price(KSM, SOCIAL), None);
+
+		run_to_block(5);
+		assert_eq!(DexModule::get_twap_price(KSM, SOCIAL), None);
+
+		// Swap moves the spot price to 20031 / 20000
+		assert_ok!(DexModule::swap_exact_in(
+			RuntimeOrigin::signed(BOB),
+			vec![KSM, SOCIAL],
+			10_000,
+			0
+		));
+		assert_eq!(DexModule::liquidity_pool(ksm_social_pair()), (20_000, 20_031));
+		assert_eq!(
+			DexModule::get_spot_price(KSM, SOCIAL),
+			Some(Ratio::saturating_from_rational(20_031, 20_000))
+		);
+
+		run_to_block(9);
+		assert_eq!(DexModule::get_twap_price(KSM, SOCIAL), None);
+
+		// 4 blocks at price 4 and 6 blocks at price 1.00155
+		run_to_block(11);
+		assert_eq!(
+			DexModule::get_twap_price(KSM, SOCIAL),
+			Some(Ratio::from_inner(2_200_930_000_000_000_000))
+		);
+
+		// Observation is moved forward once it is older than TwapPeriod
+		run_to_block(12);
+		assert_ok!(DexModule::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			KSM,
+			SOCIAL,
+			20_000,
+			20_031,
+			0
+		));
+		let (previous_observation, latest_observation) = DexModule::twap_observations(ksm_social_pair());
+		assert_eq!(previous_observation.last_updated, 1);
+		assert_eq!(latest_observation.last_updated, 12);
+
+		// Still measured from the previous observation, 4 blocks at price 4 and 7 blocks at price
+		// 1.00155
+		assert_eq!(
+			DexModule::get_twap_price(KSM, SOCIAL),
+			Some(Ratio::from_inner(2_091_895_454_545_454_545))
+		);
+
+		// Measured from the latest observation once it covers a full period
+		run_to_block(22);
+		assert_eq!(
+			DexModule::get_twap_price(KSM, SOCIAL),
+			Some(Ratio::saturating_from_rational(20_031, 20_000))
+		);
+	});
+}
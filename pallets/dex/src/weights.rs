@@ -0,0 +1,142 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for dex, estimated from storage access until benchmarks are added
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for dex.
+pub trait WeightInfo {
+	fn enable_trading_pair() -> Weight;
+	fn disable_trading_pair() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn swap_exact_in(u: u32) -> Weight;
+	fn swap_exact_out(u: u32) -> Weight;
+}
+
+/// Weights for dex using the for collator node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	// Storage: Dex DEXShareTradingPair (r:1 w:1)
+	fn enable_trading_pair() -> Weight {
+		Weight::from_parts(20_000_000, 5000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	fn disable_trading_pair() -> Weight {
+		Weight::from_parts(15_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Tokens Accounts (r:5 w:5)
+	// Storage: Dex PriceCumulatives (r:1 w:1)
+	// Storage: Dex TwapObservations (r:1 w:1)
+	fn add_liquidity() -> Weight {
+		Weight::from_parts(70_000_000, 20000)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Tokens Accounts (r:5 w:5)
+	// Storage: Dex PriceCumulatives (r:1 w:1)
+	// Storage: Dex TwapObservations (r:1 w:1)
+	fn remove_liquidity() -> Weight {
+		Weight::from_parts(70_000_000, 20000)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Dex PriceCumulatives (r:1 w:1)
+	// Storage: Dex TwapObservations (r:1 w:1)
+	// Storage: Tokens Accounts (r:4 w:4)
+	/// The range of component `u` is `[2, 4]`.
+	fn swap_exact_in(u: u32) -> Weight {
+		Weight::from_parts(40_000_000, 10000)
+			.saturating_add(Weight::from_parts(20_000_000, 5000).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(u.into())))
+	}
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Dex PriceCumulatives (r:1 w:1)
+	// Storage: Dex TwapObservations (r:1 w:1)
+	// Storage: Tokens Accounts (r:4 w:4)
+	/// The range of component `u` is `[2, 4]`.
+	fn swap_exact_out(u: u32) -> Weight {
+		Weight::from_parts(40_000_000, 10000)
+			.saturating_add(Weight::from_parts(20_000_000, 5000).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(u.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn enable_trading_pair() -> Weight {
+		Weight::from_parts(20_000_000, 5000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn disable_trading_pair() -> Weight {
+		Weight::from_parts(15_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn add_liquidity() -> Weight {
+		Weight::from_parts(70_000_000, 20000)
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(9))
+	}
+	fn remove_liquidity() -> Weight {
+		Weight::from_parts(70_000_000, 20000)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(9))
+	}
+	fn swap_exact_in(u: u32) -> Weight {
+		Weight::from_parts(40_000_000, 10000)
+			.saturating_add(Weight::from_parts(20_000_000, 5000).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(u.into())))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(u.into())))
+	}
+	fn swap_exact_out(u: u32) -> Weight {
+		Weight::from_parts(40_000_000, 10000)
+			.saturating_add(Weight::from_parts(20_000_000, 5000).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(u.into())))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(u.into())))
+	}
+}
@@ -302,6 +302,8 @@ pub mod pallet {
 		LastInnovationStakingEraUpdated(BlockNumberFor<T>),
 		/// Estimated reward per era
 		EstimatedRewardPerEraUpdated(BalanceOf<T>),
+		/// Power transferred [from, to, power_amount]
+		PowerTransferred(T::AccountId, T::AccountId, PowerAmount),
 	}
 
 	#[pallet::error]
@@ -1050,6 +1052,36 @@ pub mod pallet {
 			}
 			Ok(())
 		}
+
+		/// Transfer power to another account
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `beneficiary`: the account receiving the power
+		/// `amount`: the power amount
+		///
+		/// Emit `PowerTransferred` event if successful
+		#[pallet::weight(T::WeightInfo::transfer_power())]
+		#[transactional]
+		pub fn transfer_power(origin: OriginFor<T>, beneficiary: T::AccountId, amount: PowerAmount) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::PowerAmountIsZero);
+
+			PowerBalance::<T>::try_mutate(&who, |power_balance| -> DispatchResult {
+				*power_balance = power_balance
+					.checked_sub(amount)
+					.ok_or(Error::<T>::AccountHasNoPowerBalance)?;
+				Ok(())
+			})?;
+			PowerBalance::<T>::try_mutate(&beneficiary, |power_balance| -> DispatchResult {
+				*power_balance = power_balance.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::PowerTransferred(who, beneficiary, amount));
+			Ok(())
+		}
 	}
 }
 
@@ -1404,3 +1436,20 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 }
+
+impl<T: Config> EstateStakeProvider<BalanceOf<T>> for Pallet<T> {
+	fn estate_stakes(start_after: Option<EstateId>, limit: u32) -> (Vec<(EstateId, BalanceOf<T>)>, Option<EstateId>) {
+		let mut stakes = match start_after {
+			Some(estate_id) => EstateStakingInfo::<T>::iter_from(EstateStakingInfo::<T>::hashed_key_for(estate_id)),
+			None => EstateStakingInfo::<T>::iter(),
+		}
+		.map(|(estate_id, bond)| (estate_id, bond.amount));
+
+		let page: Vec<(EstateId, BalanceOf<T>)> = stakes.by_ref().take(limit as usize).collect();
+		let next = match stakes.next() {
+			Some(_) => page.last().map(|(estate_id, _)| *estate_id),
+			None => None,
+		};
+		(page, next)
+	}
+}
@@ -2,6 +2,7 @@
 
 use frame_support::traits::{Contains, InstanceFilter, Nothing};
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
+use frame_system::pallet_prelude::BlockNumberFor;
 use frame_system::Call as SystemCall;
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
@@ -190,6 +191,16 @@ impl Estate<AccountId> for EstateHandler {
 	fn is_estate_leased(_estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(false)
 	}
+	fn get_estate_token(_estate_id: EstateId) -> Option<(ClassId, TokenId)> {
+		None
+	}
+
+	fn get_land_unit_token(
+		_metaverse_id: primitives::MetaverseId,
+		_coordinate: (i32, i32),
+	) -> Option<(ClassId, TokenId)> {
+		None
+	}
 }
 
 pub struct MetaverseStakingHandler;
@@ -335,6 +346,24 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn sealed_bid_commit_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_commitment: [u8; 32],
+		_deposit: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn sealed_bid_reveal_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_value: Self::Balance,
+		_salt: Vec<u8>,
+	) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
 	fn local_auction_bid_handler(
 		_now: u64,
 		_id: u64,
@@ -368,9 +397,25 @@ parameter_types! {
 	pub MetaverseNetworkTreasuryPalletId: PalletId = PalletId(*b"bit/trsy");
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 2000;
+	pub MaxRoyaltyRecipients: u32 = 3;
+	pub MaxEquipmentDepth: u32 = 3;
+	pub MaxEquippedItems: u32 = 10;
+	pub MaxCraftingIngredients: u32 = 5;
+	pub MaxAttributeHistory: u32 = 3;
+	pub MaxTokenAttributes: u32 = 4;
 	pub MaxMetadata: u32 = 10;
 }
 
+pub struct MockRandomness;
+impl frame_support::traits::Randomness<H256, BlockNumberFor<Runtime>> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumberFor<Runtime>) {
+		(
+			<BlakeTwo256 as sp_runtime::traits::Hash>::hash(subject),
+			frame_system::Pallet::<Runtime>::block_number(),
+		)
+	}
+}
+
 impl pallet_nft::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -388,6 +433,14 @@ impl pallet_nft::Config for Runtime {
 	type StorageDepositFee = StorageDepositFee;
 	type OffchainSignature = Signature;
 	type OffchainPublic = AccountPublic;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxEquipmentDepth = MaxEquipmentDepth;
+	type MaxEquippedItems = MaxEquippedItems;
+	type MaxCraftingIngredients = MaxCraftingIngredients;
+	type Randomness = MockRandomness;
+	type MaxAttributeHistory = MaxAttributeHistory;
+	type MaxTokenAttributes = MaxTokenAttributes;
+	type ExecutableCallFilter = ProxyType;
 }
 
 parameter_types! {
@@ -195,6 +195,25 @@ fn stake_should_work_for_estate() {
 	});
 }
 
+#[test]
+fn estate_stakes_should_list_staked_estates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(EconomyModule::estate_stakes(None, 10), (vec![], None));
+
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+
+		assert_eq!(
+			EconomyModule::estate_stakes(None, 10),
+			(vec![(OWNED_ESTATE_ID, STAKE_BALANCE)], None)
+		);
+		assert_eq!(EconomyModule::estate_stakes(Some(OWNED_ESTATE_ID), 10), (vec![], None));
+	});
+}
+
 #[test]
 fn stake_should_work_with_more_operations() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -805,3 +824,31 @@ fn claim_reward_after_unstaking_should_not_update_balance() {
 		assert_eq!(Balances::free_balance(account(1)), 9100u128);
 	});
 }
+
+#[test]
+fn transfer_power_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		PowerBalance::<Runtime>::insert(account(1), ALICE_POWER_AMOUNT);
+
+		assert_noop!(
+			EconomyModule::transfer_power(RuntimeOrigin::signed(account(1)), account(2), 0),
+			Error::<Runtime>::PowerAmountIsZero
+		);
+		assert_noop!(
+			EconomyModule::transfer_power(RuntimeOrigin::signed(account(1)), account(2), ALICE_POWER_AMOUNT + 1),
+			Error::<Runtime>::AccountHasNoPowerBalance
+		);
+
+		assert_ok!(EconomyModule::transfer_power(
+			RuntimeOrigin::signed(account(1)),
+			account(2),
+			5000
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::PowerTransferred(account(1), account(2), 5000))
+		);
+		assert_eq!(EconomyModule::get_power_balance(account(1)), ALICE_POWER_AMOUNT - 5000);
+		assert_eq!(EconomyModule::get_power_balance(account(2)), 5000);
+	});
+}
@@ -47,7 +47,7 @@ use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions needed for economy.
-pub trait WeightInfo {	fn stake_a() -> Weight;	fn stake_b() -> Weight;	fn stake_on_innovation() -> Weight;	fn unstake_a() -> Weight;	fn unstake_b() -> Weight;	fn unstake_new_estate_owner() -> Weight;	fn unstake_on_innovation() -> Weight;	fn withdraw_unreserved() -> Weight;	fn claim_reward() -> Weight;}
+pub trait WeightInfo {	fn stake_a() -> Weight;	fn stake_b() -> Weight;	fn stake_on_innovation() -> Weight;	fn unstake_a() -> Weight;	fn unstake_b() -> Weight;	fn unstake_new_estate_owner() -> Weight;	fn unstake_on_innovation() -> Weight;	fn withdraw_unreserved() -> Weight;	fn claim_reward() -> Weight;	fn transfer_power() -> Weight;}
 
 /// Weights for economy using the for collator node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -218,6 +218,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	// Storage: Mi
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	// Storage: Economy PowerBalance (r:2 w:2)
+	// Proof Skipped: Economy PowerBalance (max_values: None, max_size: None, mode: Measured)
+	fn transfer_power() -> Weight {
+		Weight::from_parts(21_480_000, 5160)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
 
 // For backwards compatibility and tests
@@ -266,4 +273,9 @@ impl WeightInfo for () {	fn stake_a() -> Weight {
 			.saturating_add(RocksDbWeight::get().reads(3))
 			.saturating_add(RocksDbWeight::get().writes(3))
 	}
+	fn transfer_power() -> Weight {
+		Weight::from_parts(21_480_000, 5160)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
 }
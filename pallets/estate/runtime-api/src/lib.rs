@@ -0,0 +1,52 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use primitives::{
+	estate::{EstateOperation, LandAsset},
+	EstateId, MetaverseId,
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Spatial queries, build permissions and batch validation of land units and estates
+	pub trait EstateApi<AccountId> where AccountId: Codec {
+		/// Estates of a metaverse with land units inside a bounding box, failing if the bounding
+		/// box is too large to be scanned at once
+		fn estates_in_bounding_box(
+			metaverse_id: MetaverseId,
+			bottom_left: (i32, i32),
+			top_right: (i32, i32),
+		) -> Result<Vec<EstateId>, DispatchError>;
+
+		/// Estates adjacent to an estate
+		fn estate_neighbours(estate_id: EstateId) -> Vec<EstateId>;
+
+		/// Check whether an account may build on a land unit or an estate
+		fn can_build(who: AccountId, asset: LandAsset) -> bool;
+
+		/// Validate a batch of land and estate operations of an account without applying it,
+		/// returning the index and error of the failing operation
+		fn validate_estate_operations(
+			who: AccountId,
+			operations: Vec<EstateOperation<AccountId>>,
+		) -> Result<(), (u32, DispatchError)>;
+	}
+}
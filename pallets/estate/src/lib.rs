@@ -21,28 +21,33 @@ use frame_support::pallet_prelude::*;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	traits::{Currency, ExistenceRequirement, Get},
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Currency, EnsureOriginWithArg, ExistenceRequirement, Get},
 	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
 use frame_system::{ensure_root, ensure_signed};
 
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
 	traits::{AccountIdConversion, Convert, One, Saturating},
-	DispatchError, Perbill, SaturatedConversion,
+	DispatchError, Perbill, Rounding, SaturatedConversion,
 };
-use sp_std::vec::Vec;
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 use auction_manager::{Auction, CheckAuctionItemHandler};
 use core_primitives::*;
 pub use pallet::*;
 use primitives::estate::EstateInfo;
 use primitives::{
-	estate::{Estate, LandUnitStatus, LeaseContract, OwnerId},
+	estate::{
+		BuildPermission, BuilderRole, Estate, EstateConnectivity, EstateOperation, LandAsset, LandMetadata,
+		LandUnitStatus, LeaseContract, OwnerId,
+	},
 	Attributes, ClassId, EstateId, ItemId, MetaverseId, NftMetadata, TokenId, UndeployedLandBlock,
-	UndeployedLandBlockId, UndeployedLandBlockType,
+	UndeployedLandBlockId, UndeployedLandBlockType, ZoneId,
 };
-pub use rate::{MintingRateInfo, Range};
+pub use rate::{AnnualIssuance, MintingRateInfo, Range, RoundLandIssuance};
 pub use weights::WeightInfo;
 
 //#[cfg(feature = "runtime-benchmarks")]
@@ -57,6 +62,9 @@ mod tests;
 
 pub mod weights;
 
+/// The current storage version
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::traits::{Currency, Imbalance, ReservableCurrency};
@@ -72,6 +80,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -96,6 +105,9 @@ pub mod pallet {
 		/// Council origin which allows to update max bound
 		type CouncilOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Origin of a metaverse acting on its own behalf, e.g. a locally enacted proposal
+		type MetaverseGovernanceOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, MetaverseId>;
+
 		/// Auction handler
 		type AuctionHandler: Auction<Self::AccountId, BlockNumberFor<Self>> + CheckAuctionItemHandler<BalanceOf<Self>>;
 
@@ -147,6 +159,52 @@ pub mod pallet {
 
 		/// Allows converting block numbers into balance
 		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self>>;
+
+		/// Maximum number of operations in a batch of land and estate operations
+		#[pallet::constant]
+		type MaxBatchOperations: Get<u32>;
+
+		/// Side length (in land units) of the chunks used by the estate spatial index
+		#[pallet::constant]
+		type EstateChunkSize: Get<u32>;
+
+		/// Maximum number of chunks scanned by a spatial query of estates
+		#[pallet::constant]
+		type MaxSpatialQueryChunks: Get<u32>;
+
+		/// Maximum number of land units of the estates reshaped by a merge or split
+		#[pallet::constant]
+		type MaxReshapeLandUnits: Get<u32>;
+
+		/// Maximum length of the name of a land zoning type
+		#[pallet::constant]
+		type MaxZoneNameLength: Get<u32>;
+
+		/// Maximum number of accounts with a builder role on a land unit or estate
+		#[pallet::constant]
+		type MaxBuildersPerAsset: Get<u32>;
+
+		/// Source of the stake bonded on estates, used to share land block issuance between
+		/// metaverses
+		type EstateStakeSource: EstateStakeProvider<BalanceOf<Self>>;
+
+		/// Number of land units in each land block issued at the start of a round
+		#[pallet::constant]
+		type LandUnitsPerIssuedLandBlock: Get<u32>;
+
+		/// Maximum number of estate stakes read, or of metaverses receiving land blocks, in each
+		/// block of a round issuance
+		#[pallet::constant]
+		type MaxIssuanceRecipients: Get<u32>;
+
+		/// Minimum number of land units issued over a year, used while the circulating supply is
+		/// too low for the annual minting rate to issue any land
+		#[pallet::constant]
+		type MinAnnualLandIssuance: Get<u64>;
+
+		/// Vesting period after which a bound to address undeployed land block can be unlocked
+		#[pallet::constant]
+		type BoundLandBlockVestingPeriod: Get<BlockNumberFor<Self>>;
 	}
 
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -247,6 +305,93 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn estate_connectivity)]
+	/// Connectivity rule that land units of the estates of a metaverse must satisfy
+	pub type MetaverseEstateConnectivity<T: Config> =
+		StorageMap<_, Twox64Concat, MetaverseId, EstateConnectivity, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn estate_chunks)]
+	/// Spatial index of estates by metaverse ID and chunk of land units
+	pub type EstateChunks<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, MetaverseId>,
+			NMapKey<Twox64Concat, (i32, i32)>,
+			NMapKey<Twox64Concat, EstateId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn zones)]
+	/// Land zoning types defined by metaverse ID and zone ID
+	pub type MetaverseZones<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MetaverseId,
+		Twox64Concat,
+		ZoneId,
+		BoundedVec<u8, T::MaxZoneNameLength>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_metadata)]
+	/// Metadata of land units and estates
+	pub type LandAssetMetadata<T: Config> = StorageMap<_, Twox64Concat, LandAsset, LandMetadata<T::Hash>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn builders)]
+	/// Build permissions by land unit or estate and account ID
+	pub type LandBuilders<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		LandAsset,
+		Blake2_128Concat,
+		T::AccountId,
+		BuildPermission<T::AccountId>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_builder_count)]
+	/// Number of accounts with a builder role by land unit or estate
+	pub type LandBuilderCount<T: Config> = StorageMap<_, Twox64Concat, LandAsset, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_land_issuance_paused)]
+	/// Whether the land block issuance at the start of each round is paused
+	pub type LandIssuancePaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn annual_land_issuance)]
+	/// Land units issued at the start of rounds during the current year
+	pub type AnnualLandIssuance<T: Config> = StorageValue<_, AnnualIssuance, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_issuance_stake_cursor)]
+	/// Estate after which the estate stakes of the pending round issuance are read
+	pub type LandIssuanceStakeCursor<T: Config> = StorageValue<_, EstateId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_land_issuance)]
+	/// Land block issuance of the current round which is not yet shared between metaverses
+	pub type PendingLandIssuance<T: Config> = StorageValue<_, RoundLandIssuance, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_issuance_stake_snapshot)]
+	/// Stake bonded on the estates of each metaverse, read for the pending round issuance
+	pub type LandIssuanceStakeSnapshot<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, u128, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn undeployed_land_block_vesting_end)]
+	/// Block number after which a bound to address undeployed land block can be unlocked
+	pub type UndeployedLandBlockVestingEnd<T: Config> =
+		StorageMap<_, Twox64Concat, UndeployedLandBlockId, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -336,6 +481,41 @@ pub mod pallet {
 		EstateRentCollected(EstateId, BalanceOf<T>),
 		/// New staking round started [Starting Block, Round, Total Land Unit]
 		NewRound(BlockNumberFor<T>, RoundIndex, u64),
+		/// Batch of land and estate operations is applied [Account Id, Number of Operations]
+		EstateOperationsExecuted(T::AccountId, u32),
+		/// Estate connectivity rule of a metaverse is updated [Metaverse Id, Connectivity]
+		EstateConnectivityUpdated(MetaverseId, Option<EstateConnectivity>),
+		/// Estates are merged [Estate Id, Merged Estate Id, Land Units]
+		EstatesMerged(EstateId, EstateId, Vec<(i32, i32)>),
+		/// Estate is split [Estate Id, New Estate Id, Land Units Of New Estate]
+		EstateSplit(EstateId, EstateId, Vec<(i32, i32)>),
+		/// Land zoning type is defined [Metaverse Id, Zone Id, Name]
+		ZoneDefined(MetaverseId, ZoneId, Vec<u8>),
+		/// Land zoning type is removed [Metaverse Id, Zone Id]
+		ZoneRemoved(MetaverseId, ZoneId),
+		/// Zoning of a land unit or an estate is updated [Land Asset, Zone Id]
+		LandZoneUpdated(LandAsset, Option<ZoneId>),
+		/// Scene of a land unit or an estate is updated [Land Asset, Account Id, Scene Hash]
+		LandSceneUpdated(LandAsset, T::AccountId, Option<T::Hash>),
+		/// Builder role is granted [Land Asset, Account Id, Role]
+		BuilderRoleGranted(LandAsset, T::AccountId, BuilderRole),
+		/// Builder role is revoked [Land Asset, Account Id]
+		BuilderRoleRevoked(LandAsset, T::AccountId),
+		/// Land blocks issued to a metaverse during a round [Round, Metaverse Id, Beneficiary,
+		/// Number of Land Blocks]
+		RoundLandBlocksIssued(RoundIndex, MetaverseId, T::AccountId, u32),
+		/// Land blocks of a round are issued to all metaverses [Round, Total Land Unit]
+		RoundLandIssuanceCompleted(RoundIndex, u64),
+		/// Land block issuance at the start of each round is paused or resumed [Paused]
+		LandIssuancePauseUpdated(bool),
+		/// Undeployed land block split [Owner, Undeployed Land Block Id, New Undeployed Land
+		/// Block Ids]
+		UndeployedLandBlockSplit(T::AccountId, UndeployedLandBlockId, Vec<UndeployedLandBlockId>),
+		/// Undeployed land blocks merged [Owner, Undeployed Land Block Id, Merged Undeployed Land
+		/// Block Ids]
+		UndeployedLandBlocksMerged(T::AccountId, UndeployedLandBlockId, Vec<UndeployedLandBlockId>),
+		/// Bound to address undeployed land block unlocked after vesting [Undeployed Land Block Id]
+		UndeployedLandBlockUnlocked(UndeployedLandBlockId),
 	}
 
 	#[pallet::error]
@@ -428,14 +608,78 @@ pub mod pallet {
 		LeaseOfferDurationAboveMaximum,
 		/// No unclaimed rent balance
 		NoUnclaimedRentLeft,
+		/// Batch has no operations
+		EmptyBatchOperations,
+		/// Exceed maximum batch operations
+		ExceedMaximumBatchOperations,
+		/// Land units of estate are not connected
+		EstateLandUnitsNotConnected,
+		/// Estates are not in the same metaverse
+		EstatesNotInSameMetaverse,
+		/// Estate cannot be merged with itself
+		CannotMergeEstateWithItself,
+		/// Land units does not split the estate into two valid estates
+		InvalidEstateSplit,
+		/// Estates have more land units than can be merged or split at once
+		ExceedMaximumReshapeLandUnits,
+		/// Bounding box of a spatial query covers more chunks than can be scanned at once
+		SpatialQueryTooLarge,
+		/// Zone name is too long
+		ZoneNameTooLong,
+		/// Zone does not exist
+		ZoneDoesNotExist,
+		/// Land unit or estate does not exist
+		LandAssetDoesNotExist,
+		/// Account has no builder role
+		BuilderRoleDoesNotExist,
+		/// Land unit or estate has reached the maximum number of builders
+		TooManyBuilders,
+		/// Land units of the split undeployed land blocks are invalid
+		InvalidUndeployedLandBlockSplit,
+		/// Merged undeployed land blocks are invalid
+		InvalidUndeployedLandBlockMerge,
+		/// Undeployed land block types do not match
+		UndeployedLandBlockTypeDoesNotMatch,
+		/// Undeployed land block is still vesting
+		UndeployedLandBlockStillVesting,
+		/// Undeployed land block is not bound to address or has no vesting end
+		UndeployedLandBlockNotVesting,
 	}
 
-	// TO DO: Implement offchain removal of expired lease offers
-	//#[pallet::hooks]
-	//impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-	//	fn offchain_worker(block_number: BlockNumberFor<T>) {
-	//	}
-	//}
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut round = Self::round();
+			if !round.should_update(now) {
+				return T::DbWeight::get()
+					.reads(1)
+					.saturating_add(Self::process_round_land_issuance());
+			}
+
+			round.update(now);
+			Round::<T>::put(round);
+
+			let (scheduled_land_units, weight) = if Self::is_land_issuance_paused() {
+				(0, T::DbWeight::get().reads_writes(2, 1))
+			} else {
+				Self::schedule_round_land_issuance(round.current)
+			};
+
+			Self::deposit_event(Event::<T>::NewRound(round.first, round.current, scheduled_land_units));
+			T::WeightInfo::on_initialize()
+				.saturating_add(weight)
+				.saturating_add(Self::process_round_land_issuance())
+		}
+
+		/// Hook that is called every time the runtime is upgraded.
+		fn on_runtime_upgrade() -> Weight {
+			Self::upgrade_estate_chunks_v1().saturating_add(Self::upgrade_undeployed_land_block_vesting_v2())
+		}
+
+		// TO DO: Implement offchain removal of expired lease offers
+		//	fn offchain_worker(block_number: BlockNumberFor<T>) {
+		//	}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -532,6 +776,7 @@ pub mod pallet {
 			coordinates: Vec<(i32, i32)>,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
+			Self::ensure_estate_connectivity(metaverse_id, &coordinates)?;
 
 			// Generate new estate id
 			let new_estate_id = Self::get_new_estate_id()?;
@@ -602,46 +847,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				Self::verify_land_unit_for_estate(coordinates.clone()),
-				Error::<T>::CoordinatesForEstateIsNotValid
-			);
-			// Collect network fee
-			Self::collect_network_fee(&who)?;
-			// Generate new estate id
-			let new_estate_id = Self::get_new_estate_id()?;
-			// Generate sub account from estate
-			let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account_truncating(new_estate_id);
-
-			let token_properties = Self::get_estate_token_properties(metaverse_id, new_estate_id);
-			let class_id = T::MetaverseInfoSource::get_metaverse_estate_class(metaverse_id)?;
-			let token_id: TokenId =
-				T::NFTTokenizationSource::mint_token(&who, class_id, token_properties.0, token_properties.1)?;
-			let beneficiary = OwnerId::Token(class_id, token_id);
-
-			let storage_fee: BalanceOf<T> =
-				Perbill::from_percent(100u32.saturating_mul(coordinates.len() as u32)) * T::StorageDepositFee::get();
-			T::Currency::transfer(
-				&who,
-				&T::MetaverseInfoSource::get_network_treasury(),
-				storage_fee.saturated_into(),
-				ExistenceRequirement::KeepAlive,
-			)?;
-
-			// Mint land units
-			for coordinate in coordinates.clone() {
-				Self::mint_land_unit(
-					metaverse_id,
-					beneficiary.clone(),
-					estate_account_id.clone(),
-					coordinate,
-					LandUnitStatus::Existing(who.clone()),
-				)?;
-			}
-
-			// Update estate information
-			Self::update_estate_information(new_estate_id, metaverse_id, beneficiary, coordinates.clone())?;
-
+			Self::do_create_estate(&who, metaverse_id, coordinates)?;
 			Ok(().into())
 		}
 
@@ -688,79 +894,13 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				!T::AuctionHandler::check_item_in_auction(ItemId::UndeployedLandBlock(undeployed_land_block_id)),
-				Error::<T>::UndeployedLandBlockAlreadyInAuction
-			);
-
-			ensure!(
-				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
-				Error::<T>::NoPermission
-			);
-
-			// Ensure the max bound is set for the metaverse
-			let max_bound = T::DefaultMaxBound::get();
-
-			// Check whether the coordinate is within the bound
-			ensure!(
-				(land_block_coordinate.0 >= max_bound.0 && max_bound.1 >= land_block_coordinate.0)
-					&& (land_block_coordinate.1 >= max_bound.0 && max_bound.1 >= land_block_coordinate.1),
-				Error::<T>::LandUnitIsOutOfBound
-			);
-
-			ensure!(
-				Self::verify_land_unit_in_bound(&land_block_coordinate, &coordinates),
-				Error::<T>::LandUnitIsOutOfBound
-			);
-
-			let undeployed_land_block_record = UndeployedLandBlocks::<T>::get(undeployed_land_block_id)
-				.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
-
-			ensure!(
-				undeployed_land_block_record.owner == who.clone(),
-				Error::<T>::NoPermission
-			);
-
-			ensure!(
-				undeployed_land_block_record.is_locked == false,
-				Error::<T>::UndeployedLandBlockFreezed
-			);
-
-			let land_units_to_mint = coordinates.len() as u32;
-
-			// Ensure undeployed land block only deployed once
-			ensure!(
-				undeployed_land_block_record.number_land_units == land_units_to_mint,
-				Error::<T>::UndeployedLandBlockUnitAndInputDoesNotMatch
-			);
-
-			// Collect network fee
-			Self::collect_network_fee(&who)?;
-
-			// Mint land units
-			for coordinate in coordinates.clone() {
-				Self::mint_land_unit(
-					metaverse_id,
-					OwnerId::Account(who.clone()),
-					who.clone(),
-					coordinate,
-					LandUnitStatus::NonExisting,
-				)?;
-			}
-
-			// Update total land count
-			Self::set_total_land_unit(coordinates.len() as u64, false)?;
-
-			// Burn undeployed land block
-			Self::do_burn_undeployed_land_block(undeployed_land_block_id)?;
-
-			Self::deposit_event(Event::<T>::LandBlockDeployed(
-				who.clone(),
-				metaverse_id,
+			Self::do_deploy_land_block(
+				&who,
 				undeployed_land_block_id,
+				metaverse_id,
+				land_block_coordinate,
 				coordinates,
-			));
-
+			)?;
 			Ok(().into())
 		}
 
@@ -795,6 +935,38 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Distributes an undeployed land block held by the treasury of a metaverse, e.g. one
+		/// issued to the metaverse at the start of a round
+		///
+		/// The dispatch origin for this call must be the metaverse origin of `metaverse_id` or
+		/// _Signed_ by the metaverse owner.
+		/// - `metaverse_id`: the metaverse ID whose treasury holds the undeployed land block
+		/// - `beneficiary`: the account that will receive the undeployed land block
+		/// - `undeployed_land_block_id`: the ID of the undeployed land block that will be
+		///   distributed
+		///
+		/// Emits `UndeployedLandBlockTransferred` if successful.
+		#[pallet::weight(T::WeightInfo::distribute_undeployed_land_blocks())]
+		#[transactional]
+		pub fn distribute_undeployed_land_blocks(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			beneficiary: T::AccountId,
+			undeployed_land_block_id: UndeployedLandBlockId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_metaverse_owner_or_governance(origin, metaverse_id)?;
+
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::UndeployedLandBlock(undeployed_land_block_id)),
+				Error::<T>::UndeployedLandBlockAlreadyInAuction
+			);
+
+			let metaverse_treasury = T::MetaverseInfoSource::get_metaverse_treasury(metaverse_id);
+			Self::do_transfer_undeployed_land_block(&metaverse_treasury, &beneficiary, undeployed_land_block_id)?;
+
+			Ok(().into())
+		}
+
 		/// Freezes undeployed land block which is not already frozen
 		///
 		/// The dispatch origin for this call must be _Root_.
@@ -1046,6 +1218,8 @@ pub mod pallet {
 						}
 
 						// Remove estate
+						Self::remove_estate_from_index(estate_info.metaverse_id, estate_id, &estate_info.land_units);
+						Self::remove_land_asset_metadata(LandAsset::Estate(estate_id));
 						Estates::<T>::remove(&estate_id);
 
 						// Update total estates
@@ -1108,67 +1282,8 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
-			match estate_owner_value {
-				OwnerId::Token(c, t) => {
-					ensure!(
-						!T::AuctionHandler::check_item_in_auction(ItemId::NFT(c, t)),
-						Error::<T>::EstateAlreadyInAuction
-					);
-					ensure!(
-						Self::check_if_land_or_estate_owner(&who, &estate_owner_value),
-						Error::<T>::NoPermission
-					);
-
-					let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
-					let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account_truncating(estate_id);
-
-					let storage_fee: BalanceOf<T> =
-						Perbill::from_percent(100u32.saturating_mul(land_units.len() as u32))
-							* T::StorageDepositFee::get();
-					T::Currency::transfer(
-						&who,
-						&T::MetaverseInfoSource::get_network_treasury(),
-						storage_fee.saturated_into(),
-						ExistenceRequirement::KeepAlive,
-					)?;
-
-					// Check land unit ownership
-					for land_unit in land_units.clone() {
-						let metaverse_land_unit = Self::get_land_units(estate_info.metaverse_id, land_unit)
-							.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
-						ensure!(
-							Self::check_if_land_or_estate_owner(&who, &metaverse_land_unit,),
-							Error::<T>::LandUnitDoesNotExist
-						);
-
-						// Mint land unit
-						Self::mint_land_unit(
-							estate_info.metaverse_id,
-							estate_owner_value.clone(),
-							estate_account_id.clone(),
-							land_unit,
-							LandUnitStatus::Existing(who.clone()),
-						)?;
-					}
-
-					// Mutate estates
-					Estates::<T>::try_mutate_exists(&estate_id, |maybe_estate_info| {
-						// Append new coordinates to estate
-						let mut_estate_info = maybe_estate_info.as_mut().ok_or(Error::<T>::EstateDoesNotExist)?;
-						mut_estate_info.land_units.append(&mut land_units.clone());
-
-						Self::deposit_event(Event::<T>::LandUnitAdded(
-							estate_id.clone(),
-							estate_owner_value.clone(),
-							land_units.clone(),
-						));
-
-						Ok(().into())
-					})
-				}
-				_ => Err(Error::<T>::InvalidOwnerValue.into()),
-			}
+			Self::do_add_land_unit_to_estate(&who, estate_id, land_units)?;
+			Ok(().into())
 		}
 
 		/// Remove land units from existing estate if it is not in auction.
@@ -1188,84 +1303,28 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
-			match estate_owner_value {
-				OwnerId::Token(c, t) => {
-					ensure!(
-						!T::AuctionHandler::check_item_in_auction(ItemId::NFT(c, t)),
-						Error::<T>::EstateAlreadyInAuction
-					);
-					ensure!(
-						Self::check_if_land_or_estate_owner(&who, &estate_owner_value),
-						Error::<T>::NoPermission
-					);
-					let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
-					let _estate_account_id: T::AccountId =
-						T::LandTreasury::get().into_sub_account_truncating(estate_id);
+			Self::do_remove_land_unit_from_estate(&who, estate_id, land_units)?;
+			Ok(().into())
+		}
 
-					// Mutate estates
-					Estates::<T>::try_mutate_exists(&estate_id, |maybe_estate_info| {
-						let mut_estate_info = maybe_estate_info.as_mut().ok_or(Error::<T>::EstateDoesNotExist)?;
-
-						let storage_fee: BalanceOf<T> =
-							Perbill::from_percent(100u32.saturating_mul(land_units.len() as u32))
-								* T::StorageDepositFee::get();
-						T::Currency::transfer(
-							&who,
-							&T::MetaverseInfoSource::get_network_treasury(),
-							storage_fee.saturated_into(),
-							ExistenceRequirement::KeepAlive,
-						)?;
-						// Mutate land unit ownership
-						for land_unit in land_units.clone() {
-							// Transfer land unit from treasury to estate owner
-							Self::mint_land_unit(
-								estate_info.metaverse_id,
-								estate_owner_value.clone(),
-								who.clone(),
-								land_unit,
-								LandUnitStatus::RemovedFromEstate,
-							)?;
-							// Remove coordinates from estate
-							let index = mut_estate_info
-								.land_units
-								.iter()
-								.position(|x| *x == land_unit)
-								.ok_or(Error::<T>::LandUnitIsNotAvailable)?;
-							mut_estate_info.land_units.remove(index);
-						}
-
-						Self::deposit_event(Event::<T>::LandUnitsRemoved(
-							estate_id.clone(),
-							estate_owner_value.clone(),
-							land_units.clone(),
-						));
-
-						Ok(().into())
-					})
-				}
-				_ => Err(Error::<T>::InvalidOwnerValue.into()),
-			}
-		}
-
-		/// Create a lease offer for estate that is not leased
-		///
-		/// The dispatch origin for this call must be _Singed_.
-		/// Only  origin that is not the estate owner can make this call.
-		/// - `estate_id`: the ID of the estate that will be leased
-		/// - `price_per_block`: lease price per block
-		/// - `duration`: lease duration (in number of blocks)
-		///
-		/// Emits `EstateLeaseOfferCreated` if successful
-		#[pallet::weight(T::WeightInfo::create_lease_offer())]
-		#[transactional]
-		pub fn create_lease_offer(
-			origin: OriginFor<T>,
-			estate_id: EstateId,
-			price_per_block: BalanceOf<T>,
-			duration: u32,
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+		/// Create a lease offer for estate that is not leased
+		///
+		/// The dispatch origin for this call must be _Singed_.
+		/// Only  origin that is not the estate owner can make this call.
+		/// - `estate_id`: the ID of the estate that will be leased
+		/// - `price_per_block`: lease price per block
+		/// - `duration`: lease duration (in number of blocks)
+		///
+		/// Emits `EstateLeaseOfferCreated` if successful
+		#[pallet::weight(T::WeightInfo::create_lease_offer())]
+		#[transactional]
+		pub fn create_lease_offer(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			price_per_block: BalanceOf<T>,
+			duration: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
 			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 			ensure!(
@@ -1567,349 +1626,1925 @@ pub mod pallet {
 				Ok(().into())
 			})
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// Internal getter for new estate ID
-	fn get_new_estate_id() -> Result<EstateId, DispatchError> {
-		let estate_id = NextEstateId::<T>::try_mutate(|id| -> Result<EstateId, DispatchError> {
-			let current_id = *id;
-			*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableEstateId)?;
-			Ok(current_id)
-		})?;
-		Ok(estate_id)
-	}
+		/// Apply a batch of land and estate operations in a single transaction
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// The caller must own every land unit, estate and undeployed land block the batch starts
+		/// from. Ownership of these is checked once for the whole batch before any operation is
+		/// applied, and any failing operation reverts the whole batch. A batch can be validated
+		/// without being applied through the `validate_estate_operations` runtime API.
+		/// - `operations`: list of operations applied in order
+		///
+		/// Emits `EstateOperationsExecuted` if successful.
+		#[pallet::weight(Self::estate_operations_weight(&operations))]
+		#[transactional]
+		pub fn batch_estate_operations(
+			origin: OriginFor<T>,
+			operations: Vec<EstateOperation<T::AccountId>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-	/// Internal minting of land unit
-	fn mint_land_unit(
-		metaverse_id: MetaverseId,
-		token_owner: OwnerId<T::AccountId, ClassId, TokenId>,
-		to: T::AccountId,
-		coordinate: (i32, i32),
-		land_unit_status: LandUnitStatus<T::AccountId>,
-	) -> Result<OwnerId<T::AccountId, ClassId, TokenId>, DispatchError> {
-		let mut owner = token_owner.clone();
+			Self::ensure_valid_estate_operations_length(&operations)?;
 
-		match land_unit_status {
-			// Use case - create new estate.
-			LandUnitStatus::Existing(a) => {
-				ensure!(
-					LandUnits::<T>::contains_key(metaverse_id, coordinate),
-					Error::<T>::LandUnitIsNotAvailable
-				);
+			let number_of_operations = operations.len() as u32;
+			Self::do_apply_estate_operations(&who, operations).map_err(|(_, error)| error)?;
 
-				let existing_owner_value = Self::get_land_units(metaverse_id, coordinate);
-				match existing_owner_value {
-					Some(owner_value) => match owner_value {
-						OwnerId::Token(class_id, token_id) => {
-							// Implement check if user own nft
-							ensure!(
-								T::NFTTokenizationSource::check_ownership(&a, &(class_id, token_id))?,
-								Error::<T>::NoPermission
-							);
+			Self::deposit_event(Event::<T>::EstateOperationsExecuted(who, number_of_operations));
+			Ok(().into())
+		}
 
-							if let OwnerId::Token(owner_class_id, _owner_token_id) = token_owner {
-								ensure!(owner_class_id != class_id, Error::<T>::LandUnitAlreadyInEstate)
-							}
+		/// Set the connectivity rule that land units of the estates of a metaverse must satisfy
+		///
+		/// The dispatch origin for this call must be the metaverse origin of `metaverse_id` or
+		/// _Signed_ by the metaverse owner.
+		/// The rule applies to estates created or reshaped after it is set.
+		/// - `metaverse_id`: the metaverse ID the rule applies to
+		/// - `connectivity`: the connectivity rule, `None` removes the rule
+		///
+		/// Emits `EstateConnectivityUpdated` if successful.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_estate_connectivity(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			connectivity: Option<EstateConnectivity>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_metaverse_owner_or_governance(origin, metaverse_id)?;
 
-							// Ensure not locked
-							T::NFTTokenizationSource::set_lock_nft((class_id, token_id), false)?;
-							T::NFTTokenizationSource::burn_nft(&a, &(class_id, token_id));
+			MetaverseEstateConnectivity::<T>::set(metaverse_id, connectivity);
 
-							LandUnits::<T>::insert(metaverse_id, coordinate, token_owner.clone());
-						}
-						_ => (),
-					},
-					/* It doesn't make sense to mint existing land unit when ownership doesn't exists */
-					_ => (),
-				}
-			}
-			LandUnitStatus::NonExisting => {
-				ensure!(
-					!LandUnits::<T>::contains_key(metaverse_id, coordinate),
-					Error::<T>::LandUnitIsNotAvailable
-				);
+			Self::deposit_event(Event::<T>::EstateConnectivityUpdated(metaverse_id, connectivity));
+			Ok(().into())
+		}
 
-				let token_properties = Self::get_land_token_properties(metaverse_id, coordinate);
-				let class_id = T::MetaverseInfoSource::get_metaverse_land_class(metaverse_id)?;
-				let token_id =
-					T::NFTTokenizationSource::mint_token(&to, class_id, token_properties.0, token_properties.1)?;
-				owner = OwnerId::Token(class_id, token_id);
-				LandUnits::<T>::insert(metaverse_id, coordinate, OwnerId::Token(class_id, token_id.clone()));
-			}
-			LandUnitStatus::NonExistingWithEstate => {
-				ensure!(
-					!LandUnits::<T>::contains_key(metaverse_id, coordinate),
-					Error::<T>::LandUnitIsNotAvailable
-				);
+		/// Merge an estate into another estate of the same metaverse
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the owner of both estates can make this call, and neither estate can be in
+		/// auction or leased. The land units of the merged estates must be connected according to
+		/// the metaverse connectivity rule, or 4-connected if the metaverse has no rule, and
+		/// cannot exceed `MaxReshapeLandUnits`.
+		/// - `estate_id`: the ID of the estate that will receive the land units
+		/// - `merged_estate_id`: the ID of the estate that will be merged and destroyed
+		///
+		/// Emits `EstatesMerged` if successful.
+		#[pallet::weight(T::WeightInfo::merge_estates(T::MaxReshapeLandUnits::get())
+			.saturating_add(T::DbWeight::get().reads_writes(
+				T::MaxOffersPerEstate::get() as u64,
+				(T::MaxOffersPerEstate::get() as u64).saturating_mul(2),
+			)))]
+		#[transactional]
+		pub fn merge_estates(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			merged_estate_id: EstateId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(estate_id != merged_estate_id, Error::<T>::CannotMergeEstateWithItself);
 
-				owner = token_owner.clone();
+			let estate_owner_value = Self::ensure_estate_can_be_reshaped(&who, estate_id)?;
+			let merged_estate_owner_value = Self::ensure_estate_can_be_reshaped(&who, merged_estate_id)?;
+			let estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			let merged_estate_info = Estates::<T>::get(merged_estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			let metaverse_id = estate_info.metaverse_id;
+			ensure!(
+				metaverse_id == merged_estate_info.metaverse_id,
+				Error::<T>::EstatesNotInSameMetaverse
+			);
 
-				LandUnits::<T>::insert(metaverse_id, coordinate, token_owner.clone());
+			let mut land_units = estate_info.land_units;
+			land_units.extend(merged_estate_info.land_units.iter().cloned());
+			ensure!(
+				land_units.len() as u32 <= T::MaxReshapeLandUnits::get(),
+				Error::<T>::ExceedMaximumReshapeLandUnits
+			);
+			ensure!(
+				Self::is_land_units_connected(&land_units, Self::reshaping_connectivity(metaverse_id)),
+				Error::<T>::EstateLandUnitsNotConnected
+			);
+
+			// Land units of the merged estate now belong to the estate
+			for land_unit in merged_estate_info.land_units.iter() {
+				LandUnits::<T>::insert(metaverse_id, land_unit, estate_owner_value.clone());
 			}
-			LandUnitStatus::RemovedFromEstate => {
-				ensure!(
-					LandUnits::<T>::contains_key(metaverse_id, coordinate),
-					Error::<T>::LandUnitIsNotAvailable
-				);
 
-				let token_properties = Self::get_land_token_properties(metaverse_id, coordinate);
-				let class_id = T::MetaverseInfoSource::get_metaverse_land_class(metaverse_id)?;
-				let token_id =
-					T::NFTTokenizationSource::mint_token(&to, class_id, token_properties.0, token_properties.1)?;
-				owner = OwnerId::Token(class_id, token_id);
-				LandUnits::<T>::remove(metaverse_id, coordinate);
-				LandUnits::<T>::insert(metaverse_id, coordinate, OwnerId::Token(class_id, token_id));
+			// Remove merged estate and refund its lease offers
+			if let OwnerId::Token(class_id, token_id) = merged_estate_owner_value {
+				T::NFTTokenizationSource::burn_nft(&who, &(class_id, token_id))?;
 			}
+			let mut refunded_lease_offers: u64 = 0;
+			for (leasor, lease_offer) in EstateLeaseOffers::<T>::drain_prefix(merged_estate_id) {
+				T::Currency::unreserve(&leasor, lease_offer.unclaimed_rent);
+				refunded_lease_offers = refunded_lease_offers.saturating_add(1);
+			}
+			Self::remove_estate_from_index(metaverse_id, merged_estate_id, &merged_estate_info.land_units);
+			Self::remove_land_asset_metadata(LandAsset::Estate(merged_estate_id));
+			Estates::<T>::remove(merged_estate_id);
+			EstateOwner::<T>::remove(merged_estate_id);
+			let new_total_estates_count = Self::all_estates_count()
+				.checked_sub(One::one())
+				.ok_or("Overflow adding new count to total estates")?;
+			AllEstatesCount::<T>::put(new_total_estates_count);
+
+			// Update estate
+			Self::add_estate_to_index(metaverse_id, estate_id, &merged_estate_info.land_units);
+			Estates::<T>::insert(
+				estate_id,
+				EstateInfo {
+					metaverse_id,
+					land_units: land_units.clone(),
+				},
+			);
+
+			let actual_weight = T::WeightInfo::merge_estates(land_units.len() as u32).saturating_add(
+				T::DbWeight::get().reads_writes(refunded_lease_offers, refunded_lease_offers.saturating_mul(2)),
+			);
+			Self::deposit_event(Event::<T>::EstatesMerged(estate_id, merged_estate_id, land_units));
+			Ok(Some(actual_weight).into())
 		}
-		Ok(owner)
-	}
 
-	/// Internal updating information about an estate
-	fn update_estate_information(
-		new_estate_id: EstateId,
-		metaverse_id: MetaverseId,
-		estate_owner: OwnerId<T::AccountId, ClassId, TokenId>,
-		coordinates: Vec<(i32, i32)>,
-	) -> DispatchResult {
-		// Update total estates
-		let total_estates_count = Self::all_estates_count();
-		let new_total_estates_count = total_estates_count
-			.checked_add(One::one())
-			.ok_or("Overflow adding new count to total estates")?;
-		AllEstatesCount::<T>::put(new_total_estates_count);
+		/// Split land units of an estate into a new estate
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the estate owner can make this call, and the estate cannot be in auction or
+		/// leased. Both resulting estates must be connected according to the metaverse
+		/// connectivity rule, or 4-connected if the metaverse has no rule, and the split estate
+		/// cannot exceed `MaxReshapeLandUnits`.
+		/// - `estate_id`: the ID of the estate that will be split
+		/// - `land_units`: list of land unit coordinates that will form the new estate
+		///
+		/// Emits `EstateSplit` and `NewEstateMinted` if successful.
+		#[pallet::weight(T::WeightInfo::split_estate(T::MaxReshapeLandUnits::get()))]
+		#[transactional]
+		pub fn split_estate(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			land_units: Vec<(i32, i32)>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-		// Update estates
-		let estate_info = EstateInfo {
-			metaverse_id,
-			land_units: coordinates.clone(),
-		};
+			Self::ensure_estate_can_be_reshaped(&who, estate_id)?;
+			let estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			let metaverse_id = estate_info.metaverse_id;
+			let estate_land_units_count = estate_info.land_units.len() as u32;
+			ensure!(
+				estate_land_units_count <= T::MaxReshapeLandUnits::get(),
+				Error::<T>::ExceedMaximumReshapeLandUnits
+			);
 
-		Estates::<T>::insert(new_estate_id, estate_info);
-		EstateOwner::<T>::insert(new_estate_id, estate_owner.clone());
+			let split_land_units: BTreeSet<(i32, i32)> = land_units.iter().cloned().collect();
+			ensure!(
+				split_land_units.len() == land_units.len()
+					&& land_units
+						.iter()
+						.all(|land_unit| estate_info.land_units.contains(land_unit)),
+				Error::<T>::InvalidEstateSplit
+			);
+			let remaining_land_units: Vec<(i32, i32)> = estate_info
+				.land_units
+				.iter()
+				.filter(|land_unit| !split_land_units.contains(land_unit))
+				.cloned()
+				.collect();
+			ensure!(
+				!land_units.is_empty()
+					&& !remaining_land_units.is_empty()
+					&& Self::verify_land_unit_for_estate(land_units.clone())
+					&& Self::verify_land_unit_for_estate(remaining_land_units.clone()),
+				Error::<T>::InvalidEstateSplit
+			);
 
-		Self::deposit_event(Event::<T>::NewEstateMinted(
-			new_estate_id.clone(),
-			estate_owner,
-			metaverse_id,
-			coordinates.clone(),
-		));
+			let connectivity = Self::reshaping_connectivity(metaverse_id);
+			ensure!(
+				Self::is_land_units_connected(&land_units, connectivity)
+					&& Self::is_land_units_connected(&remaining_land_units, connectivity),
+				Error::<T>::EstateLandUnitsNotConnected
+			);
 
-		Ok(())
-	}
+			// Collect network fee
+			Self::collect_network_fee(&who)?;
+			// Generate new estate id
+			let new_estate_id = Self::get_new_estate_id()?;
+			let token_properties = Self::get_estate_token_properties(metaverse_id, new_estate_id);
+			let class_id = T::MetaverseInfoSource::get_metaverse_estate_class(metaverse_id)?;
+			let token_id: TokenId =
+				T::NFTTokenizationSource::mint_token(&who, class_id, token_properties.0, token_properties.1)?;
+			let new_estate_owner = OwnerId::Token(class_id, token_id);
 
-	/// Internal getter of new undeployed land block ID
-	fn get_new_undeployed_land_block_id() -> Result<UndeployedLandBlockId, DispatchError> {
-		let undeployed_land_block_id =
-			NextUndeployedLandBlockId::<T>::try_mutate(|id| -> Result<UndeployedLandBlockId, DispatchError> {
-				let current_id = *id;
-				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableEstateId)?;
-				Ok(current_id)
-			})?;
-		Ok(undeployed_land_block_id)
-	}
+			// Land units of the new estate belong to the new estate token
+			for land_unit in land_units.iter() {
+				LandUnits::<T>::insert(metaverse_id, land_unit, new_estate_owner.clone());
+			}
 
-	/// Internal transfer of undeployed land block
-	fn do_transfer_undeployed_land_block(
-		who: &T::AccountId,
-		to: &T::AccountId,
-		undeployed_land_block_id: UndeployedLandBlockId,
-	) -> Result<UndeployedLandBlockId, DispatchError> {
-		// 200% storage fee since there are 2  storage inserts
-		let storage_fee: BalanceOf<T> = Perbill::from_percent(200) * T::StorageDepositFee::get();
-		T::Currency::transfer(
-			&who,
-			&T::MetaverseInfoSource::get_network_treasury(),
-			storage_fee.saturated_into(),
-			ExistenceRequirement::KeepAlive,
-		)?;
+			// Update estate
+			Self::remove_estate_from_index(metaverse_id, estate_id, &estate_info.land_units);
+			Self::add_estate_to_index(metaverse_id, estate_id, &remaining_land_units);
+			Estates::<T>::insert(
+				estate_id,
+				EstateInfo {
+					metaverse_id,
+					land_units: remaining_land_units,
+				},
+			);
 
-		UndeployedLandBlocks::<T>::try_mutate(
-			&undeployed_land_block_id,
-			|undeployed_land_block| -> Result<UndeployedLandBlockId, DispatchError> {
-				let undeployed_land_block_record = undeployed_land_block
-					.as_mut()
-					.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+			// Update new estate information
+			Self::update_estate_information(new_estate_id, metaverse_id, new_estate_owner, land_units.clone())?;
 
-				ensure!(
-					undeployed_land_block_record.owner == who.clone(),
-					Error::<T>::NoPermission
+			Self::deposit_event(Event::<T>::EstateSplit(estate_id, new_estate_id, land_units));
+			Ok(Some(T::WeightInfo::split_estate(estate_land_units_count)).into())
+		}
+
+		/// Define or rename a land zoning type of a metaverse
+		///
+		/// The dispatch origin for this call must be the metaverse origin of `metaverse_id` or
+		/// _Signed_ by the metaverse owner.
+		/// - `metaverse_id`: the metaverse ID the zoning type belongs to
+		/// - `zone_id`: the zoning type ID
+		/// - `name`: the zoning type name, e.g. residential or commercial
+		///
+		/// Emits `ZoneDefined` if successful.
+		#[pallet::weight(T::WeightInfo::define_zone())]
+		pub fn define_zone(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			zone_id: ZoneId,
+			name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_metaverse_owner_or_governance(origin, metaverse_id)?;
+
+			let bounded_name: BoundedVec<u8, T::MaxZoneNameLength> =
+				name.clone().try_into().map_err(|_| Error::<T>::ZoneNameTooLong)?;
+			MetaverseZones::<T>::insert(metaverse_id, zone_id, bounded_name);
+
+			Self::deposit_event(Event::<T>::ZoneDefined(metaverse_id, zone_id, name));
+			Ok(().into())
+		}
+
+		/// Remove a land zoning type of a metaverse
+		///
+		/// The dispatch origin for this call must be the metaverse origin of `metaverse_id` or
+		/// _Signed_ by the metaverse owner.
+		/// Land units and estates keep the removed zone until they are rezoned.
+		/// - `metaverse_id`: the metaverse ID the zoning type belongs to
+		/// - `zone_id`: the zoning type ID
+		///
+		/// Emits `ZoneRemoved` if successful.
+		#[pallet::weight(T::WeightInfo::remove_zone())]
+		pub fn remove_zone(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			zone_id: ZoneId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_metaverse_owner_or_governance(origin, metaverse_id)?;
+			ensure!(
+				MetaverseZones::<T>::contains_key(metaverse_id, zone_id),
+				Error::<T>::ZoneDoesNotExist
+			);
+
+			MetaverseZones::<T>::remove(metaverse_id, zone_id);
+
+			Self::deposit_event(Event::<T>::ZoneRemoved(metaverse_id, zone_id));
+			Ok(().into())
+		}
+
+		/// Set the zoning type of a land unit or an estate
+		///
+		/// The dispatch origin for this call must be the metaverse origin of the land metaverse or
+		/// _Signed_ by the metaverse owner.
+		/// - `asset`: the land unit or estate
+		/// - `zone_id`: the zoning type ID, `None` removes the zoning
+		///
+		/// Emits `LandZoneUpdated` if successful.
+		#[pallet::weight(T::WeightInfo::set_land_zone())]
+		pub fn set_land_zone(
+			origin: OriginFor<T>,
+			asset: LandAsset,
+			zone_id: Option<ZoneId>,
+		) -> DispatchResultWithPostInfo {
+			let metaverse_id = Self::land_asset_metaverse(&asset).ok_or(Error::<T>::LandAssetDoesNotExist)?;
+			Self::ensure_metaverse_owner_or_governance(origin, metaverse_id)?;
+			if let Some(zone_id) = zone_id {
+				ensure!(
+					MetaverseZones::<T>::contains_key(metaverse_id, zone_id),
+					Error::<T>::ZoneDoesNotExist
+				);
+			}
+
+			LandAssetMetadata::<T>::mutate_exists(asset, |maybe_metadata| {
+				let mut metadata = maybe_metadata.take().unwrap_or_default();
+				metadata.zone = zone_id;
+				*maybe_metadata = Self::non_empty_land_metadata(metadata);
+			});
+
+			Self::deposit_event(Event::<T>::LandZoneUpdated(asset, zone_id));
+			Ok(().into())
+		}
+
+		/// Set the content hash of the scene built on a land unit or an estate
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the land owner, the estate lease tenant or an account with a builder role can
+		/// make this call.
+		/// - `asset`: the land unit or estate
+		/// - `scene_hash`: the content hash of the scene, `None` removes the scene
+		///
+		/// Emits `LandSceneUpdated` if successful.
+		#[pallet::weight(T::WeightInfo::set_land_scene())]
+		pub fn set_land_scene(
+			origin: OriginFor<T>,
+			asset: LandAsset,
+			scene_hash: Option<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::can_build(&who, &asset), Error::<T>::NoPermission);
+
+			LandAssetMetadata::<T>::mutate_exists(asset, |maybe_metadata| {
+				let mut metadata = maybe_metadata.take().unwrap_or_default();
+				metadata.scene_hash = scene_hash;
+				*maybe_metadata = Self::non_empty_land_metadata(metadata);
+			});
+
+			Self::deposit_event(Event::<T>::LandSceneUpdated(asset, who, scene_hash));
+			Ok(().into())
+		}
+
+		/// Pause or resume the land block issuance at the start of each round
+		///
+		/// The dispatch origin for this call must be _Council_.
+		/// - `paused`: whether the issuance is paused
+		///
+		/// Emits `LandIssuancePauseUpdated` if successful.
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_land_issuance_paused(origin: OriginFor<T>, paused: bool) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
+
+			LandIssuancePaused::<T>::put(paused);
+
+			Self::deposit_event(Event::<T>::LandIssuancePauseUpdated(paused));
+			Ok(().into())
+		}
+
+		/// Grant a builder role on a land unit or an estate
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the land owner or the estate lease tenant can grant any role, moderators can
+		/// grant the builder role to accounts which are not moderators. Roles stay valid while the
+		/// account which granted them controls the land.
+		/// - `asset`: the land unit or estate
+		/// - `builder`: the account which will be granted the role
+		/// - `role`: the granted role
+		///
+		/// Emits `BuilderRoleGranted` if successful.
+		#[pallet::weight(T::WeightInfo::grant_builder_role())]
+		pub fn grant_builder_role(
+			origin: OriginFor<T>,
+			asset: LandAsset,
+			builder: T::AccountId,
+			role: BuilderRole,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let granted_by = Self::ensure_can_manage_builder_role(&who, &asset, &builder, role)?;
+
+			if !LandBuilders::<T>::contains_key(asset, &builder) {
+				LandBuilderCount::<T>::try_mutate(asset, |count| -> DispatchResult {
+					ensure!(*count < T::MaxBuildersPerAsset::get(), Error::<T>::TooManyBuilders);
+					*count = count.saturating_add(1);
+					Ok(())
+				})?;
+			}
+			LandBuilders::<T>::insert(asset, &builder, BuildPermission { role, granted_by });
+
+			Self::deposit_event(Event::<T>::BuilderRoleGranted(asset, builder, role));
+			Ok(().into())
+		}
+
+		/// Revoke the builder role of an account on a land unit or an estate
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the land owner or the estate lease tenant can revoke any role, moderators can
+		/// revoke the builder role.
+		/// - `asset`: the land unit or estate
+		/// - `builder`: the account which role will be revoked
+		///
+		/// Emits `BuilderRoleRevoked` if successful.
+		#[pallet::weight(T::WeightInfo::revoke_builder_role())]
+		pub fn revoke_builder_role(
+			origin: OriginFor<T>,
+			asset: LandAsset,
+			builder: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let permission = Self::builders(asset, &builder).ok_or(Error::<T>::BuilderRoleDoesNotExist)?;
+			Self::ensure_can_manage_builder_role(&who, &asset, &builder, permission.role)?;
+
+			LandBuilders::<T>::remove(asset, &builder);
+			LandBuilderCount::<T>::mutate_exists(asset, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+			});
+
+			Self::deposit_event(Event::<T>::BuilderRoleRevoked(asset, builder));
+			Ok(().into())
+		}
+
+		/// Split land units off an undeployed land block into new undeployed land blocks
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the undeployed land block owner can make this call.
+		/// - `undeployed_land_block_id`: the ID of the undeployed land block that will be split
+		/// - `land_units`: the number of land units of each new undeployed land block, the
+		///   remaining land units are kept by the split undeployed land block
+		///
+		/// Emits `UndeployedLandBlockSplit` if successful.
+		#[pallet::weight(T::WeightInfo::issue_undeployed_land_blocks().saturating_mul(land_units.len() as u64))]
+		#[transactional]
+		pub fn split_undeployed_land_block(
+			origin: OriginFor<T>,
+			undeployed_land_block_id: UndeployedLandBlockId,
+			land_units: Vec<u32>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_split_undeployed_land_block(&who, undeployed_land_block_id, land_units)?;
+			Ok(().into())
+		}
+
+		/// Merge undeployed land blocks of the same type into an undeployed land block
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the owner of all the undeployed land blocks can make this call.
+		/// - `undeployed_land_block_id`: the ID of the undeployed land block that will receive the
+		///   land units
+		/// - `undeployed_land_block_ids`: the IDs of the undeployed land blocks that will be merged
+		///
+		/// Emits `UndeployedLandBlocksMerged` if successful.
+		#[pallet::weight(T::WeightInfo::burn_undeployed_land_blocks().saturating_mul(undeployed_land_block_ids.len() as u64))]
+		#[transactional]
+		pub fn merge_undeployed_land_blocks(
+			origin: OriginFor<T>,
+			undeployed_land_block_id: UndeployedLandBlockId,
+			undeployed_land_block_ids: Vec<UndeployedLandBlockId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_merge_undeployed_land_blocks(&who, undeployed_land_block_id, undeployed_land_block_ids)?;
+			Ok(().into())
+		}
+
+		/// Unlock a bound to address undeployed land block once its vesting period is over,
+		/// making it transferable. Land blocks without a vesting end cannot be unlocked.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the undeployed land block owner can make this call.
+		/// - `undeployed_land_block_id`: the ID of the undeployed land block that will be unlocked
+		///
+		/// Emits `UndeployedLandBlockUnlocked` if successful.
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		#[transactional]
+		pub fn unlock_undeployed_land_block(
+			origin: OriginFor<T>,
+			undeployed_land_block_id: UndeployedLandBlockId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			UndeployedLandBlocks::<T>::try_mutate_exists(
+				&undeployed_land_block_id,
+				|undeployed_land_block| -> DispatchResultWithPostInfo {
+					let undeployed_land_block_record = undeployed_land_block
+						.as_mut()
+						.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+
+					ensure!(undeployed_land_block_record.owner == who, Error::<T>::NoPermission);
+
+					ensure!(
+						undeployed_land_block_record.undeployed_land_block_type
+							== UndeployedLandBlockType::BoundToAddress,
+						Error::<T>::UndeployedLandBlockNotVesting
+					);
+					let vesting_end = Self::undeployed_land_block_vesting_end(undeployed_land_block_id)
+						.ok_or(Error::<T>::UndeployedLandBlockNotVesting)?;
+					ensure!(
+						<frame_system::Pallet<T>>::block_number() >= vesting_end,
+						Error::<T>::UndeployedLandBlockStillVesting
+					);
+
+					undeployed_land_block_record.undeployed_land_block_type = UndeployedLandBlockType::Transferable;
+					UndeployedLandBlockVestingEnd::<T>::remove(undeployed_land_block_id);
+
+					Self::deposit_event(Event::<T>::UndeployedLandBlockUnlocked(undeployed_land_block_id));
+					Ok(().into())
+				},
+			)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Internal getter for new estate ID
+	fn get_new_estate_id() -> Result<EstateId, DispatchError> {
+		let estate_id = NextEstateId::<T>::try_mutate(|id| -> Result<EstateId, DispatchError> {
+			let current_id = *id;
+			*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableEstateId)?;
+			Ok(current_id)
+		})?;
+		Ok(estate_id)
+	}
+
+	/// Internal minting of land unit
+	fn mint_land_unit(
+		metaverse_id: MetaverseId,
+		token_owner: OwnerId<T::AccountId, ClassId, TokenId>,
+		to: T::AccountId,
+		coordinate: (i32, i32),
+		land_unit_status: LandUnitStatus<T::AccountId>,
+	) -> Result<OwnerId<T::AccountId, ClassId, TokenId>, DispatchError> {
+		let mut owner = token_owner.clone();
+
+		match land_unit_status {
+			// Use case - create new estate.
+			LandUnitStatus::Existing(a) => {
+				ensure!(
+					LandUnits::<T>::contains_key(metaverse_id, coordinate),
+					Error::<T>::LandUnitIsNotAvailable
+				);
+
+				let existing_owner_value = Self::get_land_units(metaverse_id, coordinate);
+				match existing_owner_value {
+					Some(owner_value) => match owner_value {
+						OwnerId::Token(class_id, token_id) => {
+							// Implement check if user own nft
+							ensure!(
+								T::NFTTokenizationSource::check_ownership(&a, &(class_id, token_id))?,
+								Error::<T>::NoPermission
+							);
+
+							if let OwnerId::Token(owner_class_id, _owner_token_id) = token_owner {
+								ensure!(owner_class_id != class_id, Error::<T>::LandUnitAlreadyInEstate)
+							}
+
+							// Ensure not locked
+							T::NFTTokenizationSource::set_lock_nft((class_id, token_id), false)?;
+							T::NFTTokenizationSource::burn_nft(&a, &(class_id, token_id));
+
+							LandUnits::<T>::insert(metaverse_id, coordinate, token_owner.clone());
+						}
+						_ => (),
+					},
+					/* It doesn't make sense to mint existing land unit when ownership doesn't exists */
+					_ => (),
+				}
+			}
+			LandUnitStatus::NonExisting => {
+				ensure!(
+					!LandUnits::<T>::contains_key(metaverse_id, coordinate),
+					Error::<T>::LandUnitIsNotAvailable
+				);
+
+				let token_properties = Self::get_land_token_properties(metaverse_id, coordinate);
+				let class_id = T::MetaverseInfoSource::get_metaverse_land_class(metaverse_id)?;
+				let token_id =
+					T::NFTTokenizationSource::mint_token(&to, class_id, token_properties.0, token_properties.1)?;
+				owner = OwnerId::Token(class_id, token_id);
+				LandUnits::<T>::insert(metaverse_id, coordinate, OwnerId::Token(class_id, token_id.clone()));
+			}
+			LandUnitStatus::NonExistingWithEstate => {
+				ensure!(
+					!LandUnits::<T>::contains_key(metaverse_id, coordinate),
+					Error::<T>::LandUnitIsNotAvailable
+				);
+
+				owner = token_owner.clone();
+
+				LandUnits::<T>::insert(metaverse_id, coordinate, token_owner.clone());
+			}
+			LandUnitStatus::RemovedFromEstate => {
+				ensure!(
+					LandUnits::<T>::contains_key(metaverse_id, coordinate),
+					Error::<T>::LandUnitIsNotAvailable
+				);
+
+				let token_properties = Self::get_land_token_properties(metaverse_id, coordinate);
+				let class_id = T::MetaverseInfoSource::get_metaverse_land_class(metaverse_id)?;
+				let token_id =
+					T::NFTTokenizationSource::mint_token(&to, class_id, token_properties.0, token_properties.1)?;
+				owner = OwnerId::Token(class_id, token_id);
+				LandUnits::<T>::remove(metaverse_id, coordinate);
+				LandUnits::<T>::insert(metaverse_id, coordinate, OwnerId::Token(class_id, token_id));
+			}
+		}
+		Ok(owner)
+	}
+
+	/// Internal updating information about an estate
+	fn update_estate_information(
+		new_estate_id: EstateId,
+		metaverse_id: MetaverseId,
+		estate_owner: OwnerId<T::AccountId, ClassId, TokenId>,
+		coordinates: Vec<(i32, i32)>,
+	) -> DispatchResult {
+		// Update total estates
+		let total_estates_count = Self::all_estates_count();
+		let new_total_estates_count = total_estates_count
+			.checked_add(One::one())
+			.ok_or("Overflow adding new count to total estates")?;
+		AllEstatesCount::<T>::put(new_total_estates_count);
+
+		// Update estates
+		let estate_info = EstateInfo {
+			metaverse_id,
+			land_units: coordinates.clone(),
+		};
+
+		Estates::<T>::insert(new_estate_id, estate_info);
+		EstateOwner::<T>::insert(new_estate_id, estate_owner.clone());
+		Self::add_estate_to_index(metaverse_id, new_estate_id, &coordinates);
+
+		Self::deposit_event(Event::<T>::NewEstateMinted(
+			new_estate_id.clone(),
+			estate_owner,
+			metaverse_id,
+			coordinates.clone(),
+		));
+
+		Ok(())
+	}
+
+	/// Internal getter of new undeployed land block ID
+	fn get_new_undeployed_land_block_id() -> Result<UndeployedLandBlockId, DispatchError> {
+		let undeployed_land_block_id =
+			NextUndeployedLandBlockId::<T>::try_mutate(|id| -> Result<UndeployedLandBlockId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableEstateId)?;
+				Ok(current_id)
+			})?;
+		Ok(undeployed_land_block_id)
+	}
+
+	/// Internal transfer of undeployed land block
+	fn do_transfer_undeployed_land_block(
+		who: &T::AccountId,
+		to: &T::AccountId,
+		undeployed_land_block_id: UndeployedLandBlockId,
+	) -> Result<UndeployedLandBlockId, DispatchError> {
+		// 200% storage fee since there are 2  storage inserts
+		let storage_fee: BalanceOf<T> = Perbill::from_percent(200) * T::StorageDepositFee::get();
+		T::Currency::transfer(
+			&who,
+			&T::MetaverseInfoSource::get_network_treasury(),
+			storage_fee.saturated_into(),
+			ExistenceRequirement::KeepAlive,
+		)?;
+
+		UndeployedLandBlocks::<T>::try_mutate(
+			&undeployed_land_block_id,
+			|undeployed_land_block| -> Result<UndeployedLandBlockId, DispatchError> {
+				let undeployed_land_block_record = undeployed_land_block
+					.as_mut()
+					.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+
+				ensure!(
+					undeployed_land_block_record.owner == who.clone(),
+					Error::<T>::NoPermission
+				);
+
+				ensure!(
+					undeployed_land_block_record.is_locked == false,
+					Error::<T>::UndeployedLandBlockAlreadyFreezed
+				);
+
+				ensure!(
+					undeployed_land_block_record.undeployed_land_block_type == UndeployedLandBlockType::Transferable,
+					Error::<T>::UndeployedLandBlockIsNotTransferable
+				);
+
+				undeployed_land_block_record.owner = to.clone();
+
+				UndeployedLandBlocksOwner::<T>::remove(who.clone(), &undeployed_land_block_id);
+				UndeployedLandBlocksOwner::<T>::insert(to.clone(), &undeployed_land_block_id, ());
+
+				Self::deposit_event(Event::<T>::UndeployedLandBlockTransferred(
+					who.clone(),
+					to.clone(),
+					undeployed_land_block_id.clone(),
+				));
+
+				Ok(undeployed_land_block_id)
+			},
+		)
+	}
+
+	/// Internal burn of undeployed land block
+	fn do_burn_undeployed_land_block(
+		undeployed_land_block_id: UndeployedLandBlockId,
+	) -> Result<UndeployedLandBlockId, DispatchError> {
+		let undeployed_land_block_info =
+			UndeployedLandBlocks::<T>::get(undeployed_land_block_id).ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+
+		ensure!(
+			!T::AuctionHandler::check_item_in_auction(ItemId::UndeployedLandBlock(undeployed_land_block_id)),
+			Error::<T>::UndeployedLandBlockAlreadyInAuction
+		);
+
+		ensure!(
+			!undeployed_land_block_info.is_locked,
+			Error::<T>::OnlyFrozenUndeployedLandBlockCanBeDestroyed
+		);
+
+		Self::set_total_undeployed_land_unit(undeployed_land_block_info.number_land_units as u64, true)?;
+		UndeployedLandBlocksOwner::<T>::remove(undeployed_land_block_info.owner, &undeployed_land_block_id);
+		UndeployedLandBlocks::<T>::remove(&undeployed_land_block_id);
+		UndeployedLandBlockVestingEnd::<T>::remove(&undeployed_land_block_id);
+
+		Self::deposit_event(Event::<T>::UndeployedLandBlockBurnt(undeployed_land_block_id.clone()));
+
+		Ok(undeployed_land_block_id)
+	}
+
+	/// Ensure an undeployed land block is owned by the account and can be reshaped
+	fn ensure_undeployed_land_block_available(
+		who: &T::AccountId,
+		undeployed_land_block_id: UndeployedLandBlockId,
+	) -> Result<UndeployedLandBlock<T::AccountId>, DispatchError> {
+		let undeployed_land_block =
+			UndeployedLandBlocks::<T>::get(undeployed_land_block_id).ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+
+		ensure!(undeployed_land_block.owner == *who, Error::<T>::NoPermission);
+		ensure!(!undeployed_land_block.is_locked, Error::<T>::UndeployedLandBlockFreezed);
+		ensure!(
+			!T::AuctionHandler::check_item_in_auction(ItemId::UndeployedLandBlock(undeployed_land_block_id)),
+			Error::<T>::UndeployedLandBlockAlreadyInAuction
+		);
+
+		Ok(undeployed_land_block)
+	}
+
+	/// Internal split of undeployed land block
+	fn do_split_undeployed_land_block(
+		who: &T::AccountId,
+		undeployed_land_block_id: UndeployedLandBlockId,
+		land_units: Vec<u32>,
+	) -> Result<Vec<UndeployedLandBlockId>, DispatchError> {
+		let mut undeployed_land_block = Self::ensure_undeployed_land_block_available(who, undeployed_land_block_id)?;
+
+		ensure!(!land_units.is_empty(), Error::<T>::InvalidUndeployedLandBlockSplit);
+		ensure!(
+			land_units.len() as u32 <= T::MaxBatchOperations::get(),
+			Error::<T>::ExceedMaximumBatchOperations
+		);
+		ensure!(
+			land_units.iter().all(|units| *units > 0),
+			Error::<T>::InvalidUndeployedLandBlockSplit
+		);
+		let split_land_units = land_units
+			.iter()
+			.try_fold(0u32, |total, units| total.checked_add(*units))
+			.ok_or(Error::<T>::InvalidUndeployedLandBlockSplit)?;
+		ensure!(
+			split_land_units < undeployed_land_block.number_land_units,
+			Error::<T>::UndeployedLandBlockDoesNotHaveEnoughLandUnits
+		);
+
+		// 2 inserts per new land blocks
+		let storage_fee: BalanceOf<T> =
+			Perbill::from_percent((land_units.len() as u32).saturating_mul(200)) * T::StorageDepositFee::get();
+		T::Currency::transfer(
+			who,
+			&T::MetaverseInfoSource::get_network_treasury(),
+			storage_fee.saturated_into(),
+			ExistenceRequirement::KeepAlive,
+		)?;
+
+		let vesting_end = Self::undeployed_land_block_vesting_end(undeployed_land_block_id);
+		let mut new_undeployed_land_block_ids: Vec<UndeployedLandBlockId> = Vec::new();
+		for number_land_units in land_units {
+			let new_undeployed_land_block_id = Self::get_new_undeployed_land_block_id()?;
+
+			UndeployedLandBlocks::<T>::insert(
+				new_undeployed_land_block_id,
+				UndeployedLandBlock {
+					id: new_undeployed_land_block_id,
+					number_land_units,
+					undeployed_land_block_type: undeployed_land_block.undeployed_land_block_type,
+					owner: who.clone(),
+					approved: None,
+					is_locked: false,
+				},
+			);
+			UndeployedLandBlocksOwner::<T>::insert(who.clone(), new_undeployed_land_block_id, ());
+			if let Some(vesting_end) = vesting_end {
+				UndeployedLandBlockVestingEnd::<T>::insert(new_undeployed_land_block_id, vesting_end);
+			}
+
+			new_undeployed_land_block_ids.push(new_undeployed_land_block_id);
+		}
+
+		undeployed_land_block.number_land_units =
+			undeployed_land_block.number_land_units.saturating_sub(split_land_units);
+		UndeployedLandBlocks::<T>::insert(undeployed_land_block_id, undeployed_land_block);
+
+		Self::deposit_event(Event::<T>::UndeployedLandBlockSplit(
+			who.clone(),
+			undeployed_land_block_id,
+			new_undeployed_land_block_ids.clone(),
+		));
+
+		Ok(new_undeployed_land_block_ids)
+	}
+
+	/// Internal merge of undeployed land blocks
+	fn do_merge_undeployed_land_blocks(
+		who: &T::AccountId,
+		undeployed_land_block_id: UndeployedLandBlockId,
+		undeployed_land_block_ids: Vec<UndeployedLandBlockId>,
+	) -> DispatchResult {
+		let mut undeployed_land_block = Self::ensure_undeployed_land_block_available(who, undeployed_land_block_id)?;
+
+		ensure!(
+			!undeployed_land_block_ids.is_empty(),
+			Error::<T>::InvalidUndeployedLandBlockMerge
+		);
+		ensure!(
+			undeployed_land_block_ids.len() as u32 <= T::MaxBatchOperations::get(),
+			Error::<T>::ExceedMaximumBatchOperations
+		);
+
+		let mut vesting_end = Self::undeployed_land_block_vesting_end(undeployed_land_block_id);
+		let mut merged_undeployed_land_block_ids: Vec<UndeployedLandBlockId> = Vec::new();
+		for merged_undeployed_land_block_id in undeployed_land_block_ids {
+			ensure!(
+				merged_undeployed_land_block_id != undeployed_land_block_id
+					&& !merged_undeployed_land_block_ids.contains(&merged_undeployed_land_block_id),
+				Error::<T>::InvalidUndeployedLandBlockMerge
+			);
+
+			let merged_undeployed_land_block =
+				Self::ensure_undeployed_land_block_available(who, merged_undeployed_land_block_id)?;
+			ensure!(
+				merged_undeployed_land_block.undeployed_land_block_type
+					== undeployed_land_block.undeployed_land_block_type,
+				Error::<T>::UndeployedLandBlockTypeDoesNotMatch
+			);
+
+			undeployed_land_block.number_land_units = undeployed_land_block
+				.number_land_units
+				.checked_add(merged_undeployed_land_block.number_land_units)
+				.ok_or(Error::<T>::Overflow)?;
+			vesting_end = vesting_end.max(Self::undeployed_land_block_vesting_end(merged_undeployed_land_block_id));
+
+			UndeployedLandBlocksOwner::<T>::remove(who, merged_undeployed_land_block_id);
+			UndeployedLandBlocks::<T>::remove(merged_undeployed_land_block_id);
+			UndeployedLandBlockVestingEnd::<T>::remove(merged_undeployed_land_block_id);
+
+			merged_undeployed_land_block_ids.push(merged_undeployed_land_block_id);
+		}
+
+		UndeployedLandBlocks::<T>::insert(undeployed_land_block_id, undeployed_land_block);
+		if let Some(vesting_end) = vesting_end {
+			UndeployedLandBlockVestingEnd::<T>::insert(undeployed_land_block_id, vesting_end);
+		}
+
+		Self::deposit_event(Event::<T>::UndeployedLandBlocksMerged(
+			who.clone(),
+			undeployed_land_block_id,
+			merged_undeployed_land_block_ids,
+		));
+
+		Ok(())
+	}
+
+	/// Internal freeze of undeployed land block
+	fn do_freeze_undeployed_land_block(
+		undeployed_land_block_id: UndeployedLandBlockId,
+	) -> Result<UndeployedLandBlockId, DispatchError> {
+		UndeployedLandBlocks::<T>::try_mutate_exists(
+			&undeployed_land_block_id,
+			|undeployed_land_block| -> Result<UndeployedLandBlockId, DispatchError> {
+				let undeployed_land_block_record = undeployed_land_block
+					.as_mut()
+					.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+
+				ensure!(
+					undeployed_land_block_record.is_locked == false,
+					Error::<T>::UndeployedLandBlockAlreadyFreezed
+				);
+
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::UndeployedLandBlock(undeployed_land_block_id)),
+					Error::<T>::UndeployedLandBlockAlreadyInAuction
 				);
 
-				ensure!(
-					undeployed_land_block_record.is_locked == false,
-					Error::<T>::UndeployedLandBlockAlreadyFreezed
-				);
+				undeployed_land_block_record.is_locked = true;
+
+				Self::deposit_event(Event::<T>::UndeployedLandBlockFreezed(undeployed_land_block_id));
+
+				Ok(undeployed_land_block_id)
+			},
+		)
+	}
+
+	/// Internal issue of undeployed land block
+	fn do_issue_undeployed_land_blocks(
+		beneficiary: &T::AccountId,
+		number_of_land_block: u32,
+		number_land_units_per_land_block: u32,
+		undeployed_land_block_type: UndeployedLandBlockType,
+	) -> Result<Vec<UndeployedLandBlockId>, DispatchError> {
+		// 2 inserts per land blocks
+		let storage_fee: BalanceOf<T> =
+			Perbill::from_percent(number_of_land_block.saturating_mul(2).saturating_mul(100))
+				* T::StorageDepositFee::get();
+
+		T::Currency::transfer(
+			beneficiary,
+			&T::MetaverseInfoSource::get_network_treasury(),
+			storage_fee.saturated_into(),
+			ExistenceRequirement::KeepAlive,
+		)?;
+
+		Self::mint_undeployed_land_blocks(
+			beneficiary,
+			number_of_land_block,
+			number_land_units_per_land_block,
+			undeployed_land_block_type,
+		)
+	}
+
+	/// Internal minting of undeployed land blocks
+	fn mint_undeployed_land_blocks(
+		beneficiary: &T::AccountId,
+		number_of_land_block: u32,
+		number_land_units_per_land_block: u32,
+		undeployed_land_block_type: UndeployedLandBlockType,
+	) -> Result<Vec<UndeployedLandBlockId>, DispatchError> {
+		let mut undeployed_land_block_ids: Vec<UndeployedLandBlockId> = Vec::new();
+		let vesting_end =
+			<frame_system::Pallet<T>>::block_number().saturating_add(T::BoundLandBlockVestingPeriod::get());
+
+		for _ in 0..number_of_land_block {
+			let new_undeployed_land_block_id = Self::get_new_undeployed_land_block_id()?;
+
+			if undeployed_land_block_type == UndeployedLandBlockType::BoundToAddress {
+				UndeployedLandBlockVestingEnd::<T>::insert(new_undeployed_land_block_id, vesting_end);
+			}
+
+			let undeployed_land_block = UndeployedLandBlock {
+				id: new_undeployed_land_block_id,
+				number_land_units: number_land_units_per_land_block,
+				undeployed_land_block_type,
+				approved: None,
+				is_locked: false,
+				owner: beneficiary.clone(),
+			};
+
+			UndeployedLandBlocks::<T>::insert(new_undeployed_land_block_id, undeployed_land_block);
+			UndeployedLandBlocksOwner::<T>::insert(beneficiary.clone(), new_undeployed_land_block_id, ());
+
+			// Update total undeployed land  count
+			Self::set_total_undeployed_land_unit(number_land_units_per_land_block as u64, false)?;
+
+			Self::deposit_event(Event::<T>::UndeployedLandBlockIssued(
+				beneficiary.clone(),
+				new_undeployed_land_block_id.clone(),
+			));
+
+			undeployed_land_block_ids.push(new_undeployed_land_block_id);
+		}
+
+		Ok(undeployed_land_block_ids)
+	}
 
-				ensure!(
-					undeployed_land_block_record.undeployed_land_block_type == UndeployedLandBlockType::Transferable,
-					Error::<T>::UndeployedLandBlockIsNotTransferable
-				);
+	/// Schedule the land block issuance of a round, returning the land units to issue and the
+	/// consumed weight. The land blocks are shared between metaverses over the following blocks
+	/// by `process_round_land_issuance`, a round does not issue land blocks while the issuance of
+	/// a previous round is still pending.
+	fn schedule_round_land_issuance(round: RoundIndex) -> (u64, Weight) {
+		if PendingLandIssuance::<T>::exists() {
+			return (0, T::DbWeight::get().reads(2));
+		}
 
-				undeployed_land_block_record.owner = to.clone();
+		let minting_rate_config = Self::minting_rate_config();
+		let year = round.saturating_sub(1) / rate::rounds_per_year::<T>();
 
-				UndeployedLandBlocksOwner::<T>::remove(who.clone(), &undeployed_land_block_id);
-				UndeployedLandBlocksOwner::<T>::insert(to.clone(), &undeployed_land_block_id, ());
+		// Reset the annual cap at the first round of each year
+		let mut annual_issuance = Self::annual_land_issuance();
+		if annual_issuance.year != year || (annual_issuance.cap == 0 && annual_issuance.issued == 0) {
+			annual_issuance = AnnualIssuance {
+				year,
+				cap: rate::get_annual_max_issuance::<T>(minting_rate_config.max, minting_rate_config.annual),
+				issued: 0,
+			};
+			AnnualLandIssuance::<T>::put(annual_issuance);
+		}
 
-				Self::deposit_event(Event::<T>::UndeployedLandBlockTransferred(
-					who.clone(),
-					to.clone(),
-					undeployed_land_block_id.clone(),
-				));
+		let land_units_per_land_block = T::LandUnitsPerIssuedLandBlock::get().max(1);
+		let round_land_units = rate::round_issuance_range::<T>(minting_rate_config)
+			.ideal
+			.min(annual_issuance.cap.saturating_sub(annual_issuance.issued));
+		let number_of_land_blocks = (round_land_units / land_units_per_land_block as u64).saturated_into::<u32>();
+		if number_of_land_blocks == 0 {
+			return (0, T::DbWeight::get().reads_writes(6, 1));
+		}
 
-				Ok(undeployed_land_block_id)
-			},
+		LandIssuanceStakeCursor::<T>::kill();
+		PendingLandIssuance::<T>::put(RoundLandIssuance {
+			round,
+			land_blocks: number_of_land_blocks,
+			..Default::default()
+		});
+
+		(
+			(number_of_land_blocks as u64).saturating_mul(land_units_per_land_block as u64),
+			T::DbWeight::get().reads_writes(6, 3),
 		)
 	}
 
-	/// Internal burn of undeployed land block
-	fn do_burn_undeployed_land_block(
-		undeployed_land_block_id: UndeployedLandBlockId,
-	) -> Result<UndeployedLandBlockId, DispatchError> {
-		let undeployed_land_block_info =
-			UndeployedLandBlocks::<T>::get(undeployed_land_block_id).ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+	/// Share the land blocks of the pending round issuance between metaverses, returning the
+	/// consumed weight. Each block either reads a page of at most `MaxIssuanceRecipients` estate
+	/// stakes into the stake snapshot or, once all the estate stakes are read, issues land blocks
+	/// to at most `MaxIssuanceRecipients` metaverses of the snapshot.
+	fn process_round_land_issuance() -> Weight {
+		let mut issuance = match Self::pending_land_issuance() {
+			Some(issuance) => issuance,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let limit = T::MaxIssuanceRecipients::get();
+
+		if !issuance.stakes_read {
+			let (estate_stakes, next_cursor) =
+				T::EstateStakeSource::estate_stakes(Self::land_issuance_stake_cursor(), limit);
+			// Every stake read is followed by a read of its estate and an update of the snapshot
+			let weight = T::DbWeight::get().reads_writes(
+				(estate_stakes.len() as u64).saturating_mul(3).saturating_add(2),
+				(estate_stakes.len() as u64).saturating_add(2),
+			);
+
+			for (estate_id, stake) in estate_stakes {
+				if let Some(estate_info) = Self::get_estates(estate_id) {
+					let stake: u128 = stake.saturated_into();
+					LandIssuanceStakeSnapshot::<T>::mutate(estate_info.metaverse_id, |metaverse_stake| {
+						*metaverse_stake = metaverse_stake.saturating_add(stake)
+					});
+					issuance.total_stake = issuance.total_stake.saturating_add(stake);
+				}
+			}
+			match next_cursor {
+				Some(estate_id) => LandIssuanceStakeCursor::<T>::put(estate_id),
+				None => {
+					LandIssuanceStakeCursor::<T>::kill();
+					issuance.stakes_read = true;
+				}
+			}
+
+			PendingLandIssuance::<T>::put(issuance);
+			return weight;
+		}
+
+		let metaverse_stakes: Vec<(MetaverseId, u128)> =
+			LandIssuanceStakeSnapshot::<T>::drain().take(limit as usize).collect();
+		let is_completed = (metaverse_stakes.len() as u32) < limit;
+		let mut weight = T::DbWeight::get().reads_writes(
+			(metaverse_stakes.len() as u64).saturating_add(3),
+			(metaverse_stakes.len() as u64).saturating_add(2),
+		);
+
+		let land_units_per_land_block = T::LandUnitsPerIssuedLandBlock::get().max(1);
+		let mut issued_land_units: u64 = 0;
+		for (metaverse_id, number_of_metaverse_land_blocks) in
+			Self::allocate_land_blocks(&mut issuance, metaverse_stakes)
+		{
+			let beneficiary = T::MetaverseInfoSource::get_metaverse_treasury(metaverse_id);
+			match Self::mint_undeployed_land_blocks(
+				&beneficiary,
+				number_of_metaverse_land_blocks,
+				land_units_per_land_block,
+				UndeployedLandBlockType::Transferable,
+			) {
+				Ok(_) => {
+					issued_land_units = issued_land_units.saturating_add(
+						(number_of_metaverse_land_blocks as u64).saturating_mul(land_units_per_land_block as u64),
+					);
+					Self::deposit_event(Event::<T>::RoundLandBlocksIssued(
+						issuance.round,
+						metaverse_id,
+						beneficiary,
+						number_of_metaverse_land_blocks,
+					));
+				}
+				Err(e) => log::warn!("Failed to issue land blocks to metaverse {}: {:?}", metaverse_id, e),
+			}
+			weight = weight.saturating_add(
+				T::DbWeight::get().reads_writes(2, (number_of_metaverse_land_blocks as u64).saturating_mul(3)),
+			);
+		}
+
+		AnnualLandIssuance::<T>::mutate(|annual_issuance| {
+			annual_issuance.issued = annual_issuance.issued.saturating_add(issued_land_units)
+		});
+		issuance.issued_land_units = issuance.issued_land_units.saturating_add(issued_land_units);
+
+		if is_completed {
+			PendingLandIssuance::<T>::kill();
+			Self::deposit_event(Event::<T>::RoundLandIssuanceCompleted(
+				issuance.round,
+				issuance.issued_land_units,
+			));
+		} else {
+			PendingLandIssuance::<T>::put(issuance);
+		}
+
+		weight
+	}
+
+	/// Share the land blocks of a round issuance between metaverses in proportion to their stake
+	/// in the stake snapshot. Shares are rounded on the cumulative stake so that the metaverses of
+	/// all the pages together receive exactly the land blocks of the round.
+	pub fn allocate_land_blocks(
+		issuance: &mut RoundLandIssuance,
+		metaverse_stakes: Vec<(MetaverseId, u128)>,
+	) -> Vec<(MetaverseId, u32)> {
+		if issuance.total_stake == 0 {
+			return Vec::new();
+		}
+
+		let (land_blocks, total_stake) = (issuance.land_blocks as u128, issuance.total_stake);
+		let cumulative_land_blocks = |stake: u128| {
+			multiply_by_rational_with_rounding(land_blocks, stake, total_stake, Rounding::Down)
+				.unwrap_or_default()
+				.saturated_into::<u32>()
+		};
+
+		let mut allocations: Vec<(MetaverseId, u32)> = Vec::new();
+		for (metaverse_id, stake) in metaverse_stakes {
+			let allocated_land_blocks = cumulative_land_blocks(issuance.allocated_stake);
+			issuance.allocated_stake = issuance.allocated_stake.saturating_add(stake).min(total_stake);
+			let share = cumulative_land_blocks(issuance.allocated_stake).saturating_sub(allocated_land_blocks);
+			if share > 0 {
+				allocations.push((metaverse_id, share));
+			}
+		}
+
+		allocations
+	}
+
+	/// Internal transfer of estate
+	fn do_transfer_estate(
+		estate_id: EstateId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) -> Result<EstateId, DispatchError> {
+		EstateOwner::<T>::try_mutate_exists(&estate_id, |_estate_owner| -> Result<EstateId, DispatchError> {
+			//ensure there is record of the estate owner with estate id and account id
+			ensure!(from != to, Error::<T>::AlreadyOwnTheEstate);
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+			let _estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(
+				!EstateLeases::<T>::contains_key(estate_id),
+				Error::<T>::EstateIsAlreadyLeased
+			);
+
+			match estate_owner_value {
+				OwnerId::Token(class_id, token_id) => {
+					ensure!(
+						!T::AuctionHandler::check_item_in_auction(ItemId::NFT(class_id, token_id)),
+						Error::<T>::EstateAlreadyInAuction
+					);
+					ensure!(
+						Self::check_if_land_or_estate_owner(from, &estate_owner_value),
+						Error::<T>::NoPermission
+					);
+					T::NFTTokenizationSource::transfer_nft(from, to, &(class_id, token_id));
+
+					Self::deposit_event(Event::<T>::TransferredEstate(
+						estate_id.clone(),
+						from.clone(),
+						to.clone(),
+					));
+
+					Ok(estate_id)
+				}
+				_ => Err(Error::<T>::InvalidOwnerValue.into()),
+			}
+		})
+	}
+
+	/// Internal creation of estate from existing land units
+	fn do_create_estate(who: &T::AccountId, metaverse_id: MetaverseId, coordinates: Vec<(i32, i32)>) -> DispatchResult {
+		ensure!(
+			Self::verify_land_unit_for_estate(coordinates.clone()),
+			Error::<T>::CoordinatesForEstateIsNotValid
+		);
+		Self::ensure_estate_connectivity(metaverse_id, &coordinates)?;
+		// Collect network fee
+		Self::collect_network_fee(who)?;
+		// Generate new estate id
+		let new_estate_id = Self::get_new_estate_id()?;
+		// Generate sub account from estate
+		let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account_truncating(new_estate_id);
+
+		let token_properties = Self::get_estate_token_properties(metaverse_id, new_estate_id);
+		let class_id = T::MetaverseInfoSource::get_metaverse_estate_class(metaverse_id)?;
+		let token_id: TokenId =
+			T::NFTTokenizationSource::mint_token(who, class_id, token_properties.0, token_properties.1)?;
+		let beneficiary = OwnerId::Token(class_id, token_id);
+
+		let storage_fee: BalanceOf<T> =
+			Perbill::from_percent(100u32.saturating_mul(coordinates.len() as u32)) * T::StorageDepositFee::get();
+		T::Currency::transfer(
+			who,
+			&T::MetaverseInfoSource::get_network_treasury(),
+			storage_fee.saturated_into(),
+			ExistenceRequirement::KeepAlive,
+		)?;
+
+		// Mint land units
+		for coordinate in coordinates.clone() {
+			Self::mint_land_unit(
+				metaverse_id,
+				beneficiary.clone(),
+				estate_account_id.clone(),
+				coordinate,
+				LandUnitStatus::Existing(who.clone()),
+			)?;
+		}
+
+		// Update estate information
+		Self::update_estate_information(new_estate_id, metaverse_id, beneficiary, coordinates.clone())?;
+
+		Ok(())
+	}
 
+	/// Internal deployment of undeployed land block
+	fn do_deploy_land_block(
+		who: &T::AccountId,
+		undeployed_land_block_id: UndeployedLandBlockId,
+		metaverse_id: MetaverseId,
+		land_block_coordinate: (i32, i32),
+		coordinates: Vec<(i32, i32)>,
+	) -> DispatchResult {
 		ensure!(
 			!T::AuctionHandler::check_item_in_auction(ItemId::UndeployedLandBlock(undeployed_land_block_id)),
 			Error::<T>::UndeployedLandBlockAlreadyInAuction
 		);
 
 		ensure!(
-			!undeployed_land_block_info.is_locked,
-			Error::<T>::OnlyFrozenUndeployedLandBlockCanBeDestroyed
+			T::MetaverseInfoSource::check_ownership(who, &metaverse_id),
+			Error::<T>::NoPermission
+		);
+
+		// Ensure the max bound is set for the metaverse
+		let max_bound = T::DefaultMaxBound::get();
+
+		// Check whether the coordinate is within the bound
+		ensure!(
+			(land_block_coordinate.0 >= max_bound.0 && max_bound.1 >= land_block_coordinate.0)
+				&& (land_block_coordinate.1 >= max_bound.0 && max_bound.1 >= land_block_coordinate.1),
+			Error::<T>::LandUnitIsOutOfBound
+		);
+
+		ensure!(
+			Self::verify_land_unit_in_bound(&land_block_coordinate, &coordinates),
+			Error::<T>::LandUnitIsOutOfBound
+		);
+
+		let undeployed_land_block_record =
+			UndeployedLandBlocks::<T>::get(undeployed_land_block_id).ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+
+		ensure!(undeployed_land_block_record.owner == *who, Error::<T>::NoPermission);
+
+		ensure!(
+			undeployed_land_block_record.is_locked == false,
+			Error::<T>::UndeployedLandBlockFreezed
+		);
+
+		let land_units_to_mint = coordinates.len() as u32;
+
+		ensure!(
+			land_units_to_mint > 0,
+			Error::<T>::UndeployedLandBlockUnitAndInputDoesNotMatch
+		);
+
+		// Land units which are not deployed remain in the undeployed land block
+		ensure!(
+			undeployed_land_block_record.number_land_units >= land_units_to_mint,
+			Error::<T>::UndeployedLandBlockDoesNotHaveEnoughLandUnits
+		);
+
+		// Collect network fee
+		Self::collect_network_fee(who)?;
+
+		// Mint land units
+		for coordinate in coordinates.clone() {
+			Self::mint_land_unit(
+				metaverse_id,
+				OwnerId::Account(who.clone()),
+				who.clone(),
+				coordinate,
+				LandUnitStatus::NonExisting,
+			)?;
+		}
+
+		// Update total land count
+		Self::set_total_land_unit(coordinates.len() as u64, false)?;
+
+		if undeployed_land_block_record.number_land_units == land_units_to_mint {
+			// Burn undeployed land block
+			Self::do_burn_undeployed_land_block(undeployed_land_block_id)?;
+		} else {
+			// Keep the remaining land units in the undeployed land block
+			Self::set_total_undeployed_land_unit(land_units_to_mint as u64, true)?;
+			UndeployedLandBlocks::<T>::mutate(undeployed_land_block_id, |undeployed_land_block| {
+				if let Some(undeployed_land_block) = undeployed_land_block {
+					undeployed_land_block.number_land_units = undeployed_land_block
+						.number_land_units
+						.saturating_sub(land_units_to_mint);
+				}
+			});
+		}
+
+		Self::deposit_event(Event::<T>::LandBlockDeployed(
+			who.clone(),
+			metaverse_id,
+			undeployed_land_block_id,
+			coordinates,
+		));
+
+		Ok(())
+	}
+
+	/// Internal adding of land units to estate
+	fn do_add_land_unit_to_estate(
+		who: &T::AccountId,
+		estate_id: EstateId,
+		land_units: Vec<(i32, i32)>,
+	) -> DispatchResult {
+		let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+		match estate_owner_value {
+			OwnerId::Token(c, t) => {
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::NFT(c, t)),
+					Error::<T>::EstateAlreadyInAuction
+				);
+				ensure!(
+					Self::check_if_land_or_estate_owner(who, &estate_owner_value),
+					Error::<T>::NoPermission
+				);
+
+				let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+				let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account_truncating(estate_id);
+
+				let mut estate_land_units = estate_info.land_units.clone();
+				estate_land_units.extend(land_units.iter().cloned());
+				Self::ensure_estate_connectivity(estate_info.metaverse_id, &estate_land_units)?;
+
+				let storage_fee: BalanceOf<T> =
+					Perbill::from_percent(100u32.saturating_mul(land_units.len() as u32)) * T::StorageDepositFee::get();
+				T::Currency::transfer(
+					who,
+					&T::MetaverseInfoSource::get_network_treasury(),
+					storage_fee.saturated_into(),
+					ExistenceRequirement::KeepAlive,
+				)?;
+
+				// Check land unit ownership
+				for land_unit in land_units.clone() {
+					let metaverse_land_unit = Self::get_land_units(estate_info.metaverse_id, land_unit)
+						.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+					ensure!(
+						Self::check_if_land_or_estate_owner(who, &metaverse_land_unit,),
+						Error::<T>::LandUnitDoesNotExist
+					);
+
+					// Mint land unit
+					Self::mint_land_unit(
+						estate_info.metaverse_id,
+						estate_owner_value.clone(),
+						estate_account_id.clone(),
+						land_unit,
+						LandUnitStatus::Existing(who.clone()),
+					)?;
+				}
+
+				// Mutate estates
+				Estates::<T>::try_mutate_exists(&estate_id, |maybe_estate_info| {
+					// Append new coordinates to estate
+					let mut_estate_info = maybe_estate_info.as_mut().ok_or(Error::<T>::EstateDoesNotExist)?;
+					mut_estate_info.land_units.append(&mut land_units.clone());
+					Self::add_estate_to_index(estate_info.metaverse_id, estate_id, &land_units);
+
+					Self::deposit_event(Event::<T>::LandUnitAdded(
+						estate_id.clone(),
+						estate_owner_value.clone(),
+						land_units.clone(),
+					));
+
+					Ok(().into())
+				})
+			}
+			_ => Err(Error::<T>::InvalidOwnerValue.into()),
+		}
+	}
+
+	/// Internal removing of land units from estate
+	fn do_remove_land_unit_from_estate(
+		who: &T::AccountId,
+		estate_id: EstateId,
+		land_units: Vec<(i32, i32)>,
+	) -> DispatchResult {
+		let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+		match estate_owner_value {
+			OwnerId::Token(c, t) => {
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::NFT(c, t)),
+					Error::<T>::EstateAlreadyInAuction
+				);
+				ensure!(
+					Self::check_if_land_or_estate_owner(who, &estate_owner_value),
+					Error::<T>::NoPermission
+				);
+				let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+				let _estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account_truncating(estate_id);
+
+				let remaining_land_units: Vec<(i32, i32)> = estate_info
+					.land_units
+					.iter()
+					.filter(|land_unit| !land_units.contains(land_unit))
+					.cloned()
+					.collect();
+				Self::ensure_estate_connectivity(estate_info.metaverse_id, &remaining_land_units)?;
+
+				// Mutate estates
+				Estates::<T>::try_mutate_exists(&estate_id, |maybe_estate_info| {
+					let mut_estate_info = maybe_estate_info.as_mut().ok_or(Error::<T>::EstateDoesNotExist)?;
+
+					let storage_fee: BalanceOf<T> =
+						Perbill::from_percent(100u32.saturating_mul(land_units.len() as u32))
+							* T::StorageDepositFee::get();
+					T::Currency::transfer(
+						who,
+						&T::MetaverseInfoSource::get_network_treasury(),
+						storage_fee.saturated_into(),
+						ExistenceRequirement::KeepAlive,
+					)?;
+					// Mutate land unit ownership
+					for land_unit in land_units.clone() {
+						// Transfer land unit from treasury to estate owner
+						Self::mint_land_unit(
+							estate_info.metaverse_id,
+							estate_owner_value.clone(),
+							who.clone(),
+							land_unit,
+							LandUnitStatus::RemovedFromEstate,
+						)?;
+						// Remove coordinates from estate
+						let index = mut_estate_info
+							.land_units
+							.iter()
+							.position(|x| *x == land_unit)
+							.ok_or(Error::<T>::LandUnitIsNotAvailable)?;
+						mut_estate_info.land_units.remove(index);
+					}
+					Self::remove_estate_from_index(estate_info.metaverse_id, estate_id, &estate_info.land_units);
+					Self::add_estate_to_index(estate_info.metaverse_id, estate_id, &mut_estate_info.land_units);
+
+					Self::deposit_event(Event::<T>::LandUnitsRemoved(
+						estate_id.clone(),
+						estate_owner_value.clone(),
+						land_units.clone(),
+					));
+
+					Ok(().into())
+				})
+			}
+			_ => Err(Error::<T>::InvalidOwnerValue.into()),
+		}
+	}
+
+	/// Weight of a batch of land and estate operations. Operations on a list of coordinates are
+	/// charged at least the cost of a single coordinate.
+	pub fn estate_operations_weight(operations: &[EstateOperation<T::AccountId>]) -> Weight {
+		operations.iter().fold(Weight::zero(), |total, operation| {
+			total.saturating_add(match operation {
+				EstateOperation::CreateEstate(_, coordinates) => {
+					T::WeightInfo::create_estate().saturating_mul(coordinates.len().max(1) as u64)
+				}
+				EstateOperation::AddLandUnits(_, land_units) => {
+					T::WeightInfo::add_land_unit_to_estate().saturating_mul(land_units.len().max(1) as u64)
+				}
+				EstateOperation::RemoveLandUnits(_, land_units) => {
+					T::WeightInfo::remove_land_unit_from_estate().saturating_mul(land_units.len().max(1) as u64)
+				}
+				EstateOperation::TransferLand(..) => T::WeightInfo::transfer_land(),
+				EstateOperation::TransferEstate(..) => T::WeightInfo::transfer_estate(),
+				EstateOperation::DeployLandBlock(_, _, _, coordinates) => {
+					T::WeightInfo::deploy_land_block().saturating_mul(coordinates.len().max(1) as u64)
+				}
+			})
+		})
+	}
+
+	/// Validates a batch of land and estate operations by applying and rolling it back.
+	/// Returns the index of the failing operation and its error.
+	pub fn validate_estate_operations(
+		who: &T::AccountId,
+		operations: Vec<EstateOperation<T::AccountId>>,
+	) -> Result<(), (u32, DispatchError)> {
+		Self::ensure_valid_estate_operations_length(&operations).map_err(|error| (0, error))?;
+
+		with_transaction(
+			|| -> TransactionOutcome<Result<Result<(), (u32, DispatchError)>, DispatchError>> {
+				TransactionOutcome::Rollback(Ok(Self::do_apply_estate_operations(who, operations)))
+			},
+		)
+		.unwrap_or_else(|error| Err((0, error)))
+	}
+
+	/// Check that a batch of land and estate operations is not empty and within the maximum size
+	fn ensure_valid_estate_operations_length(operations: &[EstateOperation<T::AccountId>]) -> DispatchResult {
+		ensure!(!operations.is_empty(), Error::<T>::EmptyBatchOperations);
+		ensure!(
+			operations.len() as u32 <= T::MaxBatchOperations::get(),
+			Error::<T>::ExceedMaximumBatchOperations
 		);
+		Ok(())
+	}
 
-		Self::set_total_undeployed_land_unit(undeployed_land_block_info.number_land_units as u64, true)?;
-		UndeployedLandBlocksOwner::<T>::remove(undeployed_land_block_info.owner, &undeployed_land_block_id);
-		UndeployedLandBlocks::<T>::remove(&undeployed_land_block_id);
+	/// Internal applying of a batch of land and estate operations
+	fn do_apply_estate_operations(
+		who: &T::AccountId,
+		operations: Vec<EstateOperation<T::AccountId>>,
+	) -> Result<(), (u32, DispatchError)> {
+		// Check the ownership of the assets the batch starts from once, before applying anything
+		for (index, operation) in operations.iter().enumerate() {
+			Self::check_estate_operation_ownership(who, operation).map_err(|error| (index as u32, error))?;
+		}
 
-		Self::deposit_event(Event::<T>::UndeployedLandBlockBurnt(undeployed_land_block_id.clone()));
+		for (index, operation) in operations.into_iter().enumerate() {
+			Self::do_apply_estate_operation(who, operation).map_err(|error| (index as u32, error))?;
+		}
 
-		Ok(undeployed_land_block_id)
+		Ok(())
 	}
 
-	/// Internal freeze of undeployed land block
-	fn do_freeze_undeployed_land_block(
-		undeployed_land_block_id: UndeployedLandBlockId,
-	) -> Result<UndeployedLandBlockId, DispatchError> {
-		UndeployedLandBlocks::<T>::try_mutate_exists(
-			&undeployed_land_block_id,
-			|undeployed_land_block| -> Result<UndeployedLandBlockId, DispatchError> {
-				let undeployed_land_block_record = undeployed_land_block
-					.as_mut()
-					.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+	/// Internal applying of a single land or estate operation
+	fn do_apply_estate_operation(who: &T::AccountId, operation: EstateOperation<T::AccountId>) -> DispatchResult {
+		match operation {
+			EstateOperation::CreateEstate(metaverse_id, coordinates) => {
+				Self::do_create_estate(who, metaverse_id, coordinates)
+			}
+			EstateOperation::AddLandUnits(estate_id, land_units) => {
+				Self::do_add_land_unit_to_estate(who, estate_id, land_units)
+			}
+			EstateOperation::RemoveLandUnits(estate_id, land_units) => {
+				Self::do_remove_land_unit_from_estate(who, estate_id, land_units)
+			}
+			EstateOperation::TransferLand(to, metaverse_id, coordinate) => {
+				Self::do_transfer_landunit(coordinate, who, &to, metaverse_id).map(|_| ())
+			}
+			EstateOperation::TransferEstate(to, estate_id) => Self::do_transfer_estate(estate_id, who, &to).map(|_| ()),
+			EstateOperation::DeployLandBlock(
+				undeployed_land_block_id,
+				metaverse_id,
+				land_block_coordinate,
+				coordinates,
+			) => Self::do_deploy_land_block(
+				who,
+				undeployed_land_block_id,
+				metaverse_id,
+				land_block_coordinate,
+				coordinates,
+			),
+		}
+	}
 
+	/// Check the caller owns the existing land units, estates and undeployed land blocks an
+	/// operation uses. Assets created by earlier operations of the batch are checked when applied.
+	fn check_estate_operation_ownership(
+		who: &T::AccountId,
+		operation: &EstateOperation<T::AccountId>,
+	) -> DispatchResult {
+		match operation {
+			EstateOperation::CreateEstate(metaverse_id, coordinates) => {
+				Self::check_land_units_ownership(who, *metaverse_id, coordinates)
+			}
+			EstateOperation::AddLandUnits(estate_id, land_units) => {
+				Self::check_estate_ownership_and_listing(who, *estate_id)?;
+				match Estates::<T>::get(estate_id) {
+					Some(estate_info) => Self::check_land_units_ownership(who, estate_info.metaverse_id, land_units),
+					None => Ok(()),
+				}
+			}
+			EstateOperation::RemoveLandUnits(estate_id, _) | EstateOperation::TransferEstate(_, estate_id) => {
+				Self::check_estate_ownership_and_listing(who, *estate_id)
+			}
+			EstateOperation::TransferLand(_, metaverse_id, coordinate) => {
+				Self::check_land_units_ownership(who, *metaverse_id, &[*coordinate])
+			}
+			EstateOperation::DeployLandBlock(undeployed_land_block_id, metaverse_id, _, _) => {
+				if let Some(undeployed_land_block) = UndeployedLandBlocks::<T>::get(undeployed_land_block_id) {
+					ensure!(undeployed_land_block.owner == *who, Error::<T>::NoPermission);
+				}
 				ensure!(
-					undeployed_land_block_record.is_locked == false,
-					Error::<T>::UndeployedLandBlockAlreadyFreezed
+					T::MetaverseInfoSource::check_ownership(who, metaverse_id),
+					Error::<T>::NoPermission
 				);
+				Ok(())
+			}
+		}
+	}
 
+	fn check_land_units_ownership(
+		who: &T::AccountId,
+		metaverse_id: MetaverseId,
+		coordinates: &[(i32, i32)],
+	) -> DispatchResult {
+		for coordinate in coordinates {
+			if let Some(owner) = Self::get_land_units(metaverse_id, coordinate) {
 				ensure!(
-					!T::AuctionHandler::check_item_in_auction(ItemId::UndeployedLandBlock(undeployed_land_block_id)),
-					Error::<T>::UndeployedLandBlockAlreadyInAuction
+					Self::check_if_land_or_estate_owner(who, &owner),
+					Error::<T>::NoPermission
 				);
+			}
+		}
+		Ok(())
+	}
 
-				undeployed_land_block_record.is_locked = true;
+	fn check_estate_ownership_and_listing(who: &T::AccountId, estate_id: EstateId) -> DispatchResult {
+		if let Some(owner) = Self::get_estate_owner(estate_id) {
+			ensure!(
+				Self::check_if_land_or_estate_owner(who, &owner),
+				Error::<T>::NoPermission
+			);
+			if let OwnerId::Token(class_id, token_id) = owner {
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::NFT(class_id, token_id)),
+					Error::<T>::EstateAlreadyInAuction
+				);
+			}
+		}
+		Ok(())
+	}
 
-				Self::deposit_event(Event::<T>::UndeployedLandBlockFreezed(undeployed_land_block_id));
+	/// Check whether an account may build on a land unit or an estate
+	pub fn can_build(who: &T::AccountId, asset: &LandAsset) -> bool {
+		Self::is_land_asset_controller(who, asset)
+			|| Self::builders(asset, who).map_or(false, |permission| {
+				Self::is_land_asset_controller(&permission.granted_by, asset)
+			})
+	}
 
-				Ok(undeployed_land_block_id)
-			},
-		)
+	/// Check whether an account owns a land unit or an estate, or is the tenant of a leased
+	/// estate
+	fn is_land_asset_controller(who: &T::AccountId, asset: &LandAsset) -> bool {
+		match asset {
+			LandAsset::LandUnit(metaverse_id, coordinate) => Self::get_land_units(metaverse_id, coordinate)
+				.map_or(false, |owner| Self::check_if_land_or_estate_owner(who, &owner)),
+			LandAsset::Estate(estate_id) => {
+				let is_tenant = EstateLeasors::<T>::contains_key(who, estate_id)
+					&& Self::leases(estate_id).map_or(false, |lease| {
+						lease.end_block >= <frame_system::Pallet<T>>::block_number()
+					});
+				is_tenant
+					|| Self::get_estate_owner(estate_id)
+						.map_or(false, |owner| Self::check_if_land_or_estate_owner(who, &owner))
+			}
+		}
 	}
 
-	/// Internal issue of undeployed land block
-	fn do_issue_undeployed_land_blocks(
-		beneficiary: &T::AccountId,
-		number_of_land_block: u32,
-		number_land_units_per_land_block: u32,
-		undeployed_land_block_type: UndeployedLandBlockType,
-	) -> Result<Vec<UndeployedLandBlockId>, DispatchError> {
-		let mut undeployed_land_block_ids: Vec<UndeployedLandBlockId> = Vec::new();
+	/// Ensure an account can grant or revoke a role of `builder`, returning the account the role
+	/// is granted on behalf of
+	///
+	/// Moderators can only manage the builder role of accounts which are not moderators.
+	fn ensure_can_manage_builder_role(
+		who: &T::AccountId,
+		asset: &LandAsset,
+		builder: &T::AccountId,
+		role: BuilderRole,
+	) -> Result<T::AccountId, DispatchError> {
+		if Self::is_land_asset_controller(who, asset) {
+			return Ok(who.clone());
+		}
 
-		// 2 inserts per land blocks
-		let storage_fee: BalanceOf<T> =
-			Perbill::from_percent(number_of_land_block.saturating_mul(2).saturating_mul(100))
-				* T::StorageDepositFee::get();
+		let is_builder_moderator =
+			Self::builders(asset, builder).map_or(false, |permission| permission.role == BuilderRole::Moderator);
+		match Self::builders(asset, who) {
+			Some(permission)
+				if permission.role == BuilderRole::Moderator
+					&& role == BuilderRole::Builder
+					&& !is_builder_moderator
+					&& Self::is_land_asset_controller(&permission.granted_by, asset) =>
+			{
+				Ok(permission.granted_by)
+			}
+			_ => Err(Error::<T>::NoPermission.into()),
+		}
+	}
 
-		T::Currency::transfer(
-			beneficiary,
-			&T::MetaverseInfoSource::get_network_treasury(),
-			storage_fee.saturated_into(),
-			ExistenceRequirement::KeepAlive,
-		)?;
+	fn land_asset_metaverse(asset: &LandAsset) -> Option<MetaverseId> {
+		match asset {
+			LandAsset::LandUnit(metaverse_id, coordinate) => {
+				LandUnits::<T>::contains_key(metaverse_id, coordinate).then(|| *metaverse_id)
+			}
+			LandAsset::Estate(estate_id) => Self::get_estates(estate_id).map(|estate_info| estate_info.metaverse_id),
+		}
+	}
 
-		for _ in 0..number_of_land_block {
-			let new_undeployed_land_block_id = Self::get_new_undeployed_land_block_id()?;
+	fn non_empty_land_metadata(metadata: LandMetadata<T::Hash>) -> Option<LandMetadata<T::Hash>> {
+		(metadata != LandMetadata::default()).then(|| metadata)
+	}
 
-			let undeployed_land_block = UndeployedLandBlock {
-				id: new_undeployed_land_block_id,
-				number_land_units: number_land_units_per_land_block,
-				undeployed_land_block_type,
-				approved: None,
-				is_locked: false,
-				owner: beneficiary.clone(),
-			};
+	fn remove_land_asset_metadata(asset: LandAsset) {
+		LandAssetMetadata::<T>::remove(asset);
+		// The builder count is bounded when granting roles so the prefix is removed at once
+		let _ = LandBuilders::<T>::clear_prefix(asset, T::MaxBuildersPerAsset::get(), None);
+		LandBuilderCount::<T>::remove(asset);
+	}
 
-			UndeployedLandBlocks::<T>::insert(new_undeployed_land_block_id, undeployed_land_block);
-			UndeployedLandBlocksOwner::<T>::insert(beneficiary.clone(), new_undeployed_land_block_id, ());
+	/// Ensure the origin is the metaverse origin or is signed by the metaverse owner
+	fn ensure_metaverse_owner_or_governance(origin: OriginFor<T>, metaverse_id: MetaverseId) -> DispatchResult {
+		if let Err(origin) = T::MetaverseGovernanceOrigin::try_origin(origin, &metaverse_id) {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
+				Error::<T>::NoPermission
+			);
+		}
+		Ok(())
+	}
 
-			// Update total undeployed land  count
-			Self::set_total_undeployed_land_unit(number_land_units_per_land_block as u64, false)?;
+	/// Estates of a metaverse with land units inside a bounding box. Bounding boxes covering more
+	/// than `MaxSpatialQueryChunks` chunks of the spatial index are rejected, split them into
+	/// smaller queries.
+	pub fn estates_in_bounding_box(
+		metaverse_id: MetaverseId,
+		bottom_left: (i32, i32),
+		top_right: (i32, i32),
+	) -> Result<Vec<EstateId>, DispatchError> {
+		let min_chunk = Self::estate_chunk(&bottom_left);
+		let max_chunk = Self::estate_chunk(&top_right);
+		if min_chunk.0 > max_chunk.0 || min_chunk.1 > max_chunk.1 {
+			return Ok(Vec::new());
+		}
 
-			Self::deposit_event(Event::<T>::UndeployedLandBlockIssued(
-				beneficiary.clone(),
-				new_undeployed_land_block_id.clone(),
-			));
+		let chunks_count = (i64::from(max_chunk.0) - i64::from(min_chunk.0) + 1)
+			.saturating_mul(i64::from(max_chunk.1) - i64::from(min_chunk.1) + 1);
+		ensure!(
+			chunks_count <= i64::from(T::MaxSpatialQueryChunks::get()),
+			Error::<T>::SpatialQueryTooLarge
+		);
 
-			undeployed_land_block_ids.push(new_undeployed_land_block_id);
+		let mut candidate_estates = BTreeSet::new();
+		for chunk_x in min_chunk.0..=max_chunk.0 {
+			for chunk_y in min_chunk.1..=max_chunk.1 {
+				candidate_estates.extend(EstateChunks::<T>::iter_key_prefix((metaverse_id, (chunk_x, chunk_y))));
+			}
 		}
 
-		Ok(undeployed_land_block_ids)
+		Ok(candidate_estates
+			.into_iter()
+			.filter(|estate_id| {
+				Estates::<T>::get(estate_id).map_or(false, |estate_info| {
+					estate_info.land_units.iter().any(|land_unit| {
+						bottom_left.0 <= land_unit.0
+							&& land_unit.0 <= top_right.0
+							&& bottom_left.1 <= land_unit.1
+							&& land_unit.1 <= top_right.1
+					})
+				})
+			})
+			.collect())
 	}
 
-	/// Internal transfer of estate
-	fn do_transfer_estate(
-		estate_id: EstateId,
-		from: &T::AccountId,
-		to: &T::AccountId,
-	) -> Result<EstateId, DispatchError> {
-		EstateOwner::<T>::try_mutate_exists(&estate_id, |_estate_owner| -> Result<EstateId, DispatchError> {
-			//ensure there is record of the estate owner with estate id and account id
-			ensure!(from != to, Error::<T>::AlreadyOwnTheEstate);
-			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
-			let _estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
-			ensure!(
-				!EstateLeases::<T>::contains_key(estate_id),
-				Error::<T>::EstateIsAlreadyLeased
-			);
-
-			match estate_owner_value {
-				OwnerId::Token(class_id, token_id) => {
-					ensure!(
-						!T::AuctionHandler::check_item_in_auction(ItemId::NFT(class_id, token_id)),
-						Error::<T>::EstateAlreadyInAuction
-					);
-					ensure!(
-						Self::check_if_land_or_estate_owner(from, &estate_owner_value),
-						Error::<T>::NoPermission
-					);
-					T::NFTTokenizationSource::transfer_nft(from, to, &(class_id, token_id));
+	/// Estates adjacent to an estate according to the connectivity rule of its metaverse, or
+	/// 4-connected if the metaverse has no rule
+	pub fn estate_neighbours(estate_id: EstateId) -> Vec<EstateId> {
+		let estate_info = match Estates::<T>::get(estate_id) {
+			Some(estate_info) => estate_info,
+			None => return Vec::new(),
+		};
+		let connectivity = Self::reshaping_connectivity(estate_info.metaverse_id);
+		let estate_land_units: BTreeSet<(i32, i32)> = estate_info.land_units.iter().cloned().collect();
+		let neighbour_land_units: BTreeSet<(i32, i32)> = estate_land_units
+			.iter()
+			.flat_map(|land_unit| Self::neighbour_land_units(land_unit, connectivity))
+			.filter(|land_unit| !estate_land_units.contains(land_unit))
+			.collect();
+
+		// Only the chunks of the neighbour land units are scanned, so large estates are not
+		// limited by the size of a spatial query
+		let neighbour_chunks: BTreeSet<(i32, i32)> = neighbour_land_units.iter().map(Self::estate_chunk).collect();
+		let candidate_estates: BTreeSet<EstateId> = neighbour_chunks
+			.into_iter()
+			.flat_map(|chunk| EstateChunks::<T>::iter_key_prefix((estate_info.metaverse_id, chunk)))
+			.collect();
+
+		candidate_estates
+			.into_iter()
+			.filter(|neighbour_estate_id| {
+				*neighbour_estate_id != estate_id
+					&& Estates::<T>::get(neighbour_estate_id).map_or(false, |neighbour_estate_info| {
+						neighbour_estate_info
+							.land_units
+							.iter()
+							.any(|land_unit| neighbour_land_units.contains(land_unit))
+					})
+			})
+			.collect()
+	}
 
-					Self::deposit_event(Event::<T>::TransferredEstate(
-						estate_id.clone(),
-						from.clone(),
-						to.clone(),
-					));
+	/// Check whether land units form a single connected shape
+	pub fn is_land_units_connected(land_units: &[(i32, i32)], connectivity: EstateConnectivity) -> bool {
+		let all_land_units: BTreeSet<(i32, i32)> = land_units.iter().cloned().collect();
+		let mut land_units_to_visit = match land_units.first() {
+			Some(land_unit) => sp_std::vec![*land_unit],
+			None => return true,
+		};
+		let mut visited_land_units = BTreeSet::new();
+		visited_land_units.insert(land_units_to_visit[0]);
 
-					Ok(estate_id)
+		while let Some(land_unit) = land_units_to_visit.pop() {
+			for neighbour in Self::neighbour_land_units(&land_unit, connectivity) {
+				if all_land_units.contains(&neighbour) && visited_land_units.insert(neighbour) {
+					land_units_to_visit.push(neighbour);
 				}
-				_ => Err(Error::<T>::InvalidOwnerValue.into()),
 			}
+		}
+
+		visited_land_units.len() == all_land_units.len()
+	}
+
+	fn neighbour_land_units(
+		land_unit: &(i32, i32),
+		connectivity: EstateConnectivity,
+	) -> impl Iterator<Item = (i32, i32)> + '_ {
+		connectivity.neighbour_offsets().iter().map(move |offset| {
+			(
+				land_unit.0.saturating_add(offset.0),
+				land_unit.1.saturating_add(offset.1),
+			)
 		})
 	}
 
+	/// Ensure land units satisfy the connectivity rule of the metaverse, if any
+	fn ensure_estate_connectivity(metaverse_id: MetaverseId, land_units: &[(i32, i32)]) -> DispatchResult {
+		if let Some(connectivity) = Self::estate_connectivity(metaverse_id) {
+			ensure!(
+				Self::is_land_units_connected(land_units, connectivity),
+				Error::<T>::EstateLandUnitsNotConnected
+			);
+		}
+		Ok(())
+	}
+
+	/// Connectivity required when merging or splitting estates
+	fn reshaping_connectivity(metaverse_id: MetaverseId) -> EstateConnectivity {
+		Self::estate_connectivity(metaverse_id).unwrap_or(EstateConnectivity::FourConnected)
+	}
+
+	/// Ensure an estate can be merged or split by an account
+	fn ensure_estate_can_be_reshaped(
+		who: &T::AccountId,
+		estate_id: EstateId,
+	) -> Result<OwnerId<T::AccountId, ClassId, TokenId>, DispatchError> {
+		let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+		match estate_owner_value {
+			OwnerId::Token(class_id, token_id) => {
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::NFT(class_id, token_id)),
+					Error::<T>::EstateAlreadyInAuction
+				);
+				ensure!(
+					Self::check_if_land_or_estate_owner(who, &estate_owner_value),
+					Error::<T>::NoPermission
+				);
+				ensure!(
+					!EstateLeases::<T>::contains_key(estate_id),
+					Error::<T>::EstateIsAlreadyLeased
+				);
+				Ok(estate_owner_value)
+			}
+			_ => Err(Error::<T>::InvalidOwnerValue.into()),
+		}
+	}
+
+	/// Chunk of the spatial index containing a land unit
+	fn estate_chunk(land_unit: &(i32, i32)) -> (i32, i32) {
+		let chunk_size = T::EstateChunkSize::get().clamp(1, i32::MAX as u32) as i32;
+		(land_unit.0.div_euclid(chunk_size), land_unit.1.div_euclid(chunk_size))
+	}
+
+	fn add_estate_to_index(metaverse_id: MetaverseId, estate_id: EstateId, land_units: &[(i32, i32)]) {
+		let chunks: BTreeSet<(i32, i32)> = land_units.iter().map(Self::estate_chunk).collect();
+		for chunk in chunks {
+			EstateChunks::<T>::insert((metaverse_id, chunk, estate_id), ());
+		}
+	}
+
+	fn remove_estate_from_index(metaverse_id: MetaverseId, estate_id: EstateId, land_units: &[(i32, i32)]) {
+		let chunks: BTreeSet<(i32, i32)> = land_units.iter().map(Self::estate_chunk).collect();
+		for chunk in chunks {
+			EstateChunks::<T>::remove((metaverse_id, chunk, estate_id));
+		}
+	}
+
 	/// Internal transfer of land unit
 	fn do_transfer_landunit(
 		coordinate: (i32, i32),
@@ -2112,12 +3747,71 @@ impl<T: Config> Pallet<T> {
 			&& top_right_yaxis <= *min_yaxis
 	}
 
+	/// Index the existing estates in the spatial index of estate chunks
+	pub fn upgrade_estate_chunks_v1() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!("Start indexing estate chunks");
+		let mut num_estates: u64 = 0;
+		let mut num_chunks: u64 = 0;
+		for (estate_id, estate_info) in Estates::<T>::iter() {
+			let chunks: BTreeSet<(i32, i32)> = estate_info.land_units.iter().map(Self::estate_chunk).collect();
+			num_estates += 1;
+			num_chunks += chunks.len() as u64;
+			Self::add_estate_to_index(estate_info.metaverse_id, estate_id, &estate_info.land_units);
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!("{} estates indexed in {} estate chunks", num_estates, num_chunks);
+
+		T::DbWeight::get().reads_writes(num_estates + 1, num_chunks + 1)
+	}
+
+	/// Start the vesting period of the bound to address undeployed land blocks issued before
+	/// vesting periods existed, so they can be unlocked once it is over
+	pub fn upgrade_undeployed_land_block_vesting_v2() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 2 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!("Start setting the vesting end of bound undeployed land blocks");
+		let vesting_end =
+			<frame_system::Pallet<T>>::block_number().saturating_add(T::BoundLandBlockVestingPeriod::get());
+		let mut num_land_blocks: u64 = 0;
+		let mut num_vesting_land_blocks: u64 = 0;
+		for (undeployed_land_block_id, undeployed_land_block) in UndeployedLandBlocks::<T>::iter() {
+			num_land_blocks += 1;
+			if undeployed_land_block.undeployed_land_block_type == UndeployedLandBlockType::BoundToAddress
+				&& !UndeployedLandBlockVestingEnd::<T>::contains_key(undeployed_land_block_id)
+			{
+				UndeployedLandBlockVestingEnd::<T>::insert(undeployed_land_block_id, vesting_end);
+				num_vesting_land_blocks += 1;
+			}
+		}
+		STORAGE_VERSION.put::<Pallet<T>>();
+		log::info!(
+			"Vesting end set on {} of {} undeployed land blocks",
+			num_vesting_land_blocks,
+			num_land_blocks
+		);
+
+		T::DbWeight::get().reads_writes(
+			num_land_blocks.saturating_mul(2).saturating_add(2),
+			num_vesting_land_blocks.saturating_add(1),
+		)
+	}
+
 	/// Remove all land unit and estate
 	pub fn remove_all_estate_storage() -> Weight {
 		log::info!("Start removing all land unit and estates");
 		LandUnits::<T>::remove_all(None);
 		Estates::<T>::remove_all(None);
 		EstateOwner::<T>::remove_all(None);
+		EstateChunks::<T>::remove_all(None);
+		LandAssetMetadata::<T>::remove_all(None);
+		LandBuilders::<T>::remove_all(None);
+		LandBuilderCount::<T>::remove_all(None);
 		NextEstateId::<T>::put(1);
 		AllLandUnitsCount::<T>::put(0);
 		AllEstatesCount::<T>::put(0);
@@ -2318,4 +4012,61 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 	fn is_estate_leased(estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(EstateLeases::<T>::contains_key(estate_id))
 	}
+
+	fn get_estate_token(estate_id: EstateId) -> Option<(ClassId, TokenId)> {
+		match Self::get_estate_owner(estate_id) {
+			Some(OwnerId::Token(class_id, token_id)) => Some((class_id, token_id)),
+			_ => None,
+		}
+	}
+
+	fn get_land_unit_token(metaverse_id: MetaverseId, coordinate: (i32, i32)) -> Option<(ClassId, TokenId)> {
+		match Self::get_land_units(metaverse_id, coordinate) {
+			Some(OwnerId::Token(class_id, token_id)) => Some((class_id, token_id)),
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config> AccountAssetsMigration<T::AccountId> for Pallet<T> {
+	/// Moves at most `limit` undeployed land blocks of `from` to `to`. Locked and bound land
+	/// blocks are moved as well, only listed land blocks fail the migration. Land units and
+	/// estates are owned through their NFTs, so they follow the NFT migration.
+	fn migrate_account_assets(from: &T::AccountId, to: &T::AccountId, limit: u32) -> Result<u32, DispatchError> {
+		let undeployed_land_block_ids: Vec<UndeployedLandBlockId> =
+			UndeployedLandBlocksOwner::<T>::iter_key_prefix(from)
+				.take(limit as usize)
+				.collect();
+
+		for undeployed_land_block_id in undeployed_land_block_ids.iter() {
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::UndeployedLandBlock(*undeployed_land_block_id)),
+				Error::<T>::UndeployedLandBlockAlreadyInAuction
+			);
+		}
+
+		for undeployed_land_block_id in undeployed_land_block_ids.iter() {
+			UndeployedLandBlocks::<T>::try_mutate(
+				undeployed_land_block_id,
+				|undeployed_land_block| -> DispatchResult {
+					let undeployed_land_block_record = undeployed_land_block
+						.as_mut()
+						.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+					undeployed_land_block_record.owner = to.clone();
+					Ok(())
+				},
+			)?;
+
+			UndeployedLandBlocksOwner::<T>::remove(from, undeployed_land_block_id);
+			UndeployedLandBlocksOwner::<T>::insert(to, undeployed_land_block_id, ());
+
+			Self::deposit_event(Event::<T>::UndeployedLandBlockTransferred(
+				from.clone(),
+				to.clone(),
+				*undeployed_land_block_id,
+			));
+		}
+
+		Ok(undeployed_land_block_ids.len() as u32)
+	}
 }
@@ -1,5 +1,6 @@
 #![cfg(test)]
 
+use frame_support::traits::AsEnsureOriginWithArg;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
 use frame_system::EnsureSignedBy;
 use sp_core::H256;
@@ -72,8 +73,11 @@ pub const OWNER_ESTATE_ASSET_ID: OwnerId<AccountId, ClassId, TokenId> =
 
 pub const GENERAL_METAVERSE_FUND: AccountId = 102;
 
+pub const METAVERSE_GOVERNANCE: AccountId = 103;
+
 ord_parameter_types! {
 	pub const One: AccountId = ALICE;
+	pub const MetaverseGovernance: AccountId = METAVERSE_GOVERNANCE;
 }
 
 // Configure a mock runtime to test the pallet.
@@ -261,6 +265,24 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn sealed_bid_commit_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_commitment: [u8; 32],
+		_deposit: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn sealed_bid_reveal_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_value: Self::Balance,
+		_salt: Vec<u8>,
+	) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
 	fn local_auction_bid_handler(
 		_now: u64,
 		_id: u64,
@@ -535,6 +557,13 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 	) -> sp_runtime::DispatchResult {
 		Ok(())
 	}
+
+	fn get_royalty_payouts(
+		_class_id: &Self::ClassId,
+		_sale_price: Balance,
+	) -> Result<Vec<(AccountId, Balance)>, DispatchError> {
+		Ok(vec![])
+	}
 }
 
 parameter_types! {
@@ -545,6 +574,17 @@ parameter_types! {
 	pub const DefaultMaxBound: (i32,i32) = MAX_BOUND;
 	pub const NetworkFee: Balance = 1; // Network fee
 	pub const MaxOffersPerEstate: u32 = 2;
+	pub const MaxBatchEstateOperations: u32 = 5;
+	pub const EstateChunkSize: u32 = 4;
+	pub const MaxSpatialQueryChunks: u32 = 64;
+	pub const MaxReshapeLandUnits: u32 = 6;
+	pub const MaxZoneNameLength: u32 = 8;
+	pub const MaxBuildersPerAsset: u32 = 2;
+	pub const LandUnitsPerIssuedLandBlock: u32 = 10;
+	pub const MaxIssuanceRecipients: u32 = 2;
+	pub const MinAnnualLandIssuance: u64 = 500;
+	pub const BoundLandBlockVestingPeriod: u64 = 10;
+	pub static EstateStakes: Vec<(EstateId, Balance)> = vec![];
 	pub const MinLeasePricePerBlock: Balance = 1u128;
 	pub const MaxLeasePeriod: u32 = 9;
 	pub const LeaseOfferExpiryPeriod: u32 = 6;
@@ -558,6 +598,7 @@ impl Config for Runtime {
 	type Currency = Balances;
 	type MinimumLandPrice = MinimumLandPrice;
 	type CouncilOrigin = EnsureSignedBy<One, AccountId>;
+	type MetaverseGovernanceOrigin = AsEnsureOriginWithArg<EnsureSignedBy<MetaverseGovernance, AccountId>>;
 	type AuctionHandler = MockAuctionManager;
 	type MinBlocksPerRound = MinBlocksPerRound;
 	type WeightInfo = ();
@@ -572,6 +613,35 @@ impl Config for Runtime {
 	type LeaseOfferExpiryPeriod = LeaseOfferExpiryPeriod;
 	type BlockNumberToBalance = ConvertInto;
 	type StorageDepositFee = StorageDepositFee;
+	type MaxBatchOperations = MaxBatchEstateOperations;
+	type EstateChunkSize = EstateChunkSize;
+	type MaxSpatialQueryChunks = MaxSpatialQueryChunks;
+	type MaxReshapeLandUnits = MaxReshapeLandUnits;
+	type MaxZoneNameLength = MaxZoneNameLength;
+	type MaxBuildersPerAsset = MaxBuildersPerAsset;
+	type EstateStakeSource = MockEstateStakeSource;
+	type LandUnitsPerIssuedLandBlock = LandUnitsPerIssuedLandBlock;
+	type MaxIssuanceRecipients = MaxIssuanceRecipients;
+	type MinAnnualLandIssuance = MinAnnualLandIssuance;
+	type BoundLandBlockVestingPeriod = BoundLandBlockVestingPeriod;
+}
+
+pub struct MockEstateStakeSource;
+
+impl EstateStakeProvider<Balance> for MockEstateStakeSource {
+	fn estate_stakes(start_after: Option<EstateId>, limit: u32) -> (Vec<(EstateId, Balance)>, Option<EstateId>) {
+		let stakes: Vec<(EstateId, Balance)> = EstateStakes::get()
+			.into_iter()
+			.filter(|(estate_id, _)| start_after.map_or(true, |start| *estate_id > start))
+			.collect();
+		let page: Vec<(EstateId, Balance)> = stakes.iter().take(limit as usize).cloned().collect();
+		let next = if stakes.len() > page.len() {
+			page.last().map(|(estate_id, _)| *estate_id)
+		} else {
+			None
+		};
+		(page, next)
+	}
 }
 
 construct_runtime!(
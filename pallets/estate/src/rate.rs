@@ -16,10 +16,13 @@
 // limitations under the License.
 
 use codec::{Decode, Encode};
+use frame_support::traits::Get;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_runtime::{Perbill, RuntimeDebug};
 
+use primitives::RoundIndex;
+
 use crate::{AllLandUnitsCount, TotalUndeployedLandUnit};
 // Helper methods to compute the issuance rate for undeployed land.
 use crate::pallet::{Config, Pallet};
@@ -28,16 +31,21 @@ const SECONDS_PER_YEAR: u32 = 31557600;
 const SECONDS_PER_BLOCK: u32 = 12;
 const BLOCKS_PER_YEAR: u32 = SECONDS_PER_YEAR / SECONDS_PER_BLOCK;
 
-fn rounds_per_year<T: Config>() -> u32 {
+pub fn rounds_per_year<T: Config>() -> u32 {
 	let blocks_per_round = <Pallet<T>>::round().length;
-	BLOCKS_PER_YEAR / blocks_per_round
+	BLOCKS_PER_YEAR.checked_div(blocks_per_round).unwrap_or_default().max(1)
 }
 
-fn get_annual_max_issuance<T: Config>(max_supply: u64, annual_percentage: u64) -> u64 {
+/// Compute the land units that can be issued over a year, at least `MinAnnualLandIssuance` and
+/// capped by the max total supply
+pub fn get_annual_max_issuance<T: Config>(max_supply: u64, annual_percentage: u64) -> u64 {
 	let total_land_unit_circulating = <AllLandUnitsCount<T>>::get();
 	let total_undeployed_land_unit_circulating = <TotalUndeployedLandUnit<T>>::get();
 	let circulating = total_land_unit_circulating.saturating_add(total_undeployed_land_unit_circulating);
-	max_supply.saturating_sub(circulating).saturating_mul(annual_percentage)
+	let annual_issuance = Perbill::from_percent(annual_percentage as u32) * circulating;
+	annual_issuance
+		.max(T::MinAnnualLandIssuance::get())
+		.min(max_supply.saturating_sub(circulating))
 }
 
 /// Compute round issuance range from round inflation range and current total issuance
@@ -58,16 +66,20 @@ pub fn round_issuance_range<T: Config>(config: MintingRateInfo) -> Range<u64> {
 	let annual_percentage = Perbill::from_percent(config.annual as u32).deconstruct();
 
 	// Round percentage minting rate
-	let round_percentage = annual_percentage.checked_div(total_round_per_year).unwrap();
+	let round_percentage = annual_percentage.checked_div(total_round_per_year).unwrap_or_default();
 
 	// Convert to percentage
 	let round_percentage_per_bill = Perbill::from_parts(round_percentage);
 
+	// Minimum round issuance while the circulating supply is too low
+	let round_issuance_floor = T::MinAnnualLandIssuance::get() / total_round_per_year as u64;
+	let round_issuance = (round_percentage_per_bill * circulating).max(round_issuance_floor);
+
 	// Return range - could implement more cases in the future.
 	Range {
-		min: round_percentage_per_bill * circulating,
-		ideal: round_percentage_per_bill * circulating,
-		max: round_percentage_per_bill * circulating,
+		min: round_issuance,
+		ideal: round_issuance,
+		max: round_issuance,
 	}
 }
 
@@ -122,6 +134,35 @@ impl MintingRateInfo {
 	}
 }
 
+/// Land block issuance of a round, shared between metaverses over the following blocks
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+pub struct RoundLandIssuance {
+	/// Round of the issuance
+	pub round: RoundIndex,
+	/// Land blocks issued during the round
+	pub land_blocks: u32,
+	/// Whether all the estate stakes are read into the stake snapshot
+	pub stakes_read: bool,
+	/// Stake of all the metaverses in the stake snapshot
+	pub total_stake: u128,
+	/// Stake of the metaverses which received their land blocks
+	pub allocated_stake: u128,
+	/// Land units issued to metaverses so far
+	pub issued_land_units: u64,
+}
+
+/// Land units issued at the start of rounds during a year
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, Default, RuntimeDebug, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnualIssuance {
+	/// Index of the year since the first round
+	pub year: u32,
+	/// Max land units issued during the year
+	pub cap: u64,
+	/// Land units issued during the year
+	pub issued: u64,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
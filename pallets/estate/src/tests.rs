@@ -18,6 +18,7 @@
 #![cfg(test)]
 
 use frame_support::{assert_err, assert_noop, assert_ok};
+use sp_core::H256;
 use sp_runtime::traits::BadOrigin;
 
 use mock::{RuntimeEvent, *};
@@ -66,6 +67,21 @@ fn mint_land_should_work_with_one_coordinate() {
 	});
 }
 
+#[test]
+fn mint_land_should_reject_metaverse_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EstateModule::mint_land(
+				RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				COORDINATE_IN_1
+			),
+			BadOrigin
+		);
+	});
+}
+
 #[test]
 fn mint_land_token_should_work_have_correct_owner() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1395,6 +1411,59 @@ fn transfer_undeployed_land_block_should_fail_if_already_in_auction() {
 	});
 }
 
+#[test]
+fn distribute_undeployed_land_blocks_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			GENERAL_METAVERSE_FUND,
+			2,
+			20,
+			UndeployedLandBlockType::Transferable
+		));
+
+		// Only the owner or the governance of the metaverse can distribute its land blocks
+		assert_noop!(
+			EstateModule::distribute_undeployed_land_blocks(RuntimeOrigin::signed(BOB), ALICE_METAVERSE_ID, BOB, 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::distribute_undeployed_land_blocks(RuntimeOrigin::root(), ALICE_METAVERSE_ID, BOB, 0),
+			BadOrigin
+		);
+
+		assert_ok!(EstateModule::distribute_undeployed_land_blocks(
+			RuntimeOrigin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			BOB,
+			0
+		));
+		assert_eq!(EstateModule::get_undeployed_land_block(0).map(|a| a.owner), Some(BOB));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::UndeployedLandBlockTransferred(
+				GENERAL_METAVERSE_FUND,
+				BOB,
+				0
+			))
+		);
+
+		assert_ok!(EstateModule::distribute_undeployed_land_blocks(
+			RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+			ALICE_METAVERSE_ID,
+			ALICE,
+			1
+		));
+		assert_eq!(EstateModule::get_undeployed_land_block(1).map(|a| a.owner), Some(ALICE));
+
+		// Land blocks not held by the metaverse treasury cannot be distributed
+		assert_noop!(
+			EstateModule::distribute_undeployed_land_blocks(RuntimeOrigin::signed(ALICE), ALICE_METAVERSE_ID, ALICE, 0),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
 #[test]
 fn transfer_undeployed_land_block_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -2906,3 +2975,1350 @@ fn collect_rent_should_work() {
 		assert_eq!(Balances::free_balance(BENEFICIARY_ID), 1000029);
 	});
 }
+
+#[test]
+fn migrate_account_assets_should_move_undeployed_land_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			ALICE,
+			2,
+			20,
+			UndeployedLandBlockType::Transferable
+		));
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			BOB,
+			2,
+			20,
+			UndeployedLandBlockType::Transferable
+		));
+
+		// Undeployed land blocks in auction can not be migrated
+		assert_noop!(
+			EstateModule::migrate_account_assets(&ALICE, &BOB, 10),
+			Error::<Runtime>::UndeployedLandBlockAlreadyInAuction
+		);
+
+		// At most `limit` land blocks are moved at once
+		assert_eq!(EstateModule::migrate_account_assets(&BOB, &ALICE, 1), Ok(1));
+		assert_eq!(EstateModule::migrate_account_assets(&BOB, &ALICE, 10), Ok(1));
+		assert_eq!(EstateModule::migrate_account_assets(&BOB, &ALICE, 10), Ok(0));
+		for undeployed_land_block_id in [2, 3] {
+			let undeployed_land_block = EstateModule::get_undeployed_land_block(undeployed_land_block_id).unwrap();
+			assert_eq!(undeployed_land_block.owner, ALICE);
+			assert_eq!(
+				EstateModule::get_undeployed_land_block_owner(BOB, undeployed_land_block_id),
+				None
+			);
+			assert_eq!(
+				EstateModule::get_undeployed_land_block_owner(ALICE, undeployed_land_block_id),
+				Some(())
+			);
+		}
+
+		// Bound and locked land blocks are recovered as they are
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			BOB,
+			1,
+			20,
+			UndeployedLandBlockType::BoundToAddress
+		));
+		assert_ok!(EstateModule::freeze_undeployed_land_blocks(RuntimeOrigin::root(), 4));
+		assert_eq!(EstateModule::migrate_account_assets(&BOB, &ALICE, 10), Ok(1));
+		let undeployed_land_block = EstateModule::get_undeployed_land_block(4).unwrap();
+		assert_eq!(undeployed_land_block.owner, ALICE);
+		assert_eq!(
+			undeployed_land_block.undeployed_land_block_type,
+			UndeployedLandBlockType::BoundToAddress
+		);
+		assert!(undeployed_land_block.is_locked);
+		assert_eq!(EstateModule::get_undeployed_land_block_owner(ALICE, 4), Some(()));
+	});
+}
+
+#[test]
+fn batch_estate_operations_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_3]
+		));
+
+		// The estate created by the first operation is extended by the second one
+		assert_ok!(EstateModule::batch_estate_operations(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			vec![
+				EstateOperation::CreateEstate(METAVERSE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2]),
+				EstateOperation::AddLandUnits(ESTATE_ID, vec![COORDINATE_IN_3]),
+			]
+		));
+
+		assert_eq!(EstateModule::all_estates_count(), 1);
+		assert_eq!(
+			EstateModule::get_estates(ESTATE_ID),
+			Some(EstateInfo {
+				metaverse_id: METAVERSE_ID,
+				land_units: vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_3]
+			})
+		);
+		assert_eq!(
+			EstateModule::get_land_units(METAVERSE_ID, COORDINATE_IN_3),
+			Some(OWNER_ESTATE_ASSET_ID)
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::EstateOperationsExecuted(BENEFICIARY_ID, 2))
+		);
+	});
+}
+
+#[test]
+fn validate_estate_operations_should_not_apply_operations() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		let create_estate = EstateOperation::CreateEstate(METAVERSE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2]);
+
+		assert_eq!(
+			EstateModule::validate_estate_operations(&BENEFICIARY_ID, vec![create_estate.clone()]),
+			Ok(())
+		);
+		assert_eq!(EstateModule::all_estates_count(), 0);
+		assert_eq!(EstateModule::get_estates(ESTATE_ID), None);
+
+		// The failing operation is reported
+		assert_eq!(
+			EstateModule::validate_estate_operations(
+				&BENEFICIARY_ID,
+				vec![create_estate, EstateOperation::TransferEstate(ALICE, ESTATE_IN_AUCTION)]
+			),
+			Err((1, Error::<Runtime>::NoPermission.into()))
+		);
+		assert_eq!(EstateModule::all_estates_count(), 0);
+		assert_eq!(
+			EstateModule::validate_estate_operations(&BENEFICIARY_ID, vec![]),
+			Err((0, Error::<Runtime>::EmptyBatchOperations.into()))
+		);
+	});
+}
+
+#[test]
+fn estate_operations_weight_should_charge_empty_coordinates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			EstateModule::estate_operations_weight(&[EstateOperation::CreateEstate(METAVERSE_ID, vec![])]),
+			<Runtime as Config>::WeightInfo::create_estate()
+		);
+	});
+}
+
+#[test]
+fn estate_operations_weight_should_scale_with_land_units() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			EstateModule::estate_operations_weight(&[
+				EstateOperation::AddLandUnits(ESTATE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2]),
+				EstateOperation::RemoveLandUnits(ESTATE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_3]),
+			]),
+			<Runtime as Config>::WeightInfo::add_land_unit_to_estate()
+				.saturating_mul(2)
+				.saturating_add(<Runtime as Config>::WeightInfo::remove_land_unit_from_estate().saturating_mul(3))
+		);
+	});
+}
+
+#[test]
+fn batch_estate_operations_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		let create_estate = EstateOperation::CreateEstate(METAVERSE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2]);
+
+		assert_noop!(
+			EstateModule::batch_estate_operations(RuntimeOrigin::signed(BENEFICIARY_ID), vec![]),
+			Error::<Runtime>::EmptyBatchOperations
+		);
+		assert_noop!(
+			EstateModule::batch_estate_operations(
+				RuntimeOrigin::signed(BENEFICIARY_ID),
+				vec![EstateOperation::TransferLand(ALICE, METAVERSE_ID, COORDINATE_IN_1); 6]
+			),
+			Error::<Runtime>::ExceedMaximumBatchOperations
+		);
+		// Ownership is checked before anything is applied
+		assert_noop!(
+			EstateModule::batch_estate_operations(RuntimeOrigin::signed(BOB), vec![create_estate.clone()]),
+			Error::<Runtime>::NoPermission
+		);
+		// A failing operation reverts the ones before it
+		assert_noop!(
+			EstateModule::batch_estate_operations(
+				RuntimeOrigin::signed(BENEFICIARY_ID),
+				vec![create_estate, EstateOperation::TransferEstate(ALICE, ESTATE_IN_AUCTION)]
+			),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_estate_connectivity_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EstateModule::set_estate_connectivity(
+				RuntimeOrigin::signed(BOB),
+				ALICE_METAVERSE_ID,
+				Some(EstateConnectivity::FourConnected)
+			),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::set_estate_connectivity(
+			RuntimeOrigin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			Some(EstateConnectivity::EightConnected)
+		));
+		assert_eq!(
+			EstateModule::estate_connectivity(ALICE_METAVERSE_ID),
+			Some(EstateConnectivity::EightConnected)
+		);
+
+		assert_ok!(EstateModule::set_estate_connectivity(
+			RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+			ALICE_METAVERSE_ID,
+			None
+		));
+		assert_eq!(EstateModule::estate_connectivity(ALICE_METAVERSE_ID), None);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::EstateConnectivityUpdated(ALICE_METAVERSE_ID, None))
+		);
+	});
+}
+
+#[test]
+fn estate_connectivity_should_be_enforced() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_4, (-3, 6)]
+		));
+		assert_ok!(EstateModule::set_estate_connectivity(
+			RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+			METAVERSE_ID,
+			Some(EstateConnectivity::FourConnected)
+		));
+
+		assert_noop!(
+			EstateModule::create_estate(
+				RuntimeOrigin::signed(BENEFICIARY_ID),
+				METAVERSE_ID,
+				vec![COORDINATE_IN_1, COORDINATE_IN_4]
+			),
+			Error::<Runtime>::EstateLandUnitsNotConnected
+		);
+
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		assert_noop!(
+			EstateModule::add_land_unit_to_estate(RuntimeOrigin::signed(BENEFICIARY_ID), ESTATE_ID, vec![(-3, 6)]),
+			Error::<Runtime>::EstateLandUnitsNotConnected
+		);
+
+		// (-3, 6) only touches (-4, 5) by a corner
+		assert_ok!(EstateModule::set_estate_connectivity(
+			RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+			METAVERSE_ID,
+			Some(EstateConnectivity::EightConnected)
+		));
+		assert_ok!(EstateModule::add_land_unit_to_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			ESTATE_ID,
+			vec![(-3, 6)]
+		));
+		assert_noop!(
+			EstateModule::remove_land_unit_from_estate(
+				RuntimeOrigin::signed(BENEFICIARY_ID),
+				ESTATE_ID,
+				vec![COORDINATE_IN_2]
+			),
+			Error::<Runtime>::EstateLandUnitsNotConnected
+		);
+		assert_eq!(
+			EstateModule::get_estates(ESTATE_ID),
+			Some(EstateInfo {
+				metaverse_id: METAVERSE_ID,
+				land_units: vec![COORDINATE_IN_1, COORDINATE_IN_2, (-3, 6)]
+			})
+		);
+	});
+}
+
+#[test]
+fn merge_and_split_estates_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_3, (-3, 6)]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_3, (-3, 6)]
+		));
+
+		assert_ok!(EstateModule::merge_estates(RuntimeOrigin::signed(BENEFICIARY_ID), 0, 1));
+		assert_eq!(EstateModule::all_estates_count(), 1);
+		assert_eq!(EstateModule::get_estates(1), None);
+		assert_eq!(EstateModule::get_estate_owner(1), None);
+		assert_eq!(
+			EstateModule::get_estates(0),
+			Some(EstateInfo {
+				metaverse_id: METAVERSE_ID,
+				land_units: vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_3, (-3, 6)]
+			})
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::EstatesMerged(
+				0,
+				1,
+				vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_3, (-3, 6)]
+			))
+		);
+
+		assert_ok!(EstateModule::split_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			0,
+			vec![COORDINATE_IN_3, (-3, 6)]
+		));
+		assert_eq!(EstateModule::all_estates_count(), 2);
+		assert_eq!(
+			EstateModule::get_estates(0),
+			Some(EstateInfo {
+				metaverse_id: METAVERSE_ID,
+				land_units: vec![COORDINATE_IN_1, COORDINATE_IN_2]
+			})
+		);
+		assert_eq!(
+			EstateModule::get_estates(2),
+			Some(EstateInfo {
+				metaverse_id: METAVERSE_ID,
+				land_units: vec![COORDINATE_IN_3, (-3, 6)]
+			})
+		);
+		assert_eq!(EstateModule::get_estate_owner(2), Some(OWNER_ESTATE_ASSET_ID));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::EstateSplit(0, 2, vec![COORDINATE_IN_3, (-3, 6)]))
+		);
+	});
+}
+
+#[test]
+fn merge_estates_should_refund_lease_offers() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_3, (-3, 6)]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_3, (-3, 6)]
+		));
+		assert_ok!(EstateModule::create_lease_offer(
+			RuntimeOrigin::signed(ALICE),
+			1u64,
+			10u128,
+			8u32
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 80);
+
+		assert_ok!(EstateModule::merge_estates(RuntimeOrigin::signed(BENEFICIARY_ID), 0, 1));
+		assert_eq!(EstateModule::lease_offers(1u64, ALICE), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+	});
+}
+
+#[test]
+fn merge_and_split_estates_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![
+				COORDINATE_IN_1,
+				COORDINATE_IN_2,
+				COORDINATE_IN_3,
+				(-3, 4),
+				COORDINATE_IN_4,
+				(-3, 8)
+			]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_3, (-3, 4)]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_4, (-3, 8)]
+		));
+
+		assert_noop!(
+			EstateModule::merge_estates(RuntimeOrigin::signed(BENEFICIARY_ID), 0, 0),
+			Error::<Runtime>::CannotMergeEstateWithItself
+		);
+		assert_noop!(
+			EstateModule::merge_estates(RuntimeOrigin::signed(BOB), 0, 1),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::merge_estates(RuntimeOrigin::signed(BENEFICIARY_ID), 0, 1),
+			Error::<Runtime>::EstateLandUnitsNotConnected
+		);
+
+		assert_noop!(
+			EstateModule::split_estate(RuntimeOrigin::signed(BENEFICIARY_ID), 0, vec![COORDINATE_IN_4, (-3, 8)]),
+			Error::<Runtime>::InvalidEstateSplit
+		);
+		assert_noop!(
+			EstateModule::split_estate(
+				RuntimeOrigin::signed(BENEFICIARY_ID),
+				0,
+				vec![COORDINATE_IN_2, COORDINATE_IN_3, (-3, 4)]
+			),
+			Error::<Runtime>::InvalidEstateSplit
+		);
+		assert_noop!(
+			EstateModule::split_estate(
+				RuntimeOrigin::signed(BENEFICIARY_ID),
+				0,
+				vec![COORDINATE_IN_1, COORDINATE_IN_3]
+			),
+			Error::<Runtime>::EstateLandUnitsNotConnected
+		);
+	});
+}
+
+#[test]
+fn merge_and_split_estates_should_fail_if_exceed_maximum_reshape_land_units() {
+	ExtBuilder::default().build().execute_with(|| {
+		let land_units: Vec<(i32, i32)> = (0..9).map(|x| (x, 0)).collect();
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			land_units.clone()
+		));
+		for estate_land_units in [&land_units[..5], &land_units[5..7], &land_units[7..]] {
+			assert_ok!(EstateModule::create_estate(
+				RuntimeOrigin::signed(BENEFICIARY_ID),
+				METAVERSE_ID,
+				estate_land_units.to_vec()
+			));
+		}
+
+		// Merged estate would have 7 land units
+		assert_noop!(
+			EstateModule::merge_estates(RuntimeOrigin::signed(BENEFICIARY_ID), 0, 1),
+			Error::<Runtime>::ExceedMaximumReshapeLandUnits
+		);
+		assert_ok!(EstateModule::merge_estates(RuntimeOrigin::signed(BENEFICIARY_ID), 1, 2));
+
+		// Estates created with more land units can not be split
+		assert_ok!(EstateModule::dissolve_estate(RuntimeOrigin::signed(BENEFICIARY_ID), 0));
+		assert_ok!(EstateModule::dissolve_estate(RuntimeOrigin::signed(BENEFICIARY_ID), 1));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			land_units
+		));
+		assert_noop!(
+			EstateModule::split_estate(RuntimeOrigin::signed(BENEFICIARY_ID), 3, vec![(7, 0), (8, 0)]),
+			Error::<Runtime>::ExceedMaximumReshapeLandUnits
+		);
+	});
+}
+
+#[test]
+fn spatial_queries_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![
+				COORDINATE_IN_1,
+				COORDINATE_IN_2,
+				COORDINATE_IN_3,
+				(-3, 6),
+				(8, 8),
+				(8, 9)
+			]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![COORDINATE_IN_3, (-3, 6)]
+		));
+		assert_ok!(EstateModule::create_estate(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			METAVERSE_ID,
+			vec![(8, 8), (8, 9)]
+		));
+
+		assert_eq!(
+			EstateModule::estates_in_bounding_box(METAVERSE_ID, (-10, -10), (10, 10)),
+			Ok(vec![0, 1, 2])
+		);
+		assert_eq!(
+			EstateModule::estates_in_bounding_box(METAVERSE_ID, (-5, 3), (0, 5)),
+			Ok(vec![0])
+		);
+		assert_eq!(
+			EstateModule::estates_in_bounding_box(METAVERSE_ID, (0, 0), (1, 1)),
+			Ok(Vec::<EstateId>::new())
+		);
+		assert_eq!(
+			EstateModule::estates_in_bounding_box(ALICE_METAVERSE_ID, (-10, -10), (10, 10)),
+			Ok(Vec::<EstateId>::new())
+		);
+		// Bounding boxes larger than the spatial query limit are rejected instead of truncated
+		assert_eq!(
+			EstateModule::estates_in_bounding_box(METAVERSE_ID, (-100, -100), (100, 100)),
+			Err(Error::<Runtime>::SpatialQueryTooLarge.into())
+		);
+
+		assert_eq!(EstateModule::estate_neighbours(0), vec![1]);
+		assert_eq!(EstateModule::estate_neighbours(1), vec![0]);
+		assert_eq!(EstateModule::estate_neighbours(2), Vec::<EstateId>::new());
+
+		// Estates created before the spatial index are indexed by the migration
+		EstateChunks::<Runtime>::remove_all(None);
+		StorageVersion::new(0).put::<EstateModule>();
+		assert_eq!(
+			EstateModule::estates_in_bounding_box(METAVERSE_ID, (-10, -10), (10, 10)),
+			Ok(Vec::<EstateId>::new())
+		);
+
+		EstateModule::on_runtime_upgrade();
+		assert_eq!(EstateModule::on_chain_storage_version(), 2);
+		assert_eq!(
+			EstateModule::estates_in_bounding_box(METAVERSE_ID, (-10, -10), (10, 10)),
+			Ok(vec![0, 1, 2])
+		);
+
+		// Dissolved estates are removed from the spatial index
+		assert_ok!(EstateModule::dissolve_estate(RuntimeOrigin::signed(BENEFICIARY_ID), 1));
+		assert_eq!(EstateModule::estate_chunks((METAVERSE_ID, (-1, 1), 1)), None);
+		assert_eq!(EstateModule::estate_neighbours(0), Vec::<EstateId>::new());
+	});
+}
+
+#[test]
+fn land_zoning_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1]
+		));
+		let land_unit = LandAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1);
+
+		assert_noop!(
+			EstateModule::define_zone(RuntimeOrigin::signed(BOB), METAVERSE_ID, 1, b"shop".to_vec()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::define_zone(
+				RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+				METAVERSE_ID,
+				1,
+				b"residential".to_vec()
+			),
+			Error::<Runtime>::ZoneNameTooLong
+		);
+		assert_ok!(EstateModule::define_zone(
+			RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+			METAVERSE_ID,
+			1,
+			b"shop".to_vec()
+		));
+		assert_eq!(
+			EstateModule::zones(METAVERSE_ID, 1),
+			Some(b"shop".to_vec().try_into().unwrap())
+		);
+
+		assert_noop!(
+			EstateModule::set_land_zone(RuntimeOrigin::signed(BOB), land_unit, Some(1)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::set_land_zone(RuntimeOrigin::signed(METAVERSE_GOVERNANCE), land_unit, Some(2)),
+			Error::<Runtime>::ZoneDoesNotExist
+		);
+		assert_noop!(
+			EstateModule::set_land_zone(
+				RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+				LandAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_2),
+				Some(1)
+			),
+			Error::<Runtime>::LandAssetDoesNotExist
+		);
+
+		assert_ok!(EstateModule::set_land_zone(
+			RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+			land_unit,
+			Some(1)
+		));
+		assert_eq!(
+			EstateModule::land_metadata(land_unit),
+			Some(LandMetadata {
+				zone: Some(1),
+				scene_hash: None
+			})
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::LandZoneUpdated(land_unit, Some(1)))
+		);
+
+		assert_ok!(EstateModule::set_land_zone(
+			RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+			land_unit,
+			None
+		));
+		assert_eq!(EstateModule::land_metadata(land_unit), None);
+
+		assert_ok!(EstateModule::remove_zone(
+			RuntimeOrigin::signed(METAVERSE_GOVERNANCE),
+			METAVERSE_ID,
+			1
+		));
+		assert_eq!(EstateModule::zones(METAVERSE_ID, 1), None);
+	});
+}
+
+#[test]
+fn builder_roles_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1]
+		));
+		let land_unit = LandAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1);
+		let scene_hash = H256::repeat_byte(1);
+
+		assert_noop!(
+			EstateModule::set_land_scene(RuntimeOrigin::signed(ALICE), land_unit, Some(scene_hash)),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			ALICE,
+			BuilderRole::Moderator
+		));
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(ALICE),
+			land_unit,
+			BOB,
+			BuilderRole::Builder
+		));
+		assert_eq!(
+			EstateModule::builders(land_unit, BOB),
+			Some(BuildPermission {
+				role: BuilderRole::Builder,
+				granted_by: BENEFICIARY_ID
+			})
+		);
+		assert_noop!(
+			EstateModule::grant_builder_role(RuntimeOrigin::signed(ALICE), land_unit, CHARLIE, BuilderRole::Moderator),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::grant_builder_role(RuntimeOrigin::signed(BOB), land_unit, CHARLIE, BuilderRole::Builder),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::set_land_scene(
+			RuntimeOrigin::signed(BOB),
+			land_unit,
+			Some(scene_hash)
+		));
+		assert_eq!(
+			EstateModule::land_metadata(land_unit),
+			Some(LandMetadata {
+				zone: None,
+				scene_hash: Some(scene_hash)
+			})
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::LandSceneUpdated(land_unit, BOB, Some(scene_hash)))
+		);
+		assert!(EstateModule::can_build(&BOB, &land_unit));
+		assert!(!EstateModule::can_build(&CHARLIE, &land_unit));
+
+		assert_noop!(
+			EstateModule::revoke_builder_role(RuntimeOrigin::signed(BOB), land_unit, ALICE),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::revoke_builder_role(
+			RuntimeOrigin::signed(ALICE),
+			land_unit,
+			BOB
+		));
+		assert!(!EstateModule::can_build(&BOB, &land_unit));
+		assert_noop!(
+			EstateModule::revoke_builder_role(RuntimeOrigin::signed(ALICE), land_unit, BOB),
+			Error::<Runtime>::BuilderRoleDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn moderator_should_not_manage_other_moderators() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1]
+		));
+		let land_unit = LandAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1);
+
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			ALICE,
+			BuilderRole::Moderator
+		));
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			BOB,
+			BuilderRole::Moderator
+		));
+
+		assert_noop!(
+			EstateModule::grant_builder_role(RuntimeOrigin::signed(ALICE), land_unit, BOB, BuilderRole::Builder),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::revoke_builder_role(RuntimeOrigin::signed(ALICE), land_unit, BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_eq!(
+			EstateModule::builders(land_unit, BOB),
+			Some(BuildPermission {
+				role: BuilderRole::Moderator,
+				granted_by: BENEFICIARY_ID
+			})
+		);
+
+		// The land owner can still downgrade a moderator
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			BOB,
+			BuilderRole::Builder
+		));
+		assert_eq!(
+			EstateModule::builders(land_unit, BOB).map(|permission| permission.role),
+			Some(BuilderRole::Builder)
+		);
+	});
+}
+
+#[test]
+fn grant_builder_role_should_fail_when_too_many_builders() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_lands(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1]
+		));
+		let land_unit = LandAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1);
+
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			ALICE,
+			BuilderRole::Builder
+		));
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			BOB,
+			BuilderRole::Builder
+		));
+		// Updating the role of an existing builder does not count as a new builder
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			BOB,
+			BuilderRole::Moderator
+		));
+		assert_eq!(EstateModule::land_builder_count(land_unit), 2);
+		assert_noop!(
+			EstateModule::grant_builder_role(
+				RuntimeOrigin::signed(BENEFICIARY_ID),
+				land_unit,
+				CHARLIE,
+				BuilderRole::Builder
+			),
+			Error::<Runtime>::TooManyBuilders
+		);
+
+		assert_ok!(EstateModule::revoke_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			ALICE
+		));
+		assert_eq!(EstateModule::land_builder_count(land_unit), 1);
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			land_unit,
+			CHARLIE,
+			BuilderRole::Builder
+		));
+		assert_eq!(EstateModule::land_builder_count(land_unit), 2);
+	});
+}
+
+#[test]
+fn estate_lease_tenant_builder_roles_should_expire_with_lease() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_estate(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		let estate = LandAsset::Estate(0);
+
+		assert_ok!(EstateModule::create_lease_offer(
+			RuntimeOrigin::signed(ALICE),
+			0u64,
+			10u128,
+			8u32
+		));
+		assert_ok!(EstateModule::accept_lease_offer(
+			RuntimeOrigin::signed(BENEFICIARY_ID),
+			0u64,
+			ALICE
+		));
+
+		assert_ok!(EstateModule::grant_builder_role(
+			RuntimeOrigin::signed(ALICE),
+			estate,
+			BOB,
+			BuilderRole::Builder
+		));
+		assert!(EstateModule::can_build(&ALICE, &estate));
+		assert!(EstateModule::can_build(&BOB, &estate));
+
+		run_to_block(10);
+		assert!(!EstateModule::can_build(&ALICE, &estate));
+		assert!(!EstateModule::can_build(&BOB, &estate));
+		assert!(EstateModule::can_build(&BENEFICIARY_ID, &estate));
+	});
+}
+
+fn setup_round_land_issuance() {
+	// Round issuance of 76 land blocks with an annual cap of 1000 land units once the estates are
+	// minted
+	TotalUndeployedLandUnit::<Runtime>::put(100_000_000);
+	MintingRateConfig::<Runtime>::put(MintingRateInfo {
+		expect: Default::default(),
+		annual: 100,
+		max: 100_001_003,
+	});
+
+	assert_ok!(EstateModule::mint_estate(
+		RuntimeOrigin::root(),
+		BENEFICIARY_ID,
+		ALICE_METAVERSE_ID,
+		vec![COORDINATE_IN_1]
+	));
+	assert_ok!(EstateModule::mint_estate(
+		RuntimeOrigin::root(),
+		BENEFICIARY_ID,
+		BOB_METAVERSE_ID,
+		vec![COORDINATE_IN_2]
+	));
+	assert_ok!(EstateModule::mint_estate(
+		RuntimeOrigin::root(),
+		BENEFICIARY_ID,
+		METAVERSE_ID,
+		vec![COORDINATE_IN_3]
+	));
+	EstateStakes::set(vec![(0, 200), (1, 100), (2, 50)]);
+}
+
+#[test]
+fn allocate_land_blocks_should_share_land_blocks_across_pages() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut issuance = RoundLandIssuance {
+			round: 2,
+			land_blocks: 76,
+			stakes_read: true,
+			total_stake: 350,
+			..Default::default()
+		};
+
+		assert_eq!(
+			EstateModule::allocate_land_blocks(&mut issuance, vec![(ALICE_METAVERSE_ID, 200), (BOB_METAVERSE_ID, 100)]),
+			vec![(ALICE_METAVERSE_ID, 43), (BOB_METAVERSE_ID, 22)]
+		);
+		assert_eq!(
+			EstateModule::allocate_land_blocks(&mut issuance, vec![(METAVERSE_ID, 50)]),
+			vec![(METAVERSE_ID, 11)]
+		);
+		assert_eq!(issuance.allocated_stake, 350);
+	});
+}
+
+#[test]
+fn round_land_issuance_should_be_shared_by_estate_stake() {
+	ExtBuilder::default().build().execute_with(|| {
+		// More estate stakes than `MaxIssuanceRecipients` are read over several pages
+		setup_round_land_issuance();
+
+		run_to_block(21);
+		assert_eq!(EstateModule::round().current, 2);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == RuntimeEvent::Estate(crate::Event::NewRound(21, 2, 760))));
+		assert_eq!(EstateModule::land_issuance_stake_cursor(), Some(1));
+		assert_eq!(EstateModule::all_undeployed_land_unit(), 100_000_000);
+
+		run_to_block(22);
+		assert_eq!(EstateModule::land_issuance_stake_cursor(), None);
+		assert_eq!(
+			EstateModule::pending_land_issuance(),
+			Some(RoundLandIssuance {
+				round: 2,
+				land_blocks: 76,
+				stakes_read: true,
+				total_stake: 350,
+				allocated_stake: 0,
+				issued_land_units: 0,
+			})
+		);
+		assert_eq!(EstateModule::land_issuance_stake_snapshot(METAVERSE_ID), 50);
+
+		run_to_block(24);
+		assert_eq!(EstateModule::pending_land_issuance(), None);
+		assert_eq!(
+			EstateModule::annual_land_issuance(),
+			AnnualIssuance {
+				year: 0,
+				cap: 1000,
+				issued: 760,
+			}
+		);
+		assert_eq!(EstateModule::all_undeployed_land_unit(), 100_000_760);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::RoundLandIssuanceCompleted(2, 760))
+		);
+
+		// Every metaverse receives its share, rounded up or down, of the 76 land blocks
+		let issued_land_blocks = |metaverse_id: MetaverseId| {
+			System::events()
+				.iter()
+				.find_map(|record| match record.event {
+					RuntimeEvent::Estate(crate::Event::RoundLandBlocksIssued(2, id, _, number_of_land_blocks))
+						if id == metaverse_id =>
+					{
+						Some(number_of_land_blocks)
+					}
+					_ => None,
+				})
+				.unwrap_or_default()
+		};
+		assert!((43..=44).contains(&issued_land_blocks(ALICE_METAVERSE_ID)));
+		assert!((21..=22).contains(&issued_land_blocks(BOB_METAVERSE_ID)));
+		assert!((10..=11).contains(&issued_land_blocks(METAVERSE_ID)));
+		assert_eq!(
+			issued_land_blocks(ALICE_METAVERSE_ID)
+				+ issued_land_blocks(BOB_METAVERSE_ID)
+				+ issued_land_blocks(METAVERSE_ID),
+			76
+		);
+	});
+}
+
+#[test]
+fn round_land_issuance_should_not_exceed_annual_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_round_land_issuance();
+
+		run_to_block(24);
+		assert_eq!(EstateModule::annual_land_issuance().issued, 760);
+
+		run_to_block(41);
+		assert_eq!(last_event(), RuntimeEvent::Estate(crate::Event::NewRound(41, 3, 240)));
+		run_to_block(44);
+		assert_eq!(EstateModule::annual_land_issuance().issued, 1000);
+
+		run_to_block(61);
+		assert_eq!(EstateModule::annual_land_issuance().issued, 1000);
+		assert_eq!(last_event(), RuntimeEvent::Estate(crate::Event::NewRound(61, 4, 0)));
+		assert_eq!(EstateModule::pending_land_issuance(), None);
+
+		// A new year resets the annual cap
+		AnnualLandIssuance::<Runtime>::put(AnnualIssuance {
+			year: 1,
+			cap: 1000,
+			issued: 1000,
+		});
+		run_to_block(84);
+		assert_eq!(EstateModule::annual_land_issuance().year, 0);
+		assert!(EstateModule::annual_land_issuance().issued > 0);
+	});
+}
+
+#[test]
+fn round_land_issuance_should_keep_minimum_without_circulating_supply() {
+	ExtBuilder::default().build().execute_with(|| {
+		AllLandUnitsCount::<Runtime>::put(0);
+		TotalUndeployedLandUnit::<Runtime>::put(0);
+
+		assert_eq!(crate::rate::get_annual_max_issuance::<Runtime>(1_000_000, 10), 500);
+		assert!(crate::rate::round_issuance_range::<Runtime>(EstateModule::minting_rate_config()).ideal > 0);
+	});
+}
+
+#[test]
+fn set_land_issuance_paused_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_round_land_issuance();
+
+		assert_noop!(
+			EstateModule::set_land_issuance_paused(RuntimeOrigin::signed(BOB), true),
+			BadOrigin
+		);
+		assert_ok!(EstateModule::set_land_issuance_paused(
+			RuntimeOrigin::signed(ALICE),
+			true
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::LandIssuancePauseUpdated(true))
+		);
+
+		run_to_block(21);
+		assert_eq!(EstateModule::round().current, 2);
+		assert_eq!(EstateModule::all_undeployed_land_unit(), 100_000_000);
+		assert_eq!(last_event(), RuntimeEvent::Estate(crate::Event::NewRound(21, 2, 0)));
+
+		assert_ok!(EstateModule::set_land_issuance_paused(
+			RuntimeOrigin::signed(ALICE),
+			false
+		));
+		run_to_block(44);
+		assert_eq!(EstateModule::all_undeployed_land_unit(), 100_000_760);
+	});
+}
+
+#[test]
+fn split_and_merge_undeployed_land_blocks_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			BOB,
+			1,
+			20,
+			UndeployedLandBlockType::Transferable,
+		));
+
+		assert_noop!(
+			EstateModule::split_undeployed_land_block(RuntimeOrigin::signed(ALICE), 0, vec![5]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::split_undeployed_land_block(RuntimeOrigin::signed(BOB), 0, vec![]),
+			Error::<Runtime>::InvalidUndeployedLandBlockSplit
+		);
+		assert_noop!(
+			EstateModule::split_undeployed_land_block(RuntimeOrigin::signed(BOB), 0, vec![5, 0]),
+			Error::<Runtime>::InvalidUndeployedLandBlockSplit
+		);
+		assert_noop!(
+			EstateModule::split_undeployed_land_block(RuntimeOrigin::signed(BOB), 0, vec![10, 10]),
+			Error::<Runtime>::UndeployedLandBlockDoesNotHaveEnoughLandUnits
+		);
+
+		assert_ok!(EstateModule::split_undeployed_land_block(
+			RuntimeOrigin::signed(BOB),
+			0,
+			vec![5, 5]
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::UndeployedLandBlockSplit(BOB, 0, vec![1, 2]))
+		);
+		assert_eq!(
+			EstateModule::get_undeployed_land_block(0).unwrap().number_land_units,
+			10
+		);
+		assert_eq!(EstateModule::get_undeployed_land_block(2).unwrap().number_land_units, 5);
+		assert_eq!(EstateModule::get_undeployed_land_block_owner(BOB, 2), Some(()));
+		assert_eq!(EstateModule::all_undeployed_land_unit(), 20);
+		// Split land blocks can be listed in auctions
+		assert_eq!(
+			<EstateModule as UndeployedLandBlocksTrait<AccountId>>::check_undeployed_land_block(&BOB, 2),
+			Ok(true)
+		);
+
+		assert_noop!(
+			EstateModule::merge_undeployed_land_blocks(RuntimeOrigin::signed(BOB), 0, vec![0]),
+			Error::<Runtime>::InvalidUndeployedLandBlockMerge
+		);
+		assert_noop!(
+			EstateModule::merge_undeployed_land_blocks(
+				RuntimeOrigin::signed(BOB),
+				0,
+				vec![UNDEPLOYED_LAND_BLOCK_IN_AUCTION]
+			),
+			Error::<Runtime>::UndeployedLandBlockAlreadyInAuction
+		);
+
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			BOB,
+			1,
+			20,
+			UndeployedLandBlockType::BoundToAddress,
+		));
+		assert_noop!(
+			EstateModule::merge_undeployed_land_blocks(RuntimeOrigin::signed(BOB), 0, vec![3]),
+			Error::<Runtime>::UndeployedLandBlockTypeDoesNotMatch
+		);
+
+		assert_ok!(EstateModule::merge_undeployed_land_blocks(
+			RuntimeOrigin::signed(BOB),
+			0,
+			vec![2]
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::UndeployedLandBlocksMerged(BOB, 0, vec![2]))
+		);
+		assert_eq!(
+			EstateModule::get_undeployed_land_block(0).unwrap().number_land_units,
+			15
+		);
+		assert_eq!(EstateModule::get_undeployed_land_block(2), None);
+		assert_eq!(EstateModule::get_undeployed_land_block_owner(BOB, 2), None);
+		assert_eq!(EstateModule::all_undeployed_land_unit(), 40);
+	});
+}
+
+#[test]
+fn partial_deploy_land_block_should_keep_remaining_land_units() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			BOB,
+			1,
+			3,
+			UndeployedLandBlockType::Transferable,
+		));
+
+		assert_ok!(EstateModule::deploy_land_block(
+			RuntimeOrigin::signed(BOB),
+			0,
+			BOB_METAVERSE_ID,
+			LANDBLOCK_COORDINATE,
+			vec![COORDINATE_IN_1]
+		));
+		assert_eq!(EstateModule::get_undeployed_land_block(0).unwrap().number_land_units, 2);
+		assert_eq!(EstateModule::all_undeployed_land_unit(), 2);
+		assert_eq!(EstateModule::all_land_units_count(), 1);
+
+		assert_noop!(
+			EstateModule::deploy_land_block(
+				RuntimeOrigin::signed(BOB),
+				0,
+				BOB_METAVERSE_ID,
+				LANDBLOCK_COORDINATE,
+				vec![COORDINATE_IN_2, COORDINATE_IN_3, COORDINATE_IN_4]
+			),
+			Error::<Runtime>::UndeployedLandBlockDoesNotHaveEnoughLandUnits
+		);
+
+		assert_ok!(EstateModule::deploy_land_block(
+			RuntimeOrigin::signed(BOB),
+			0,
+			BOB_METAVERSE_ID,
+			LANDBLOCK_COORDINATE,
+			vec![COORDINATE_IN_2, COORDINATE_IN_3]
+		));
+		assert_eq!(EstateModule::get_undeployed_land_block(0), None);
+		assert_eq!(EstateModule::all_undeployed_land_unit(), 0);
+		assert_eq!(EstateModule::all_land_units_count(), 3);
+	});
+}
+
+#[test]
+fn unlock_undeployed_land_block_should_work_after_vesting() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			BOB,
+			1,
+			5,
+			UndeployedLandBlockType::BoundToAddress,
+		));
+		assert_eq!(EstateModule::undeployed_land_block_vesting_end(0), Some(11));
+
+		assert_noop!(
+			EstateModule::transfer_undeployed_land_blocks(RuntimeOrigin::signed(BOB), ALICE, 0),
+			Error::<Runtime>::UndeployedLandBlockIsNotTransferable
+		);
+		assert_noop!(
+			EstateModule::unlock_undeployed_land_block(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::UndeployedLandBlockStillVesting
+		);
+
+		run_to_block(11);
+
+		assert_noop!(
+			EstateModule::unlock_undeployed_land_block(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::unlock_undeployed_land_block(
+			RuntimeOrigin::signed(BOB),
+			0
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Estate(crate::Event::UndeployedLandBlockUnlocked(0))
+		);
+		assert_eq!(
+			EstateModule::get_undeployed_land_block(0)
+				.unwrap()
+				.undeployed_land_block_type,
+			UndeployedLandBlockType::Transferable
+		);
+		assert_noop!(
+			EstateModule::unlock_undeployed_land_block(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::UndeployedLandBlockNotVesting
+		);
+
+		assert_ok!(EstateModule::transfer_undeployed_land_blocks(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			0
+		));
+	});
+}
+
+#[test]
+fn unlock_undeployed_land_block_should_vest_legacy_land_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			RuntimeOrigin::root(),
+			BOB,
+			1,
+			6,
+			UndeployedLandBlockType::BoundToAddress,
+		));
+		// Land block bound before vesting periods existed, and a land block split from it
+		UndeployedLandBlockVestingEnd::<Runtime>::remove(0);
+		assert_ok!(EstateModule::split_undeployed_land_block(
+			RuntimeOrigin::signed(BOB),
+			0,
+			vec![3]
+		));
+		assert_eq!(EstateModule::undeployed_land_block_vesting_end(1), None);
+
+		assert_noop!(
+			EstateModule::unlock_undeployed_land_block(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::UndeployedLandBlockNotVesting
+		);
+		assert_noop!(
+			EstateModule::unlock_undeployed_land_block(RuntimeOrigin::signed(BOB), 1),
+			Error::<Runtime>::UndeployedLandBlockNotVesting
+		);
+
+		// The migration starts the vesting period of legacy land blocks
+		run_to_block(5);
+		StorageVersion::new(1).put::<EstateModule>();
+		EstateModule::on_runtime_upgrade();
+		assert_eq!(EstateModule::on_chain_storage_version(), 2);
+		assert_eq!(EstateModule::undeployed_land_block_vesting_end(0), Some(15));
+		assert_eq!(EstateModule::undeployed_land_block_vesting_end(1), Some(15));
+
+		assert_noop!(
+			EstateModule::unlock_undeployed_land_block(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::UndeployedLandBlockStillVesting
+		);
+
+		run_to_block(15);
+		assert_ok!(EstateModule::unlock_undeployed_land_block(
+			RuntimeOrigin::signed(BOB),
+			0
+		));
+		assert_eq!(
+			EstateModule::get_undeployed_land_block(0)
+				.unwrap()
+				.undeployed_land_block_type,
+			UndeployedLandBlockType::Transferable
+		);
+		assert_ok!(EstateModule::unlock_undeployed_land_block(
+			RuntimeOrigin::signed(BOB),
+			1
+		));
+	});
+}
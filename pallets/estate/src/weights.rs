@@ -47,7 +47,7 @@ use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions needed for estate.
-pub trait WeightInfo {	fn mint_land() -> Weight;	fn mint_lands() -> Weight;	fn transfer_land() -> Weight;	fn mint_estate() -> Weight;	fn dissolve_estate() -> Weight;	fn add_land_unit_to_estate() -> Weight;	fn remove_land_unit_from_estate() -> Weight;	fn create_estate() -> Weight;	fn transfer_estate() -> Weight;	fn issue_undeployed_land_blocks() -> Weight;	fn freeze_undeployed_land_blocks() -> Weight;	fn unfreeze_undeployed_land_blocks() -> Weight;	fn approve_undeployed_land_blocks() -> Weight;	fn unapprove_undeployed_land_blocks() -> Weight;	fn transfer_undeployed_land_blocks() -> Weight;	fn deploy_land_block() -> Weight;	fn burn_undeployed_land_blocks() -> Weight;	fn create_lease_offer() -> Weight;	fn accept_lease_offer() -> Weight;	fn cancel_lease() -> Weight;	fn remove_expired_lease() -> Weight;	fn remove_lease_offer() -> Weight;	fn collect_rent() -> Weight;	fn on_initialize() -> Weight;}
+pub trait WeightInfo {	fn mint_land() -> Weight;	fn mint_lands() -> Weight;	fn transfer_land() -> Weight;	fn mint_estate() -> Weight;	fn dissolve_estate() -> Weight;	fn add_land_unit_to_estate() -> Weight;	fn remove_land_unit_from_estate() -> Weight;	fn create_estate() -> Weight;	fn transfer_estate() -> Weight;	fn issue_undeployed_land_blocks() -> Weight;	fn freeze_undeployed_land_blocks() -> Weight;	fn unfreeze_undeployed_land_blocks() -> Weight;	fn approve_undeployed_land_blocks() -> Weight;	fn unapprove_undeployed_land_blocks() -> Weight;	fn transfer_undeployed_land_blocks() -> Weight;	fn deploy_land_block() -> Weight;	fn burn_undeployed_land_blocks() -> Weight;	fn create_lease_offer() -> Weight;	fn accept_lease_offer() -> Weight;	fn cancel_lease() -> Weight;	fn remove_expired_lease() -> Weight;	fn remove_lease_offer() -> Weight;	fn collect_rent() -> Weight;	fn on_initialize() -> Weight;	fn distribute_undeployed_land_blocks() -> Weight;	fn merge_estates(u: u32) -> Weight;	fn split_estate(u: u32) -> Weight;	fn define_zone() -> Weight;	fn remove_zone() -> Weight;	fn set_land_zone() -> Weight;	fn set_land_scene() -> Weight;	fn grant_builder_role() -> Weight;	fn revoke_builder_role() -> Weight;}
 
 /// Weights for estate using the for collator node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -575,6 +575,157 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	// Storage: Es
 		// Minimum execution time: 176 nanoseconds.
 		Weight::from_parts(191_000, 0)
 	}
+	// Storage: Metaverse MetaverseOwner (r:1 w:0)
+	// Proof Skipped: Metaverse MetaverseOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction ItemsInAuction (r:1 w:0)
+	// Proof Skipped: Auction ItemsInAuction (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Estate UndeployedLandBlocks (r:1 w:1)
+	// Proof Skipped: Estate UndeployedLandBlocks (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate UndeployedLandBlocksOwner (r:0 w:2)
+	// Proof Skipped: Estate UndeployedLandBlocksOwner (max_values: None, max_size: None, mode: Measured)
+	fn distribute_undeployed_land_blocks() -> Weight {
+		Weight::from_parts(44_512_000, 17210)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Estate EstateOwner (r:2 w:1)
+	// Proof Skipped: Estate EstateOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate Estates (r:2 w:2)
+	// Proof Skipped: Estate Estates (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateLeases (r:2 w:0)
+	// Proof Skipped: Estate EstateLeases (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction ItemsInAuction (r:2 w:0)
+	// Proof Skipped: Auction ItemsInAuction (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate ReshapingConnectivity (r:1 w:0)
+	// Proof Skipped: Estate ReshapingConnectivity (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate AllEstatesCount (r:1 w:1)
+	// Proof Skipped: Estate AllEstatesCount (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Estate LandUnits (r:0 w:1)
+	// Proof Skipped: Estate LandUnits (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateChunks (r:0 w:1)
+	// Proof Skipped: Estate EstateChunks (max_values: None, max_size: None, mode: Measured)
+	// The range of component `u` is `[4, 1000]`.
+	fn merge_estates(u: u32) -> Weight {
+		Weight::from_parts(112_410_000, 70412)
+			.saturating_add(Weight::from_parts(4_120_000, 2520).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(12))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
+	}
+	// Storage: Estate EstateOwner (r:1 w:1)
+	// Proof Skipped: Estate EstateOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate Estates (r:1 w:2)
+	// Proof Skipped: Estate Estates (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateLeases (r:1 w:0)
+	// Proof Skipped: Estate EstateLeases (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction ItemsInAuction (r:1 w:0)
+	// Proof Skipped: Auction ItemsInAuction (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate ReshapingConnectivity (r:1 w:0)
+	// Proof Skipped: Estate ReshapingConnectivity (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate NextEstateId (r:1 w:1)
+	// Proof Skipped: Estate NextEstateId (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:0 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate LandUnits (r:0 w:1)
+	// Proof Skipped: Estate LandUnits (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateChunks (r:0 w:1)
+	// Proof Skipped: Estate EstateChunks (max_values: None, max_size: None, mode: Measured)
+	// The range of component `u` is `[4, 1000]`.
+	fn split_estate(u: u32) -> Weight {
+		Weight::from_parts(128_530_000, 66058)
+			.saturating_add(Weight::from_parts(3_870_000, 2520).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
+	}
+	// Storage: Metaverse MetaverseOwner (r:1 w:0)
+	// Proof Skipped: Metaverse MetaverseOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate MetaverseZones (r:0 w:1)
+	// Proof Skipped: Estate MetaverseZones (max_values: None, max_size: None, mode: Measured)
+	fn define_zone() -> Weight {
+		Weight::from_parts(18_240_000, 3618)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Metaverse MetaverseOwner (r:1 w:0)
+	// Proof Skipped: Metaverse MetaverseOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate MetaverseZones (r:1 w:1)
+	// Proof Skipped: Estate MetaverseZones (max_values: None, max_size: None, mode: Measured)
+	fn remove_zone() -> Weight {
+		Weight::from_parts(20_110_000, 7236)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Estate Estates (r:1 w:0)
+	// Proof Skipped: Estate Estates (max_values: None, max_size: None, mode: Measured)
+	// Storage: Metaverse MetaverseOwner (r:1 w:0)
+	// Proof Skipped: Metaverse MetaverseOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate MetaverseZones (r:1 w:0)
+	// Proof Skipped: Estate MetaverseZones (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate LandAssetMetadata (r:1 w:1)
+	// Proof Skipped: Estate LandAssetMetadata (max_values: None, max_size: None, mode: Measured)
+	fn set_land_zone() -> Weight {
+		Weight::from_parts(27_630_000, 14472)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Estate EstateLeasors (r:1 w:0)
+	// Proof Skipped: Estate EstateLeasors (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateLeases (r:1 w:0)
+	// Proof Skipped: Estate EstateLeases (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateOwner (r:1 w:0)
+	// Proof Skipped: Estate EstateOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate LandBuilders (r:1 w:0)
+	// Proof Skipped: Estate LandBuilders (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate LandAssetMetadata (r:1 w:1)
+	// Proof Skipped: Estate LandAssetMetadata (max_values: None, max_size: None, mode: Measured)
+	fn set_land_scene() -> Weight {
+		Weight::from_parts(34_870_000, 21708)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Estate EstateLeasors (r:2 w:0)
+	// Proof Skipped: Estate EstateLeasors (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateLeases (r:1 w:0)
+	// Proof Skipped: Estate EstateLeases (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateOwner (r:1 w:0)
+	// Proof Skipped: Estate EstateOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate LandBuilders (r:2 w:1)
+	// Proof Skipped: Estate LandBuilders (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate LandBuilderCount (r:1 w:1)
+	// Proof Skipped: Estate LandBuilderCount (max_values: None, max_size: None, mode: Measured)
+	fn grant_builder_role() -> Weight {
+		Weight::from_parts(41_520_000, 28944)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Estate EstateLeasors (r:2 w:0)
+	// Proof Skipped: Estate EstateLeasors (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateLeases (r:1 w:0)
+	// Proof Skipped: Estate EstateLeases (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate EstateOwner (r:1 w:0)
+	// Proof Skipped: Estate EstateOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate LandBuilders (r:2 w:1)
+	// Proof Skipped: Estate LandBuilders (max_values: None, max_size: None, mode: Measured)
+	// Storage: Estate LandBuilderCount (r:1 w:1)
+	// Proof Skipped: Estate LandBuilderCount (max_values: None, max_size: None, mode: Measured)
+	fn revoke_builder_role() -> Weight {
+		Weight::from_parts(38_960_000, 25326)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
 
 // For backwards compatibility and tests
@@ -696,4 +847,55 @@ impl WeightInfo for () {	fn mint_land() -> Weight {
 	fn on_initialize() -> Weight {
 		Weight::from_parts(191_000, 0)
 	}
+	fn distribute_undeployed_land_blocks() -> Weight {
+		Weight::from_parts(44_512_000, 17210)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn merge_estates(u: u32) -> Weight {
+		Weight::from_parts(112_410_000, 70412)
+			.saturating_add(Weight::from_parts(4_120_000, 2520).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(14))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(RocksDbWeight::get().writes(12))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(u.into())))
+	}
+	fn split_estate(u: u32) -> Weight {
+		Weight::from_parts(128_530_000, 66058)
+			.saturating_add(Weight::from_parts(3_870_000, 2520).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(RocksDbWeight::get().writes(11))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(u.into())))
+	}
+	fn define_zone() -> Weight {
+		Weight::from_parts(18_240_000, 3618)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn remove_zone() -> Weight {
+		Weight::from_parts(20_110_000, 7236)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn set_land_zone() -> Weight {
+		Weight::from_parts(27_630_000, 14472)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn set_land_scene() -> Weight {
+		Weight::from_parts(34_870_000, 21708)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn grant_builder_role() -> Weight {
+		Weight::from_parts(41_520_000, 28944)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn revoke_builder_role() -> Weight {
+		Weight::from_parts(38_960_000, 25326)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
 }
@@ -28,6 +28,7 @@
 #![allow(clippy::unused_unit)]
 
 use codec::Encode;
+use core_primitives::AccountAssetsMigration;
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
@@ -377,6 +378,28 @@ fn account_to_default_evm_address(account_id: &impl Encode) -> EvmAddress {
 
 pub struct EvmAddressMapping<T>(sp_std::marker::PhantomData<T>);
 
+impl<T: Config> AccountAssetsMigration<T::AccountId> for Pallet<T> {
+	/// Moves the claimed EVM address of `from` to `to`, which must not have claimed one
+	fn migrate_account_assets(from: &T::AccountId, to: &T::AccountId, limit: u32) -> Result<u32, DispatchError> {
+		let evm_address = match EvmAddresses::<T>::get(from) {
+			Some(evm_address) if limit > 0 => evm_address,
+			_ => return Ok(0),
+		};
+		ensure!(!EvmAddresses::<T>::contains_key(to), Error::<T>::AccountIdHasMapped);
+
+		EvmAddresses::<T>::remove(from);
+		EvmAddresses::<T>::insert(to, evm_address);
+		Accounts::<T>::insert(evm_address, to);
+
+		Self::deposit_event(Event::ClaimAccount {
+			account_id: to.clone(),
+			evm_address,
+		});
+
+		Ok(1)
+	}
+}
+
 impl<T: Config> AddressMapping<T::AccountId> for EvmAddressMapping<T>
 where
 	T::AccountId: IsType<AccountId32>,
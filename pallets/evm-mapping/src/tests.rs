@@ -19,9 +19,11 @@
 
 #![cfg(test)]
 
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 
-use mock::{alice, secp_utils::*, EVMMapping, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System, ALICE};
+use mock::{
+	alice, bob, secp_utils::*, EVMMapping, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System, ALICE, BOB,
+};
 
 use super::*;
 
@@ -40,3 +42,34 @@ fn claim_account_work() {
 		assert!(Accounts::<Runtime>::contains_key(eth(&alice())) && EvmAddresses::<Runtime>::contains_key(ALICE));
 	});
 }
+
+#[test]
+fn migrate_account_assets_should_move_claimed_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Nothing to move without a claimed address
+		assert_eq!(EVMMapping::migrate_account_assets(&ALICE, &BOB, 1), Ok(0));
+
+		assert_ok!(EVMMapping::claim_eth_account(
+			RuntimeOrigin::signed(ALICE),
+			eth(&alice()),
+			sig::<Runtime>(&alice(), &eth(&alice()).encode(), &[][..])
+		));
+		// Nothing is moved without room left in the page
+		assert_eq!(EVMMapping::migrate_account_assets(&ALICE, &BOB, 0), Ok(0));
+		assert_eq!(EVMMapping::migrate_account_assets(&ALICE, &BOB, 1), Ok(1));
+		assert_eq!(EVMMapping::accounts(eth(&alice())), Some(BOB));
+		assert_eq!(EVMMapping::evm_addresses(BOB), Some(eth(&alice())));
+		assert_eq!(EVMMapping::evm_addresses(ALICE), None);
+
+		// The new account must not have claimed an address
+		assert_ok!(EVMMapping::claim_eth_account(
+			RuntimeOrigin::signed(ALICE),
+			eth(&bob()),
+			sig::<Runtime>(&bob(), &eth(&bob()).encode(), &[][..])
+		));
+		assert_noop!(
+			EVMMapping::migrate_account_assets(&ALICE, &BOB, 1),
+			Error::<Runtime>::AccountIdHasMapped
+		);
+	});
+}
@@ -28,18 +28,19 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		schedule::{DispatchTime, Named as ScheduleNamed},
-		Currency, ExistenceRequirement, Get, InstanceFilter, LockIdentifier, LockableCurrency, OnUnbalanced,
-		ReservableCurrency, WithdrawReasons,
+		BalanceStatus, Currency, ExistenceRequirement, Get, InstanceFilter, LockIdentifier, LockableCurrency,
+		OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
 };
-use sp_runtime::traits::{Dispatchable, Hash, Saturating, Zero};
-use sp_runtime::{Perbill, SaturatedConversion};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use sp_runtime::traits::{Dispatchable, Hash, One, Saturating, Zero};
+use sp_runtime::{ArithmeticError, Perbill, SaturatedConversion};
 use sp_std::prelude::*;
 
 use frame_system::pallet_prelude::BlockNumberFor;
-use metaverse_primitive::MetaverseTrait;
+use metaverse_primitive::{CredentialTrait, MetaverseTrait};
 pub use pallet::*;
-use primitives::{MetaverseId, ProposalId, ReferendumId};
+use primitives::{ClassId, FungibleTokenId, MetaverseId, ProposalId, ReferendumId, VestingSchedule};
 pub use types::*;
 
 mod types;
@@ -51,6 +52,9 @@ mod tests;
 
 const GOVERNANCE_ID: LockIdentifier = *b"bcgovern";
 
+/// The current storage version
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{dispatch::DispatchResultWithPostInfo, traits::EnsureOrigin, Parameter};
@@ -108,6 +112,13 @@ pub mod pallet {
 		type Currency: ReservableCurrency<Self::AccountId>
 			+ LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
 
+		/// Multi currencies type that handles metaverse fund spends
+		type FungibleTokenCurrency: MultiReservableCurrency<
+			Self::AccountId,
+			CurrencyId = FungibleTokenId,
+			Balance = BalanceOf<Self>,
+		>;
+
 		/// Slashing handler
 		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
@@ -118,7 +129,9 @@ pub mod pallet {
 		type MetaverseLandInfo: MetaverseLandTrait<Self::AccountId>;
 
 		/// Overarching type of all pallets origins.
-		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>
+			+ From<pallet_metaverse::MetaverseOrigin>
+			+ Into<<Self as frame_system::Config>::RuntimeOrigin>;
 
 		/// The proposal.
 		type Proposal: Parameter + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin> + From<Call<Self>>;
@@ -140,9 +153,21 @@ pub mod pallet {
 		/// Network treasury account
 		#[pallet::constant]
 		type NetworkTreasury: Get<Self::AccountId>;
+
+		/// Number of blocks a noted preimage is kept before it is reaped if no proposal uses it
+		#[pallet::constant]
+		type PreimageExpiryPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of referendum finalisations and proposal launches processed in a block
+		#[pallet::constant]
+		type MaxLifecycleOperationsPerBlock: Get<u32>;
+
+		/// Metaverse credentials used to gate voting on local referendums
+		type Credentials: CredentialTrait<Self::AccountId>;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -217,6 +242,82 @@ pub mod pallet {
 	pub type VotingOf<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, VotingRecord<BalanceOf<T>, BlockNumberFor<T>>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn ongoing_referendum)]
+	/// Track the ongoing referendum of each metaverse.
+	pub type OngoingReferendums<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, ReferendumId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn referendum_endings)]
+	/// Index ongoing referendums by the block their voting period ends at.
+	pub type ReferendumEndings<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, MetaverseId, ReferendumId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_launches)]
+	/// Index metaverses which queued proposals can be launched by block number.
+	pub type ScheduledLaunches<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, MetaverseId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn voting_credential)]
+	/// Credential class required to vote on the local referendums of a metaverse.
+	pub type VotingCredentials<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, ClassId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn lifecycle_cursor)]
+	/// Track the earliest block which referendum endings and proposal launches are not processed
+	/// yet.
+	pub type LifecycleCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn preimage_expiries)]
+	/// Index noted preimages by their expiry block.
+	pub type PreimageExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, (MetaverseId, T::Hash), (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn preimage_reap_cursor)]
+	/// Track the earliest block which expired preimages are not reaped yet.
+	pub type PreimageReapCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn referendum_lifecycle_migration_cursor)]
+	/// Track the raw key of the last referendum indexed by the referendum lifecycle migration,
+	/// empty until the first one is indexed and removed once all of them are.
+	pub type ReferendumLifecycleMigrationCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn metaverse_spend_cap)]
+	/// Store the maximum amount a single local referendum can spend from each metaverse fund
+	pub type MetaverseSpendCap<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn spend_proposals)]
+	/// Index metaverse fund spends waiting for a local referendum by metaverse ID and proposal ID.
+	pub type SpendProposals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MetaverseId,
+		Twox64Concat,
+		ProposalId,
+		MetaverseSpend<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn vested_spends)]
+	/// Index enacted metaverse fund spends that are still vesting by metaverse ID and proposal ID.
+	pub type VestedSpends<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MetaverseId,
+		Twox64Concat,
+		ProposalId,
+		VestedSpend<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -258,6 +359,22 @@ pub mod pallet {
 		Seconded(T::AccountId, ProposalId),
 		/// Local governance proposal is added to the proposal queue
 		Tabled(ProposalId, BalanceOf<T>, Vec<T::AccountId>),
+		/// Metaverse fund spend cap per referendum is updated
+		MetaverseSpendCapUpdated(MetaverseId, BalanceOf<T>),
+		/// Metaverse fund spend proposal is submitted
+		SpendProposed(MetaverseId, ProposalId, T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Metaverse fund spend proposal is enacted
+		SpendEnacted(MetaverseId, ProposalId, DispatchResult),
+		/// Metaverse fund paid a beneficiary
+		MetaverseFundSpent(MetaverseId, ProposalId, T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Metaverse fund spend is reserved for vesting
+		MetaverseFundSpendVesting(MetaverseId, ProposalId, T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Vested metaverse fund spend is claimed
+		VestedSpendClaimed(MetaverseId, ProposalId, T::AccountId, BalanceOf<T>),
+		/// Expired local governance preimage is removed and its deposit is refunded
+		PreimageReaped(MetaverseId, T::Hash, T::AccountId, BalanceOf<T>),
+		/// Credential required to vote on local referendums is updated
+		VotingCredentialUpdated(MetaverseId, Option<ClassId>),
 	}
 
 	#[pallet::error]
@@ -320,6 +437,22 @@ pub mod pallet {
 		WrongUpperBound,
 		/// No proposals are waiting to launch as referendums
 		NoneWaiting,
+		/// Metaverse owner has not set a spend cap
+		SpendCapNotSet,
+		/// Spend amount exceeds the metaverse spend cap
+		SpendExceedsCap,
+		/// Spend amount is zero
+		SpendAmountIsZero,
+		/// Vesting schedule does not match the spend
+		InvalidVestingSchedule,
+		/// Vested spend does not exist
+		VestedSpendDoesNotExist,
+		/// No vested funds to claim
+		NothingToClaim,
+		/// Credential class is not issued by the metaverse
+		InvalidVotingCredential,
+		/// Account does not hold a valid voting credential of the metaverse
+		MissingVotingCredential,
 	}
 
 	#[pallet::call]
@@ -429,62 +562,7 @@ pub mod pallet {
 						Self::deposit_event(Event::<T>::ProposalRefused(metaverse_id, preimage_hash));
 						Err(Error::<T>::PreimageInvalid.into())
 					} else {
-						let launch_block = Self::get_proposal_launch_block(metaverse_id)?;
-						let proposal_info = ProposalInfo {
-							proposed_by: from.clone(),
-							hash: preimage_hash,
-							title: proposal_description.clone(),
-							referendum_launch_block: launch_block,
-						};
-
-						let proposal_id = Self::get_next_proposal_id()?;
-
-						// 2 storage inserts
-						let storage_fee: BalanceOf<T> =
-							Perbill::from_percent(2u32.saturating_mul(100)) * T::StorageDepositFee::get();
-
-						T::Currency::transfer(
-							&from,
-							&T::NetworkTreasury::get(),
-							storage_fee.saturated_into(),
-							ExistenceRequirement::KeepAlive,
-						)?;
-						<Proposals<T>>::insert(metaverse_id, proposal_id, proposal_info);
-
-						Self::update_proposals_per_metaverse_number(metaverse_id, true);
-						T::Currency::reserve(&from, balance);
-						<DepositOf<T>>::insert(proposal_id, (&[&from][..], balance));
-
-						Self::deposit_event(Event::ProposalSubmitted(from, metaverse_id, proposal_id));
-
-						let mut metaverse_has_referendum_running: bool = false;
-						for (_, referendum_info) in ReferendumInfoOf::<T>::iter_prefix(metaverse_id) {
-							match referendum_info {
-								ReferendumInfo::Ongoing(_status) => {
-									metaverse_has_referendum_running = true;
-									break;
-								}
-								_ => (),
-							}
-						}
-						if !metaverse_has_referendum_running {
-							if let Some((depositors, deposit)) = <DepositOf<T>>::take(proposal_id) {
-								<Proposals<T>>::remove(metaverse_id, proposal_id);
-								Self::update_proposals_per_metaverse_number(metaverse_id, false);
-								// refund depositors
-								for d in &depositors {
-									T::Currency::unreserve(d, deposit);
-								}
-								Self::deposit_event(Event::Tabled(proposal_id, deposit, depositors));
-								Self::start_referendum(
-									metaverse_id,
-									proposal_id,
-									preimage_hash,
-									proposal_description,
-									launch_block,
-								);
-							}
-						}
+						Self::do_submit_proposal(from, metaverse_id, balance, preimage_hash, proposal_description)?;
 
 						Ok(().into())
 					}
@@ -518,6 +596,7 @@ pub mod pallet {
 			let _proposal_info = Self::proposals(metaverse_id, proposal).ok_or(Error::<T>::ProposalDoesNotExist)?;
 			if let Some((depositors, deposit)) = <DepositOf<T>>::take(proposal) {
 				<Proposals<T>>::remove(metaverse_id, proposal);
+				<SpendProposals<T>>::remove(metaverse_id, proposal);
 				Self::update_proposals_per_metaverse_number(metaverse_id, false); // slash depositors
 				for d in &depositors {
 					T::Slash::on_unbalanced(T::Currency::slash_reserved(d, deposit).0);
@@ -557,6 +636,7 @@ pub mod pallet {
 				ExistenceRequirement::KeepAlive,
 			)?;
 
+			<ScheduledLaunches<T>>::insert(proposal_info.referendum_launch_block, metaverse_id, ());
 			<Proposals<T>>::remove(metaverse_id, proposal);
 			<Proposals<T>>::insert(metaverse_id, proposal, proposal_info);
 			Self::deposit_event(Event::ProposalFastTracked(metaverse_id, proposal));
@@ -609,6 +689,12 @@ pub mod pallet {
 				T::MetaverseLandInfo::is_user_own_metaverse_land(&from, &status.metaverse),
 				Error::<T>::AccountIsNotMetaverseMember
 			);
+			if let Some(class_id) = Self::voting_credential(status.metaverse) {
+				ensure!(
+					T::Credentials::has_valid_credential(&from, class_id),
+					Error::<T>::MissingVotingCredential
+				);
+			}
 			ensure!(
 				vote.balance <= T::Currency::free_balance(&from),
 				Error::<T>::InsufficientBalance
@@ -719,8 +805,14 @@ pub mod pallet {
 			match referendum_info {
 				ReferendumInfo::Ongoing(referendum_status) => {
 					<ReferendumInfoOf<T>>::remove(metaverse, referendum);
+					<OngoingReferendums<T>>::remove(metaverse);
+					<ReferendumEndings<T>>::remove(referendum_status.end, metaverse);
+					// Give queued proposals a chance to launch
+					let next_block = <frame_system::Pallet<T>>::block_number() + T::OneBlock::get();
+					<ScheduledLaunches<T>>::insert(next_block, metaverse, ());
 					Self::update_proposals_per_metaverse_number(referendum_status.metaverse, false);
 					<DepositOf<T>>::remove(referendum_status.proposal);
+					<SpendProposals<T>>::remove(metaverse, referendum_status.proposal);
 					Self::deposit_event(Event::ReferendumCancelled(referendum));
 				}
 				_ => (),
@@ -763,23 +855,186 @@ pub mod pallet {
 
 			Ok(().into())
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		/// Hooks that call every new block finalized.
-		fn on_finalize(now: BlockNumberFor<T>) {
-			for (metaverse_id, referendum_id, referendum_info) in <ReferendumInfoOf<T>>::iter() {
-				match referendum_info {
-					ReferendumInfo::Ongoing(status) => {
-						if status.end == now {
-							Self::finalize_vote(metaverse_id, referendum_id, status);
-							Self::launch_public(now, metaverse_id);
-						}
+		/// Metaverse owner can update the maximum amount a single local referendum can spend
+		/// from the metaverse fund
+		///
+		/// The dispatch origin for this call must be _Signed_. Only owner of metaverse can make
+		/// this call
+		/// - `metaverse_id`: the metaverse ID which will have its spend cap updated
+		/// - `spend_cap`: the maximum amount per referendum
+		///
+		/// Emits `MetaverseSpendCapUpdated` if successful.
+		#[pallet::weight(Weight::from_parts(10000, 0) + T::DbWeight::get().writes(1))]
+		pub fn update_metaverse_spend_cap(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			spend_cap: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfo::check_ownership(&from, &metaverse_id),
+				Error::<T>::AccountIsNotMetaverseOwner
+			);
+			T::Currency::transfer(
+				&from,
+				&T::NetworkTreasury::get(),
+				T::StorageDepositFee::get(),
+				ExistenceRequirement::KeepAlive,
+			)?;
+			<MetaverseSpendCap<T>>::insert(metaverse_id, spend_cap);
+			Self::deposit_event(Event::MetaverseSpendCapUpdated(metaverse_id, spend_cap));
+			Ok(().into())
+		}
+
+		/// Create new metaverse-level referendum proposal that pays a beneficiary from the
+		/// metaverse fund once the referendum passes.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only metaverse members who own piece of land has the ability to create local metaverse
+		/// proposal
+		/// - `metaverse_id`: the metaverse ID which fund will be spent
+		/// - `balance`: deposit for the proposal
+		/// - `spend`: the beneficiary, amount, currency and optional vesting schedule of the spend
+		/// - `proposal_description`: description of the proposal encoded as vector of numbers
+		///
+		/// Emits `SpendProposed` if successful.
+		#[pallet::weight(Weight::from_parts(10000, 0) + T::DbWeight::get().writes(2))]
+		pub fn propose_spend(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			balance: BalanceOf<T>,
+			spend: MetaverseSpend<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+			proposal_description: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseLandInfo::is_user_own_metaverse_land(&from, &metaverse_id),
+				Error::<T>::AccountIsNotMetaverseMember
+			);
+			ensure!(balance >= T::MinimumProposalDeposit::get(), Error::<T>::DepositTooLow);
+			ensure!(
+				T::Currency::free_balance(&from) >= balance,
+				Error::<T>::InsufficientBalance
+			);
+			Self::ensure_valid_spend(metaverse_id, &spend)?;
+
+			let spend_hash = T::Hashing::hash_of(&spend);
+			let proposal_id = Self::do_submit_proposal(from, metaverse_id, balance, spend_hash, proposal_description)?;
+			<SpendProposals<T>>::insert(metaverse_id, proposal_id, spend.clone());
+
+			Self::deposit_event(Event::SpendProposed(
+				metaverse_id,
+				proposal_id,
+				spend.beneficiary,
+				spend.currency_id,
+				spend.amount,
+			));
+			Ok(().into())
+		}
+
+		/// Release the vested part of an enacted metaverse fund spend to its beneficiary.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `metaverse_id`: the metaverse ID which fund is spent
+		/// - `proposal_id`: the ID of the enacted spend proposal
+		///
+		/// Emits `VestedSpendClaimed` if successful.
+		#[pallet::weight(Weight::from_parts(10000, 0) + T::DbWeight::get().writes(2))]
+		pub fn claim_vested_spend(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			proposal_id: ProposalId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			<VestedSpends<T>>::try_mutate_exists(
+				metaverse_id,
+				proposal_id,
+				|maybe_vested_spend| -> DispatchResultWithPostInfo {
+					let vested_spend = maybe_vested_spend.as_mut().ok_or(Error::<T>::VestedSpendDoesNotExist)?;
+
+					let now = <frame_system::Pallet<T>>::block_number();
+					let total = vested_spend.schedule.total_amount().ok_or(ArithmeticError::Overflow)?;
+					let released = total.saturating_sub(vested_spend.schedule.locked_amount(now));
+					let claimable = released.saturating_sub(vested_spend.claimed);
+					ensure!(!claimable.is_zero(), Error::<T>::NothingToClaim);
+
+					T::FungibleTokenCurrency::repatriate_reserved(
+						vested_spend.schedule.token,
+						&T::MetaverseInfo::get_metaverse_treasury(metaverse_id),
+						&vested_spend.beneficiary,
+						claimable,
+						BalanceStatus::Free,
+					)?;
+					vested_spend.claimed = vested_spend.claimed.saturating_add(claimable);
+
+					Self::deposit_event(Event::VestedSpendClaimed(
+						metaverse_id,
+						proposal_id,
+						vested_spend.beneficiary.clone(),
+						claimable,
+					));
+
+					if vested_spend.claimed >= total {
+						*maybe_vested_spend = None;
 					}
-					_ => (),
+					Ok(().into())
+				},
+			)
+		}
+
+		/// Metaverse owner can require a credential issued by the metaverse to vote on its local
+		/// referendums
+		///
+		/// The dispatch origin for this call must be _Signed_. Only owner of metaverse can make
+		/// this call
+		/// - `metaverse_id`: the metaverse ID which voting credential will be updated
+		/// - `class_id`: the credential class required to vote, `None` lets every member vote
+		///
+		/// Emits `VotingCredentialUpdated` if successful.
+		#[pallet::weight(Weight::from_parts(18_000_000, 3_500) + T::DbWeight::get().reads_writes(2, 1))]
+		pub fn update_voting_credential(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			class_id: Option<ClassId>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfo::check_ownership(&from, &metaverse_id),
+				Error::<T>::AccountIsNotMetaverseOwner
+			);
+			match class_id {
+				Some(class_id) => {
+					ensure!(
+						T::Credentials::get_credential_issuer(class_id) == Some(metaverse_id),
+						Error::<T>::InvalidVotingCredential
+					);
+					<VotingCredentials<T>>::insert(metaverse_id, class_id);
 				}
+				None => <VotingCredentials<T>>::remove(metaverse_id),
 			}
+			Self::deposit_event(Event::VotingCredentialUpdated(metaverse_id, class_id));
+			Ok(().into())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Hook that indexes existing referendums during the lifecycle migration, finalises ending
+		/// referendums and launches queued proposals.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::migrate_referendum_lifecycle_v2().saturating_add(Self::process_referendum_lifecycle(now))
+		}
+
+		/// Hook that reaps expired preimages with the remaining block weight.
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::reap_expired_preimages(now, remaining_weight)
+		}
+
+		/// Hook that is called every time the runtime is upgraded.
+		fn on_runtime_upgrade() -> Weight {
+			Self::upgrade_referendum_lifecycle_v2()
 		}
 	}
 }
@@ -821,14 +1076,16 @@ impl<T: Config> Pallet<T> {
 				T::Currency::reserve(&who, deposit)?;
 
 				let now = <frame_system::Pallet<T>>::block_number();
+				let expiry = now + T::PreimageExpiryPeriod::get();
 				let a = PreimageStatus::Available {
 					data: encoded_proposal,
 					provider: who.clone(),
 					deposit,
 					since: now,
-					expiry: None,
+					expiry: Some(expiry),
 				};
 				<Preimages<T>>::insert(metaverse_id, preimage_hash, a);
+				<PreimageExpiries<T>>::insert(expiry, (metaverse_id, preimage_hash), ());
 
 				Self::deposit_event(Event::<T>::PreimageNoted(metaverse_id, preimage_hash, who, deposit));
 
@@ -851,7 +1108,7 @@ impl<T: Config> Pallet<T> {
 	) -> Result<u64, DispatchError> {
 		let referendum_id = Self::get_next_referendum_id()?;
 
-		let referendum_end;
+		let mut referendum_end;
 		let mut referendum_threshold = ReferendumParameters::<BlockNumberFor<T>>::default()
 			.voting_threshold
 			.ok_or("Invalid Default Referendum Threshold")?;
@@ -865,6 +1122,11 @@ impl<T: Config> Pallet<T> {
 			}
 			None => referendum_end = current_block + ReferendumParameters::default().voting_period,
 		}
+		// Referendums are finalised from the next block onwards
+		let earliest_end = <frame_system::Pallet<T>>::block_number() + T::OneBlock::get();
+		if referendum_end < earliest_end {
+			referendum_end = earliest_end;
+		}
 
 		let initial_tally = Tally {
 			ayes: Zero::zero(),
@@ -883,6 +1145,8 @@ impl<T: Config> Pallet<T> {
 		};
 		let referendum_info = ReferendumInfo::Ongoing(referendum_status);
 		<ReferendumInfoOf<T>>::insert(metaverse_id, referendum_id, referendum_info);
+		<OngoingReferendums<T>>::insert(metaverse_id, referendum_id);
+		<ReferendumEndings<T>>::insert(referendum_end, metaverse_id, referendum_id);
 
 		Self::deposit_event(Event::ReferendumStarted(
 			metaverse_id,
@@ -893,37 +1157,178 @@ impl<T: Config> Pallet<T> {
 		Ok(referendum_id)
 	}
 
-	/// Table the waiting public proposal with the highest backing for a vote.
-	fn launch_public(_now: BlockNumberFor<T>, metaverse_id: MetaverseId) -> DispatchResult {
-		let launch_block = Self::get_proposal_launch_block(metaverse_id)?;
-		if let Some((_, proposal)) = Proposals::<T>::iter_prefix(metaverse_id).enumerate().max_by_key(
+	/// Table the waiting public proposal with the highest backing for a vote. Proposals are only
+	/// eligible once their launch block is reached, otherwise a launch is scheduled for the
+	/// earliest one.
+	fn launch_public(now: BlockNumberFor<T>, metaverse_id: MetaverseId) -> DispatchResult {
+		let mut next_launch_block: Option<BlockNumberFor<T>> = None;
+		let mut winner: Option<(ProposalId, T::Hash, Vec<u8>, BalanceOf<T>)> = None;
+		for (proposal_id, proposal_info) in Proposals::<T>::iter_prefix(metaverse_id) {
+			if proposal_info.referendum_launch_block > now {
+				next_launch_block = Some(match next_launch_block {
+					Some(block) => block.min(proposal_info.referendum_launch_block),
+					None => proposal_info.referendum_launch_block,
+				});
+				continue;
+			}
 			// defensive only: All current public proposals have an amount locked
-			|x| Self::backing_for((x.1).0).unwrap_or_else(Zero::zero),
-		) {
-			let winner_proposal_id: u64;
-			winner_proposal_id = proposal.0;
-			let proposal_hash: T::Hash;
-			proposal_hash = proposal.1.hash;
-			if let Some((depositors, deposit)) = <DepositOf<T>>::take(winner_proposal_id) {
-				<Proposals<T>>::remove(metaverse_id, winner_proposal_id);
+			let backing = Self::backing_for(proposal_id).unwrap_or_else(Zero::zero);
+			if winner.as_ref().map_or(true, |(_, _, _, best)| backing > *best) {
+				winner = Some((proposal_id, proposal_info.hash, proposal_info.title, backing));
+			}
+		}
+
+		let (winner_proposal_id, proposal_hash, title, _) = match winner {
+			Some(winner) => winner,
+			None => {
+				if let Some(launch_block) = next_launch_block {
+					<ScheduledLaunches<T>>::insert(launch_block, metaverse_id, ());
+				}
+				return Err(Error::<T>::NoneWaiting.into());
+			}
+		};
+
+		if let Some((depositors, deposit)) = <DepositOf<T>>::take(winner_proposal_id) {
+			<Proposals<T>>::remove(metaverse_id, winner_proposal_id);
+			Self::update_proposals_per_metaverse_number(metaverse_id, false);
+			// refund depositors
+			for d in &depositors {
+				T::Currency::unreserve(d, deposit);
+			}
+			Self::deposit_event(Event::Tabled(winner_proposal_id, deposit, depositors));
+			Self::start_referendum(metaverse_id, winner_proposal_id, proposal_hash, title, now)?;
+		}
+		Ok(())
+	}
+
+	/// Internal submission of a local governance proposal. The referendum is launched straight away
+	/// if the metaverse has none running.
+	fn do_submit_proposal(
+		from: T::AccountId,
+		metaverse_id: MetaverseId,
+		balance: BalanceOf<T>,
+		proposal_hash: T::Hash,
+		proposal_description: Vec<u8>,
+	) -> Result<ProposalId, DispatchError> {
+		let launch_block = Self::get_proposal_launch_block(metaverse_id)?;
+		let proposal_info = ProposalInfo {
+			proposed_by: from.clone(),
+			hash: proposal_hash,
+			title: proposal_description.clone(),
+			referendum_launch_block: launch_block,
+		};
+
+		let proposal_id = Self::get_next_proposal_id()?;
+
+		// 2 storage inserts
+		let storage_fee: BalanceOf<T> = Perbill::from_percent(2u32.saturating_mul(100)) * T::StorageDepositFee::get();
+
+		T::Currency::transfer(
+			&from,
+			&T::NetworkTreasury::get(),
+			storage_fee.saturated_into(),
+			ExistenceRequirement::KeepAlive,
+		)?;
+		<Proposals<T>>::insert(metaverse_id, proposal_id, proposal_info);
+
+		Self::update_proposals_per_metaverse_number(metaverse_id, true);
+		T::Currency::reserve(&from, balance);
+		<DepositOf<T>>::insert(proposal_id, (&[&from][..], balance));
+
+		Self::deposit_event(Event::ProposalSubmitted(from, metaverse_id, proposal_id));
+
+		if !Self::has_ongoing_referendum(metaverse_id) {
+			if let Some((depositors, deposit)) = <DepositOf<T>>::take(proposal_id) {
+				<Proposals<T>>::remove(metaverse_id, proposal_id);
 				Self::update_proposals_per_metaverse_number(metaverse_id, false);
 				// refund depositors
 				for d in &depositors {
 					T::Currency::unreserve(d, deposit);
 				}
-				Self::deposit_event(Event::Tabled(winner_proposal_id, deposit, depositors));
+				Self::deposit_event(Event::Tabled(proposal_id, deposit, depositors));
 				Self::start_referendum(
 					metaverse_id,
-					winner_proposal_id,
+					proposal_id,
 					proposal_hash,
-					proposal.1.title,
+					proposal_description,
 					launch_block,
 				);
 			}
-			Ok(())
-		} else {
-			Err(Error::<T>::NoneWaiting)?
 		}
+
+		Ok(proposal_id)
+	}
+
+	/// Internal check that a metaverse fund spend is within the spend cap and has a consistent
+	/// vesting schedule
+	fn ensure_valid_spend(
+		metaverse_id: MetaverseId,
+		spend: &MetaverseSpend<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		ensure!(!spend.amount.is_zero(), Error::<T>::SpendAmountIsZero);
+		let spend_cap = Self::metaverse_spend_cap(metaverse_id).ok_or(Error::<T>::SpendCapNotSet)?;
+		ensure!(spend.amount <= spend_cap, Error::<T>::SpendExceedsCap);
+
+		if let Some(schedule) = &spend.vesting {
+			ensure!(
+				schedule.token == spend.currency_id
+					&& !schedule.period.is_zero()
+					&& schedule.period_count > 0
+					&& schedule.end().is_some()
+					&& schedule.total_amount() == Some(spend.amount),
+				Error::<T>::InvalidVestingSchedule
+			);
+		}
+		Ok(())
+	}
+
+	/// Internal payment of an approved spend from the metaverse fund
+	fn do_metaverse_spend(
+		metaverse_id: MetaverseId,
+		proposal_id: ProposalId,
+		spend: MetaverseSpend<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		// The spend cap might have been lowered while the referendum was running
+		Self::ensure_valid_spend(metaverse_id, &spend)?;
+
+		let metaverse_fund = T::MetaverseInfo::get_metaverse_treasury(metaverse_id);
+		match spend.vesting {
+			Some(schedule) => {
+				T::FungibleTokenCurrency::reserve(spend.currency_id, &metaverse_fund, spend.amount)?;
+				<VestedSpends<T>>::insert(
+					metaverse_id,
+					proposal_id,
+					VestedSpend {
+						beneficiary: spend.beneficiary.clone(),
+						schedule,
+						claimed: Zero::zero(),
+					},
+				);
+				Self::deposit_event(Event::MetaverseFundSpendVesting(
+					metaverse_id,
+					proposal_id,
+					spend.beneficiary,
+					spend.currency_id,
+					spend.amount,
+				));
+			}
+			None => {
+				T::FungibleTokenCurrency::transfer(
+					spend.currency_id,
+					&metaverse_fund,
+					&spend.beneficiary,
+					spend.amount,
+				)?;
+				Self::deposit_event(Event::MetaverseFundSpent(
+					metaverse_id,
+					proposal_id,
+					spend.beneficiary,
+					spend.currency_id,
+					spend.amount,
+				));
+			}
+		}
+		Ok(())
 	}
 
 	/// Internal getter of next proposall ID
@@ -1018,6 +1423,8 @@ impl<T: Config> Pallet<T> {
 			.threshold
 			.is_referendum_approved(referendum_status.tally.clone(), total_issuance);
 
+		<OngoingReferendums<T>>::remove(metaverse_id);
+
 		// Update referendum info
 		<ReferendumInfoOf<T>>::try_mutate(metaverse_id, referendum_id, |referendum_info| -> DispatchResult {
 			*referendum_info = Some(ReferendumInfo::Finished {
@@ -1036,6 +1443,17 @@ impl<T: Config> Pallet<T> {
 				Some(current_params) => when += current_params.enactment_period,
 				None => when += ReferendumParameters::default().enactment_period,
 			}
+			// Referendums finalised late still have to be enacted in the future
+			let earliest_enactment = <frame_system::Pallet<T>>::block_number() + T::OneBlock::get();
+			if when < earliest_enactment {
+				when = earliest_enactment;
+			}
+			// Keep the preimage until the proposal is enacted
+			<Preimages<T>>::mutate(metaverse_id, referendum_status.proposal_hash, |maybe_preimage| {
+				if let Some(PreimageStatus::Available { expiry, .. }) = maybe_preimage {
+					*expiry = None;
+				}
+			});
 
 			if T::Scheduler::schedule_named(
 				(GOVERNANCE_ID, referendum_id).encode(),
@@ -1068,6 +1486,7 @@ impl<T: Config> Pallet<T> {
 			{
 				T::Currency::unreserve(&provider, deposit);
 			}
+			<SpendProposals<T>>::remove(&metaverse_id, referendum_status.proposal);
 			Self::deposit_event(Event::ReferendumNotPassed(referendum_id));
 		}
 
@@ -1076,11 +1495,21 @@ impl<T: Config> Pallet<T> {
 
 	/// Internal enacting of successfully passed proposal
 	fn do_enact_proposal(
-		_proposal_id: ProposalId,
+		proposal_id: ProposalId,
 		metaverse_id: MetaverseId,
 		referendum_id: ReferendumId,
 		proposal_hash: T::Hash,
 	) -> DispatchResult {
+		if let Some(spend) = <SpendProposals<T>>::get(metaverse_id, proposal_id) {
+			if T::Hashing::hash_of(&spend) == proposal_hash {
+				<SpendProposals<T>>::remove(metaverse_id, proposal_id);
+				let result = Self::do_metaverse_spend(metaverse_id, proposal_id, spend);
+				Self::deposit_event(Event::<T>::SpendEnacted(metaverse_id, proposal_id, result));
+				Self::deposit_event(Event::ProposalEnacted(metaverse_id, referendum_id));
+				return result;
+			}
+		}
+
 		let preimage = <Preimages<T>>::take(&metaverse_id, &proposal_hash);
 		if let Some(PreimageStatus::Available {
 			data,
@@ -1098,10 +1527,9 @@ impl<T: Config> Pallet<T> {
 				} else {
 					T::Currency::unreserve(&provider, deposit);
 					Self::deposit_event(Event::<T>::PreimageUsed(metaverse_id, proposal_hash, provider, deposit));
-					let result = proposal
-						.dispatch(frame_system::RawOrigin::Root.into())
-						.map(|_| ())
-						.map_err(|e| e.error);
+					// Local proposals can only act on behalf of their own metaverse
+					let origin: T::PalletsOrigin = pallet_metaverse::MetaverseOrigin(metaverse_id).into();
+					let result = proposal.dispatch(origin.into()).map(|_| ()).map_err(|e| e.error);
 
 					Self::deposit_event(Event::<T>::PreimageEnacted(metaverse_id, proposal_hash, result));
 					Self::deposit_event(Event::ProposalEnacted(metaverse_id, referendum_id));
@@ -1118,6 +1546,202 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Check if a metaverse has a referendum running
+	fn has_ongoing_referendum(metaverse_id: MetaverseId) -> bool {
+		<OngoingReferendums<T>>::contains_key(metaverse_id)
+	}
+
+	/// Weight of finalising a referendum or running a scheduled launch, both of which can launch
+	/// the next queued proposal
+	fn lifecycle_operation_weight() -> Weight {
+		Weight::from_parts(10000, 0) + T::DbWeight::get().reads_writes(u8::MAX as u64 + 8, 10)
+	}
+
+	/// Weight of reaping a single expired preimage
+	fn preimage_reap_weight() -> Weight {
+		Weight::from_parts(10000, 0) + T::DbWeight::get().reads_writes(u8::MAX as u64 + 4, 4)
+	}
+
+	/// Internal processing of referendum endings and scheduled proposal launches up to the current
+	/// block. At most `MaxLifecycleOperationsPerBlock` operations are executed, any remaining ones
+	/// are carried over to the next block.
+	fn process_referendum_lifecycle(now: BlockNumberFor<T>) -> Weight {
+		let max_operations = T::MaxLifecycleOperationsPerBlock::get();
+		let mut operations: u32 = 0;
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		let mut block = Self::lifecycle_cursor();
+
+		while block <= now && operations < max_operations {
+			let ending_referendums: Vec<(MetaverseId, ReferendumId)> = <ReferendumEndings<T>>::iter_prefix(block)
+				.take(max_operations.saturating_sub(operations) as usize)
+				.collect();
+			for (metaverse_id, referendum_id) in ending_referendums {
+				<ReferendumEndings<T>>::remove(block, metaverse_id);
+				if let Some(ReferendumInfo::Ongoing(status)) = Self::referendum_info(metaverse_id, referendum_id) {
+					Self::finalize_vote(metaverse_id, referendum_id, status);
+					Self::launch_public(now, metaverse_id);
+				}
+				operations = operations.saturating_add(1);
+				weight = weight.saturating_add(Self::lifecycle_operation_weight());
+			}
+
+			let scheduled_launches: Vec<MetaverseId> = <ScheduledLaunches<T>>::iter_key_prefix(block)
+				.take(max_operations.saturating_sub(operations) as usize)
+				.collect();
+			for metaverse_id in scheduled_launches {
+				<ScheduledLaunches<T>>::remove(block, metaverse_id);
+				if !Self::has_ongoing_referendum(metaverse_id) {
+					Self::launch_public(now, metaverse_id);
+				}
+				operations = operations.saturating_add(1);
+				weight = weight.saturating_add(Self::lifecycle_operation_weight());
+			}
+
+			if operations >= max_operations {
+				break;
+			}
+
+			// All operations of this block are processed
+			block += One::one();
+			operations = operations.saturating_add(1);
+			weight = weight.saturating_add(T::DbWeight::get().reads(2));
+		}
+
+		<LifecycleCursor<T>>::put(block);
+		weight
+	}
+
+	/// Internal reaping of expired preimages, bounded by the remaining block weight
+	fn reap_expired_preimages(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let reap_weight = Self::preimage_reap_weight();
+		let mut consumed_weight = T::DbWeight::get().reads_writes(1, 1);
+		if remaining_weight.any_lt(consumed_weight.saturating_add(reap_weight)) {
+			return Weight::zero();
+		}
+
+		let mut block = Self::preimage_reap_cursor();
+		while block < now {
+			if remaining_weight.any_lt(consumed_weight.saturating_add(reap_weight)) {
+				break;
+			}
+			match <PreimageExpiries<T>>::iter_key_prefix(block).next() {
+				Some((metaverse_id, preimage_hash)) => {
+					<PreimageExpiries<T>>::remove(block, (metaverse_id, preimage_hash));
+					Self::reap_preimage(now, metaverse_id, preimage_hash);
+					consumed_weight = consumed_weight.saturating_add(reap_weight);
+				}
+				None => {
+					block += One::one();
+					consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+				}
+			}
+		}
+
+		<PreimageReapCursor<T>>::put(block);
+		consumed_weight
+	}
+
+	/// Internal removal of an expired preimage. Preimages still used by a proposal or an ongoing
+	/// referendum get their expiry extended instead.
+	fn reap_preimage(now: BlockNumberFor<T>, metaverse_id: MetaverseId, preimage_hash: T::Hash) {
+		let (provider, deposit) = match Self::preimages(metaverse_id, preimage_hash) {
+			Some(PreimageStatus::Available {
+				provider,
+				deposit,
+				expiry: Some(expiry),
+				..
+			}) if expiry < now => (provider, deposit),
+			// Preimage is removed, kept for enactment or its expiry was extended
+			_ => return,
+		};
+
+		if Self::is_preimage_in_use(metaverse_id, preimage_hash) {
+			let new_expiry = now + T::PreimageExpiryPeriod::get();
+			<Preimages<T>>::mutate(metaverse_id, preimage_hash, |maybe_preimage| {
+				if let Some(PreimageStatus::Available { expiry, .. }) = maybe_preimage {
+					*expiry = Some(new_expiry);
+				}
+			});
+			<PreimageExpiries<T>>::insert(new_expiry, (metaverse_id, preimage_hash), ());
+			return;
+		}
+
+		<Preimages<T>>::remove(metaverse_id, preimage_hash);
+		T::Currency::unreserve(&provider, deposit);
+		Self::deposit_event(Event::<T>::PreimageReaped(
+			metaverse_id,
+			preimage_hash,
+			provider,
+			deposit,
+		));
+	}
+
+	/// Check if a preimage is used by a queued proposal or the ongoing referendum of a metaverse
+	fn is_preimage_in_use(metaverse_id: MetaverseId, preimage_hash: T::Hash) -> bool {
+		if Proposals::<T>::iter_prefix_values(metaverse_id).any(|proposal_info| proposal_info.hash == preimage_hash) {
+			return true;
+		}
+		match Self::ongoing_referendum(metaverse_id).and_then(|id| Self::referendum_info(metaverse_id, id)) {
+			Some(ReferendumInfo::Ongoing(status)) => status.proposal_hash == preimage_hash,
+			_ => false,
+		}
+	}
+
+	/// Start the indexing of referendums which were started before the lifecycle driver existed,
+	/// once per storage version
+	pub fn upgrade_referendum_lifecycle_v2() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		if Self::lifecycle_cursor().is_zero() {
+			<LifecycleCursor<T>>::put(now);
+			<PreimageReapCursor<T>>::put(now);
+		}
+
+		// Existing referendums are indexed over the next blocks
+		<ReferendumLifecycleMigrationCursor<T>>::put(Vec::<u8>::new());
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2, 4)
+	}
+
+	/// Index at most `MaxLifecycleOperationsPerBlock` existing referendums in the referendum
+	/// lifecycle, continuing from the referendum indexed last.
+	fn migrate_referendum_lifecycle_v2() -> Weight {
+		let cursor = match Self::referendum_lifecycle_migration_cursor() {
+			Some(cursor) => cursor,
+			None => return T::DbWeight::get().reads(1),
+		};
+
+		let max_referendums = T::MaxLifecycleOperationsPerBlock::get() as u64;
+		let lifecycle_cursor = Self::lifecycle_cursor();
+		let mut referendums = if cursor.is_empty() {
+			<ReferendumInfoOf<T>>::iter()
+		} else {
+			<ReferendumInfoOf<T>>::iter_from(cursor)
+		};
+		let mut num_referendums: u64 = 0;
+		let mut num_ongoing_referendums: u64 = 0;
+		for (metaverse_id, referendum_id, referendum_info) in referendums.by_ref().take(max_referendums as usize) {
+			num_referendums += 1;
+			if let ReferendumInfo::Ongoing(status) = referendum_info {
+				num_ongoing_referendums += 1;
+				<OngoingReferendums<T>>::insert(metaverse_id, referendum_id);
+				<ReferendumEndings<T>>::insert(status.end.max(lifecycle_cursor), metaverse_id, referendum_id);
+			}
+		}
+
+		if num_referendums < max_referendums {
+			<ReferendumLifecycleMigrationCursor<T>>::kill();
+		} else {
+			<ReferendumLifecycleMigrationCursor<T>>::put(referendums.last_raw_key().to_vec());
+		}
+
+		T::DbWeight::get().reads_writes(num_referendums + 2, num_ongoing_referendums * 2 + 1)
+	}
+
 	/// Internal update of locked funds for account
 	fn update_lock(who: &T::AccountId) {
 		let lock_needed = VotingOf::<T>::mutate(who, |voting| {
@@ -15,8 +15,8 @@ use sp_runtime::{
 use sp_std::collections::btree_map::BTreeMap;
 
 use metaverse_primitive::{
-	Attributes, CollectionType, MetaverseInfo as MetaversePrimitiveInfo, MetaverseLandTrait, MetaverseMetadata,
-	MetaverseTrait, NFTTrait, NftClassData, NftMetadata, TokenType,
+	Attributes, CollectionType, CredentialTrait, MetaverseInfo as MetaversePrimitiveInfo, MetaverseLandTrait,
+	MetaverseMetadata, MetaverseTrait, NFTTrait, NftClassData, NftMetadata, TokenType,
 };
 use primitives::{Amount, ClassId, FungibleTokenId, GroupCollectionId, TokenId};
 use sp_runtime::BuildStorage;
@@ -72,6 +72,7 @@ pub const ASSET_TOKEN_ID: TokenId = 6;
 pub const ASSET_COLLECTION_ID: GroupCollectionId = 7;
 
 pub const ALICE_METAVERSE_ID: MetaverseId = 1;
+pub const CREDENTIAL_CLASS_ID: ClassId = 10;
 pub const GENERAL_METAVERSE_FUND: AccountId = 102;
 
 impl frame_system::Config for Runtime {
@@ -231,6 +232,8 @@ parameter_types! {
 	pub const OneBlock: BlockNumber = 1;
 	pub const MinimumProposalDeposit: Balance = 50;
 	pub const DefaultPreimageByteDeposit: Balance = 1;
+	pub const PreimageExpiryPeriod: BlockNumber = 20;
+	pub const MaxLifecycleOperationsPerBlock: u32 = 10;
 }
 
 ord_parameter_types! {
@@ -244,6 +247,21 @@ fn test_attributes(x: u8) -> Attributes {
 	attr
 }
 
+pub struct MockCredentials;
+
+impl CredentialTrait<AccountId> for MockCredentials {
+	fn get_credential_issuer(class_id: ClassId) -> Option<MetaverseId> {
+		match class_id {
+			CREDENTIAL_CLASS_ID => Some(BOB_COUNTRY_ID),
+			_ => None,
+		}
+	}
+
+	fn has_valid_credential(who: &AccountId, class_id: ClassId) -> bool {
+		*who == ALICE && class_id == CREDENTIAL_CLASS_ID
+	}
+}
+
 pub struct MockNFTHandler;
 
 impl NFTTrait<AccountId, Balance> for MockNFTHandler {
@@ -451,6 +469,13 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 	) -> sp_runtime::DispatchResult {
 		Ok(())
 	}
+
+	fn get_royalty_payouts(
+		_class_id: &Self::ClassId,
+		_sale_price: Balance,
+	) -> Result<Vec<(AccountId, Balance)>, DispatchError> {
+		Ok(vec![])
+	}
 }
 
 parameter_types! {
@@ -470,6 +495,7 @@ impl pallet_metaverse::Config for Runtime {
 	type MaxMetaverseMetadata = MaxTokenMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureSignedBy<One, AccountId>;
+	type MetaverseGovernanceOrigin = pallet_metaverse::EnsureMetaverse;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
@@ -513,6 +539,7 @@ impl Config for Runtime {
 	type MinimumProposalDeposit = MinimumProposalDeposit;
 	type OneBlock = OneBlock;
 	type Currency = Balances;
+	type FungibleTokenCurrency = Currencies;
 	type Slash = ();
 	type MetaverseInfo = MetaverseInfo;
 	type PalletsOrigin = OriginCaller;
@@ -523,6 +550,9 @@ impl Config for Runtime {
 	type ProposalType = ProposalType;
 	type StorageDepositFee = StorageDepositFee;
 	type NetworkTreasury = TreasuryModuleAccount;
+	type PreimageExpiryPeriod = PreimageExpiryPeriod;
+	type MaxLifecycleOperationsPerBlock = MaxLifecycleOperationsPerBlock;
+	type Credentials = MockCredentials;
 }
 
 parameter_type_with_key! {
@@ -582,7 +612,7 @@ construct_runtime!(
 		Governance: governance::{Pallet, Call ,Storage, Event<T>},
 		Currencies: currencies::{ Pallet, Storage, Call, Event<T>},
 		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
-		Metaverse: pallet_metaverse::{Pallet, Call ,Storage, Event<T>}
+		Metaverse: pallet_metaverse::{Pallet, Call ,Storage, Event<T>, Origin}
 	}
 );
 
@@ -605,7 +635,7 @@ impl ExtBuilder {
 			.unwrap();
 
 		pallet_balances::GenesisConfig::<Runtime> {
-			balances: vec![(ALICE, 100000), (BOB, 50000)],
+			balances: vec![(ALICE, 100000), (BOB, 50000), (GENERAL_METAVERSE_FUND, 10000)],
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
@@ -624,6 +654,7 @@ pub fn last_event() -> RuntimeEvent {
 }
 
 fn next_block() {
+	GovernanceModule::on_idle(System::block_number(), Weight::MAX);
 	GovernanceModule::on_finalize(System::block_number());
 	System::set_block_number(System::block_number() + 1);
 	GovernanceModule::on_initialize(System::block_number());
@@ -835,3 +835,552 @@ fn get_next_proposal_work() {
 		assert_eq!(GovernanceModule::proposals(BOB_COUNTRY_ID, 2), None);
 	})
 }
+
+// Metaverse fund spend tests
+#[test]
+fn update_metaverse_spend_cap_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			GovernanceModule::update_metaverse_spend_cap(RuntimeOrigin::signed(ALICE), BOB_COUNTRY_ID, 1000),
+			Error::<Runtime>::AccountIsNotMetaverseOwner
+		);
+		assert_ok!(GovernanceModule::update_metaverse_spend_cap(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			1000
+		));
+		assert_eq!(GovernanceModule::metaverse_spend_cap(BOB_COUNTRY_ID), Some(1000));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Governance(crate::Event::MetaverseSpendCapUpdated(BOB_COUNTRY_ID, 1000))
+		);
+	});
+}
+
+#[test]
+fn spend_proposal_outside_spend_cap_does_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let spend = MetaverseSpend {
+			beneficiary: BENEFICIARY_ID,
+			amount: 500,
+			currency_id: FungibleTokenId::NativeToken(0),
+			vesting: None,
+		};
+		assert_noop!(
+			GovernanceModule::propose_spend(
+				RuntimeOrigin::signed(ALICE),
+				BOB_COUNTRY_ID,
+				600,
+				spend.clone(),
+				PROPOSAL_DESCRIPTION.to_vec()
+			),
+			Error::<Runtime>::SpendCapNotSet
+		);
+		assert_ok!(GovernanceModule::update_metaverse_spend_cap(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			100
+		));
+		assert_noop!(
+			GovernanceModule::propose_spend(
+				RuntimeOrigin::signed(ALICE),
+				BOB_COUNTRY_ID,
+				600,
+				spend,
+				PROPOSAL_DESCRIPTION.to_vec()
+			),
+			Error::<Runtime>::SpendExceedsCap
+		);
+	});
+}
+
+#[test]
+fn spend_proposal_with_invalid_vesting_schedule_does_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(GovernanceModule::update_metaverse_spend_cap(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			1000
+		));
+		let spend = MetaverseSpend {
+			beneficiary: BENEFICIARY_ID,
+			amount: 500,
+			currency_id: FungibleTokenId::NativeToken(0),
+			vesting: Some(VestingSchedule {
+				token: FungibleTokenId::NativeToken(0),
+				start: 1,
+				period: 10,
+				period_count: 4,
+				per_period: 100,
+			}),
+		};
+		assert_noop!(
+			GovernanceModule::propose_spend(
+				RuntimeOrigin::signed(ALICE),
+				BOB_COUNTRY_ID,
+				600,
+				spend,
+				PROPOSAL_DESCRIPTION.to_vec()
+			),
+			Error::<Runtime>::InvalidVestingSchedule
+		);
+	});
+}
+
+#[test]
+fn spend_proposal_pays_beneficiary_after_referendum_passes() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(GovernanceModule::update_metaverse_spend_cap(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			1000
+		));
+		let spend = MetaverseSpend {
+			beneficiary: BENEFICIARY_ID,
+			amount: 500,
+			currency_id: FungibleTokenId::NativeToken(0),
+			vesting: None,
+		};
+		let spend_hash = <Runtime as frame_system::Config>::Hashing::hash_of(&spend);
+		assert_ok!(GovernanceModule::propose_spend(
+			RuntimeOrigin::signed(ALICE),
+			BOB_COUNTRY_ID,
+			600,
+			spend.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Governance(crate::Event::SpendProposed(
+				BOB_COUNTRY_ID,
+				0,
+				BENEFICIARY_ID,
+				FungibleTokenId::NativeToken(0),
+				500
+			))
+		);
+		assert_eq!(GovernanceModule::spend_proposals(BOB_COUNTRY_ID, 0), Some(spend));
+
+		run_to_block(16);
+		assert_ok!(GovernanceModule::try_vote(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_FOR
+		));
+		run_to_block(117);
+		assert_eq!(Balances::free_balance(&BENEFICIARY_ID), 0);
+
+		assert_ok!(GovernanceModule::enact_proposal(
+			RuntimeOrigin::root(),
+			0,
+			BOB_COUNTRY_ID,
+			0,
+			spend_hash
+		));
+		assert_eq!(Balances::free_balance(&BENEFICIARY_ID), 500);
+		assert_eq!(Balances::free_balance(&GENERAL_METAVERSE_FUND), 9500);
+		assert_eq!(GovernanceModule::spend_proposals(BOB_COUNTRY_ID, 0), None);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Governance(crate::Event::ProposalEnacted(BOB_COUNTRY_ID, 0))
+		);
+	});
+}
+
+#[test]
+fn rejected_spend_proposal_does_not_pay_beneficiary() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(GovernanceModule::update_metaverse_spend_cap(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			1000
+		));
+		let spend = MetaverseSpend {
+			beneficiary: BENEFICIARY_ID,
+			amount: 500,
+			currency_id: FungibleTokenId::NativeToken(0),
+			vesting: None,
+		};
+		assert_ok!(GovernanceModule::propose_spend(
+			RuntimeOrigin::signed(ALICE),
+			BOB_COUNTRY_ID,
+			600,
+			spend,
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		run_to_block(16);
+		assert_ok!(GovernanceModule::try_vote(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_AGAINST
+		));
+		run_to_block(117);
+		assert_eq!(GovernanceModule::spend_proposals(BOB_COUNTRY_ID, 0), None);
+		assert_eq!(Balances::free_balance(&BENEFICIARY_ID), 0);
+		assert_eq!(Balances::free_balance(&GENERAL_METAVERSE_FUND), 10000);
+	});
+}
+
+#[test]
+fn vested_spend_is_released_over_time() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(GovernanceModule::update_metaverse_spend_cap(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			1000
+		));
+		let spend = MetaverseSpend {
+			beneficiary: BENEFICIARY_ID,
+			amount: 500,
+			currency_id: FungibleTokenId::NativeToken(0),
+			vesting: Some(VestingSchedule {
+				token: FungibleTokenId::NativeToken(0),
+				start: 117,
+				period: 10,
+				period_count: 5,
+				per_period: 100,
+			}),
+		};
+		let spend_hash = <Runtime as frame_system::Config>::Hashing::hash_of(&spend);
+		assert_ok!(GovernanceModule::propose_spend(
+			RuntimeOrigin::signed(ALICE),
+			BOB_COUNTRY_ID,
+			600,
+			spend,
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		run_to_block(16);
+		assert_ok!(GovernanceModule::try_vote(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_FOR
+		));
+		run_to_block(117);
+		assert_ok!(GovernanceModule::enact_proposal(
+			RuntimeOrigin::root(),
+			0,
+			BOB_COUNTRY_ID,
+			0,
+			spend_hash
+		));
+		assert_eq!(Balances::reserved_balance(&GENERAL_METAVERSE_FUND), 500);
+		assert_noop!(
+			GovernanceModule::claim_vested_spend(RuntimeOrigin::signed(BOB), BOB_COUNTRY_ID, 0),
+			Error::<Runtime>::NothingToClaim
+		);
+
+		run_to_block(127);
+		assert_ok!(GovernanceModule::claim_vested_spend(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			0
+		));
+		assert_eq!(Balances::free_balance(&BENEFICIARY_ID), 100);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Governance(crate::Event::VestedSpendClaimed(BOB_COUNTRY_ID, 0, BENEFICIARY_ID, 100))
+		);
+
+		run_to_block(170);
+		assert_ok!(GovernanceModule::claim_vested_spend(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			0
+		));
+		assert_eq!(Balances::free_balance(&BENEFICIARY_ID), 500);
+		assert_eq!(Balances::reserved_balance(&GENERAL_METAVERSE_FUND), 0);
+		assert_eq!(GovernanceModule::vested_spends(BOB_COUNTRY_ID, 0), None);
+	});
+}
+
+#[test]
+fn enacted_proposal_dispatches_with_metaverse_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		let root = RuntimeOrigin::root();
+		assert_ok!(Metaverse::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		let hash = set_freeze_metaverse_proposal_hash(0);
+		Preimages::<Runtime>::insert(
+			0,
+			hash,
+			PreimageStatus::Available {
+				data: set_freeze_metaverse_proposal(0),
+				provider: ALICE,
+				deposit: 200,
+				since: 1,
+				expiry: Some(150),
+			},
+		);
+		assert_ok!(GovernanceModule::enact_proposal(root, 0, 0, 0, hash));
+		System::assert_has_event(RuntimeEvent::Governance(crate::Event::PreimageEnacted(0, hash, Ok(()))));
+		assert_eq!(Metaverse::get_metaverse(0).map(|info| info.is_frozen), Some(true));
+	});
+}
+
+#[test]
+fn enacted_proposal_cannot_act_on_other_metaverse() {
+	ExtBuilder::default().build().execute_with(|| {
+		let root = RuntimeOrigin::root();
+		let hash = set_freeze_metaverse_proposal_hash(1);
+		add_freeze_metaverse_preimage(hash);
+		assert_ok!(GovernanceModule::enact_proposal(root, 0, BOB_COUNTRY_ID, 0, hash));
+		System::assert_has_event(RuntimeEvent::Governance(crate::Event::PreimageEnacted(
+			BOB_COUNTRY_ID,
+			hash,
+			Err(BadOrigin),
+		)));
+	});
+}
+
+// Referendum lifecycle tests
+#[test]
+fn queued_proposal_is_launched_after_referendum_is_cancelled() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = RuntimeOrigin::signed(ALICE);
+		let hash = set_freeze_metaverse_proposal_hash(1);
+		let hash2 = set_freeze_metaverse_proposal_hash(2);
+		add_freeze_metaverse_preimage(hash);
+		add_freeze_metaverse_preimage(hash2);
+		assert_ok!(GovernanceModule::propose(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			600,
+			hash.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		assert_ok!(GovernanceModule::propose(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			600,
+			hash2.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		run_to_block(2);
+		assert_ok!(GovernanceModule::emergency_cancel_referendum(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			0
+		));
+		assert_eq!(GovernanceModule::ongoing_referendum(BOB_COUNTRY_ID), None);
+		// Proposal is not launched before its launch period is over
+		run_to_block(15);
+		assert!(GovernanceModule::proposals(BOB_COUNTRY_ID, 1).is_some());
+		run_to_block(16);
+		assert_eq!(GovernanceModule::proposals(BOB_COUNTRY_ID, 1), None);
+		assert_eq!(GovernanceModule::ongoing_referendum(BOB_COUNTRY_ID), Some(1));
+		System::assert_has_event(RuntimeEvent::Governance(crate::Event::ReferendumStarted(
+			BOB_COUNTRY_ID,
+			1,
+			1,
+			VoteThreshold::RelativeMajority,
+		)));
+	});
+}
+
+#[test]
+fn passed_referendum_is_finalised_and_enactment_is_scheduled() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = RuntimeOrigin::signed(ALICE);
+		let hash = set_freeze_metaverse_proposal_hash(1);
+		add_freeze_metaverse_preimage(hash);
+		assert_ok!(GovernanceModule::propose(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			600,
+			hash.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		assert_eq!(GovernanceModule::referendum_endings(116, BOB_COUNTRY_ID), Some(0));
+		run_to_block(16);
+		assert_ok!(GovernanceModule::try_vote(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_FOR
+		));
+		run_to_block(116);
+		assert_eq!(GovernanceModule::referendum_endings(116, BOB_COUNTRY_ID), None);
+		assert_eq!(GovernanceModule::ongoing_referendum(BOB_COUNTRY_ID), None);
+		System::assert_has_event(RuntimeEvent::Governance(crate::Event::ReferendumPassed(0)));
+		// Preimage is kept until the proposal is enacted
+		assert!(matches!(
+			GovernanceModule::preimages(BOB_COUNTRY_ID, hash),
+			Some(PreimageStatus::Available { expiry: None, .. })
+		));
+		System::assert_has_event(RuntimeEvent::Scheduler(pallet_scheduler::Event::Scheduled {
+			when: 126,
+			index: 0,
+		}));
+	});
+}
+
+#[test]
+fn expired_preimage_is_reaped_and_deposit_is_refunded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = RuntimeOrigin::signed(ALICE);
+		let encoded_proposal = set_freeze_metaverse_proposal(4);
+		let hash = set_freeze_metaverse_proposal_hash(4);
+		assert_ok!(GovernanceModule::note_preimage(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			encoded_proposal
+		));
+		assert_eq!(Balances::reserved_balance(&ALICE), 10);
+		run_to_block(21);
+		assert!(GovernanceModule::preimages(BOB_COUNTRY_ID, hash).is_some());
+		run_to_block(23);
+		assert_eq!(GovernanceModule::preimages(BOB_COUNTRY_ID, hash), None);
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+		System::assert_has_event(RuntimeEvent::Governance(crate::Event::PreimageReaped(
+			BOB_COUNTRY_ID,
+			hash,
+			ALICE,
+			10,
+		)));
+	});
+}
+
+#[test]
+fn preimage_used_by_referendum_is_not_reaped() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = RuntimeOrigin::signed(ALICE);
+		let encoded_proposal = set_freeze_metaverse_proposal(4);
+		let hash = set_freeze_metaverse_proposal_hash(4);
+		assert_ok!(GovernanceModule::note_preimage(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			encoded_proposal
+		));
+		assert_ok!(GovernanceModule::propose(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			600,
+			hash.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		run_to_block(30);
+		assert!(matches!(
+			GovernanceModule::preimages(BOB_COUNTRY_ID, hash),
+			Some(PreimageStatus::Available { expiry: Some(42), .. })
+		));
+	});
+}
+
+#[test]
+fn lifecycle_operations_are_bounded_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		for metaverse_id in 0..12u64 {
+			ScheduledLaunches::<Runtime>::insert(2, metaverse_id, ());
+		}
+		run_to_block(2);
+		assert_eq!(ScheduledLaunches::<Runtime>::iter_prefix(2).count(), 4);
+		assert_eq!(GovernanceModule::lifecycle_cursor(), 2);
+		run_to_block(3);
+		assert_eq!(ScheduledLaunches::<Runtime>::iter_prefix(2).count(), 0);
+		assert_eq!(GovernanceModule::lifecycle_cursor(), 4);
+	});
+}
+
+#[test]
+fn referendum_lifecycle_migration_indexes_ongoing_referendums() {
+	ExtBuilder::default().build().execute_with(|| {
+		let hash = set_freeze_metaverse_proposal_hash(1);
+		add_freeze_metaverse_preimage(hash);
+		assert_ok!(GovernanceModule::propose(
+			RuntimeOrigin::signed(ALICE),
+			BOB_COUNTRY_ID,
+			600,
+			hash.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		run_to_block(2);
+		let end = match GovernanceModule::referendum_info(BOB_COUNTRY_ID, 0) {
+			Some(ReferendumInfo::Ongoing(status)) => status.end,
+			_ => panic!("referendum should be ongoing"),
+		};
+
+		// Referendum created before the lifecycle was indexed
+		OngoingReferendums::<Runtime>::remove(BOB_COUNTRY_ID);
+		ReferendumEndings::<Runtime>::remove(end, BOB_COUNTRY_ID);
+		StorageVersion::new(0).put::<GovernanceModule>();
+
+		GovernanceModule::on_runtime_upgrade();
+		assert_eq!(GovernanceModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			GovernanceModule::referendum_lifecycle_migration_cursor(),
+			Some(Vec::new())
+		);
+
+		// Migration only runs once
+		assert_eq!(
+			GovernanceModule::upgrade_referendum_lifecycle_v2(),
+			<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+		);
+
+		run_to_block(3);
+		assert_eq!(GovernanceModule::ongoing_referendum(BOB_COUNTRY_ID), Some(0));
+		assert_eq!(GovernanceModule::referendum_endings(end, BOB_COUNTRY_ID), Some(0));
+		assert_eq!(GovernanceModule::referendum_lifecycle_migration_cursor(), None);
+	});
+}
+
+#[test]
+fn vote_with_voting_credential_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let hash = set_freeze_metaverse_proposal_hash(1);
+		add_freeze_metaverse_preimage(hash);
+		assert_ok!(GovernanceModule::propose(
+			RuntimeOrigin::signed(ALICE),
+			BOB_COUNTRY_ID,
+			600,
+			hash.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		assert_noop!(
+			GovernanceModule::update_voting_credential(
+				RuntimeOrigin::signed(ALICE),
+				BOB_COUNTRY_ID,
+				Some(CREDENTIAL_CLASS_ID)
+			),
+			Error::<Runtime>::AccountIsNotMetaverseOwner
+		);
+		assert_noop!(
+			GovernanceModule::update_voting_credential(
+				RuntimeOrigin::signed(BOB),
+				BOB_COUNTRY_ID,
+				Some(ASSET_CLASS_ID)
+			),
+			Error::<Runtime>::InvalidVotingCredential
+		);
+		assert_ok!(GovernanceModule::update_voting_credential(
+			RuntimeOrigin::signed(BOB),
+			BOB_COUNTRY_ID,
+			Some(CREDENTIAL_CLASS_ID)
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Governance(crate::Event::VotingCredentialUpdated(
+				BOB_COUNTRY_ID,
+				Some(CREDENTIAL_CLASS_ID)
+			))
+		);
+
+		run_to_block(2);
+		assert_noop!(
+			GovernanceModule::try_vote(RuntimeOrigin::signed(BOB), BOB_COUNTRY_ID, 0, VOTE_FOR),
+			Error::<Runtime>::MissingVotingCredential
+		);
+		assert_ok!(GovernanceModule::try_vote(
+			RuntimeOrigin::signed(ALICE),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_FOR
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Governance(crate::Event::VoteRecorded(ALICE, 0, true))
+		);
+	});
+}
@@ -1,4 +1,4 @@
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, HasCompact};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, IntegerSquareRoot, Saturating, Zero},
@@ -8,7 +8,7 @@ use sp_std::convert::TryFrom;
 use sp_std::ops::{Add, Div, Mul, Rem};
 use sp_std::vec::Vec;
 
-use primitives::{MetaverseId, ProposalId, ReferendumId};
+use primitives::{FungibleTokenId, MetaverseId, ProposalId, ReferendumId, VestingSchedule};
 
 use crate::*;
 
@@ -348,3 +348,27 @@ pub enum ReferendumInfo<BlockNumber, Balance, Hash> {
 		end: BlockNumber,
 	},
 }
+
+/// A payment from a metaverse fund that is enacted by a local referendum
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MetaverseSpend<AccountId, Balance: HasCompact, BlockNumber> {
+	/// The account receiving the funds
+	pub beneficiary: AccountId,
+	/// The total amount paid from the metaverse fund
+	pub amount: Balance,
+	/// The currency of the payment
+	pub currency_id: FungibleTokenId,
+	/// Optional release schedule of the payment
+	pub vesting: Option<VestingSchedule<BlockNumber, Balance>>,
+}
+
+/// An approved metaverse fund payment which is released according to a vesting schedule
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VestedSpend<AccountId, Balance: HasCompact, BlockNumber> {
+	/// The account receiving the funds
+	pub beneficiary: AccountId,
+	/// The release schedule of the payment
+	pub schedule: VestingSchedule<BlockNumber, Balance>,
+	/// The amount already paid to the beneficiary
+	pub claimed: Balance,
+}
@@ -18,7 +18,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode, HasCompact};
-use frame_support::traits::{LockIdentifier, WithdrawReasons};
+use frame_support::traits::{EnsureOriginWithArg, LockIdentifier, WithdrawReasons};
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
@@ -27,18 +27,22 @@ use frame_support::{
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
 use orml_traits::MultiCurrency;
+use sp_core::U256;
 use sp_runtime::traits::{CheckedSub, Saturating};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
-	DispatchError, Perbill,
+	traits::{AccountIdConversion, One, UniqueSaturatedInto, Zero},
+	DispatchError, FixedPointNumber, Perbill, SaturatedConversion,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
+use auction_manager::SwapManager;
 use core_primitives::*;
 use core_primitives::{MetaverseInfo, MetaverseInfoV1, MetaverseTrait};
 pub use pallet::*;
 use primitives::staking::MetaverseStakingTrait;
-use primitives::{ClassId, FungibleTokenId, MetaverseId, RoundIndex, TokenId};
+use primitives::{
+	AssetMetadata, ClassId, FungibleTokenId, FungibleTokenRegistry, MetaverseId, Ratio, RoundIndex, TokenId,
+};
 pub use weights::WeightInfo;
 
 // #[cfg(feature = "runtime-benchmarks")]
@@ -61,7 +65,37 @@ pub struct MetaverseStakingSnapshot<Balance> {
 	staked: Balance,
 }
 
+/// Linear bonding curve used to price a metaverse social token in native currency.
+/// The price of the next token is `initial_price + slope * supply`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct BondingCurve<Balance> {
+	/// Price of the first token
+	pub initial_price: Ratio,
+	/// Price increase per issued token
+	pub slope: Ratio,
+	/// Maximum amount of tokens that can be issued through the curve
+	pub max_supply: Balance,
+}
+
+/// Launch state of a metaverse social token
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct SocialTokenLaunch<Balance> {
+	/// The social token currency
+	pub currency_id: FungibleTokenId,
+	/// The bonding curve the token is issued on
+	pub curve: BondingCurve<Balance>,
+	/// Amount of tokens issued through the curve
+	pub supply: Balance,
+	/// Native currency held by the curve reserve
+	pub reserve: Balance,
+	/// Market cap in native currency at which the token can graduate into a DEX pool
+	pub graduation_market_cap: Balance,
+	/// Whether the token graduated into a DEX pool
+	pub graduated: bool,
+}
+
 const LOCK_STAKING: LockIdentifier = *b"stakelok";
+const SOCIAL_TOKEN_RESERVE_ID: [u8; 4] = *b"curv";
 const ESTATE_CLASS_ROYALTY_FEE: u32 = 0;
 const LAND_CLASS_ROYALTY_FEE: u32 = 0;
 
@@ -77,6 +111,35 @@ pub struct MetaverseStakingPoints<AccountId: Ord, Balance: HasCompact> {
 	claimed_rewards: Balance,
 }
 
+/// Origin of a metaverse acting on its own behalf, e.g. when a local referendum is enacted.
+/// It can only be used to perform admin actions on the metaverse it was issued for.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MetaverseOrigin(pub MetaverseId);
+
+/// Ensures that the origin is `MetaverseOrigin` of the given metaverse
+pub struct EnsureMetaverse;
+
+impl<O: Into<Result<MetaverseOrigin, O>> + From<MetaverseOrigin>> EnsureOriginWithArg<O, MetaverseId>
+	for EnsureMetaverse
+{
+	type Success = MetaverseId;
+
+	fn try_origin(o: O, metaverse_id: &MetaverseId) -> Result<Self::Success, O> {
+		o.into().and_then(|MetaverseOrigin(origin_metaverse_id)| {
+			if origin_metaverse_id == *metaverse_id {
+				Ok(origin_metaverse_id)
+			} else {
+				Err(O::from(MetaverseOrigin(origin_metaverse_id)))
+			}
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(metaverse_id: &MetaverseId) -> Result<O, ()> {
+		Ok(O::from(MetaverseOrigin(*metaverse_id)))
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use orml_traits::MultiCurrencyExtended;
@@ -95,6 +158,9 @@ pub mod pallet {
 
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	#[pallet::origin]
+	pub type Origin = MetaverseOrigin;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
@@ -122,6 +188,8 @@ pub mod pallet {
 		type MinContribution: Get<BalanceOf<Self>>;
 		/// Origin to add new metaverse
 		type MetaverseCouncil: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin of a metaverse acting on its own behalf, e.g. a locally enacted proposal
+		type MetaverseGovernanceOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, MetaverseId>;
 		/// Mininum deposit for registering a metaverse
 		type MetaverseRegistrationDeposit: Get<BalanceOf<Self>>;
 		/// Mininum staking amount
@@ -136,6 +204,13 @@ pub mod pallet {
 		/// The fee will be unreserved after the storage is freed.
 		#[pallet::constant]
 		type StorageDepositFee: Get<BalanceOf<Self>>;
+		/// Asset registry used to register metaverse social tokens
+		type AssetRegistry: FungibleTokenRegistry<BalanceOf<Self>>;
+		/// Swap handler used to graduate social tokens into a DEX pool
+		type SwapHandler: SwapManager<Self::AccountId, FungibleTokenId, BalanceOf<Self>>;
+		/// Share of every social token purchase that goes to the metaverse fund
+		#[pallet::constant]
+		type SocialTokenReserveShare: Get<Perbill>;
 	}
 
 	#[pallet::storage]
@@ -199,6 +274,30 @@ pub mod pallet {
 	/// Stores staking info of individual stakers.
 	pub(crate) type StakingInfo<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn social_token_launch)]
+	/// Stores the bonding curve launch of each metaverse social token.
+	pub type SocialTokenLaunches<T: Config> =
+		StorageMap<_, Twox64Concat, MetaverseId, SocialTokenLaunch<BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn credential_issuer)]
+	/// The metaverse issuing the credentials of a class
+	pub type CredentialClasses<T: Config> = StorageMap<_, Twox64Concat, ClassId, MetaverseId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn credentials)]
+	/// Credentials held by accounts by class ID
+	pub type Credentials<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ClassId,
+		Blake2_128Concat,
+		T::AccountId,
+		CredentialInfo<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -226,6 +325,20 @@ pub mod pallet {
 		MetaverseListingFeeUpdated(MetaverseId, Perbill),
 		/// Successfully withdrawn funds from a metaverse treasury fund
 		MetaverseTreasuryFundsWithdrawn(MetaverseId),
+		/// Successfully launched a metaverse social token on a bonding curve
+		SocialTokenLaunched(MetaverseId, FungibleTokenId, BondingCurve<BalanceOf<T>>),
+		/// Successfully bought social tokens from the bonding curve [who, metaverse, amount, cost]
+		SocialTokenBought(T::AccountId, MetaverseId, BalanceOf<T>, BalanceOf<T>),
+		/// Successfully sold social tokens to the bonding curve [who, metaverse, amount, refund]
+		SocialTokenSold(T::AccountId, MetaverseId, BalanceOf<T>, BalanceOf<T>),
+		/// Social token graduated into a DEX pool [metaverse, native liquidity, token liquidity]
+		SocialTokenGraduated(MetaverseId, BalanceOf<T>, BalanceOf<T>),
+		/// Successfully created a credential class [metaverse, class]
+		CredentialClassCreated(MetaverseId, ClassId),
+		/// Successfully issued a credential [metaverse, class, token, holder, expiry]
+		CredentialIssued(MetaverseId, ClassId, TokenId, T::AccountId, Option<BlockNumberFor<T>>),
+		/// Successfully revoked a credential [metaverse, class, holder]
+		CredentialRevoked(MetaverseId, ClassId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -266,6 +379,28 @@ pub mod pallet {
 		MetaverseHasNoStake,
 		/// Listing fee exceed threshold
 		MetaverseListingFeeExceedThreshold,
+		/// Bonding curve parameters are invalid
+		InvalidBondingCurve,
+		/// Social token is not launched
+		SocialTokenNotLaunched,
+		/// Social token already graduated into a DEX pool
+		SocialTokenAlreadyGraduated,
+		/// Amount exceeds the bonding curve max supply
+		ExceedBondingCurveSupply,
+		/// Cost of the purchase is above the accepted maximum
+		BondingCurveCostTooHigh,
+		/// Refund of the sale is below the accepted minimum
+		BondingCurveRefundTooLow,
+		/// Social token market cap has not reached the graduation threshold
+		GraduationMarketCapNotReached,
+		/// Class is not a credential class
+		CredentialClassNotFound,
+		/// Account already holds a credential of the class
+		CredentialAlreadyIssued,
+		/// Account does not hold a credential of the class
+		CredentialNotFound,
+		/// Credential expiry is not in the future
+		InvalidCredentialExpiry,
 	}
 
 	#[pallet::call]
@@ -337,14 +472,16 @@ pub mod pallet {
 		/// Freeze an existing meataverse.
 		///
 		/// The dispatch origin for this call must be _Signed_.
-		/// Only metaverse council can perform this call.
+		/// Only metaverse council or the metaverse itself can perform this call.
 		/// - `metaverse_id`: the metaverse ID which will be freezed
 		///
 		/// Emits `MetaverseFreezed` if successful.
 		#[pallet::weight(T::WeightInfo::freeze_metaverse())]
 		pub fn freeze_metaverse(origin: OriginFor<T>, metaverse_id: MetaverseId) -> DispatchResultWithPostInfo {
-			// Only Council can freeze a metaverse
-			T::MetaverseCouncil::ensure_origin(origin)?;
+			// Only Council or the metaverse itself can freeze a metaverse
+			if let Err(origin) = T::MetaverseGovernanceOrigin::try_origin(origin, &metaverse_id) {
+				T::MetaverseCouncil::ensure_origin(origin)?;
+			}
 
 			Metaverses::<T>::try_mutate(metaverse_id, |maybe_metaverse| {
 				let metaverse_info = maybe_metaverse.as_mut().ok_or(Error::<T>::MetaverseInfoNotFound)?;
@@ -404,8 +541,8 @@ pub mod pallet {
 
 		/// Updates the meatverse's local marketplace listing fee
 		///
-		/// The dispatch origin for this call must be _Signed_.
-		/// Only metaverse owner can withdraw funds.
+		/// The dispatch origin for this call must be _Signed_ or the metaverse origin.
+		/// Only metaverse owner or the metaverse itself can update the listing fee.
 		/// - `metaverse_id`: the meatverse ID which fees will be updated
 		/// - 'new_listng_fee': the updated metaverse's local marketplace listing fee
 		///
@@ -416,8 +553,11 @@ pub mod pallet {
 			metaverse_id: MetaverseId,
 			new_listing_fee: Perbill,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
-			Self::do_update_metaverse_listing_fee(&who, &metaverse_id, new_listing_fee)?;
+			if let Err(origin) = T::MetaverseGovernanceOrigin::try_origin(origin, &metaverse_id) {
+				let who = ensure_signed(origin)?;
+				ensure!(Self::check_ownership(&who, &metaverse_id), Error::<T>::NoPermission);
+			}
+			Self::do_update_metaverse_listing_fee(&metaverse_id, new_listing_fee)?;
 			Self::deposit_event(Event::<T>::MetaverseListingFeeUpdated(metaverse_id, new_listing_fee));
 
 			Ok(().into())
@@ -455,6 +595,333 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Launches the metaverse social token on a bonding curve
+		///
+		/// The dispatch origin for this call must be _Signed_ or the metaverse origin.
+		/// Only metaverse owner or the metaverse itself can launch the token.
+		/// - `metaverse_id`: the metaverse ID which token will be launched
+		/// - `metadata`: the metadata of the social token
+		/// - `curve`: the bonding curve the social token is issued on
+		/// - `graduation_market_cap`: the market cap at which the token can move into a DEX pool
+		///
+		/// Emits `SocialTokenLaunched` if successful.
+		#[pallet::weight(T::WeightInfo::launch_social_token())]
+		pub fn launch_social_token(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+			curve: BondingCurve<BalanceOf<T>>,
+			graduation_market_cap: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			if let Err(origin) = T::MetaverseGovernanceOrigin::try_origin(origin, &metaverse_id) {
+				let who = ensure_signed(origin)?;
+				ensure!(Self::check_ownership(&who, &metaverse_id), Error::<T>::NoPermission);
+			}
+			ensure!(
+				!curve.initial_price.is_zero() && !curve.max_supply.is_zero(),
+				Error::<T>::InvalidBondingCurve
+			);
+			ensure!(
+				Self::get_metaverse_token(metaverse_id) == Some(FungibleTokenId::NativeToken(0)),
+				Error::<T>::FungibleTokenAlreadyIssued
+			);
+
+			let currency_id = T::AssetRegistry::register_fungible_token(&metadata)?;
+			Self::update_metaverse_token(metaverse_id, currency_id)?;
+			SocialTokenLaunches::<T>::insert(
+				metaverse_id,
+				SocialTokenLaunch {
+					currency_id,
+					curve: curve.clone(),
+					supply: Zero::zero(),
+					reserve: Zero::zero(),
+					graduation_market_cap,
+					graduated: false,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::SocialTokenLaunched(metaverse_id, currency_id, curve));
+
+			Ok(().into())
+		}
+
+		/// Buys metaverse social tokens from the bonding curve
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `metaverse_id`: the metaverse ID which token will be bought
+		/// - `amount`: the amount of social tokens to buy
+		/// - `max_cost`: the maximum amount of native currency to pay
+		///
+		/// Emits `SocialTokenBought` if successful.
+		#[pallet::weight(T::WeightInfo::buy_social_token())]
+		pub fn buy_social_token(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			amount: BalanceOf<T>,
+			max_cost: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			SocialTokenLaunches::<T>::try_mutate(metaverse_id, |maybe_launch| -> DispatchResultWithPostInfo {
+				let launch = maybe_launch.as_mut().ok_or(Error::<T>::SocialTokenNotLaunched)?;
+				ensure!(!launch.graduated, Error::<T>::SocialTokenAlreadyGraduated);
+
+				let new_supply = launch.supply.saturating_add(amount);
+				ensure!(
+					new_supply <= launch.curve.max_supply,
+					Error::<T>::ExceedBondingCurveSupply
+				);
+
+				let cost = Self::bonding_curve_cost(&launch.curve, launch.supply, amount, true)?;
+				ensure!(cost <= max_cost, Error::<T>::BondingCurveCostTooHigh);
+
+				// Reserve share goes to the metaverse fund, the rest backs the curve
+				let fund_share = T::SocialTokenReserveShare::get() * cost;
+				let reserve_share = cost.saturating_sub(fund_share);
+				T::Currency::transfer(
+					&who,
+					&Self::get_metaverse_treasury(metaverse_id),
+					fund_share,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				T::Currency::transfer(
+					&who,
+					&Self::social_token_reserve_account(metaverse_id),
+					reserve_share,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				T::MultiCurrency::deposit(launch.currency_id, &who, amount)?;
+
+				launch.supply = new_supply;
+				launch.reserve = launch.reserve.saturating_add(reserve_share);
+
+				Self::deposit_event(Event::<T>::SocialTokenBought(who.clone(), metaverse_id, amount, cost));
+
+				Ok(().into())
+			})
+		}
+
+		/// Sells metaverse social tokens back to the bonding curve
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `metaverse_id`: the metaverse ID which token will be sold
+		/// - `amount`: the amount of social tokens to sell
+		/// - `min_refund`: the minimum amount of native currency to receive
+		///
+		/// Emits `SocialTokenSold` if successful.
+		#[pallet::weight(T::WeightInfo::sell_social_token())]
+		pub fn sell_social_token(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			amount: BalanceOf<T>,
+			min_refund: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			SocialTokenLaunches::<T>::try_mutate(metaverse_id, |maybe_launch| -> DispatchResultWithPostInfo {
+				let launch = maybe_launch.as_mut().ok_or(Error::<T>::SocialTokenNotLaunched)?;
+				ensure!(!launch.graduated, Error::<T>::SocialTokenAlreadyGraduated);
+
+				let new_supply = launch.supply.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+				let value = Self::bonding_curve_cost(&launch.curve, new_supply, amount, false)?;
+				let refund = value.saturating_sub(T::SocialTokenReserveShare::get() * value);
+				ensure!(refund >= min_refund, Error::<T>::BondingCurveRefundTooLow);
+
+				T::MultiCurrency::withdraw(launch.currency_id, &who, amount)?;
+				T::Currency::transfer(
+					&Self::social_token_reserve_account(metaverse_id),
+					&who,
+					refund,
+					ExistenceRequirement::AllowDeath,
+				)?;
+
+				launch.supply = new_supply;
+				launch.reserve = launch.reserve.checked_sub(&refund).ok_or(ArithmeticError::Underflow)?;
+
+				Self::deposit_event(Event::<T>::SocialTokenSold(who.clone(), metaverse_id, amount, refund));
+
+				Ok(().into())
+			})
+		}
+
+		/// Graduates a social token into a DEX pool once its market cap reached the threshold
+		///
+		/// The dispatch origin for this call must be _Signed_ or the metaverse origin.
+		/// Only metaverse owner or the metaverse itself can graduate its social token, as the
+		/// graduation decides when the curve reserve becomes DEX liquidity.
+		/// The curve reserve is paired with newly issued tokens at the current curve price
+		/// and the liquidity stays locked in the curve reserve account.
+		/// - `metaverse_id`: the metaverse ID which token will graduate
+		///
+		/// Emits `SocialTokenGraduated` if successful.
+		#[pallet::weight(T::WeightInfo::graduate_social_token())]
+		pub fn graduate_social_token(origin: OriginFor<T>, metaverse_id: MetaverseId) -> DispatchResultWithPostInfo {
+			Self::ensure_metaverse_origin(origin, metaverse_id)?;
+
+			SocialTokenLaunches::<T>::try_mutate(metaverse_id, |maybe_launch| -> DispatchResultWithPostInfo {
+				let launch = maybe_launch.as_mut().ok_or(Error::<T>::SocialTokenNotLaunched)?;
+				ensure!(!launch.graduated, Error::<T>::SocialTokenAlreadyGraduated);
+
+				let price = Self::bonding_curve_price(&launch.curve, launch.supply);
+				ensure!(
+					price.saturating_mul_int(launch.supply) >= launch.graduation_market_cap,
+					Error::<T>::GraduationMarketCapNotReached
+				);
+
+				let reserve_account = Self::social_token_reserve_account(metaverse_id);
+				let token_amount = price
+					.reciprocal()
+					.ok_or(Error::<T>::InvalidBondingCurve)?
+					.saturating_mul_int(launch.reserve);
+				T::MultiCurrency::deposit(launch.currency_id, &reserve_account, token_amount)?;
+				T::SwapHandler::enable_trading_pair(FungibleTokenId::NativeToken(0), launch.currency_id)?;
+				T::SwapHandler::add_liquidity(
+					&reserve_account,
+					FungibleTokenId::NativeToken(0),
+					launch.currency_id,
+					launch.reserve,
+					token_amount,
+				)?;
+
+				launch.graduated = true;
+
+				Self::deposit_event(Event::<T>::SocialTokenGraduated(
+					metaverse_id,
+					launch.reserve,
+					token_amount,
+				));
+
+				Ok(().into())
+			})
+		}
+
+		/// Create a non-transferable credential class issued by a metaverse
+		///
+		/// The dispatch origin for this call must be _Signed_ or the metaverse origin.
+		/// Only metaverse owner or the metaverse itself can create credential classes. The class
+		/// is owned and paid for by the metaverse fund.
+		/// - `metaverse_id`: the metaverse ID issuing the credentials
+		/// - `metadata`: the metadata of the credential class
+		/// - `attributes`: the attributes of the credential class
+		///
+		/// Emits `CredentialClassCreated` if successful.
+		#[pallet::weight(T::WeightInfo::create_credential_class())]
+		pub fn create_credential_class(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			metadata: NftMetadata,
+			attributes: Attributes,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_metaverse_origin(origin, metaverse_id)?;
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetaverseMetadata::get(),
+				Error::<T>::MaxMetadataExceeded
+			);
+
+			let mut class_attributes = attributes;
+			class_attributes.insert("MetaverseId:".as_bytes().to_vec(), metaverse_id.to_be_bytes().to_vec());
+			class_attributes.insert("Category:".as_bytes().to_vec(), "Credentials".as_bytes().to_vec());
+			let class_id = T::NFTHandler::create_token_class(
+				&Self::get_metaverse_treasury(metaverse_id),
+				metadata,
+				class_attributes,
+				0,
+				TokenType::BoundToAddress,
+				CollectionType::Collectable,
+				Perbill::from_percent(0u32),
+				None,
+			)?;
+			CredentialClasses::<T>::insert(class_id, metaverse_id);
+
+			Self::deposit_event(Event::<T>::CredentialClassCreated(metaverse_id, class_id));
+
+			Ok(().into())
+		}
+
+		/// Issue a credential of a metaverse credential class to an account
+		///
+		/// The dispatch origin for this call must be _Signed_ or the metaverse origin.
+		/// Only owner of the issuing metaverse or the metaverse itself can issue credentials.
+		/// - `class_id`: the credential class
+		/// - `holder`: the account receiving the credential
+		/// - `metadata`: the metadata of the credential token
+		/// - `expiry`: the block the credential expires, `None` if it never expires
+		///
+		/// Emits `CredentialIssued` if successful.
+		#[pallet::weight(T::WeightInfo::issue_credential())]
+		pub fn issue_credential(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			holder: T::AccountId,
+			metadata: NftMetadata,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let metaverse_id = Self::credential_issuer(class_id).ok_or(Error::<T>::CredentialClassNotFound)?;
+			Self::ensure_metaverse_origin(origin, metaverse_id)?;
+			ensure!(
+				!Credentials::<T>::contains_key(class_id, &holder),
+				Error::<T>::CredentialAlreadyIssued
+			);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				expiry.map_or(true, |expiry| expiry > now),
+				Error::<T>::InvalidCredentialExpiry
+			);
+
+			// Credentials are bound to their holder, only the class owner can move them
+			let metaverse_fund = Self::get_metaverse_treasury(metaverse_id);
+			let token_id = T::NFTHandler::mint_token(&metaverse_fund, class_id, metadata, Attributes::new())?;
+			T::NFTHandler::transfer_nft(&metaverse_fund, &holder, &(class_id, token_id))?;
+			Credentials::<T>::insert(
+				class_id,
+				&holder,
+				CredentialInfo {
+					token_id,
+					issued_at: now,
+					expiry,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::CredentialIssued(
+				metaverse_id,
+				class_id,
+				token_id,
+				holder,
+				expiry,
+			));
+
+			Ok(().into())
+		}
+
+		/// Revoke the credential of an account, burning its credential token
+		///
+		/// The dispatch origin for this call must be _Signed_ or the metaverse origin.
+		/// Only owner of the issuing metaverse or the metaverse itself can revoke credentials.
+		/// - `class_id`: the credential class
+		/// - `holder`: the account holding the credential
+		///
+		/// Emits `CredentialRevoked` if successful.
+		#[pallet::weight(T::WeightInfo::revoke_credential())]
+		pub fn revoke_credential(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			holder: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let metaverse_id = Self::credential_issuer(class_id).ok_or(Error::<T>::CredentialClassNotFound)?;
+			Self::ensure_metaverse_origin(origin, metaverse_id)?;
+			let credential = Credentials::<T>::take(class_id, &holder).ok_or(Error::<T>::CredentialNotFound)?;
+
+			// The holder may have burned the credential already
+			let asset_id = (class_id, credential.token_id);
+			if T::NFTHandler::check_ownership(&holder, &asset_id)? {
+				T::NFTHandler::burn_nft(&holder, &asset_id)?;
+			}
+
+			Self::deposit_event(Event::<T>::CredentialRevoked(metaverse_id, class_id, holder));
+
+			Ok(().into())
+		}
 	}
 
 	#[pallet::hooks]
@@ -525,6 +992,15 @@ impl<T: Config> Pallet<T> {
 		Ok(metaverse_id)
 	}
 
+	/// Ensure the origin is the metaverse origin or the metaverse owner
+	fn ensure_metaverse_origin(origin: OriginFor<T>, metaverse_id: MetaverseId) -> DispatchResult {
+		if let Err(origin) = T::MetaverseGovernanceOrigin::try_origin(origin, &metaverse_id) {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::check_ownership(&who, &metaverse_id), Error::<T>::NoPermission);
+		}
+		Ok(())
+	}
+
 	/// The account ID of the treasury pot.
 	///
 	/// This actually does computation. If you need to keep using it, then make sure you cache the
@@ -533,6 +1009,53 @@ impl<T: Config> Pallet<T> {
 		T::MetaverseTreasury::get().into_account_truncating()
 	}
 
+	/// The account ID holding the bonding curve reserve of a metaverse social token.
+	pub fn social_token_reserve_account(metaverse_id: MetaverseId) -> T::AccountId {
+		T::MetaverseTreasury::get().into_sub_account_truncating((SOCIAL_TOKEN_RESERVE_ID, metaverse_id))
+	}
+
+	/// Price of the next social token at the given supply
+	fn bonding_curve_price(curve: &BondingCurve<BalanceOf<T>>, supply: BalanceOf<T>) -> Ratio {
+		curve
+			.initial_price
+			.saturating_add(curve.slope.saturating_mul(Ratio::saturating_from_integer(supply)))
+	}
+
+	/// Native currency value of `amount` social tokens issued on top of `supply`, i.e. the
+	/// integral of the linear price between `supply` and `supply + amount`
+	fn bonding_curve_cost(
+		curve: &BondingCurve<BalanceOf<T>>,
+		supply: BalanceOf<T>,
+		amount: BalanceOf<T>,
+		round_up: bool,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let supply = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(supply));
+		let amount = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(amount));
+		let initial_price = U256::from(curve.initial_price.into_inner());
+		let slope = U256::from(curve.slope.into_inner());
+
+		// 2 * (initial_price * amount + slope * amount * (2 * supply + amount) / 2)
+		let numerator = initial_price
+			.checked_mul(amount)
+			.and_then(|n| n.checked_mul(U256::from(2u8)))
+			.and_then(|n| {
+				slope
+					.checked_mul(amount)?
+					.checked_mul(supply.checked_mul(U256::from(2u8))?.checked_add(amount)?)?
+					.checked_add(n)
+			})
+			.ok_or(ArithmeticError::Overflow)?;
+		let denominator = U256::from(Ratio::accuracy()).saturating_mul(U256::from(2u8));
+		let cost = if round_up {
+			numerator.saturating_add(denominator - U256::one()) / denominator
+		} else {
+			numerator / denominator
+		};
+
+		let cost: u128 = cost.try_into().map_err(|_| ArithmeticError::Overflow)?;
+		Ok(cost.saturated_into())
+	}
+
 	/// Update staking info of origin
 	fn update_staking_info(who: &T::AccountId, staking_info: BalanceOf<T>) {
 		if staking_info.is_zero() {
@@ -589,11 +1112,9 @@ impl<T: Config> Pallet<T> {
 
 	/// Internal update of a metaverse listing fee
 	fn do_update_metaverse_listing_fee(
-		who: &T::AccountId,
 		metaverse_id: &MetaverseId,
 		new_listing_fee: Perbill,
 	) -> Result<(), DispatchError> {
-		ensure!(Self::check_ownership(who, metaverse_id), Error::<T>::NoPermission);
 		ensure!(
 			new_listing_fee <= Perbill::from_percent(25u32),
 			Error::<T>::MetaverseListingFeeExceedThreshold
@@ -745,6 +1266,66 @@ impl<T: Config> MetaverseTrait<T::AccountId> for Pallet<T> {
 	}
 }
 
+impl<T: Config> AccountAssetsMigration<T::AccountId> for Pallet<T> {
+	/// Moves at most `limit` metaverses and credentials of `from` to `to`. Credential tokens move
+	/// with the NFT migration, their records follow here so the rescuer holds the credentials.
+	fn migrate_account_assets(from: &T::AccountId, to: &T::AccountId, limit: u32) -> Result<u32, DispatchError> {
+		let metaverse_ids: Vec<MetaverseId> = MetaverseOwner::<T>::iter_key_prefix(from)
+			.take(limit as usize)
+			.collect();
+
+		for metaverse_id in metaverse_ids.iter() {
+			Metaverses::<T>::try_mutate_exists(metaverse_id, |metaverse| -> DispatchResult {
+				let metaverse_record = metaverse.as_mut().ok_or(Error::<T>::MetaverseInfoNotFound)?;
+				metaverse_record.owner = to.clone();
+				Ok(())
+			})?;
+
+			MetaverseOwner::<T>::remove(from, metaverse_id);
+			MetaverseOwner::<T>::insert(to, metaverse_id, ());
+
+			Self::deposit_event(Event::<T>::TransferredMetaverse(
+				*metaverse_id,
+				from.clone(),
+				to.clone(),
+			));
+		}
+
+		let credential_class_ids: Vec<ClassId> = CredentialClasses::<T>::iter_keys()
+			.filter(|class_id| Credentials::<T>::contains_key(class_id, from))
+			.take(limit.saturating_sub(metaverse_ids.len() as u32) as usize)
+			.collect();
+
+		for class_id in credential_class_ids.iter() {
+			if let Some(credential) = Credentials::<T>::take(class_id, from) {
+				// The rescuer keeps its own credential of the class
+				if !Credentials::<T>::contains_key(class_id, to) {
+					Credentials::<T>::insert(class_id, to, credential);
+				}
+			}
+		}
+
+		Ok(metaverse_ids.len().saturating_add(credential_class_ids.len()) as u32)
+	}
+}
+
+impl<T: Config> CredentialTrait<T::AccountId> for Pallet<T> {
+	fn get_credential_issuer(class_id: ClassId) -> Option<MetaverseId> {
+		Self::credential_issuer(class_id)
+	}
+
+	fn has_valid_credential(who: &T::AccountId, class_id: ClassId) -> bool {
+		match Self::credentials(class_id, who) {
+			Some(credential) => {
+				let now = <frame_system::Pallet<T>>::block_number();
+				credential.expiry.map_or(true, |expiry| now < expiry)
+					&& T::NFTHandler::check_ownership(who, &(class_id, credential.token_id)).unwrap_or(false)
+			}
+			None => false,
+		}
+	}
+}
+
 impl<T: Config> MetaverseStakingTrait<BalanceOf<T>> for Pallet<T> {
 	fn update_staking_reward(round: RoundIndex, total_reward: BalanceOf<T>) -> DispatchResult {
 		// Update total reward value of current round - for reward distribution
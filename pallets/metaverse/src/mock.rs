@@ -34,6 +34,7 @@ pub const ASSET_TOKEN_ID: TokenId = 6;
 pub const ASSET_COLLECTION_ID: GroupCollectionId = 7;
 
 pub const DOLLARS: Balance = 1_000_000_000_000_000_000;
+pub const SOCIAL_TOKEN: FungibleTokenId = FungibleTokenId::FungibleToken(1);
 
 // Configure a mock runtime to test the pallet.
 
@@ -96,7 +97,35 @@ parameter_types! {
 	pub const MinStakingAmount: Balance = 100;
 	pub const MaxNumberOfStakersPerMetaverse: u32 = 1;
 	pub StorageDepositFee: Balance = 1;
+	pub const SocialTokenReserveShare: Perbill = Perbill::from_percent(10);
+	pub static AddedLiquidity: Option<(AccountId, FungibleTokenId, FungibleTokenId, Balance, Balance)> = None;
+}
+
+pub struct MockAssetRegistry;
+
+impl FungibleTokenRegistry<Balance> for MockAssetRegistry {
+	fn register_fungible_token(_metadata: &AssetMetadata<Balance>) -> Result<FungibleTokenId, DispatchError> {
+		Ok(SOCIAL_TOKEN)
+	}
+}
+
+pub struct MockSwapHandler;
+
+impl SwapManager<AccountId, FungibleTokenId, Balance> for MockSwapHandler {
+	fn add_liquidity(
+		who: &AccountId,
+		token_id_a: FungibleTokenId,
+		token_id_b: FungibleTokenId,
+		max_amount_a: Balance,
+		max_amount_b: Balance,
+	) -> DispatchResult {
+		AddedLiquidity::set(Some((*who, token_id_a, token_id_b, max_amount_a, max_amount_b)));
+		Ok(())
+	}
 
+	fn enable_trading_pair(_token_id_a: FungibleTokenId, _token_id_b: FungibleTokenId) -> DispatchResult {
+		Ok(())
+	}
 }
 
 fn test_attributes(x: u8) -> Attributes {
@@ -116,6 +145,7 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 		if (*who == ALICE && (nft_value.1 == 1 || nft_value.1 == 3))
 			|| (*who == BOB && (nft_value.1 == 2 || nft_value.1 == 4))
 			|| (*who == BENEFICIARY_ID && (nft_value.1 == 100 || nft_value.1 == 101))
+			|| (*who == FREEDY && nft_value.1 == 1000)
 		{
 			return Ok(true);
 		}
@@ -312,6 +342,13 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 	) -> sp_runtime::DispatchResult {
 		Ok(())
 	}
+
+	fn get_royalty_payouts(
+		_class_id: &Self::ClassId,
+		_sale_price: Balance,
+	) -> Result<Vec<(AccountId, Balance)>, DispatchError> {
+		Ok(vec![])
+	}
 }
 
 ord_parameter_types! {
@@ -328,12 +365,16 @@ impl Config for Runtime {
 	type MaxMetaverseMetadata = MaxTokenMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureSignedBy<One, AccountId>;
+	type MetaverseGovernanceOrigin = EnsureMetaverse;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinStakingAmount;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
 	type WeightInfo = ();
 	type NFTHandler = MockNFTHandler;
 	type StorageDepositFee = StorageDepositFee;
+	type AssetRegistry = MockAssetRegistry;
+	type SwapHandler = MockSwapHandler;
+	type SocialTokenReserveShare = SocialTokenReserveShare;
 }
 
 parameter_type_with_key! {
@@ -391,7 +432,7 @@ construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Currencies: currencies::{ Pallet, Storage, Call, Event<T>},
 		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
-		Metaverse: metaverse::{Pallet, Call ,Storage, Event<T>},
+		Metaverse: metaverse::{Pallet, Call ,Storage, Event<T>, Origin},
 	}
 );
 
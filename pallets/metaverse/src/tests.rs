@@ -96,6 +96,50 @@ fn transfer_metaverse_should_work() {
 	})
 }
 
+#[test]
+fn migrate_account_assets_should_move_metaverses() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+
+		assert_eq!(MetaverseModule::migrate_account_assets(&ALICE, &BOB, 1), Ok(1));
+		assert_eq!(MetaverseModule::migrate_account_assets(&ALICE, &BOB, 1), Ok(0));
+		assert_eq!(MetaverseModule::get_metaverse(&METAVERSE_ID).unwrap().owner, BOB);
+		assert_eq!(MetaverseModule::get_metaverse_owner(BOB, METAVERSE_ID), Some(()));
+		assert_eq!(MetaverseModule::get_metaverse_owner(ALICE, METAVERSE_ID), None);
+		let event = RuntimeEvent::Metaverse(crate::Event::TransferredMetaverse(METAVERSE_ID, ALICE, BOB));
+		assert_eq!(last_event(), event);
+	})
+}
+
+#[test]
+fn migrate_account_assets_should_move_credentials() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_credential_class(
+			RuntimeOrigin::signed(ALICE),
+			METAVERSE_ID,
+			vec![1],
+			Attributes::new()
+		));
+		assert_ok!(MetaverseModule::issue_credential(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			FREEDY,
+			vec![1],
+			None
+		));
+
+		assert_eq!(MetaverseModule::migrate_account_assets(&FREEDY, &BOB, 10), Ok(1));
+		assert_eq!(MetaverseModule::migrate_account_assets(&FREEDY, &BOB, 10), Ok(0));
+		// The credential token itself moves with the NFT migration
+		assert_eq!(
+			MetaverseModule::credentials(0, BOB).map(|credential| credential.token_id),
+			Some(1000)
+		);
+		assert_eq!(MetaverseModule::credentials(0, FREEDY), None);
+	})
+}
+
 #[test]
 fn transfer_metaverse_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -237,6 +281,54 @@ fn update_metaverse_listing_fee_should_fail_if_not_metaverse_owner() {
 	})
 }
 
+#[test]
+fn update_metaverse_listing_fee_with_metaverse_origin_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::update_metaverse_listing_fee(
+			MetaverseOrigin(METAVERSE_ID).into(),
+			METAVERSE_ID,
+			Perbill::from_percent(10u32)
+		));
+		assert_eq!(
+			MetaverseModule::get_metaverse_marketplace_listing_fee(METAVERSE_ID),
+			Ok(Perbill::from_percent(10u32))
+		);
+	})
+}
+
+#[test]
+fn metaverse_origin_cannot_act_on_other_metaverse() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_noop!(
+			MetaverseModule::update_metaverse_listing_fee(
+				MetaverseOrigin(METAVERSE_ID).into(),
+				METAVERSE_ID + 1,
+				Perbill::from_percent(10u32)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			MetaverseModule::freeze_metaverse(MetaverseOrigin(METAVERSE_ID).into(), METAVERSE_ID + 1),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn freeze_metaverse_with_metaverse_origin_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::freeze_metaverse(
+			MetaverseOrigin(METAVERSE_ID).into(),
+			METAVERSE_ID
+		));
+		let event = RuntimeEvent::Metaverse(crate::Event::MetaverseFreezed(METAVERSE_ID));
+		assert_eq!(last_event(), event);
+	})
+}
+
 #[test]
 fn do_withdraw_funds_from_metaverse_treasury_fund_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -274,3 +366,296 @@ fn do_withdraw_from_metaverse_fund_should_work() {
 		assert_eq!(free_native_balance(metaverse_fund), 1);
 	})
 }
+
+fn social_token_metadata() -> Box<AssetMetadata<Balance>> {
+	Box::new(AssetMetadata {
+		name: b"Social Token".to_vec(),
+		symbol: b"SOCIAL".to_vec(),
+		decimals: 18,
+		minimal_balance: 1,
+	})
+}
+
+fn social_token_curve() -> BondingCurve<Balance> {
+	BondingCurve {
+		initial_price: Ratio::one(),
+		slope: Ratio::saturating_from_rational(1, 100),
+		max_supply: 1000,
+	}
+}
+
+#[test]
+fn launch_social_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::launch_social_token(
+			RuntimeOrigin::signed(ALICE),
+			METAVERSE_ID,
+			social_token_metadata(),
+			social_token_curve(),
+			200
+		));
+
+		assert_eq!(MetaverseModule::get_metaverse_token(METAVERSE_ID), Some(SOCIAL_TOKEN));
+		assert_eq!(
+			MetaverseModule::social_token_launch(METAVERSE_ID),
+			Some(SocialTokenLaunch {
+				currency_id: SOCIAL_TOKEN,
+				curve: social_token_curve(),
+				supply: 0,
+				reserve: 0,
+				graduation_market_cap: 200,
+				graduated: false,
+			})
+		);
+		let event = RuntimeEvent::Metaverse(crate::Event::SocialTokenLaunched(
+			METAVERSE_ID,
+			SOCIAL_TOKEN,
+			social_token_curve(),
+		));
+		assert_eq!(last_event(), event);
+	})
+}
+
+#[test]
+fn launch_social_token_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_noop!(
+			MetaverseModule::launch_social_token(
+				RuntimeOrigin::signed(BOB),
+				METAVERSE_ID,
+				social_token_metadata(),
+				social_token_curve(),
+				200
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			MetaverseModule::launch_social_token(
+				RuntimeOrigin::signed(ALICE),
+				METAVERSE_ID,
+				social_token_metadata(),
+				BondingCurve {
+					initial_price: Ratio::zero(),
+					slope: Ratio::one(),
+					max_supply: 1000,
+				},
+				200
+			),
+			Error::<Runtime>::InvalidBondingCurve
+		);
+		assert_ok!(MetaverseModule::launch_social_token(
+			MetaverseOrigin(METAVERSE_ID).into(),
+			METAVERSE_ID,
+			social_token_metadata(),
+			social_token_curve(),
+			200
+		));
+		assert_noop!(
+			MetaverseModule::launch_social_token(
+				RuntimeOrigin::signed(ALICE),
+				METAVERSE_ID,
+				social_token_metadata(),
+				social_token_curve(),
+				200
+			),
+			Error::<Runtime>::FungibleTokenAlreadyIssued
+		);
+	})
+}
+
+#[test]
+fn buy_and_sell_social_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::launch_social_token(
+			RuntimeOrigin::signed(ALICE),
+			METAVERSE_ID,
+			social_token_metadata(),
+			social_token_curve(),
+			200
+		));
+		let metaverse_fund = MetaverseModule::get_metaverse_treasury(METAVERSE_ID);
+		let reserve_account = MetaverseModule::social_token_reserve_account(METAVERSE_ID);
+		let metaverse_fund_balance = free_native_balance(metaverse_fund);
+
+		// 100 tokens from supply 0 cost 100 * 1 + 0.01 * 100 * 100 / 2 = 150
+		assert_noop!(
+			MetaverseModule::buy_social_token(RuntimeOrigin::signed(BOB), METAVERSE_ID, 100, 149),
+			Error::<Runtime>::BondingCurveCostTooHigh
+		);
+		assert_noop!(
+			MetaverseModule::buy_social_token(RuntimeOrigin::signed(BOB), METAVERSE_ID, 1001, 20000),
+			Error::<Runtime>::ExceedBondingCurveSupply
+		);
+		assert_ok!(MetaverseModule::buy_social_token(
+			RuntimeOrigin::signed(BOB),
+			METAVERSE_ID,
+			100,
+			150
+		));
+		let event = RuntimeEvent::Metaverse(crate::Event::SocialTokenBought(BOB, METAVERSE_ID, 100, 150));
+		assert_eq!(last_event(), event);
+		assert_eq!(free_native_balance(BOB), 19850);
+		assert_eq!(Currencies::free_balance(SOCIAL_TOKEN, &BOB), 100);
+		// 10% reserve share goes to the metaverse fund
+		assert_eq!(free_native_balance(metaverse_fund), metaverse_fund_balance + 15);
+		assert_eq!(free_native_balance(reserve_account), 135);
+
+		// 50 tokens from supply 50 are worth 87, minus 9 reserve share
+		assert_noop!(
+			MetaverseModule::sell_social_token(RuntimeOrigin::signed(BOB), METAVERSE_ID, 50, 79),
+			Error::<Runtime>::BondingCurveRefundTooLow
+		);
+		assert_ok!(MetaverseModule::sell_social_token(
+			RuntimeOrigin::signed(BOB),
+			METAVERSE_ID,
+			50,
+			78
+		));
+		let event = RuntimeEvent::Metaverse(crate::Event::SocialTokenSold(BOB, METAVERSE_ID, 50, 78));
+		assert_eq!(last_event(), event);
+		assert_eq!(free_native_balance(BOB), 19928);
+		assert_eq!(Currencies::free_balance(SOCIAL_TOKEN, &BOB), 50);
+		assert_eq!(free_native_balance(reserve_account), 57);
+
+		let launch = MetaverseModule::social_token_launch(METAVERSE_ID).unwrap();
+		assert_eq!(launch.supply, 50);
+		assert_eq!(launch.reserve, 57);
+	})
+}
+
+#[test]
+fn graduate_social_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MetaverseModule::graduate_social_token(RuntimeOrigin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::SocialTokenNotLaunched
+		);
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::launch_social_token(
+			RuntimeOrigin::signed(ALICE),
+			METAVERSE_ID,
+			social_token_metadata(),
+			social_token_curve(),
+			200
+		));
+		assert_ok!(MetaverseModule::buy_social_token(
+			RuntimeOrigin::signed(BOB),
+			METAVERSE_ID,
+			50,
+			100
+		));
+		// Market cap is 1.5 * 50 = 75
+		assert_noop!(
+			MetaverseModule::graduate_social_token(RuntimeOrigin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::GraduationMarketCapNotReached
+		);
+		assert_ok!(MetaverseModule::buy_social_token(
+			RuntimeOrigin::signed(BOB),
+			METAVERSE_ID,
+			50,
+			100
+		));
+
+		// Market cap is 2 * 100 = 200, reserve is 136 as both purchases round their cost up
+		let reserve_account = MetaverseModule::social_token_reserve_account(METAVERSE_ID);
+		assert_eq!(free_native_balance(reserve_account), 136);
+		// Only metaverse owner can graduate the social token
+		assert_noop!(
+			MetaverseModule::graduate_social_token(RuntimeOrigin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(MetaverseModule::graduate_social_token(
+			RuntimeOrigin::signed(ALICE),
+			METAVERSE_ID
+		));
+		let event = RuntimeEvent::Metaverse(crate::Event::SocialTokenGraduated(METAVERSE_ID, 136, 68));
+		assert_eq!(last_event(), event);
+		assert_eq!(Currencies::free_balance(SOCIAL_TOKEN, &reserve_account), 68);
+		assert_eq!(
+			AddedLiquidity::get(),
+			Some((reserve_account, FungibleTokenId::NativeToken(0), SOCIAL_TOKEN, 136, 68))
+		);
+
+		assert_noop!(
+			MetaverseModule::buy_social_token(RuntimeOrigin::signed(BOB), METAVERSE_ID, 1, 100),
+			Error::<Runtime>::SocialTokenAlreadyGraduated
+		);
+		assert_noop!(
+			MetaverseModule::sell_social_token(RuntimeOrigin::signed(BOB), METAVERSE_ID, 1, 0),
+			Error::<Runtime>::SocialTokenAlreadyGraduated
+		);
+		assert_noop!(
+			MetaverseModule::graduate_social_token(RuntimeOrigin::signed(ALICE), METAVERSE_ID),
+			Error::<Runtime>::SocialTokenAlreadyGraduated
+		);
+	})
+}
+
+#[test]
+fn issue_and_revoke_credential_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(RuntimeOrigin::signed(ALICE), vec![1]));
+		assert_noop!(
+			MetaverseModule::create_credential_class(
+				RuntimeOrigin::signed(BOB),
+				METAVERSE_ID,
+				vec![1],
+				Attributes::new()
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(MetaverseModule::create_credential_class(
+			RuntimeOrigin::signed(ALICE),
+			METAVERSE_ID,
+			vec![1],
+			Attributes::new()
+		));
+		let event = RuntimeEvent::Metaverse(crate::Event::CredentialClassCreated(METAVERSE_ID, 0));
+		assert_eq!(last_event(), event);
+		assert_eq!(MetaverseModule::get_credential_issuer(0), Some(METAVERSE_ID));
+
+		assert_noop!(
+			MetaverseModule::issue_credential(RuntimeOrigin::signed(ALICE), 0, FREEDY, vec![1], Some(1)),
+			Error::<Runtime>::InvalidCredentialExpiry
+		);
+		assert_ok!(MetaverseModule::issue_credential(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			FREEDY,
+			vec![1],
+			Some(10)
+		));
+		let event = RuntimeEvent::Metaverse(crate::Event::CredentialIssued(METAVERSE_ID, 0, 1000, FREEDY, Some(10)));
+		assert_eq!(last_event(), event);
+		assert!(MetaverseModule::has_valid_credential(&FREEDY, 0));
+		assert!(!MetaverseModule::has_valid_credential(&BOB, 0));
+		assert_noop!(
+			MetaverseModule::issue_credential(RuntimeOrigin::signed(ALICE), 0, FREEDY, vec![1], None),
+			Error::<Runtime>::CredentialAlreadyIssued
+		);
+
+		// Credentials are no longer valid once expired
+		System::set_block_number(10);
+		assert!(!MetaverseModule::has_valid_credential(&FREEDY, 0));
+
+		assert_noop!(
+			MetaverseModule::revoke_credential(RuntimeOrigin::signed(BOB), 0, FREEDY),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(MetaverseModule::revoke_credential(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			FREEDY
+		));
+		let event = RuntimeEvent::Metaverse(crate::Event::CredentialRevoked(METAVERSE_ID, 0, FREEDY));
+		assert_eq!(last_event(), event);
+		assert_eq!(MetaverseModule::credentials(0, FREEDY), None);
+		assert_noop!(
+			MetaverseModule::revoke_credential(RuntimeOrigin::signed(ALICE), 0, FREEDY),
+			Error::<Runtime>::CredentialNotFound
+		);
+	})
+}
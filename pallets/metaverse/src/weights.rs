@@ -47,7 +47,7 @@ use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions needed for metaverse.
-pub trait WeightInfo {	fn create_metaverse() -> Weight;	fn transfer_metaverse() -> Weight;	fn freeze_metaverse() -> Weight;	fn unfreeze_metaverse() -> Weight;	fn destroy_metaverse() -> Weight;	fn update_metaverse_listing_fee() -> Weight;	fn withdraw_from_metaverse_fund() -> Weight;}
+pub trait WeightInfo {	fn create_metaverse() -> Weight;	fn transfer_metaverse() -> Weight;	fn freeze_metaverse() -> Weight;	fn unfreeze_metaverse() -> Weight;	fn destroy_metaverse() -> Weight;	fn update_metaverse_listing_fee() -> Weight;	fn withdraw_from_metaverse_fund() -> Weight;	fn launch_social_token() -> Weight;	fn buy_social_token() -> Weight;	fn sell_social_token() -> Weight;	fn graduate_social_token() -> Weight;	fn create_credential_class() -> Weight;	fn issue_credential() -> Weight;	fn revoke_credential() -> Weight;}
 
 /// Weights for metaverse using the for collator node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -154,6 +154,107 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	// Storage: Sy
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: Metaverse Metaverses (r:1 w:1)
+	// Storage: AssetManager NextForeignAssetId (r:1 w:1)
+	// Storage: AssetManager AssetMetadatas (r:1 w:1)
+	// Storage: Metaverse SocialTokenLaunches (r:0 w:1)
+	fn launch_social_token() -> Weight {
+		Weight::from_parts(62_000_000, 14000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Metaverse SocialTokenLaunches (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn buy_social_token() -> Weight {
+		Weight::from_parts(78_000_000, 12500)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: Metaverse SocialTokenLaunches (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn sell_social_token() -> Weight {
+		Weight::from_parts(72_000_000, 11000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: Metaverse Metaverses (r:1 w:0)
+	// Storage: Metaverse SocialTokenLaunches (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Tokens Accounts (r:2 w:3)
+	// Storage: Tokens TotalIssuance (r:2 w:2)
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Dex TwapObservations (r:1 w:1)
+	fn graduate_social_token() -> Weight {
+		Weight::from_parts(135_000_000, 21000)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	// Storage: Metaverse Metaverses (r:1 w:0)
+	// Proof Skipped: Metaverse Metaverses (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT NextClassId (r:1 w:1)
+	// Proof Skipped: OrmlNFT NextClassId (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Nft GroupCollections (r:1 w:0)
+	// Proof Skipped: Nft GroupCollections (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: OrmlNFT Classes (r:0 w:1)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft ClassDataCollection (r:0 w:1)
+	// Proof Skipped: Nft ClassDataCollection (max_values: None, max_size: None, mode: Measured)
+	// Storage: Metaverse CredentialClasses (r:0 w:1)
+	// Proof Skipped: Metaverse CredentialClasses (max_values: None, max_size: None, mode: Measured)
+	fn create_credential_class() -> Weight {
+		Weight::from_parts(52_000_000, 7890)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: Metaverse CredentialClasses (r:1 w:0)
+	// Proof Skipped: Metaverse CredentialClasses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Metaverse Metaverses (r:1 w:0)
+	// Proof Skipped: Metaverse Metaverses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Metaverse Credentials (r:1 w:1)
+	// Proof Skipped: Metaverse Credentials (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft LockedCollection (r:1 w:0)
+	// Proof Skipped: Nft LockedCollection (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Classes (r:1 w:1)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: OrmlNFT NextTokenId (r:1 w:1)
+	// Proof Skipped: OrmlNFT NextTokenId (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:2)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:3)
+	// Proof Skipped: OrmlNFT TokensByOwner (max_values: None, max_size: None, mode: Measured)
+	fn issue_credential() -> Weight {
+		Weight::from_parts(98_000_000, 21840)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: Metaverse CredentialClasses (r:1 w:0)
+	// Proof Skipped: Metaverse CredentialClasses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Metaverse Metaverses (r:1 w:0)
+	// Proof Skipped: Metaverse Metaverses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Metaverse Credentials (r:1 w:1)
+	// Proof Skipped: Metaverse Credentials (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Classes (r:1 w:1)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:1)
+	// Proof Skipped: OrmlNFT TokensByOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft TokenAttributeHistory (r:0 w:1)
+	// Proof Skipped: Nft TokenAttributeHistory (max_values: None, max_size: None, mode: Measured)
+	fn revoke_credential() -> Weight {
+		Weight::from_parts(46_000_000, 11260)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
 
 // For backwards compatibility and tests
@@ -192,4 +293,39 @@ impl WeightInfo for () {	fn create_metaverse() -> Weight {
 			.saturating_add(RocksDbWeight::get().reads(3))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
+	fn launch_social_token() -> Weight {
+		Weight::from_parts(62_000_000, 14000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn buy_social_token() -> Weight {
+		Weight::from_parts(78_000_000, 12500)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	fn sell_social_token() -> Weight {
+		Weight::from_parts(72_000_000, 11000)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	fn graduate_social_token() -> Weight {
+		Weight::from_parts(135_000_000, 21000)
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(11))
+	}
+	fn create_credential_class() -> Weight {
+		Weight::from_parts(52_000_000, 7890)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	fn issue_credential() -> Weight {
+		Weight::from_parts(98_000_000, 21840)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	fn revoke_credential() -> Weight {
+		Weight::from_parts(46_000_000, 11260)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
 }
@@ -12,7 +12,7 @@ use sp_runtime::{
 use primitives::estate::Estate;
 use primitives::staking::MetaverseStakingTrait;
 
-use primitives::{Amount, CurrencyId, EstateId, FungibleTokenId, RoundIndex, UndeployedLandBlockId};
+use primitives::{Amount, ClassId, CurrencyId, EstateId, FungibleTokenId, RoundIndex, TokenId, UndeployedLandBlockId};
 
 use crate as mining;
 use crate::Config;
@@ -192,6 +192,13 @@ impl Estate<u128> for EstateHandler {
 	fn is_estate_leased(_estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(false)
 	}
+	fn get_estate_token(_estate_id: EstateId) -> Option<(ClassId, TokenId)> {
+		None
+	}
+
+	fn get_land_unit_token(_metaverse_id: MetaverseId, _coordinate: (i32, i32)) -> Option<(ClassId, TokenId)> {
+		None
+	}
 }
 
 pub struct MetaverseStakingHandler;
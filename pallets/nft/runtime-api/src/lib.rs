@@ -0,0 +1,29 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use primitives::{ClassId, EquippedNft, TokenId};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Queries of NFTs for game clients
+	pub trait NftApi {
+		/// Tree of NFTs equipped into an NFT
+		fn equipped_tree(asset_id: (ClassId, TokenId)) -> Vec<EquippedNft<ClassId, TokenId>>;
+	}
+}
@@ -37,7 +37,7 @@ use sp_std::prelude::*;
 use sp_std::vec;
 
 use primitive_traits::CollectionType;
-use primitives::{AssetId, Balance, ClassId};
+use primitives::{AssetId, Balance, ClassId, FungibleTokenId};
 
 use crate::Call;
 #[allow(unused)]
@@ -71,6 +71,117 @@ fn test_attributes(x: u8) -> Attributes {
 	attr
 }
 
+fn equipment_slot(index: u32) -> Vec<u8> {
+	index.to_le_bytes().to_vec()
+}
+
+// Creates an avatar class 0 with `slots` slots accepting the wearables of class 1, mints an avatar
+// and `slots` wearables, and equips `equipped` wearables into the avatar
+fn setup_equipment<T: Config>(owner: &T::AccountId, slots: u32, equipped: u32) {
+	crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+	let mut avatar_attributes: Attributes = BTreeMap::new();
+	for index in 0..slots {
+		let mut slot_attribute = EQUIPMENT_SLOT_ATTRIBUTE_PREFIX.to_vec();
+		slot_attribute.extend(equipment_slot(index));
+		avatar_attributes.insert(slot_attribute, b"hat".to_vec());
+	}
+	let mut wearable_attributes: Attributes = BTreeMap::new();
+	wearable_attributes.insert(WEARABLE_TYPE_ATTRIBUTE.to_vec(), b"hat".to_vec());
+	crate::Pallet::<T>::create_class(
+		RawOrigin::Signed(owner.clone()).into(),
+		vec![1],
+		avatar_attributes,
+		0u32.into(),
+		TokenType::Transferable,
+		CollectionType::Collectable,
+		Perbill::from_percent(0u32),
+		None,
+	);
+	crate::Pallet::<T>::create_class(
+		RawOrigin::Signed(owner.clone()).into(),
+		vec![1],
+		wearable_attributes,
+		0u32.into(),
+		TokenType::Transferable,
+		CollectionType::Wearable,
+		Perbill::from_percent(0u32),
+		None,
+	);
+	crate::Pallet::<T>::mint(
+		RawOrigin::Signed(owner.clone()).into(),
+		0u32.into(),
+		vec![1],
+		test_attributes(1),
+		1,
+	);
+	for index in 0..slots {
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(owner.clone()).into(),
+			1u32.into(),
+			vec![1],
+			test_attributes(1),
+			1,
+		);
+		if index < equipped {
+			crate::Pallet::<T>::equip(
+				RawOrigin::Signed(owner.clone()).into(),
+				(0u32.into(), 0u32.into()),
+				(1u32.into(), index.into()),
+				equipment_slot(index),
+			);
+		}
+	}
+}
+
+// Creates a stackable class 0, an input class 1 and an output class 2, and creates a recipe with
+// the max number of each ingredient
+fn setup_crafting_recipe<T: Config>(owner: &T::AccountId) {
+	let max_ingredients = T::MaxCraftingIngredients::get();
+	crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+	for _ in 0..3 {
+		crate::Pallet::<T>::create_class(
+			RawOrigin::Signed(owner.clone()).into(),
+			vec![1],
+			test_attributes(1),
+			0u32.into(),
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32),
+			None,
+		);
+	}
+	crate::Pallet::<T>::mint_stackable_nft(
+		RawOrigin::Signed(owner.clone()).into(),
+		0u32.into(),
+		vec![1],
+		test_attributes(1),
+		100u32.into(),
+	);
+
+	let outcome = CraftingOutcome {
+		weight: 1,
+		outputs: vec![
+			CraftingOutput::Nft {
+				class_id: 2u32.into(),
+				metadata: vec![1],
+				attributes: test_attributes(1),
+			};
+			max_ingredients as usize
+		],
+	};
+	crate::Pallet::<T>::create_crafting_recipe(
+		RawOrigin::Signed(owner.clone()).into(),
+		vec![((0u32.into(), 0u32.into()), 1u32.into()); max_ingredients as usize],
+		vec![1u32.into(); max_ingredients as usize],
+		vec![(FungibleTokenId::NativeToken(0), 1u32.into()); max_ingredients as usize],
+		vec![outcome; max_ingredients as usize],
+		None,
+		None,
+		None,
+		None,
+	);
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -197,6 +308,73 @@ benchmarks! {
 		let target = funded_account::<T>("target", 1);
 
 	}: _(RawOrigin::Signed(target), Box::new(mint_data), signature.into(), caller)
+	grant_attribute_updater{
+		let caller = funded_account::<T>("caller", 0);
+		let updater = funded_account::<T>("updater", 0);
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32), None);
+	}: _(RawOrigin::Signed(caller), 0u32.into(), updater, vec![1])
+	revoke_attribute_updater{
+		let caller = funded_account::<T>("caller", 0);
+		let updater = funded_account::<T>("updater", 0);
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32), None);
+		crate::Pallet::<T>::grant_attribute_updater(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), updater.clone(), vec![1]);
+	}: _(RawOrigin::Signed(caller), 0u32.into(), updater, vec![1])
+	freeze_attribute{
+		let caller = funded_account::<T>("caller", 0);
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32), None);
+	}: _(RawOrigin::Signed(caller), 0u32.into(), vec![1])
+	set_class_royalty_recipients{
+		let caller = funded_account::<T>("caller", 0);
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(10u32), None);
+		let recipients: Vec<(T::AccountId, Perbill)> = (0..T::MaxRoyaltyRecipients::get())
+			.map(|index| (account("recipient", index, SEED), Perbill::from_parts(1_000)))
+			.collect();
+	}: _(RawOrigin::Signed(caller), 0u32.into(), recipients)
+	equip{
+		let n in 0 .. T::MaxEquippedItems::get() - 1;
+
+		let caller = funded_account::<T>("caller", 0);
+		setup_equipment::<T>(&caller, n + 1, n);
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), (1u32.into(), n.into()), equipment_slot(n))
+	unequip{
+		let caller = funded_account::<T>("caller", 0);
+		setup_equipment::<T>(&caller, 1, 1);
+	}: _(RawOrigin::Signed(caller), (1u32.into(), 0u32.into()))
+	transfer_equipped_items{
+		let n in 1 .. T::MaxEquippedItems::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let target = funded_account::<T>("target", 0);
+		setup_equipment::<T>(&caller, n, n);
+	}: {
+		crate::Pallet::<T>::transfer_equipped_items(&caller, &target, &(0u32.into(), 0u32.into()))?;
+	}
+	create_crafting_recipe{
+		let caller = funded_account::<T>("caller", 0);
+		setup_crafting_recipe::<T>(&caller);
+		let recipe = crate::Pallet::<T>::crafting_recipes(0).unwrap();
+	}: _(RawOrigin::Signed(caller), recipe.stackable_inputs, recipe.nft_inputs, recipe.fees, recipe.outcomes, None, None, None, None)
+	remove_crafting_recipe{
+		let caller = funded_account::<T>("caller", 0);
+		setup_crafting_recipe::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), 0)
+	craft{
+		let max_ingredients = T::MaxCraftingIngredients::get();
+		let caller = funded_account::<T>("caller", 0);
+		let crafter = funded_account::<T>("crafter", 0);
+		setup_crafting_recipe::<T>(&caller);
+		crate::Pallet::<T>::transfer_stackable_nft(RawOrigin::Signed(caller.clone()).into(), crafter.clone(), (0u32.into(), 0u32.into()), max_ingredients.into());
+		let mut nft_inputs = vec![];
+		for index in 0..max_ingredients {
+			crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 1u32.into(), vec![1], test_attributes(1), 1);
+			crate::Pallet::<T>::transfer(RawOrigin::Signed(caller.clone()).into(), crafter.clone(), (1u32.into(), index.into()));
+			nft_inputs.push((1u32.into(), index.into()));
+		}
+	}: _(RawOrigin::Signed(crafter), 0, nft_inputs)
 }
 
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
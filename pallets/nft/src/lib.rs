@@ -30,18 +30,24 @@ use codec::Encode;
 use frame_support::traits::ExistenceRequirement::KeepAlive;
 use frame_support::traits::Len;
 use frame_support::{
-	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	dispatch::{
+		extract_actual_weight, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo, WithPostDispatchInfo,
+	},
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, Get, LockIdentifier, ReservableCurrency},
+	traits::{
+		Currency, ExistenceRequirement, Get, InstanceFilter, LockIdentifier, OriginTrait, Randomness,
+		ReservableCurrency,
+	},
 	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
 use orml_nft::{ClassInfo, ClassInfoOf, Classes, Pallet as NftModule, TokenInfo, TokenInfoOf, TokenMetadataOf, Tokens};
+use orml_traits::MultiCurrency;
 use sp_runtime::traits::Saturating;
 use sp_runtime::Perbill;
 use sp_runtime::{
-	traits::{AccountIdConversion, One},
+	traits::{AccountIdConversion, Dispatchable, One, TrailingZeroInput, Zero},
 	DispatchError,
 };
 use sp_std::prelude::*;
@@ -49,9 +55,12 @@ use sp_std::vec::Vec;
 
 use auction_manager::{Auction, CheckAuctionItemHandler};
 pub use pallet::*;
+use primitive_traits::{
+	AccountAssetsMigration, AttributeChange, CollectionType, CraftingOutcome, CraftingOutput, CraftingRecipe,
+	NftAssetData, NftClassDataV1, PreSignedMint,
+};
 pub use primitive_traits::{Attributes, NFTTrait, NftClassData, NftGroupCollectionData, NftMetadata, TokenType};
-use primitive_traits::{CollectionType, NftAssetData, NftClassDataV1, PreSignedMint};
-use primitives::{AssetId, ClassId, GroupCollectionId, ItemId, TokenId};
+use primitives::{AssetId, ClassId, CraftingRecipeId, EquippedNft, GroupCollectionId, ItemId, TokenId};
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -65,6 +74,19 @@ mod utils;
 pub mod weights;
 
 const TIMECAPSULE_ID: LockIdentifier = *b"bctimeca";
+/// The current storage version
+const STORAGE_VERSION: frame_support::traits::StorageVersion = frame_support::traits::StorageVersion::new(1);
+/// Prefix of the class attribute defining an equipment slot, the attribute value is the
+/// wearable type accepted by the slot, e.g. `slot:head` => `hat`
+pub const EQUIPMENT_SLOT_ATTRIBUTE_PREFIX: &[u8] = b"slot:";
+/// Class attribute defining the wearable type of a wearable collection
+pub const WEARABLE_TYPE_ATTRIBUTE: &[u8] = b"wearable_type";
+/// Class attribute defining how many times a token of an executable collection can be used
+/// before it is burned, the value is a SCALE encoded `u32`. Tokens are single use by default.
+pub const EXECUTABLE_MAX_USES_ATTRIBUTE: &[u8] = b"max_uses";
+/// Seed of the placeholder account of executable calls, every occurrence of the placeholder
+/// account in the call of an executable collection is replaced by the NFT holder using it
+pub const EXECUTABLE_HOLDER_PLACEHOLDER_SEED: &[u8] = b"executable_holder";
 
 #[derive(codec::Encode, codec::Decode, Clone, RuntimeDebug, PartialEq)]
 pub enum StorageVersion {
@@ -87,6 +109,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::config]
@@ -147,6 +170,44 @@ pub mod pallet {
 		///
 		/// Must identify as an on-chain `Self::AccountId`.
 		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Max number of royalty recipients of a class
+		#[pallet::constant]
+		type MaxRoyaltyRecipients: Get<u32>;
+
+		/// Max depth of NFTs equipped into other NFTs
+		#[pallet::constant]
+		type MaxEquipmentDepth: Get<u32>;
+
+		/// Max number of NFTs equipped into the tree of an NFT
+		#[pallet::constant]
+		type MaxEquippedItems: Get<u32>;
+
+		/// Max number of inputs, fees, outcomes or outputs of a crafting recipe. Crafting calls are
+		/// weighed by a recipe with this number of each.
+		#[pallet::constant]
+		type MaxCraftingIngredients: Get<u32>;
+
+		/// Randomness source used to pick the outcome of crafting recipes. Outcomes are derived
+		/// from the output of this source and public inputs, so with a source such as the
+		/// collective flip they can be predicted in the block they are crafted in, and crafters
+		/// can wait for a favourable block.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Max number of attribute changes kept in the update history of a token
+		#[pallet::constant]
+		type MaxAttributeHistory: Get<u32>;
+
+		/// Max number of attributes of a token, when minted and after its attributes are updated
+		#[pallet::constant]
+		type MaxTokenAttributes: Get<u32>;
+
+		/// The call types filtering the calls executable NFTs can dispatch, the default call type
+		/// applies to collections which were not assigned one
+		type ExecutableCallFilter: Parameter
+			+ Member
+			+ Default
+			+ InstanceFilter<<Self as pallet_proxy::Config>::RuntimeCall>;
 	}
 
 	pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
@@ -161,6 +222,18 @@ pub mod pallet {
 		BlockNumberFor<T>,
 		BalanceOf<T>,
 	>;
+	/// A type alias for crafting recipes.
+	pub(super) type CraftingRecipeOf<T> = CraftingRecipe<
+		<T as frame_system::Config>::AccountId,
+		ClassIdOf<T>,
+		TokenIdOf<T>,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+	>;
+	/// A type alias for the possible outcomes of a crafting recipe.
+	pub(super) type CraftingOutcomeOf<T> = CraftingOutcome<ClassIdOf<T>, TokenIdOf<T>, BalanceOf<T>>;
+	/// A type alias for token attribute changes.
+	pub(super) type AttributeChangeOf<T> = AttributeChange<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_group_collection)]
@@ -218,6 +291,110 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn class_royalty_recipients)]
+	/// Royalty recipients of a class with their share of the sale price
+	pub(super) type ClassRoyaltyRecipients<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClassIdOf<T>,
+		BoundedVec<(T::AccountId, Perbill), T::MaxRoyaltyRecipients>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn stackable_nft_holdings)]
+	/// Stackable NFTs an account received a balance of, used to find the balances to move on
+	/// account recovery. Entries are not removed when the balance is spent.
+	pub(super) type StackableNftHoldings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn equipped_items)]
+	/// Index NFTs equipped into a parent NFT by slot
+	pub(super) type EquippedItems<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		Blake2_128Concat,
+		Vec<u8>,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn equipped_to)]
+	/// The parent NFT and slot an NFT is equipped into
+	pub(super) type EquippedTo<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		((ClassIdOf<T>, TokenIdOf<T>), Vec<u8>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_crafting_recipe_id)]
+	/// Track the next crafting recipe ID
+	pub(super) type NextCraftingRecipeId<T: Config> = StorageValue<_, CraftingRecipeId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn crafting_recipes)]
+	/// Stores crafting recipes
+	pub(super) type CraftingRecipes<T: Config> =
+		StorageMap<_, Twox64Concat, CraftingRecipeId, CraftingRecipeOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn crafts_by_account)]
+	/// Number of crafts of a recipe by account
+	pub(super) type CraftsByAccount<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CraftingRecipeId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn attribute_updaters)]
+	/// Accounts allowed to update an attribute key of the tokens of a class
+	pub(super) type AttributeUpdaters<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, ClassIdOf<T>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, Vec<u8>>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_attributes)]
+	/// Attribute keys of a class that can no longer be updated
+	pub(super) type FrozenAttributes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Blake2_128Concat, Vec<u8>, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn token_attribute_history)]
+	/// Latest attribute changes of a token, oldest first
+	pub(super) type TokenAttributeHistory<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), Vec<AttributeChangeOf<T>>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn executable_uses)]
+	/// Number of times an executable NFT has been used
+	pub(super) type ExecutableUses<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn executable_call_type)]
+	/// Call type of executable collections, filtering the calls their NFTs can dispatch
+	pub(super) type ExecutableCallTypes<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, T::ExecutableCallFilter, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T> {
@@ -328,6 +505,50 @@ pub mod pallet {
 		NftUnlocked(ClassIdOf<T>, TokenIdOf<T>),
 		/// Successfully updated royalty fee
 		ClassRoyaltyFeeUpdated(ClassIdOf<T>, Perbill),
+		/// Successfully updated royalty recipients
+		ClassRoyaltyRecipientsUpdated(ClassIdOf<T>, Vec<(<T as frame_system::Config>::AccountId, Perbill)>),
+		/// NFT equipped into a slot of another NFT [who, parent, child, slot]
+		NftEquipped(
+			<T as frame_system::Config>::AccountId,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			(ClassIdOf<T>, TokenIdOf<T>),
+			Vec<u8>,
+		),
+		/// NFT unequipped from a slot of another NFT [who, parent, child, slot]
+		NftUnequipped(
+			<T as frame_system::Config>::AccountId,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			(ClassIdOf<T>, TokenIdOf<T>),
+			Vec<u8>,
+		),
+		/// Crafting recipe created [recipe_id, owner]
+		CraftingRecipeCreated(CraftingRecipeId, <T as frame_system::Config>::AccountId),
+		/// Crafting recipe removed [recipe_id]
+		CraftingRecipeRemoved(CraftingRecipeId),
+		/// Craft counts of a removed crafting recipe cleared [recipe_id, num_cleared]
+		CraftingRecordsCleared(CraftingRecipeId, u32),
+		/// Recipe crafted [recipe_id, crafter, outcome_index]
+		Crafted(CraftingRecipeId, <T as frame_system::Config>::AccountId, u32),
+		/// Attribute updater granted [class_id, updater, key]
+		AttributeUpdaterGranted(ClassIdOf<T>, <T as frame_system::Config>::AccountId, Vec<u8>),
+		/// Attribute updater revoked [class_id, updater, key]
+		AttributeUpdaterRevoked(ClassIdOf<T>, <T as frame_system::Config>::AccountId, Vec<u8>),
+		/// Attribute key frozen [class_id, key]
+		AttributeFrozen(ClassIdOf<T>, Vec<u8>),
+		/// Token attributes updated [who, asset_id, keys]
+		TokenAttributesUpdated(
+			<T as frame_system::Config>::AccountId,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			Vec<Vec<u8>>,
+		),
+		/// Executable NFT used [who, asset_id, remaining_uses]
+		NftExecuted(
+			<T as frame_system::Config>::AccountId,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			u32,
+		),
+		/// Call type of an executable collection updated [class_id, call_type]
+		ExecutableCallTypeUpdated(ClassIdOf<T>, T::ExecutableCallFilter),
 		// New proxy NFT minted
 		NewProxyNftMinted(
 			(ClassIdOf<T>, TokenIdOf<T>),
@@ -338,6 +559,12 @@ pub mod pallet {
 			TokenIdOf<T>,
 			<T as frame_system::Config>::AccountId,
 		),
+		/// Class ownership moved by account recovery [class_id, from, to]
+		ClassOwnershipMigrated(
+			ClassIdOf<T>,
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::AccountId,
+		),
 	}
 
 	#[pallet::error]
@@ -374,6 +601,8 @@ pub mod pallet {
 		ExceedMaximumBatchMinting,
 		/// Exceed maximum length metadata
 		ExceedMaximumMetadataLength,
+		/// Exceed maximum number of token attributes
+		ExceedMaximumTokenAttributes,
 		/// Error when signing support
 		EmptySupporters,
 		/// Insufficient Balance
@@ -416,6 +645,56 @@ pub mod pallet {
 		SignatureExpired,
 		/// Fail to mint new proxy nft
 		FailToMintProxyNft,
+		/// Exceed maximum number of royalty recipients
+		ExceedMaximumRoyaltyRecipients,
+		/// Royalty recipient is duplicated
+		DuplicateRoyaltyRecipient,
+		/// Total share of royalty recipients exceed the class royalty fee
+		RoyaltySharesExceedRoyaltyFee,
+		/// The class of the parent NFT does not define the slot
+		InvalidEquipmentSlot,
+		/// The NFT is not a wearable of the type accepted by the slot
+		InvalidWearable,
+		/// The slot of the parent NFT is already occupied
+		EquipmentSlotOccupied,
+		/// The NFT is equipped into another NFT
+		NftIsEquipped,
+		/// The NFT is not equipped into another NFT
+		NftIsNotEquipped,
+		/// The NFT has NFTs equipped into it
+		NftHasEquippedItems,
+		/// Exceed maximum depth of equipped NFTs
+		ExceedMaximumEquipmentDepth,
+		/// Exceed maximum number of NFTs equipped into the tree of an NFT
+		ExceedMaximumEquippedItems,
+		/// Crafting recipe is invalid
+		InvalidCraftingRecipe,
+		/// Exceed maximum number of ingredients of a crafting recipe
+		ExceedMaximumCraftingIngredients,
+		/// Crafting recipe does not exist
+		CraftingRecipeNotFound,
+		/// Crafting recipe has not been removed
+		CraftingRecipeNotRemoved,
+		/// Crafting recipe is not active at the current block
+		CraftingRecipeNotActive,
+		/// Crafting limit of the recipe is reached
+		CraftingLimitReached,
+		/// Provided NFTs do not match the inputs of the recipe
+		InvalidCraftingInputs,
+		/// Recipe owner does not hold enough units of a stackable output
+		InsufficientCraftingOutputSupply,
+		/// Attribute key is frozen
+		AttributeIsFrozen,
+		/// Account is not an updater of the attribute key
+		AttributeUpdaterNotFound,
+		/// Exceed maximum number of attribute updates
+		ExceedMaximumAttributeUpdates,
+		/// NFT is not a unique NFT of an executable collection
+		NftIsNotExecutable,
+		/// Executable payload is not an allowed call
+		InvalidExecutablePayload,
+		/// Weight of the executable call exceeds the provided witness
+		ExecutableCallWeightExceeded,
 	}
 
 	#[pallet::call]
@@ -545,6 +824,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
+			Self::ensure_valid_token_attributes(&attributes)?;
 			Self::update_class_total_issuance(&sender, &class_id, 1u32, false)?;
 			// Collect minting deposit
 			let class_fund: T::AccountId = T::Treasury::get().into_account_truncating();
@@ -566,6 +846,7 @@ pub mod pallet {
 				NftModule::<T>::mint_stackable_nft(&sender, class_id, metadata, new_stackable_nft_data, amount);
 			match result {
 				Ok((token_id, _balance)) => {
+					StackableNftHoldings::<T>::insert(&sender, (class_id, token_id), ());
 					Self::deposit_event(Event::<T>::NewStackableNftMinted(sender, class_id, token_id, amount));
 					Ok(().into())
 				}
@@ -580,7 +861,8 @@ pub mod pallet {
 		/// - `asset_id`: the asset (class ID, token ID) that will be transferred
 		///
 		/// Emits `TransferedNft` if successful.
-		#[pallet::weight(<T as Config>::WeightInfo::transfer())]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer()
+			.saturating_add(<T as Config>::WeightInfo::transfer_equipped_items(T::MaxEquippedItems::get())))]
 		#[transactional]
 		pub fn transfer(
 			origin: OriginFor<T>,
@@ -594,9 +876,13 @@ pub mod pallet {
 				Error::<T>::AssetAlreadyInAuction
 			);
 
-			Self::do_transfer(sender, to, asset_id)?;
+			let moved_items = Self::do_transfer_with_equipped_items(&sender, &to, asset_id)?;
 
-			Ok(().into())
+			Ok(Some(
+				<T as Config>::WeightInfo::transfer()
+					.saturating_add(<T as Config>::WeightInfo::transfer_equipped_items(moved_items)),
+			)
+			.into())
 		}
 
 		/// Transfer an existing NFT asset if it is not listed in an auction
@@ -629,6 +915,7 @@ pub mod pallet {
 			let transfer_result = NftModule::<T>::transfer_stackable_nft(&sender, &to, asset_id, amount);
 
 			ensure!(transfer_result.is_ok(), Error::<T>::InvalidStackableNftTransfer);
+			StackableNftHoldings::<T>::insert(&to, asset_id, ());
 
 			Self::deposit_event(Event::<T>::TransferedStackableNft(sender, to, asset_id, amount.into()));
 			Ok(().into())
@@ -642,7 +929,9 @@ pub mod pallet {
 		/// - `tos`: list of assets (class ID, token ID) that will be transferred
 		///
 		/// Emits `TransferedNft` if successful.
-		#[pallet::weight(<T as Config>::WeightInfo::transfer_batch() * tos.len() as u64)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_batch()
+			.saturating_add(<T as Config>::WeightInfo::transfer_equipped_items(T::MaxEquippedItems::get()))
+			.saturating_mul(tos.len() as u64))]
 		#[transactional]
 		pub fn transfer_batch(
 			origin: OriginFor<T>,
@@ -655,19 +944,22 @@ pub mod pallet {
 				Error::<T>::ExceedMaximumBatchTransfer
 			);
 
+			let mut actual_weight = Weight::zero();
 			for (_i, x) in tos.iter().enumerate() {
 				let item = x.clone();
-				let owner = sender.clone();
 
 				ensure!(
 					Self::check_item_on_listing(item.1 .0, item.1 .1)? == false,
 					Error::<T>::AssetAlreadyInAuction
 				);
 
-				Self::do_transfer(owner, item.0, (item.1 .0, item.1 .1))?;
+				let moved_items = Self::do_transfer_with_equipped_items(&sender, &item.0, (item.1 .0, item.1 .1))?;
+				actual_weight = actual_weight
+					.saturating_add(<T as Config>::WeightInfo::transfer_batch())
+					.saturating_add(<T as Config>::WeightInfo::transfer_equipped_items(moved_items));
 			}
 
-			Ok(().into())
+			Ok(Some(actual_weight).into())
 		}
 
 		/// Support an NFT asset with provided contribution amount if not the asset owner
@@ -968,6 +1260,7 @@ pub mod pallet {
 				new_royalty_fee <= Perbill::from_percent(25u32),
 				Error::<T>::RoyaltyFeeExceedLimit
 			);
+			Self::ensure_valid_royalty_recipients(&Self::class_royalty_recipients(class_id), new_royalty_fee)?;
 			// update class total issuance
 			Classes::<T>::try_mutate(class_id.clone(), |class_info| -> DispatchResult {
 				let info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
@@ -1027,10 +1320,396 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Set the royalty recipients of a class. Each recipient is paid its share of the sale
+		/// price directly at settlement, the rest of the royalty fee goes to the class fund.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the class owner can make this call.
+		/// - `class_id`: the class ID of the collection
+		/// - `recipients`: the recipients with their share of the sale price, the total share
+		///   cannot exceed the royalty fee of the class
+		///
+		/// Emits `ClassRoyaltyRecipientsUpdated` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_class_royalty_recipients())]
+		pub fn set_class_royalty_recipients(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			recipients: Vec<(T::AccountId, Perbill)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			let bounded_recipients: BoundedVec<(T::AccountId, Perbill), T::MaxRoyaltyRecipients> = recipients
+				.clone()
+				.try_into()
+				.map_err(|_| Error::<T>::ExceedMaximumRoyaltyRecipients)?;
+			Self::ensure_valid_royalty_recipients(&bounded_recipients, class_info.data.royalty_fee)?;
+
+			if bounded_recipients.is_empty() {
+				ClassRoyaltyRecipients::<T>::remove(class_id);
+			} else {
+				ClassRoyaltyRecipients::<T>::insert(class_id, bounded_recipients);
+			}
+
+			Self::deposit_event(Event::<T>::ClassRoyaltyRecipientsUpdated(class_id, recipients));
+			Ok(())
+		}
+
+		/// Equip an NFT into a slot of another NFT. The slot is defined by the attribute
+		/// `slot:<slot>` of the parent class and accepts wearables which class attribute
+		/// `wearable_type` matches the attribute value. Equipped NFTs move with the parent NFT.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the owner of both NFTs can make this call.
+		/// - `parent`: the NFT to equip into
+		/// - `child`: the wearable NFT to equip
+		/// - `slot`: the slot of the parent NFT
+		///
+		/// Emits `NftEquipped` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::equip(T::MaxEquippedItems::get()))]
+		#[transactional]
+		pub fn equip(
+			origin: OriginFor<T>,
+			parent: (ClassIdOf<T>, TokenIdOf<T>),
+			child: (ClassIdOf<T>, TokenIdOf<T>),
+			slot: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_equip(&who, parent, child, slot)
+		}
+
+		/// Unequip an NFT from its parent NFT
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the owner of the root NFT of the equipped tree can make this call.
+		/// - `child`: the equipped NFT
+		///
+		/// Emits `NftUnequipped` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::unequip())]
+		#[transactional]
+		pub fn unequip(origin: OriginFor<T>, child: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (parent, slot) = Self::equipped_to(child).ok_or(Error::<T>::NftIsNotEquipped)?;
+			let (root, _) = Self::equipment_root(&parent)?;
+			ensure!(Self::check_nft_ownership(&who, &root)?, Error::<T>::NoPermission);
+			ensure!(
+				!Self::check_item_on_listing(root.0, root.1)?,
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			EquippedItems::<T>::remove(parent, slot.clone());
+			EquippedTo::<T>::remove(child);
+
+			Self::deposit_event(Event::<T>::NftUnequipped(who, parent, child, slot));
+			Ok(())
+		}
+
+		/// Create a crafting recipe. Outputs can only be produced in classes owned by the
+		/// caller, stackable outputs are transferred from the caller's balance when crafted.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `stackable_inputs`: stackable NFT amounts paid to the recipe owner, they are not
+		///   burned
+		/// - `nft_inputs`: classes of the unique NFTs consumed, one token of each class is burned
+		/// - `fees`: fungible fees paid to the recipe owner
+		/// - `outcomes`: possible outcomes, one is picked randomly by weight
+		/// - `max_crafts`: optional max number of crafts
+		/// - `max_crafts_per_account`: optional max number of crafts per account
+		/// - `start`: optional block the recipe can be crafted from
+		/// - `end`: optional block the recipe can be crafted until
+		///
+		/// Emits `CraftingRecipeCreated` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::create_crafting_recipe())]
+		#[transactional]
+		pub fn create_crafting_recipe(
+			origin: OriginFor<T>,
+			stackable_inputs: Vec<((ClassIdOf<T>, TokenIdOf<T>), BalanceOf<T>)>,
+			nft_inputs: Vec<ClassIdOf<T>>,
+			fees: Vec<(FungibleTokenId, BalanceOf<T>)>,
+			outcomes: Vec<CraftingOutcomeOf<T>>,
+			max_crafts: Option<u32>,
+			max_crafts_per_account: Option<u32>,
+			start: Option<BlockNumberFor<T>>,
+			end: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let recipe = CraftingRecipe {
+				owner: who.clone(),
+				stackable_inputs,
+				nft_inputs,
+				fees,
+				outcomes,
+				max_crafts,
+				max_crafts_per_account,
+				start,
+				end,
+				total_crafted: 0,
+			};
+			Self::ensure_valid_crafting_recipe(&recipe)?;
+
+			let network_treasury = T::Treasury::get().into_account_truncating();
+			// Transfer storage deposit fee
+			<T as orml_nft::Config>::Currency::transfer(
+				&who,
+				&network_treasury,
+				T::StorageDepositFee::get(),
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			let recipe_id = NextCraftingRecipeId::<T>::try_mutate(|id| -> Result<CraftingRecipeId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+			CraftingRecipes::<T>::insert(recipe_id, recipe);
+
+			Self::deposit_event(Event::<T>::CraftingRecipeCreated(recipe_id, who));
+			Ok(())
+		}
+
+		/// Remove a crafting recipe
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the recipe owner can make this call.
+		/// The craft counts of the recipe are cleared afterwards with `clear_crafting_records`.
+		/// - `recipe_id`: the recipe to remove
+		///
+		/// Emits `CraftingRecipeRemoved` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_crafting_recipe())]
+		pub fn remove_crafting_recipe(origin: OriginFor<T>, recipe_id: CraftingRecipeId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let recipe = Self::crafting_recipes(recipe_id).ok_or(Error::<T>::CraftingRecipeNotFound)?;
+			ensure!(recipe.owner == who, Error::<T>::NoPermission);
+
+			CraftingRecipes::<T>::remove(recipe_id);
+
+			Self::deposit_event(Event::<T>::CraftingRecipeRemoved(recipe_id));
+			Ok(())
+		}
+
+		/// Clear the craft counts by account of a removed crafting recipe
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `recipe_id`: the removed recipe
+		/// - `limit`: the max number of craft counts to clear
+		///
+		/// Emits `CraftingRecordsCleared` if successful.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1 + *limit as u64, *limit as u64))]
+		pub fn clear_crafting_records(origin: OriginFor<T>, recipe_id: CraftingRecipeId, limit: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				!CraftingRecipes::<T>::contains_key(recipe_id),
+				Error::<T>::CraftingRecipeNotRemoved
+			);
+
+			let result = CraftsByAccount::<T>::clear_prefix(recipe_id, limit, None);
+
+			Self::deposit_event(Event::<T>::CraftingRecordsCleared(recipe_id, result.unique));
+			Ok(())
+		}
+
+		/// Craft a recipe, burning its unique NFT inputs, paying its stackable inputs and fees to
+		/// the recipe owner and producing the outputs of one of its outcomes. The outcome is picked
+		/// with the `Randomness` source and is not hidden from the crafter.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `recipe_id`: the recipe to craft
+		/// - `nft_inputs`: the unique NFTs to burn, in the order of the recipe input classes
+		///
+		/// Emits `Crafted` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::craft())]
+		#[transactional]
+		pub fn craft(
+			origin: OriginFor<T>,
+			recipe_id: CraftingRecipeId,
+			nft_inputs: Vec<(ClassIdOf<T>, TokenIdOf<T>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_craft(&who, recipe_id, nft_inputs)
+		}
+
+		/// Grant an account the right to update an attribute key of the tokens of a class
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the class owner can make this call.
+		/// - `class_id`: the class of the tokens
+		/// - `updater`: the account allowed to update the attribute
+		/// - `key`: the attribute key
+		///
+		/// Emits `AttributeUpdaterGranted` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::grant_attribute_updater())]
+		pub fn grant_attribute_updater(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			updater: T::AccountId,
+			key: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_class_owner(&who, class_id)?;
+			ensure!(
+				key.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			ensure!(
+				!FrozenAttributes::<T>::contains_key(class_id, &key),
+				Error::<T>::AttributeIsFrozen
+			);
+
+			AttributeUpdaters::<T>::insert((class_id, updater.clone(), key.clone()), ());
+
+			Self::deposit_event(Event::<T>::AttributeUpdaterGranted(class_id, updater, key));
+			Ok(())
+		}
+
+		/// Revoke the right of an account to update an attribute key of the tokens of a class
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the class owner can make this call.
+		/// - `class_id`: the class of the tokens
+		/// - `updater`: the account to revoke
+		/// - `key`: the attribute key
+		///
+		/// Emits `AttributeUpdaterRevoked` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_attribute_updater())]
+		pub fn revoke_attribute_updater(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			updater: T::AccountId,
+			key: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_class_owner(&who, class_id)?;
+			ensure!(
+				AttributeUpdaters::<T>::contains_key((class_id, updater.clone(), key.clone())),
+				Error::<T>::AttributeUpdaterNotFound
+			);
+
+			AttributeUpdaters::<T>::remove((class_id, updater.clone(), key.clone()));
+
+			Self::deposit_event(Event::<T>::AttributeUpdaterRevoked(class_id, updater, key));
+			Ok(())
+		}
+
+		/// Permanently freeze an attribute key of the tokens of a class
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the class owner can make this call.
+		/// - `class_id`: the class of the tokens
+		/// - `key`: the attribute key to freeze
+		///
+		/// Emits `AttributeFrozen` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::freeze_attribute())]
+		pub fn freeze_attribute(origin: OriginFor<T>, class_id: ClassIdOf<T>, key: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_class_owner(&who, class_id)?;
+			ensure!(
+				key.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			ensure!(
+				!FrozenAttributes::<T>::contains_key(class_id, &key),
+				Error::<T>::AttributeIsFrozen
+			);
+
+			FrozenAttributes::<T>::insert(class_id, key.clone(), ());
+
+			Self::deposit_event(Event::<T>::AttributeFrozen(class_id, key));
+			Ok(())
+		}
+
+		/// Update attributes of a token. The class owner can update any key that is not frozen,
+		/// other accounts only the keys they were granted.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// - `asset_id`: the token to update
+		/// - `updates`: the attribute keys with their new value, `None` removes the attribute
+		///
+		/// Emits `TokenAttributesUpdated` if successful.
+		#[pallet::weight(T::DbWeight::get().reads_writes(3 + updates.len() as u64 * 2, 2))]
+		#[transactional]
+		pub fn update_token_attributes(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			updates: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_update_token_attributes(&who, asset_id, updates)
+		}
+
+		/// Use an executable NFT, dispatching the call of its collection on behalf of the class
+		/// fund, with the placeholder account of the call replaced by the caller. The NFT is
+		/// burned once it reaches the max uses of its collection.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Only the NFT owner can make this call.
+		/// - `asset_id`: the executable NFT to use
+		/// - `call_weight_witness`: upper bound of the weight of the executable call
+		///
+		/// Emits `NftExecuted` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::transfer().saturating_add(*call_weight_witness))]
+		#[transactional]
+		pub fn execute_nft(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			call_weight_witness: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_execute_nft(&who, asset_id, call_weight_witness)
+		}
+
+		/// Set the call type of an executable collection, which the call of the collection must
+		/// be allowed by
+		///
+		/// The dispatch origin for this call must be _Signed_ or _Root_.
+		/// Only the class owner or governance can make this call.
+		/// - `class_id`: the executable collection
+		/// - `call_type`: the new call type
+		///
+		/// Emits `ExecutableCallTypeUpdated` if successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_executable_call_type())]
+		pub fn set_executable_call_type(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			call_type: T::ExecutableCallFilter,
+		) -> DispatchResult {
+			if let Some(who) = frame_system::ensure_signed_or_root(origin)? {
+				Self::ensure_class_owner(&who, class_id)?;
+			}
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			let payload = match class_info.data.collection_type {
+				CollectionType::Executable(payload) => payload,
+				_ => return Err(Error::<T>::NftIsNotExecutable.into()),
+			};
+			Self::decode_executable_call(&payload, &call_type)?;
+
+			ExecutableCallTypes::<T>::insert(class_id, call_type.clone());
+
+			Self::deposit_event(Event::<T>::ExecutableCallTypeUpdated(class_id, call_type));
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Hook that is called every time the runtime is upgraded.
+		fn on_runtime_upgrade() -> Weight {
+			Self::upgrade_stackable_nft_holdings_v1()
+		}
+
 		//		fn on_runtime_upgrade() -> Weight {
 		//			Self::storage_migration_fix_locking_issue();
 		//			Weight::from_parts(0, 0)
@@ -1049,6 +1728,512 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account_truncating(class_id)
 	}
 
+	/// Internal equipping of an NFT into a slot of another NFT
+	fn do_equip(
+		who: &T::AccountId,
+		parent: (ClassIdOf<T>, TokenIdOf<T>),
+		child: (ClassIdOf<T>, TokenIdOf<T>),
+		slot: Vec<u8>,
+	) -> DispatchResult {
+		ensure!(
+			!Self::is_stackable(parent)? && !Self::is_stackable(child)?,
+			Error::<T>::InvalidAssetType
+		);
+		ensure!(!EquippedTo::<T>::contains_key(child), Error::<T>::NftIsEquipped);
+		ensure!(Self::check_nft_ownership(who, &child)?, Error::<T>::NoPermission);
+		ensure!(Self::is_transferable(&child)?, Error::<T>::NonTransferable);
+		ensure!(
+			!Self::check_item_on_listing(child.0, child.1)?,
+			Error::<T>::AssetAlreadyInAuction
+		);
+
+		// Equipping the root of the parent would create a cycle
+		let (root, parent_depth) = Self::equipment_root(&parent)?;
+		ensure!(root != child, Error::<T>::InvalidWearable);
+		ensure!(Self::check_nft_ownership(who, &root)?, Error::<T>::NoPermission);
+		ensure!(
+			!Self::check_item_on_listing(root.0, root.1)?,
+			Error::<T>::AssetAlreadyInAuction
+		);
+		ensure!(
+			parent_depth
+				.saturating_add(1)
+				.saturating_add(Self::equipment_height(&child))
+				<= T::MaxEquipmentDepth::get(),
+			Error::<T>::ExceedMaximumEquipmentDepth
+		);
+		ensure!(
+			Self::equipped_items_count(&root)
+				.saturating_add(1)
+				.saturating_add(Self::equipped_items_count(&child))
+				<= T::MaxEquippedItems::get(),
+			Error::<T>::ExceedMaximumEquippedItems
+		);
+
+		let parent_class = NftModule::<T>::classes(parent.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		let mut slot_attribute = EQUIPMENT_SLOT_ATTRIBUTE_PREFIX.to_vec();
+		slot_attribute.extend(&slot);
+		let accepted_type = parent_class
+			.data
+			.attributes
+			.get(&slot_attribute)
+			.ok_or(Error::<T>::InvalidEquipmentSlot)?;
+
+		let child_class = NftModule::<T>::classes(child.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(
+			child_class.data.collection_type.is_wearable()
+				&& child_class.data.attributes.get(WEARABLE_TYPE_ATTRIBUTE) == Some(accepted_type),
+			Error::<T>::InvalidWearable
+		);
+		ensure!(
+			!EquippedItems::<T>::contains_key(parent, &slot),
+			Error::<T>::EquipmentSlotOccupied
+		);
+
+		EquippedItems::<T>::insert(parent, &slot, child);
+		EquippedTo::<T>::insert(child, (parent, slot.clone()));
+
+		Self::deposit_event(Event::<T>::NftEquipped(who.clone(), parent, child, slot));
+		Ok(())
+	}
+
+	/// Get the root NFT of the equipped tree an NFT is part of, with the depth of the NFT
+	fn equipment_root(
+		asset_id: &(ClassIdOf<T>, TokenIdOf<T>),
+	) -> Result<((ClassIdOf<T>, TokenIdOf<T>), u32), DispatchError> {
+		let mut current = *asset_id;
+		let mut depth: u32 = 0;
+		while let Some((parent, _)) = Self::equipped_to(current) {
+			current = parent;
+			depth = depth.saturating_add(1);
+			ensure!(
+				depth <= T::MaxEquipmentDepth::get(),
+				Error::<T>::ExceedMaximumEquipmentDepth
+			);
+		}
+		Ok((current, depth))
+	}
+
+	/// Get the number of levels of NFTs equipped under an NFT
+	fn equipment_height(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> u32 {
+		EquippedItems::<T>::iter_prefix_values(asset_id)
+			.map(|child| Self::equipment_height(&child).saturating_add(1))
+			.max()
+			.unwrap_or(0)
+	}
+
+	/// Get the number of NFTs equipped into the tree of an NFT
+	fn equipped_items_count(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> u32 {
+		EquippedItems::<T>::iter_prefix_values(asset_id)
+			.map(|child| Self::equipped_items_count(&child).saturating_add(1))
+			.fold(0, |count, items| count.saturating_add(items))
+	}
+
+	/// Move the NFTs equipped into an NFT, and their own equipped NFTs, to the new owner.
+	/// Returns the number of moved NFTs.
+	fn transfer_equipped_items(
+		sender: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: &(ClassIdOf<T>, TokenIdOf<T>),
+	) -> Result<u32, DispatchError> {
+		let mut moved_items: u32 = 0;
+		let children: Vec<(ClassIdOf<T>, TokenIdOf<T>)> = EquippedItems::<T>::iter_prefix_values(asset_id).collect();
+		for child in children {
+			NftModule::<T>::transfer(sender, to, child)?;
+			Self::deposit_event(Event::<T>::TransferedNft(sender.clone(), to.clone(), child.1, child));
+			moved_items = moved_items
+				.saturating_add(1)
+				.saturating_add(Self::transfer_equipped_items(sender, to, &child)?);
+		}
+		Ok(moved_items)
+	}
+
+	/// Get the tree of NFTs equipped into an NFT
+	pub fn equipped_tree(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> Vec<EquippedNft<ClassIdOf<T>, TokenIdOf<T>>> {
+		EquippedItems::<T>::iter_prefix(asset_id)
+			.map(|(slot, child)| EquippedNft {
+				slot,
+				asset: child,
+				children: Self::equipped_tree(&child),
+			})
+			.collect()
+	}
+
+	/// Ensure the ingredients of a crafting recipe are bounded and its outputs are produced in
+	/// classes of the recipe owner
+	fn ensure_valid_crafting_recipe(recipe: &CraftingRecipeOf<T>) -> DispatchResult {
+		let max_ingredients = T::MaxCraftingIngredients::get() as usize;
+		ensure!(
+			recipe.stackable_inputs.len() <= max_ingredients
+				&& recipe.nft_inputs.len() <= max_ingredients
+				&& recipe.fees.len() <= max_ingredients
+				&& recipe.outcomes.len() <= max_ingredients,
+			Error::<T>::ExceedMaximumCraftingIngredients
+		);
+		ensure!(
+			!(recipe.stackable_inputs.is_empty() && recipe.nft_inputs.is_empty()) && !recipe.outcomes.is_empty(),
+			Error::<T>::InvalidCraftingRecipe
+		);
+		if let (Some(start), Some(end)) = (recipe.start, recipe.end) {
+			ensure!(start < end, Error::<T>::InvalidCraftingRecipe);
+		}
+
+		for (asset_id, amount) in recipe.stackable_inputs.iter() {
+			ensure!(
+				Self::is_stackable(*asset_id)? && !amount.is_zero(),
+				Error::<T>::InvalidCraftingRecipe
+			);
+		}
+		for class_id in recipe.nft_inputs.iter() {
+			ensure!(NftModule::<T>::classes(class_id).is_some(), Error::<T>::ClassIdNotFound);
+		}
+
+		for outcome in recipe.outcomes.iter() {
+			ensure!(
+				outcome.weight > 0 && !outcome.outputs.is_empty(),
+				Error::<T>::InvalidCraftingRecipe
+			);
+			ensure!(
+				outcome.outputs.len() <= max_ingredients,
+				Error::<T>::ExceedMaximumCraftingIngredients
+			);
+			for output in outcome.outputs.iter() {
+				let class_id = match output {
+					CraftingOutput::Nft { class_id, metadata, .. } => {
+						ensure!(
+							metadata.len() as u32 <= T::MaxMetadata::get(),
+							Error::<T>::ExceedMaximumMetadataLength
+						);
+						*class_id
+					}
+					CraftingOutput::StackableNft { asset_id, amount } => {
+						ensure!(
+							Self::is_stackable(*asset_id)? && !amount.is_zero(),
+							Error::<T>::InvalidCraftingRecipe
+						);
+						asset_id.0
+					}
+				};
+				let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+				ensure!(class_info.owner == recipe.owner, Error::<T>::NoPermission);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Internal crafting of a recipe
+	fn do_craft(
+		who: &T::AccountId,
+		recipe_id: CraftingRecipeId,
+		nft_inputs: Vec<(ClassIdOf<T>, TokenIdOf<T>)>,
+	) -> DispatchResult {
+		let mut recipe = Self::crafting_recipes(recipe_id).ok_or(Error::<T>::CraftingRecipeNotFound)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			recipe.start.map_or(true, |start| now >= start) && recipe.end.map_or(true, |end| now < end),
+			Error::<T>::CraftingRecipeNotActive
+		);
+		let crafted_by_account = Self::crafts_by_account(recipe_id, who);
+		ensure!(
+			recipe.max_crafts.map_or(true, |limit| recipe.total_crafted < limit)
+				&& recipe
+					.max_crafts_per_account
+					.map_or(true, |limit| crafted_by_account < limit),
+			Error::<T>::CraftingLimitReached
+		);
+
+		// Burn the unique NFT inputs
+		ensure!(
+			nft_inputs.len() == recipe.nft_inputs.len(),
+			Error::<T>::InvalidCraftingInputs
+		);
+		for (asset_id, class_id) in nft_inputs.iter().zip(recipe.nft_inputs.iter()) {
+			ensure!(asset_id.0 == *class_id, Error::<T>::InvalidCraftingInputs);
+			ensure!(!Self::is_stackable(*asset_id)?, Error::<T>::InvalidCraftingInputs);
+			ensure!(Self::check_nft_ownership(who, asset_id)?, Error::<T>::NoPermission);
+			ensure!(Self::is_transferable(asset_id)?, Error::<T>::AssetIsLocked);
+			ensure!(
+				!Self::check_item_on_listing(asset_id.0, asset_id.1)?,
+				Error::<T>::AssetAlreadyInAuction
+			);
+			Self::do_burn(who, asset_id)?;
+		}
+		// Stackable balances can not be burned, the stackable inputs are paid to the recipe owner
+		for (asset_id, amount) in recipe.stackable_inputs.iter() {
+			ensure!(
+				*amount <= Self::get_free_stackable_nft_balance(who, asset_id),
+				Error::<T>::InvalidStackableNftTransfer
+			);
+			NftModule::<T>::transfer_stackable_nft(who, &recipe.owner, *asset_id, *amount)?;
+			StackableNftHoldings::<T>::insert(&recipe.owner, asset_id, ());
+		}
+		for (currency_id, amount) in recipe.fees.iter() {
+			T::MultiCurrency::transfer(*currency_id, who, &recipe.owner, *amount)?;
+		}
+
+		// Produce the outputs of the picked outcome
+		let outcome_index = Self::pick_crafting_outcome(recipe_id, who, &recipe);
+		let outcome = recipe
+			.outcomes
+			.get(outcome_index as usize)
+			.ok_or(Error::<T>::InvalidCraftingRecipe)?;
+		for output in outcome.outputs.iter() {
+			match output {
+				CraftingOutput::Nft {
+					class_id,
+					metadata,
+					attributes,
+				} => {
+					Self::do_mint_nft_with_token_id(
+						who,
+						who,
+						*class_id,
+						None,
+						metadata.clone(),
+						attributes.clone(),
+						false,
+						true,
+					)?;
+				}
+				CraftingOutput::StackableNft { asset_id, amount } => {
+					ensure!(
+						*amount <= Self::get_free_stackable_nft_balance(&recipe.owner, asset_id),
+						Error::<T>::InsufficientCraftingOutputSupply
+					);
+					NftModule::<T>::transfer_stackable_nft(&recipe.owner, who, *asset_id, *amount)?;
+					StackableNftHoldings::<T>::insert(who, asset_id, ());
+				}
+			}
+		}
+
+		recipe.total_crafted = recipe.total_crafted.saturating_add(1);
+		CraftsByAccount::<T>::insert(recipe_id, who, crafted_by_account.saturating_add(1));
+		CraftingRecipes::<T>::insert(recipe_id, recipe);
+
+		Self::deposit_event(Event::<T>::Crafted(recipe_id, who.clone(), outcome_index));
+		Ok(())
+	}
+
+	/// Ensure the account is the owner of the class
+	fn ensure_class_owner(who: &T::AccountId, class_id: ClassIdOf<T>) -> DispatchResult {
+		let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(class_info.owner == *who, Error::<T>::NoPermission);
+		Ok(())
+	}
+
+	/// Ensure the number of attributes of a token is within the max number of token attributes
+	fn ensure_valid_token_attributes(attributes: &Attributes) -> DispatchResult {
+		ensure!(
+			attributes.len() as u32 <= T::MaxTokenAttributes::get(),
+			Error::<T>::ExceedMaximumTokenAttributes
+		);
+		Ok(())
+	}
+
+	/// Internal update of token attributes, recording the changes in the token history
+	fn do_update_token_attributes(
+		who: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+		updates: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+	) -> DispatchResult {
+		let max_history = T::MaxAttributeHistory::get() as usize;
+		ensure!(
+			!updates.is_empty() && updates.len() <= max_history,
+			Error::<T>::ExceedMaximumAttributeUpdates
+		);
+
+		let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		let is_class_owner = class_info.owner == *who;
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let mut changes: Vec<AttributeChangeOf<T>> = Vec::new();
+		Tokens::<T>::try_mutate(asset_id.0, asset_id.1, |token_info| -> DispatchResult {
+			let token = token_info.as_mut().ok_or(Error::<T>::AssetInfoNotFound)?;
+
+			for (key, value) in updates.into_iter() {
+				ensure!(
+					key.len() as u32 <= T::MaxMetadata::get()
+						&& value.as_ref().map_or(true, |v| v.len() as u32 <= T::MaxMetadata::get()),
+					Error::<T>::ExceedMaximumMetadataLength
+				);
+				ensure!(
+					!FrozenAttributes::<T>::contains_key(asset_id.0, &key),
+					Error::<T>::AttributeIsFrozen
+				);
+				ensure!(
+					is_class_owner || AttributeUpdaters::<T>::contains_key((asset_id.0, who.clone(), key.clone())),
+					Error::<T>::NoPermission
+				);
+
+				let old_value = match &value {
+					Some(new_value) => token.data.attributes.insert(key.clone(), new_value.clone()),
+					None => token.data.attributes.remove(&key),
+				};
+				changes.push(AttributeChange {
+					key,
+					old_value,
+					new_value: value,
+					updated_by: who.clone(),
+					block_number: now,
+				});
+			}
+			Self::ensure_valid_token_attributes(&token.data.attributes)
+		})?;
+
+		let keys: Vec<Vec<u8>> = changes.iter().map(|change| change.key.clone()).collect();
+		TokenAttributeHistory::<T>::mutate(asset_id, |history| {
+			history.extend(changes);
+			if history.len() > max_history {
+				let overflow = history.len() - max_history;
+				history.drain(..overflow);
+			}
+		});
+
+		Self::deposit_event(Event::<T>::TokenAttributesUpdated(who.clone(), asset_id, keys));
+		Ok(())
+	}
+
+	/// Decode an executable payload, ensuring it is a call allowed by the given call type
+	fn decode_executable_call(
+		payload: &[u8],
+		call_type: &T::ExecutableCallFilter,
+	) -> Result<<T as pallet_proxy::Config>::RuntimeCall, DispatchError> {
+		let call = <T as pallet_proxy::Config>::RuntimeCall::decode(&mut &payload[..])
+			.map_err(|_| Error::<T>::InvalidExecutablePayload)?;
+		ensure!(call_type.filter(&call), Error::<T>::InvalidExecutablePayload);
+		Ok(call)
+	}
+
+	/// Get the placeholder account of executable calls
+	pub fn executable_holder_placeholder() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(EXECUTABLE_HOLDER_PLACEHOLDER_SEED)
+	}
+
+	/// Fill the call template of an executable collection, replacing the placeholder account by
+	/// the NFT holder
+	fn fill_executable_call_template(payload: &[u8], holder: &T::AccountId) -> Vec<u8> {
+		let placeholder = Self::executable_holder_placeholder().encode();
+		let holder = holder.encode();
+		let mut filled = Vec::with_capacity(payload.len());
+		let mut index = 0;
+		while index < payload.len() {
+			if payload[index..].starts_with(&placeholder) {
+				filled.extend_from_slice(&holder);
+				index = index.saturating_add(placeholder.len());
+			} else {
+				filled.push(payload[index]);
+				index = index.saturating_add(1);
+			}
+		}
+		filled
+	}
+
+	/// Internal use of an executable NFT
+	fn do_execute_nft(
+		who: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+		call_weight_witness: Weight,
+	) -> DispatchResultWithPostInfo {
+		let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		let payload = match class_info.data.collection_type {
+			CollectionType::Executable(payload) => payload,
+			_ => return Err(Error::<T>::NftIsNotExecutable.into()),
+		};
+		ensure!(!Self::is_stackable(asset_id)?, Error::<T>::NftIsNotExecutable);
+		let token_info = NftModule::<T>::tokens(asset_id.0, asset_id.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+		ensure!(token_info.owner == *who, Error::<T>::NoPermission);
+		ensure!(!token_info.data.is_locked, Error::<T>::AssetIsLocked);
+		ensure!(
+			!Self::check_item_on_listing(asset_id.0, asset_id.1)?,
+			Error::<T>::AssetAlreadyInAuction
+		);
+
+		let call_type = Self::executable_call_type(asset_id.0);
+		let call = Self::decode_executable_call(&Self::fill_executable_call_template(&payload, who), &call_type)?;
+		let call_info = call.get_dispatch_info();
+		ensure!(
+			call_info.weight.all_lte(call_weight_witness),
+			Error::<T>::ExecutableCallWeightExceeded
+		);
+
+		// Consume a use, burning the NFT after its last use
+		let max_uses = class_info
+			.data
+			.attributes
+			.get(EXECUTABLE_MAX_USES_ATTRIBUTE)
+			.and_then(|value| u32::decode(&mut &value[..]).ok())
+			.unwrap_or(1);
+		let uses = Self::executable_uses(asset_id).saturating_add(1);
+		if uses >= max_uses {
+			Self::do_burn(who, &asset_id)?;
+		} else {
+			ExecutableUses::<T>::insert(asset_id, uses);
+		}
+
+		// Dispatch on behalf of the class fund, restricted to the allowed calls
+		let mut origin: <T as frame_system::Config>::RuntimeOrigin =
+			frame_system::RawOrigin::Signed(Self::get_class_fund(&asset_id.0)).into();
+		origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
+			let c = <T as pallet_proxy::Config>::RuntimeCall::from_ref(c);
+			call_type.filter(c)
+		});
+		let result = call.dispatch(origin);
+		let actual_weight =
+			<T as Config>::WeightInfo::transfer().saturating_add(extract_actual_weight(&result, &call_info));
+		result.map_err(|e| e.error.with_weight(actual_weight))?;
+
+		Self::deposit_event(Event::<T>::NftExecuted(
+			who.clone(),
+			asset_id,
+			max_uses.saturating_sub(uses),
+		));
+		Ok(Some(actual_weight).into())
+	}
+
+	/// Pick the outcome of a craft by weight using the randomness source. The subject is public
+	/// so the outcome is as predictable as the randomness source output.
+	fn pick_crafting_outcome(recipe_id: CraftingRecipeId, who: &T::AccountId, recipe: &CraftingRecipeOf<T>) -> u32 {
+		if recipe.outcomes.len() <= 1 {
+			return 0;
+		}
+
+		let subject = (b"nft/craft", recipe_id, who, recipe.total_crafted).encode();
+		let (random_seed, _) = T::Randomness::random(&subject);
+		let random_number = <u32>::decode(&mut TrailingZeroInput::new(random_seed.as_ref())).unwrap_or_default();
+
+		let total_weight = recipe
+			.outcomes
+			.iter()
+			.fold(0u32, |total, outcome| total.saturating_add(outcome.weight));
+		let mut roll = random_number % total_weight.max(1);
+		for (index, outcome) in recipe.outcomes.iter().enumerate() {
+			if roll < outcome.weight {
+				return index as u32;
+			}
+			roll = roll.saturating_sub(outcome.weight);
+		}
+		0
+	}
+
+	/// Ensure royalty recipients are unique and their total share is within the royalty fee
+	fn ensure_valid_royalty_recipients(recipients: &[(T::AccountId, Perbill)], royalty_fee: Perbill) -> DispatchResult {
+		let mut total_share: u64 = 0;
+		for (index, (recipient, share)) in recipients.iter().enumerate() {
+			ensure!(
+				!recipients[..index].iter().any(|(account, _)| account == recipient),
+				Error::<T>::DuplicateRoyaltyRecipient
+			);
+			total_share = total_share.saturating_add(share.deconstruct() as u64);
+		}
+		ensure!(
+			total_share <= royalty_fee.deconstruct() as u64,
+			Error::<T>::RoyaltySharesExceedRoyaltyFee
+		);
+		Ok(())
+	}
+
 	/// Internal creation of group collection
 	fn do_create_group_collection(name: Vec<u8>, properties: Vec<u8>) -> Result<GroupCollectionId, DispatchError> {
 		let next_group_collection_id =
@@ -1075,9 +2260,20 @@ impl<T: Config> Pallet<T> {
 		to: T::AccountId,
 		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
 	) -> Result<<T as orml_nft::Config>::TokenId, DispatchError> {
+		Self::do_transfer_with_equipped_items(&sender, &to, asset_id)?;
+		Ok(asset_id.1)
+	}
+
+	/// Transfer an NFT with the NFTs equipped into it. Returns the number of moved equipped NFTs.
+	fn do_transfer_with_equipped_items(
+		sender: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> Result<u32, DispatchError> {
 		ensure!(!Self::is_collection_locked(&asset_id.0), Error::<T>::CollectionIsLocked);
 
 		ensure!(!Self::is_stackable(asset_id)?, Error::<T>::InvalidAssetType);
+		ensure!(!EquippedTo::<T>::contains_key(asset_id), Error::<T>::NftIsEquipped);
 
 		let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
@@ -1087,10 +2283,11 @@ impl<T: Config> Pallet<T> {
 
 		match data.token_type {
 			TokenType::Transferable => {
-				let check_ownership = Self::check_nft_ownership(&sender, &asset_id)?;
+				let check_ownership = Self::check_nft_ownership(sender, &asset_id)?;
 				ensure!(check_ownership, Error::<T>::NoPermission);
 
-				NftModule::<T>::transfer(&sender, &to, asset_id.clone())?;
+				NftModule::<T>::transfer(sender, to, asset_id.clone())?;
+				let moved_items = Self::transfer_equipped_items(sender, to, &asset_id)?;
 
 				Self::deposit_event(Event::<T>::TransferedNft(
 					sender.clone(),
@@ -1098,19 +2295,20 @@ impl<T: Config> Pallet<T> {
 					asset_id.1,
 					asset_id.clone(),
 				));
-				Ok(asset_id.1)
+				Ok(moved_items)
 			}
 			// Only allowed collection owner to transfer
 			TokenType::BoundToAddress => {
-				ensure!(class_info.owner == sender, Error::<T>::NonTransferable);
-				NftModule::<T>::transfer(&sender, &to, asset_id.clone())?;
+				ensure!(&class_info.owner == sender, Error::<T>::NonTransferable);
+				NftModule::<T>::transfer(sender, to, asset_id.clone())?;
+				let moved_items = Self::transfer_equipped_items(sender, to, &asset_id)?;
 				Self::deposit_event(Event::<T>::TransferedNft(
 					sender.clone(),
 					to.clone(),
 					asset_id.1,
 					asset_id.clone(),
 				));
-				Ok(asset_id.1)
+				Ok(moved_items)
 			}
 		}
 	}
@@ -1134,6 +2332,25 @@ impl<T: Config> Pallet<T> {
 		return is_locked;
 	}
 
+	/// Internal transfer NFT only for account recovery. Moves the owner record of an NFT and the
+	/// NFTs equipped into it without the lock and transferability checks, so locked and soulbound
+	/// NFTs can be recovered.
+	fn do_recovery_transfer(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		NftModule::<T>::transfer(from, to, asset_id)?;
+		Self::transfer_equipped_items(from, to, &asset_id)?;
+		Self::deposit_event(Event::<T>::TransferedNft(
+			from.clone(),
+			to.clone(),
+			asset_id.1,
+			asset_id,
+		));
+		Ok(())
+	}
+
 	/// Internal force transfer NFT only for governance override action
 	fn do_force_transfer(
 		sender: &T::AccountId,
@@ -1165,6 +2382,7 @@ impl<T: Config> Pallet<T> {
 			metadata.len() as u32 <= T::MaxMetadata::get(),
 			Error::<T>::ExceedMaximumMetadataLength
 		);
+		Self::ensure_valid_token_attributes(&attributes)?;
 
 		// Update class total issuance
 		Self::update_class_total_issuance(&sender, &class_id, quantity, false)?;
@@ -1220,6 +2438,7 @@ impl<T: Config> Pallet<T> {
 			metadata.len() as u32 <= T::MaxMetadata::get(),
 			Error::<T>::ExceedMaximumMetadataLength
 		);
+		Self::ensure_valid_token_attributes(&attributes)?;
 
 		// Update class total issuance
 		Self::update_class_total_issuance(&sender, &class_id, 1u32, is_pre_signed_mint)?;
@@ -1373,6 +2592,11 @@ impl<T: Config> Pallet<T> {
 			GroupCollections::<T>::contains_key(collection_id),
 			Error::<T>::CollectionDoesNotExist
 		);
+		// The call of an executable collection is checked against its call type once that is set
+		if let CollectionType::Executable(payload) = &collection_type {
+			<T as pallet_proxy::Config>::RuntimeCall::decode(&mut &payload[..])
+				.map_err(|_| Error::<T>::InvalidExecutablePayload)?;
+		}
 
 		ensure!(
 			royalty_fee <= Perbill::from_percent(25u32),
@@ -1422,7 +2646,14 @@ impl<T: Config> Pallet<T> {
 
 	/// Internal NFT burning
 	fn do_burn(sender: &T::AccountId, asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		ensure!(!EquippedTo::<T>::contains_key(asset_id), Error::<T>::NftIsEquipped);
+		ensure!(
+			EquippedItems::<T>::iter_prefix_values(asset_id).next().is_none(),
+			Error::<T>::NftHasEquippedItems
+		);
 		NftModule::<T>::burn(&sender, *asset_id)?;
+		TokenAttributeHistory::<T>::remove(asset_id);
+		ExecutableUses::<T>::remove(asset_id);
 		Ok(())
 	}
 
@@ -1462,6 +2693,24 @@ impl<T: Config> Pallet<T> {
 		total_minted_tokens
 	}
 
+	/// Index the holders of stackable NFT balances
+	pub fn upgrade_stackable_nft_holdings_v1() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!("Start indexing stackable nft holdings");
+		let mut num_holdings: u64 = 0;
+		for ((class_id, token_id, owner), _) in orml_nft::StackableCollectionsBalances::<T>::iter() {
+			StackableNftHoldings::<T>::insert(owner, (class_id, token_id), ());
+			num_holdings += 1;
+		}
+		STORAGE_VERSION.put::<Pallet<T>>();
+		log::info!("{} stackable nft holdings indexed", num_holdings);
+
+		T::DbWeight::get().reads_writes(num_holdings + 1, num_holdings + 1)
+	}
+
 	/// Upgrading NFT class data
 	pub fn upgrade_class_data_v2() -> Weight {
 		log::info!("Start upgrading nft class data v2");
@@ -1619,6 +2868,7 @@ impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 			metadata.len() as u32 <= T::MaxMetadata::get(),
 			Error::<T>::ExceedMaximumMetadataLength
 		);
+		Self::ensure_valid_token_attributes(&attributes)?;
 
 		let class_fund: T::AccountId = T::Treasury::get().into_account_truncating();
 		let deposit = T::AssetMintingFee::get().saturating_mul(Into::<BalanceOf<T>>::into(1u32));
@@ -1673,7 +2923,7 @@ impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 		let token = NftModule::<T>::tokens(nft.0, nft.1).ok_or(Error::<T>::AssetInfoNotFound)?;
 		let token_data = token.data;
 
-		Ok(data.token_type.is_transferable() && !token_data.is_locked)
+		Ok(data.token_type.is_transferable() && !token_data.is_locked && !EquippedTo::<T>::contains_key(nft))
 	}
 
 	fn get_class_fund(class_id: &Self::ClassId) -> T::AccountId {
@@ -1762,8 +3012,101 @@ impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 		asset_id: &(Self::ClassId, Self::TokenId),
 		amount: BalanceOf<T>,
 	) -> DispatchResult {
-		NftModule::<T>::transfer_stackable_nft(&sender, &to, *asset_id, amount);
+		NftModule::<T>::transfer_stackable_nft(&sender, &to, *asset_id, amount)?;
+		StackableNftHoldings::<T>::insert(to, asset_id, ());
 
 		Ok(())
 	}
+
+	fn get_royalty_payouts(
+		class_id: &Self::ClassId,
+		sale_price: BalanceOf<T>,
+	) -> Result<Vec<(T::AccountId, BalanceOf<T>)>, DispatchError> {
+		let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		let mut remaining_royalty: BalanceOf<T> = class_info.data.royalty_fee * sale_price;
+
+		let mut payouts: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+		for (recipient, share) in Self::class_royalty_recipients(class_id) {
+			let amount = (share * sale_price).min(remaining_royalty);
+			remaining_royalty = remaining_royalty.saturating_sub(amount);
+			payouts.push((recipient, amount));
+		}
+		// The rest of the royalty goes to the class fund
+		payouts.push((Self::get_class_fund(class_id), remaining_royalty));
+
+		Ok(payouts)
+	}
+}
+
+impl<T: Config> AccountAssetsMigration<T::AccountId> for Pallet<T> {
+	/// Moves at most `limit` NFTs, stackable NFT balances and classes of `from` to `to`. Locked,
+	/// non-transferable NFTs and NFTs of locked collections are moved as well, only listed NFTs
+	/// fail the migration. An equipped NFT moves with the NFT it is equipped into. Classes have no
+	/// owner index, so finding the classes of `from` reads every class.
+	fn migrate_account_assets(from: &T::AccountId, to: &T::AccountId, limit: u32) -> Result<u32, DispatchError> {
+		let mut migrated: u32 = 0;
+
+		let owned_assets: Vec<(ClassIdOf<T>, TokenIdOf<T>)> = orml_nft::TokensByOwner::<T>::iter_key_prefix((from,))
+			.take(limit as usize)
+			.collect();
+		for asset_id in owned_assets.iter() {
+			migrated = migrated.saturating_add(1);
+			// Already moved with the NFT it is equipped into
+			if !Self::check_nft_ownership(from, asset_id)? {
+				continue;
+			}
+			if Self::is_stackable(*asset_id)? {
+				// Only the token record of a stackable NFT is owned, its balances move below
+				NftModule::<T>::transfer(from, to, *asset_id)?;
+				continue;
+			}
+			let (root, _) = Self::equipment_root(asset_id)?;
+			ensure!(
+				!Self::check_item_on_listing(root.0, root.1)?,
+				Error::<T>::AssetAlreadyInAuction
+			);
+			Self::do_recovery_transfer(from, to, root)?;
+		}
+
+		let holdings: Vec<(ClassIdOf<T>, TokenIdOf<T>)> = StackableNftHoldings::<T>::iter_key_prefix(from)
+			.take(limit.saturating_sub(migrated) as usize)
+			.collect();
+		for asset_id in holdings.iter() {
+			migrated = migrated.saturating_add(1);
+			ensure!(
+				Self::reserved_stackable_nft_balances(from, asset_id).is_zero(),
+				Error::<T>::AssetAlreadyInAuction
+			);
+			StackableNftHoldings::<T>::remove(from, asset_id);
+			let amount = NftModule::<T>::get_stackable_collections_balances((asset_id.0, asset_id.1, from));
+			if amount.is_zero() {
+				continue;
+			}
+			NftModule::<T>::transfer_stackable_nft(from, to, *asset_id, amount)?;
+			StackableNftHoldings::<T>::insert(to, asset_id, ());
+			Self::deposit_event(Event::<T>::TransferedStackableNft(
+				from.clone(),
+				to.clone(),
+				*asset_id,
+				amount,
+			));
+		}
+
+		let owned_classes: Vec<ClassIdOf<T>> = Classes::<T>::iter()
+			.filter(|(_, class_info)| class_info.owner == *from)
+			.map(|(class_id, _)| class_id)
+			.take(limit.saturating_sub(migrated) as usize)
+			.collect();
+		for class_id in owned_classes {
+			migrated = migrated.saturating_add(1);
+			Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+				let info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+				info.owner = to.clone();
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::ClassOwnershipMigrated(class_id, from.clone(), to.clone()));
+		}
+
+		Ok(migrated)
+	}
 }
@@ -3,6 +3,7 @@
 use codec::Encode;
 use frame_support::traits::{Contains, EqualPrivilegeOnly, InstanceFilter, Nothing};
 use frame_support::{construct_runtime, parameter_types};
+use frame_system::pallet_prelude::BlockNumberFor;
 use frame_system::Call as SystemCall;
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
@@ -149,6 +150,24 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn sealed_bid_commit_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_commitment: [u8; 32],
+		_deposit: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn sealed_bid_reveal_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_value: Self::Balance,
+		_salt: Vec<u8>,
+	) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
 	fn local_auction_bid_handler(
 		_: BlockNumber,
 		_: u64,
@@ -186,11 +205,27 @@ parameter_types! {
 	pub NftPalletId: PalletId = PalletId(*b"bit/bNFT");
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 12;
+	pub MaxRoyaltyRecipients: u32 = 3;
+	pub MaxEquipmentDepth: u32 = 3;
+	pub MaxEquippedItems: u32 = 3;
+	pub MaxCraftingIngredients: u32 = 5;
+	pub MaxAttributeHistory: u32 = 3;
+	pub MaxTokenAttributes: u32 = 3;
 	pub MaxMetadata: u32 = 10;
 	pub const MetaverseTreasuryPalletId: PalletId = PalletId(*b"bit/trsy");
 	pub TreasuryModuleAccount: AccountId = MetaverseTreasuryPalletId::get().into_account_truncating();
 }
 
+pub struct MockRandomness;
+impl frame_support::traits::Randomness<H256, BlockNumberFor<Runtime>> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumberFor<Runtime>) {
+		(
+			<BlakeTwo256 as sp_runtime::traits::Hash>::hash(subject),
+			frame_system::Pallet::<Runtime>::block_number(),
+		)
+	}
+}
+
 impl orml_tokens::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -279,6 +314,29 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 		self == &ProxyType::Any || self == o
 	}
 }
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum ExecutableNftCallType {
+	JustTransfer,
+	JustRemark,
+}
+impl Default for ExecutableNftCallType {
+	fn default() -> Self {
+		Self::JustTransfer
+	}
+}
+impl InstanceFilter<RuntimeCall> for ExecutableNftCallType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ExecutableNftCallType::JustTransfer => {
+				matches!(c, RuntimeCall::Balances(pallet_balances::Call::transfer { .. }))
+			}
+			ExecutableNftCallType::JustRemark => matches!(c, RuntimeCall::System(frame_system::Call::remark { .. })),
+		}
+	}
+	fn is_superset(&self, o: &Self) -> bool {
+		self == o
+	}
+}
 pub struct BaseFilter;
 impl Contains<RuntimeCall> for BaseFilter {
 	fn contains(c: &RuntimeCall) -> bool {
@@ -323,6 +381,14 @@ impl Config for Runtime {
 	type StorageDepositFee = StorageDepositFee;
 	type OffchainSignature = Signature;
 	type OffchainPublic = AccountPublic;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxEquipmentDepth = MaxEquipmentDepth;
+	type MaxEquippedItems = MaxEquippedItems;
+	type MaxCraftingIngredients = MaxCraftingIngredients;
+	type Randomness = MockRandomness;
+	type MaxAttributeHistory = MaxAttributeHistory;
+	type MaxTokenAttributes = MaxTokenAttributes;
+	type ExecutableCallFilter = ExecutableNftCallType;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -8,7 +8,7 @@ use sp_std::collections::btree_map::BTreeMap;
 use sp_std::default::Default;
 
 use mock::*;
-use primitives::{Balance, FungibleTokenId};
+use primitives::{Balance, EquippedNft, FungibleTokenId};
 
 #[cfg(test)]
 use super::*;
@@ -983,6 +983,204 @@ fn force_updating_new_royal_fee_should_fail() {
 	})
 }
 
+#[test]
+fn set_class_royalty_recipients_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = RuntimeOrigin::signed(account(1));
+		assert_ok!(Nft::create_group(RuntimeOrigin::root(), vec![1], vec![1],));
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(10u32),
+			None
+		));
+
+		let recipients = vec![
+			(account(1), Perbill::from_percent(5u32)),
+			(account(2), Perbill::from_percent(3u32)),
+		];
+
+		// Only the class owner can set the recipients
+		assert_noop!(
+			Nft::set_class_royalty_recipients(RuntimeOrigin::signed(account(2)), CLASS_ID, recipients.clone()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::set_class_royalty_recipients(
+				origin.clone(),
+				CLASS_ID,
+				vec![
+					(account(1), Perbill::from_percent(5u32)),
+					(account(1), Perbill::from_percent(3u32)),
+				]
+			),
+			Error::<Runtime>::DuplicateRoyaltyRecipient
+		);
+		assert_noop!(
+			Nft::set_class_royalty_recipients(
+				origin.clone(),
+				CLASS_ID,
+				vec![
+					(account(1), Perbill::from_percent(5u32)),
+					(account(2), Perbill::from_percent(6u32)),
+				]
+			),
+			Error::<Runtime>::RoyaltySharesExceedRoyaltyFee
+		);
+
+		assert_ok!(Nft::set_class_royalty_recipients(
+			origin.clone(),
+			CLASS_ID,
+			recipients.clone()
+		));
+		assert_eq!(Nft::class_royalty_recipients(CLASS_ID), recipients.clone());
+		assert_eq!(
+			last_event(),
+			mock::RuntimeEvent::Nft(crate::Event::ClassRoyaltyRecipientsUpdated(CLASS_ID, recipients))
+		);
+
+		// The remaining 2% of the royalty goes to the class fund
+		assert_eq!(
+			Nft::get_royalty_payouts(&CLASS_ID, 1000),
+			Ok(vec![
+				(account(1), 50),
+				(account(2), 30),
+				(Nft::get_class_fund(&CLASS_ID), 20)
+			])
+		);
+
+		// Royalty fee can not be lowered below the total share of the recipients
+		assert_noop!(
+			Nft::force_update_royalty_fee(RuntimeOrigin::root(), CLASS_ID, Perbill::from_percent(5u32)),
+			Error::<Runtime>::RoyaltySharesExceedRoyaltyFee
+		);
+	})
+}
+
+fn init_equipment_class(owner: RuntimeOrigin, attribute: (&[u8], &[u8]), collection_type: CollectionType) {
+	let mut attributes: Attributes = BTreeMap::new();
+	attributes.insert(attribute.0.to_vec(), attribute.1.to_vec());
+	assert_ok!(Nft::create_class(
+		owner.clone(),
+		vec![1],
+		attributes,
+		COLLECTION_ID,
+		TokenType::Transferable,
+		collection_type,
+		Perbill::from_percent(0u32),
+		None
+	));
+}
+
+#[test]
+fn equip_and_unequip_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(account(1));
+		assert_ok!(Nft::create_group(RuntimeOrigin::root(), vec![1], vec![1],));
+		init_equipment_class(owner.clone(), (b"slot:head", b"hat"), CollectionType::Collectable);
+		init_equipment_class(owner.clone(), (b"wearable_type", b"hat"), CollectionType::Wearable);
+		init_equipment_class(owner.clone(), (b"wearable_type", b"cape"), CollectionType::Wearable);
+		for class_id in 0..3 {
+			assert_ok!(Nft::mint(owner.clone(), class_id, vec![1], test_attributes(1), 1));
+		}
+		let (avatar, hat, cape) = ((0, 0), (1, 0), (2, 0));
+
+		assert_noop!(
+			Nft::equip(owner.clone(), avatar, hat, b"body".to_vec()),
+			Error::<Runtime>::InvalidEquipmentSlot
+		);
+		assert_noop!(
+			Nft::equip(owner.clone(), avatar, cape, b"head".to_vec()),
+			Error::<Runtime>::InvalidWearable
+		);
+		assert_noop!(
+			Nft::equip(RuntimeOrigin::signed(account(2)), avatar, hat, b"head".to_vec()),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(Nft::equip(owner.clone(), avatar, hat, b"head".to_vec()));
+		assert_eq!(
+			last_event(),
+			mock::RuntimeEvent::Nft(crate::Event::NftEquipped(account(1), avatar, hat, b"head".to_vec()))
+		);
+		assert_eq!(
+			Nft::equipped_tree(&avatar),
+			vec![EquippedNft {
+				slot: b"head".to_vec(),
+				asset: hat,
+				children: vec![],
+			}]
+		);
+
+		// Equipped NFT is locked from separate transfer and sale
+		assert_noop!(
+			Nft::transfer(owner.clone(), account(2), hat),
+			Error::<Runtime>::NftIsEquipped
+		);
+		assert_eq!(Nft::is_transferable(&hat), Ok(false));
+
+		// Equipped NFT moves with the parent
+		assert_ok!(Nft::transfer(owner.clone(), account(2), avatar));
+		assert_eq!(Nft::check_nft_ownership(&account(2), &hat), Ok(true));
+
+		// Only the root owner can unequip
+		assert_noop!(Nft::unequip(owner.clone(), hat), Error::<Runtime>::NoPermission);
+		assert_ok!(Nft::unequip(RuntimeOrigin::signed(account(2)), hat));
+		assert_eq!(Nft::equipped_tree(&avatar), vec![]);
+		assert_ok!(Nft::transfer(RuntimeOrigin::signed(account(2)), account(1), hat));
+	})
+}
+
+#[test]
+fn equip_should_limit_equipped_items_and_transfer_should_refund_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(account(1));
+		assert_ok!(Nft::create_group(RuntimeOrigin::root(), vec![1], vec![1],));
+		let mut attributes: Attributes = BTreeMap::new();
+		for slot in [b"head", b"body", b"feet", b"hand"] {
+			attributes.insert(
+				[EQUIPMENT_SLOT_ATTRIBUTE_PREFIX, slot.as_slice()].concat(),
+				b"hat".to_vec(),
+			);
+		}
+		assert_ok!(Nft::create_class(
+			owner.clone(),
+			vec![1],
+			attributes,
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32),
+			None
+		));
+		init_equipment_class(owner.clone(), (b"wearable_type", b"hat"), CollectionType::Wearable);
+		assert_ok!(Nft::mint(owner.clone(), 0, vec![1], test_attributes(1), 1));
+		assert_ok!(Nft::mint(owner.clone(), 1, vec![1], test_attributes(1), 4));
+		let avatar = (0, 0);
+
+		assert_ok!(Nft::equip(owner.clone(), avatar, (1, 0), b"head".to_vec()));
+		assert_ok!(Nft::equip(owner.clone(), avatar, (1, 1), b"body".to_vec()));
+		assert_ok!(Nft::equip(owner.clone(), avatar, (1, 2), b"feet".to_vec()));
+		// MaxEquippedItems is 3 in the mock
+		assert_noop!(
+			Nft::equip(owner.clone(), avatar, (1, 3), b"hand".to_vec()),
+			Error::<Runtime>::ExceedMaximumEquippedItems
+		);
+
+		// Only the moved equipped NFTs are charged
+		let post_info = Nft::transfer(owner.clone(), account(2), avatar).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::transfer().saturating_add(<() as WeightInfo>::transfer_equipped_items(3)))
+		);
+		assert_eq!(Nft::check_nft_ownership(&account(2), &(1, 2)), Ok(true));
+	})
+}
+
 #[test]
 fn validate_signature() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1135,3 +1333,481 @@ fn pre_signed_mint_should_collect_fee_with_mint_price() {
 		assert_eq!(OrmlNft::tokens_by_owner((account(2), 0, 0)), ());
 	})
 }
+
+#[test]
+fn migrate_account_assets_should_move_nfts_stackable_balances_and_classes() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = RuntimeOrigin::signed(account(1));
+		init_test_stackable_nft(origin.clone());
+		assert_ok!(Nft::transfer_stackable_nft(
+			origin.clone(),
+			account(3),
+			(CLASS_ID, TOKEN_ID),
+			40u32.into()
+		));
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32),
+			None
+		));
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID_1, vec![1], test_attributes(1), 2));
+
+		// A received stackable balance is found through the holdings of the account
+		assert_eq!(Nft::migrate_account_assets(&account(3), &account(4), 10), Ok(1));
+		assert_eq!(
+			OrmlNft::get_stackable_collections_balances((CLASS_ID, TOKEN_ID, account(4))),
+			40u32.into()
+		);
+		assert_eq!(
+			OrmlNft::get_stackable_collections_balances((CLASS_ID, TOKEN_ID, account(3))),
+			0u32.into()
+		);
+		assert_eq!(Nft::stackable_nft_holdings(account(4), (CLASS_ID, TOKEN_ID)), Some(()));
+		assert_eq!(Nft::stackable_nft_holdings(account(3), (CLASS_ID, TOKEN_ID)), None);
+
+		// At most `limit` assets are moved at once, owned classes are moved last
+		assert_eq!(Nft::migrate_account_assets(&account(1), &account(2), 2), Ok(2));
+		assert_eq!(Nft::migrate_account_assets(&account(1), &account(2), 10), Ok(4));
+		assert_eq!(Nft::migrate_account_assets(&account(1), &account(2), 10), Ok(0));
+		assert_eq!(OrmlNft::classes(CLASS_ID).unwrap().owner, account(2));
+		assert_eq!(OrmlNft::classes(CLASS_ID_1).unwrap().owner, account(2));
+		System::assert_has_event(mock::RuntimeEvent::Nft(crate::Event::ClassOwnershipMigrated(
+			CLASS_ID_1,
+			account(1),
+			account(2),
+		)));
+		assert_eq!(
+			OrmlNft::get_stackable_collections_balances((CLASS_ID, TOKEN_ID, account(2))),
+			60u32.into()
+		);
+		assert_eq!(OrmlNft::tokens_by_owner((account(2), CLASS_ID, TOKEN_ID)), ());
+		assert_eq!(Nft::check_nft_ownership(&account(2), &(CLASS_ID_1, TOKEN_ID)), Ok(true));
+		assert_eq!(
+			Nft::check_nft_ownership(&account(2), &(CLASS_ID_1, TOKEN_ID_1)),
+			Ok(true)
+		);
+
+		// Locked NFTs and NFTs of locked collections are recovered as well
+		assert_ok!(Nft::set_lock_nft((CLASS_ID_1, TOKEN_ID), true));
+		assert_ok!(Nft::force_lock_collection(RuntimeOrigin::root(), CLASS_ID_1));
+		assert_eq!(Nft::migrate_account_assets(&account(2), &account(1), 10), Ok(6));
+		assert_eq!(Nft::check_nft_ownership(&account(1), &(CLASS_ID_1, TOKEN_ID)), Ok(true));
+		assert_eq!(
+			Nft::check_nft_ownership(&account(1), &(CLASS_ID_1, TOKEN_ID_1)),
+			Ok(true)
+		);
+		assert_eq!(OrmlNft::classes(CLASS_ID_1).unwrap().owner, account(1));
+	})
+}
+
+#[test]
+fn migrate_account_assets_should_move_soulbound_nfts() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = RuntimeOrigin::signed(account(1));
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::BoundToAddress,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32),
+			None
+		));
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID_1, vec![1], test_attributes(1), 1));
+		assert_ok!(Nft::transfer(origin, account(3), (CLASS_ID_1, TOKEN_ID)));
+
+		// The holder can not move a soulbound NFT, the recovery moves it to the rescuer
+		assert_noop!(
+			Nft::transfer(RuntimeOrigin::signed(account(3)), account(4), (CLASS_ID_1, TOKEN_ID)),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_eq!(Nft::migrate_account_assets(&account(3), &account(4), 10), Ok(1));
+		assert_eq!(Nft::check_nft_ownership(&account(4), &(CLASS_ID_1, TOKEN_ID)), Ok(true));
+		assert_eq!(
+			Nft::check_nft_ownership(&account(3), &(CLASS_ID_1, TOKEN_ID)),
+			Ok(false)
+		);
+	})
+}
+
+#[test]
+fn craft_recipe_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(account(1));
+		let crafter = RuntimeOrigin::signed(account(2));
+		assert_ok!(Nft::create_group(RuntimeOrigin::root(), vec![1], vec![1],));
+		for _ in 0..3 {
+			assert_ok!(Nft::create_class(
+				owner.clone(),
+				vec![1],
+				test_attributes(1),
+				COLLECTION_ID,
+				TokenType::Transferable,
+				CollectionType::Collectable,
+				Perbill::from_percent(0u32),
+				None
+			));
+		}
+		let (ore, gem, sword_class) = ((0, 0), (1, 0), 2);
+		assert_ok!(Nft::mint(owner.clone(), 0, vec![1], test_attributes(1), 1));
+		assert_ok!(Nft::mint_stackable_nft(
+			owner.clone(),
+			1,
+			vec![1],
+			test_attributes(1),
+			100u32.into()
+		));
+		assert_ok!(Nft::transfer(owner.clone(), account(2), ore));
+		assert_ok!(Nft::transfer_stackable_nft(owner.clone(), account(2), gem, 10));
+
+		let outcomes = vec![CraftingOutcome {
+			weight: 1,
+			outputs: vec![
+				CraftingOutput::Nft {
+					class_id: sword_class,
+					metadata: vec![2],
+					attributes: test_attributes(2),
+				},
+				CraftingOutput::StackableNft {
+					asset_id: gem,
+					amount: 1,
+				},
+			],
+		}];
+		assert_noop!(
+			Nft::create_crafting_recipe(
+				crafter.clone(),
+				vec![(gem, 5)],
+				vec![0],
+				vec![],
+				outcomes.clone(),
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Nft::create_crafting_recipe(
+			owner.clone(),
+			vec![(gem, 5)],
+			vec![0],
+			vec![(FungibleTokenId::NativeToken(0), 10)],
+			outcomes,
+			None,
+			Some(1),
+			None,
+			Some(10)
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Nft(crate::Event::CraftingRecipeCreated(0, account(1)))
+		);
+
+		assert_noop!(
+			Nft::craft(crafter.clone(), 0, vec![]),
+			Error::<Runtime>::InvalidCraftingInputs
+		);
+		let owner_balance = free_native_balance(account(1));
+		assert_ok!(Nft::craft(crafter.clone(), 0, vec![ore]));
+		assert_eq!(last_event(), RuntimeEvent::Nft(crate::Event::Crafted(0, account(2), 0)));
+
+		assert!(NftModule::<Runtime>::tokens(ore.0, ore.1).is_none());
+		assert_eq!(Nft::check_nft_ownership(&account(2), &(sword_class, 0)), Ok(true));
+		assert_eq!(OrmlNft::get_stackable_collections_balances((1, 0, account(2))), 6);
+		// Stackable inputs are paid to the recipe owner
+		assert_eq!(OrmlNft::get_stackable_collections_balances((1, 0, account(1))), 94);
+		assert_eq!(free_native_balance(account(1)), owner_balance + 10);
+		assert_eq!(Nft::crafts_by_account(0, account(2)), 1);
+
+		assert_noop!(
+			Nft::craft(crafter.clone(), 0, vec![ore]),
+			Error::<Runtime>::CraftingLimitReached
+		);
+		System::set_block_number(10);
+		assert_noop!(
+			Nft::craft(RuntimeOrigin::signed(account(0)), 0, vec![]),
+			Error::<Runtime>::CraftingRecipeNotActive
+		);
+
+		assert_noop!(
+			Nft::remove_crafting_recipe(crafter.clone(), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::clear_crafting_records(crafter.clone(), 0, 10),
+			Error::<Runtime>::CraftingRecipeNotRemoved
+		);
+		assert_ok!(Nft::remove_crafting_recipe(owner, 0));
+		assert_eq!(Nft::crafting_recipes(0), None);
+		assert_eq!(Nft::crafts_by_account(0, account(2)), 1);
+
+		assert_ok!(Nft::clear_crafting_records(crafter, 0, 10));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Nft(crate::Event::CraftingRecordsCleared(0, 1))
+		);
+		assert_eq!(Nft::crafts_by_account(0, account(2)), 0);
+	})
+}
+
+#[test]
+fn update_token_attributes_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(account(1));
+		let game_server = RuntimeOrigin::signed(account(2));
+		init_test_nft(owner.clone());
+		let asset_id = (CLASS_ID, 0);
+		let level = b"level".to_vec();
+
+		assert_noop!(
+			Nft::update_token_attributes(game_server.clone(), asset_id, vec![(level.clone(), Some(vec![2]))]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::grant_attribute_updater(game_server.clone(), CLASS_ID, account(2), level.clone()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Nft::grant_attribute_updater(
+			owner.clone(),
+			CLASS_ID,
+			account(2),
+			level.clone()
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Nft(crate::Event::AttributeUpdaterGranted(
+				CLASS_ID,
+				account(2),
+				level.clone()
+			))
+		);
+
+		assert_noop!(
+			Nft::update_token_attributes(game_server.clone(), asset_id, vec![(vec![1, 6], None)]),
+			Error::<Runtime>::NoPermission
+		);
+		for value in 2..5u8 {
+			assert_ok!(Nft::update_token_attributes(
+				game_server.clone(),
+				asset_id,
+				vec![(level.clone(), Some(vec![value]))]
+			));
+		}
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Nft(crate::Event::TokenAttributesUpdated(
+				account(2),
+				asset_id,
+				vec![level.clone()]
+			))
+		);
+		assert_ok!(Nft::update_token_attributes(
+			owner.clone(),
+			asset_id,
+			vec![(vec![1, 6], None)]
+		));
+
+		let token = OrmlNft::tokens(CLASS_ID, 0).unwrap();
+		assert_eq!(token.data.attributes.get(&level), Some(&vec![4]));
+		assert_eq!(token.data.attributes.get(&vec![1, 6]), None);
+		let history = Nft::token_attribute_history(asset_id);
+		assert_eq!(history.len(), 3);
+		assert_eq!(history[0].old_value, Some(vec![2]));
+		assert_eq!(history[2].key, vec![1, 6]);
+		assert_eq!(history[2].old_value, Some(vec![1, 11]));
+		assert_eq!(history[2].updated_by, account(1));
+
+		assert_noop!(
+			Nft::update_token_attributes(
+				owner.clone(),
+				asset_id,
+				vec![
+					(vec![1], Some(vec![1])),
+					(vec![2], Some(vec![2])),
+					(vec![3], Some(vec![3]))
+				]
+			),
+			Error::<Runtime>::ExceedMaximumTokenAttributes
+		);
+		assert_noop!(
+			Nft::freeze_attribute(owner.clone(), CLASS_ID, vec![1; 11]),
+			Error::<Runtime>::ExceedMaximumMetadataLength
+		);
+		assert_ok!(Nft::freeze_attribute(owner.clone(), CLASS_ID, level.clone()));
+		assert_noop!(
+			Nft::update_token_attributes(owner.clone(), asset_id, vec![(level.clone(), None)]),
+			Error::<Runtime>::AttributeIsFrozen
+		);
+		assert_noop!(
+			Nft::freeze_attribute(owner.clone(), CLASS_ID, level.clone()),
+			Error::<Runtime>::AttributeIsFrozen
+		);
+
+		assert_ok!(Nft::revoke_attribute_updater(
+			owner.clone(),
+			CLASS_ID,
+			account(2),
+			level.clone()
+		));
+		assert_noop!(
+			Nft::revoke_attribute_updater(owner, CLASS_ID, account(2), level),
+			Error::<Runtime>::AttributeUpdaterNotFound
+		);
+	})
+}
+
+#[test]
+fn execute_nft_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(account(1));
+		assert_ok!(Nft::create_group(RuntimeOrigin::root(), vec![1], vec![1],));
+
+		let mut attributes: Attributes = BTreeMap::new();
+		attributes.insert(EXECUTABLE_MAX_USES_ATTRIBUTE.to_vec(), 2u32.encode());
+		assert_ok!(Nft::create_class(
+			owner.clone(),
+			vec![1],
+			attributes,
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Executable(transfer_balance_encode(Nft::executable_holder_placeholder(), 10)),
+			Perbill::from_percent(0u32),
+			None
+		));
+		assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+		let class_fund = Nft::get_class_fund(&CLASS_ID);
+		assert_ok!(Balances::transfer(owner.clone(), class_fund, 100));
+		let asset_id = (CLASS_ID, 0);
+		let holder_balance = free_native_balance(account(1));
+
+		assert_noop!(
+			Nft::execute_nft(RuntimeOrigin::signed(account(2)), asset_id, Weight::MAX),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::execute_nft(owner.clone(), asset_id, Weight::zero()),
+			Error::<Runtime>::ExecutableCallWeightExceeded
+		);
+
+		// The placeholder account of the call is the holder, only the executed call is charged
+		let filled_call = RuntimeCall::decode(&mut &transfer_balance_encode(account(1), 10)[..]).unwrap();
+		let post_info = Nft::execute_nft(owner.clone(), asset_id, Weight::MAX).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::transfer().saturating_add(filled_call.get_dispatch_info().weight))
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Nft(crate::Event::NftExecuted(account(1), asset_id, 1))
+		);
+		assert_eq!(free_native_balance(account(1)), holder_balance + 10);
+		assert_eq!(Nft::executable_uses(asset_id), 1);
+
+		// The NFT pays whoever holds it
+		assert_ok!(Nft::transfer(owner.clone(), account(3), asset_id));
+		assert_ok!(Nft::execute_nft(
+			RuntimeOrigin::signed(account(3)),
+			asset_id,
+			Weight::MAX
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Nft(crate::Event::NftExecuted(account(3), asset_id, 0))
+		);
+		assert_eq!(free_native_balance(account(3)), 10);
+		assert!(OrmlNft::tokens(CLASS_ID, 0).is_none());
+		assert_eq!(Nft::executable_uses(asset_id), 0);
+	})
+}
+
+#[test]
+fn set_executable_call_type_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = RuntimeOrigin::signed(account(1));
+		assert_ok!(Nft::create_group(RuntimeOrigin::root(), vec![1], vec![1],));
+		assert_noop!(
+			Nft::create_class(
+				owner.clone(),
+				vec![1],
+				test_attributes(1),
+				COLLECTION_ID,
+				TokenType::Transferable,
+				CollectionType::Executable(vec![u8::MAX]),
+				Perbill::from_percent(0u32),
+				None
+			),
+			Error::<Runtime>::InvalidExecutablePayload
+		);
+
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] }).encode();
+		assert_ok!(Nft::create_class(
+			owner.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Executable(remark),
+			Perbill::from_percent(0u32),
+			None
+		));
+		assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+		let asset_id = (CLASS_ID, 0);
+		assert_eq!(Nft::executable_call_type(CLASS_ID), ExecutableNftCallType::JustTransfer);
+		assert_noop!(
+			Nft::execute_nft(owner.clone(), asset_id, Weight::MAX),
+			Error::<Runtime>::InvalidExecutablePayload
+		);
+
+		assert_noop!(
+			Nft::set_executable_call_type(
+				RuntimeOrigin::signed(account(2)),
+				CLASS_ID,
+				ExecutableNftCallType::JustRemark
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::set_executable_call_type(owner.clone(), CLASS_ID, ExecutableNftCallType::JustTransfer),
+			Error::<Runtime>::InvalidExecutablePayload
+		);
+		assert_ok!(Nft::set_executable_call_type(
+			owner.clone(),
+			CLASS_ID,
+			ExecutableNftCallType::JustRemark
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Nft(crate::Event::ExecutableCallTypeUpdated(
+				CLASS_ID,
+				ExecutableNftCallType::JustRemark
+			))
+		);
+		assert_eq!(Nft::executable_call_type(CLASS_ID), ExecutableNftCallType::JustRemark);
+
+		assert_ok!(Nft::execute_nft(owner.clone(), asset_id, Weight::MAX));
+		assert!(OrmlNft::tokens(CLASS_ID, 0).is_none());
+
+		assert_ok!(Nft::create_class(
+			owner,
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32),
+			None
+		));
+		assert_noop!(
+			Nft::set_executable_call_type(RuntimeOrigin::root(), CLASS_ID + 1, ExecutableNftCallType::JustRemark),
+			Error::<Runtime>::NftIsNotExecutable
+		);
+	})
+}
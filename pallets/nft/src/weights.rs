@@ -47,7 +47,7 @@ use frame_support::{traits::Get, weights::{constants::RocksDbWeight, Weight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions needed for nft.
-pub trait WeightInfo {	fn create_group() -> Weight;	fn create_class() -> Weight;	fn mint() -> Weight;	fn mint_stackable_nft() -> Weight;	fn transfer() -> Weight;	fn transfer_stackable_nft() -> Weight;	fn transfer_batch() -> Weight;	fn sign_asset() -> Weight;	fn set_hard_limit() -> Weight;	fn withdraw_funds_from_class_fund() -> Weight;	fn force_update_total_issuance() -> Weight;	fn mint_pre_signed() -> Weight;}
+pub trait WeightInfo {	fn create_group() -> Weight;	fn create_class() -> Weight;	fn mint() -> Weight;	fn mint_stackable_nft() -> Weight;	fn transfer() -> Weight;	fn transfer_stackable_nft() -> Weight;	fn transfer_batch() -> Weight;	fn sign_asset() -> Weight;	fn set_hard_limit() -> Weight;	fn withdraw_funds_from_class_fund() -> Weight;	fn force_update_total_issuance() -> Weight;	fn mint_pre_signed() -> Weight;	fn set_executable_call_type() -> Weight;	fn grant_attribute_updater() -> Weight;	fn revoke_attribute_updater() -> Weight;	fn freeze_attribute() -> Weight;	fn set_class_royalty_recipients() -> Weight;	fn equip(n: u32) -> Weight;	fn unequip() -> Weight;	fn transfer_equipped_items(n: u32) -> Weight;	fn create_crafting_recipe() -> Weight;	fn remove_crafting_recipe() -> Weight;	fn craft() -> Weight;}
 
 /// Weights for nft using the for collator node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -257,6 +257,149 @@ fn create_group() -> Weight {
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft ExecutableCallTypes (r:0 w:1)
+	// Proof Skipped: Nft ExecutableCallTypes (max_values: None, max_size: None, mode: Measured)
+	fn set_executable_call_type() -> Weight {
+		Weight::from_parts(15_000_000, 2757)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft FrozenAttributes (r:1 w:0)
+	// Proof Skipped: Nft FrozenAttributes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft AttributeUpdaters (r:0 w:1)
+	// Proof Skipped: Nft AttributeUpdaters (max_values: None, max_size: None, mode: Measured)
+	fn grant_attribute_updater() -> Weight {
+		Weight::from_parts(17_000_000, 3722)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft AttributeUpdaters (r:1 w:1)
+	// Proof Skipped: Nft AttributeUpdaters (max_values: None, max_size: None, mode: Measured)
+	fn revoke_attribute_updater() -> Weight {
+		Weight::from_parts(18_000_000, 3774)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft FrozenAttributes (r:1 w:1)
+	// Proof Skipped: Nft FrozenAttributes (max_values: None, max_size: None, mode: Measured)
+	fn freeze_attribute() -> Weight {
+		Weight::from_parts(16_000_000, 3722)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft ClassRoyaltyRecipients (r:0 w:1)
+	// Proof Skipped: Nft ClassRoyaltyRecipients (max_values: None, max_size: None, mode: Measured)
+	fn set_class_royalty_recipients() -> Weight {
+		Weight::from_parts(19_000_000, 3722)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: OrmlNFT StackableCollection (r:2 w:0)
+	// Proof Skipped: OrmlNFT StackableCollection (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft EquippedTo (r:2 w:1)
+	// Proof Skipped: Nft EquippedTo (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:2 w:0)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Classes (r:3 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction ItemsInAuction (r:2 w:0)
+	// Proof Skipped: Auction ItemsInAuction (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft EquippedItems (r:1 w:1)
+	// Proof Skipped: Nft EquippedItems (max_values: None, max_size: None, mode: Measured)
+	fn equip(n: u32) -> Weight {
+		Weight::from_parts(52_000_000, 30240)
+			.saturating_add(Weight::from_parts(5_000_000, 2600).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Nft EquippedTo (r:7 w:1)
+	// Proof Skipped: Nft EquippedTo (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Auction ItemsInAuction (r:1 w:0)
+	// Proof Skipped: Auction ItemsInAuction (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft EquippedItems (r:0 w:1)
+	// Proof Skipped: Nft EquippedItems (max_values: None, max_size: None, mode: Measured)
+	fn unequip() -> Weight {
+		Weight::from_parts(38_000_000, 21370)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Nft EquippedItems (r:1 w:0)
+	// Proof Skipped: Nft EquippedItems (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:2)
+	// Proof Skipped: OrmlNFT TokensByOwner (max_values: None, max_size: None, mode: Measured)
+	fn transfer_equipped_items(n: u32) -> Weight {
+		Weight::from_parts(3_000_000, 1190)
+			.saturating_add(Weight::from_parts(24_000_000, 5080).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	// Storage: OrmlNFT StackableCollection (r:20 w:0)
+	// Proof Skipped: OrmlNFT StackableCollection (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Classes (r:100 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Nft NextCraftingRecipeId (r:1 w:1)
+	// Proof Skipped: Nft NextCraftingRecipeId (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Nft CraftingRecipes (r:0 w:1)
+	// Proof Skipped: Nft CraftingRecipes (max_values: None, max_size: None, mode: Measured)
+	fn create_crafting_recipe() -> Weight {
+		Weight::from_parts(420_000_000, 362710)
+			.saturating_add(T::DbWeight::get().reads(123))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Nft CraftingRecipes (r:1 w:1)
+	// Proof Skipped: Nft CraftingRecipes (max_values: None, max_size: None, mode: Measured)
+	fn remove_crafting_recipe() -> Weight {
+		Weight::from_parts(18_000_000, 3904)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nft CraftingRecipes (r:1 w:1)
+	// Proof Skipped: Nft CraftingRecipes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft CraftsByAccount (r:1 w:1)
+	// Proof Skipped: Nft CraftsByAccount (max_values: None, max_size: None, mode: Measured)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Proof: RandomnessCollectiveFlip RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	// Storage: OrmlNFT StackableCollection (r:30 w:0)
+	// Proof Skipped: OrmlNFT StackableCollection (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Classes (r:20 w:20)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:10 w:20)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:20)
+	// Proof Skipped: OrmlNFT TokensByOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT StackableCollectionsBalances (r:20 w:20)
+	// Proof Skipped: OrmlNFT StackableCollectionsBalances (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft ReservedStackableNftBalance (r:10 w:0)
+	// Proof Skipped: Nft ReservedStackableNftBalance (max_values: None, max_size: None, mode: Measured)
+	// Storage: Nft StackableNftHoldings (r:0 w:20)
+	// Proof Skipped: Nft StackableNftHoldings (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:21 w:21)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Auction ItemsInAuction (r:10 w:0)
+	// Proof Skipped: Auction ItemsInAuction (max_values: None, max_size: None, mode: Measured)
+	fn craft() -> Weight {
+		Weight::from_parts(1_660_000_000, 346420)
+			.saturating_add(T::DbWeight::get().reads(183))
+			.saturating_add(T::DbWeight::get().writes(192))
+	}
 }
 
 
@@ -321,4 +464,63 @@ impl WeightInfo for () {	fn create_group() -> Weight {
 			.saturating_add(RocksDbWeight::get().reads(6))
 			.saturating_add(RocksDbWeight::get().writes(6))
 	}
+	fn set_executable_call_type() -> Weight {
+		Weight::from_parts(15_000_000, 2757)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn grant_attribute_updater() -> Weight {
+		Weight::from_parts(17_000_000, 3722)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn revoke_attribute_updater() -> Weight {
+		Weight::from_parts(18_000_000, 3774)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn freeze_attribute() -> Weight {
+		Weight::from_parts(16_000_000, 3722)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn set_class_royalty_recipients() -> Weight {
+		Weight::from_parts(19_000_000, 3722)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn equip(n: u32) -> Weight {
+		Weight::from_parts(52_000_000, 30240)
+			.saturating_add(Weight::from_parts(5_000_000, 2600).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn unequip() -> Weight {
+		Weight::from_parts(38_000_000, 21370)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn transfer_equipped_items(n: u32) -> Weight {
+		Weight::from_parts(3_000_000, 1190)
+			.saturating_add(Weight::from_parts(24_000_000, 5080).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	fn create_crafting_recipe() -> Weight {
+		Weight::from_parts(420_000_000, 362710)
+			.saturating_add(RocksDbWeight::get().reads(123))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn remove_crafting_recipe() -> Weight {
+		Weight::from_parts(18_000_000, 3904)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn craft() -> Weight {
+		Weight::from_parts(1_660_000_000, 346420)
+			.saturating_add(RocksDbWeight::get().reads(183))
+			.saturating_add(RocksDbWeight::get().writes(192))
+	}
 }
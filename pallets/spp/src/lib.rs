@@ -106,10 +106,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimumStake: Get<BalanceOf<Self>>;
 
-		/// Network fee charged on pool creation
+		/// Network fee charged on pool creation, expressed in the reference currency of the price
+		/// provider
 		#[pallet::constant]
 		type NetworkFee: Get<BalanceOf<Self>>;
 
+		/// Price provider converting the network fee into the pool currency
+		type PriceProvider: PriceProvider;
+
 		/// Storage deposit free charged when saving data into the blockchain.
 		/// The fee will be unreserved after the storage is freed.
 		#[pallet::constant]
@@ -907,7 +911,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn collect_pool_creation_fee(who: &T::AccountId, currency_id: FungibleTokenId) -> DispatchResult {
-		let pool_fee = CurrencyNetworkFee::<T>::get(currency_id);
+		let pool_fee = Self::pool_creation_fee(currency_id);
 		T::MultiCurrency::transfer(
 			currency_id,
 			who,
@@ -916,6 +920,19 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Pool creation fee in `currency_id`. The network fee set for the currency takes precedence,
+	/// otherwise the network fee in the reference currency is charged at the current price.
+	pub fn pool_creation_fee(currency_id: FungibleTokenId) -> BalanceOf<T> {
+		let pool_fee = CurrencyNetworkFee::<T>::get(currency_id);
+		if !pool_fee.is_zero() {
+			return pool_fee;
+		}
+
+		T::PriceProvider::from_reference(currency_id, T::NetworkFee::get().saturated_into())
+			.map(|fee| fee.saturated_into())
+			.unwrap_or(pool_fee)
+	}
+
 	fn handle_update_staking_round(era_index: EraIndex, currency: FungibleTokenId) -> DispatchResult {
 		let last_staking_round = StakingRound::Era(era_index as u32);
 		let unlock_duration = match UnlockDuration::<T>::get(currency) {
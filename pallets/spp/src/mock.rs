@@ -12,7 +12,7 @@ use sp_std::vec::Vec;
 
 use asset_manager::ForeignAssetMapping;
 
-use primitives::{Amount, Attributes, FungibleTokenId, PoolId};
+use primitives::{Amount, Attributes, FungibleTokenId, PoolId, Price};
 use sp_runtime::traits::BlockNumberProvider;
 use sp_runtime::BuildStorage;
 
@@ -187,6 +187,20 @@ parameter_types! {
 	pub StorageDepositFee: Balance = 1;
 	pub const MaximumQueue: u32 = 50;
 	pub static MockRelayBlockNumberProvider: BlockNumber = 0;
+	pub static KsmPrice: Option<Price> = None;
+}
+
+/// Prices in native token, KSM has no price until set by a test
+pub struct MockPriceProvider;
+
+impl PriceProvider for MockPriceProvider {
+	fn get_price(currency_id: FungibleTokenId) -> Option<Price> {
+		match currency_id {
+			FungibleTokenId::NativeToken(0) => Some(Price::saturating_from_integer(1)),
+			FungibleTokenId::NativeToken(1) => KsmPrice::get(),
+			_ => None,
+		}
+	}
 }
 
 impl Config for Runtime {
@@ -195,6 +209,7 @@ impl Config for Runtime {
 	type WeightInfo = ();
 	type MinimumStake = MinimumStake;
 	type NetworkFee = NetworkFee;
+	type PriceProvider = MockPriceProvider;
 	type StorageDepositFee = StorageDepositFee;
 	type MultiCurrency = Currencies;
 	type PoolAccount = PoolAccountPalletId;
@@ -94,6 +94,35 @@ fn create_ksm_pool_works() {
 		});
 }
 
+#[test]
+fn create_ksm_pool_charges_network_fee_at_oracle_price() {
+	ExtBuilder::default()
+		.ksm_setup_for_alice_and_bob()
+		.build()
+		.execute_with(|| {
+			// 1 native token of network fee is worth 10 KSM
+			KsmPrice::set(Some(Price::saturating_from_rational(1, 10)));
+			assert_eq!(SppModule::pool_creation_fee(FungibleTokenId::NativeToken(1)), 10);
+			assert_ok!(SppModule::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				FungibleTokenId::NativeToken(1),
+				50,
+				Rate::saturating_from_rational(5, 100)
+			));
+			assert_eq!(Tokens::accounts(ALICE, FungibleTokenId::NativeToken(1)).free, 19990);
+
+			// The network fee set by governance takes precedence
+			CurrencyNetworkFee::<Runtime>::insert(FungibleTokenId::NativeToken(1), 3);
+			assert_ok!(SppModule::create_pool(
+				RuntimeOrigin::signed(BOB),
+				FungibleTokenId::NativeToken(1),
+				10,
+				Rate::saturating_from_rational(1, 100),
+			));
+			assert_eq!(Tokens::accounts(BOB, FungibleTokenId::NativeToken(1)).free, 19997);
+		});
+}
+
 #[test]
 fn deposit_ksm_works() {
 	ExtBuilder::default()
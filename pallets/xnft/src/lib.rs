@@ -0,0 +1,510 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-chain NFT transfers over XCM.
+//!
+//! This chain is the reserve of its own NFT classes. A local NFT leaving the chain is locked in the
+//! pallet account and tracked in `OutboundNfts` until it is withdrawn back by the chain it was sent
+//! to. NFTs of registered foreign classes arrive as derivatives minted under a mapped local class
+//! and are burned when they return to their reserve.
+//!
+//! The pallet implements `TransactAsset` for non-fungible assets so it can be plugged in front of
+//! the fungible asset transactor of the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, traits::Get, transactional, PalletId};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use orml_traits::location::{Parse, Reserve};
+use sp_runtime::{
+	traits::{AccountIdConversion, Convert, Zero},
+	Perbill,
+};
+use sp_std::{boxed::Box, vec, vec::Vec};
+use xcm::{latest::prelude::*, VersionedMultiLocation};
+use xcm_executor::{
+	traits::{ConvertLocation, TransactAsset, WeightBounds},
+	Assets,
+};
+
+use core_primitives::{Attributes, CollectionType, NFTTrait, NftMetadata, TokenType};
+pub use pallet::*;
+use primitives::{Balance, ClassId, FungibleTokenId, TokenId};
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// NFT handler used to lock, mint and burn transferred NFTs
+		type NFTHandler: NFTTrait<Self::AccountId, Balance, ClassId = ClassId, TokenId = TokenId>;
+		/// The location of this chain as seen from the relay chain
+		#[pallet::constant]
+		type SelfLocation: Get<MultiLocation>;
+		/// The universal location of this chain, used to reanchor assets
+		type UniversalLocation: Get<InteriorMultiLocation>;
+		/// Index of the NFT pallet, used to build the location of local NFT classes
+		#[pallet::constant]
+		type NftPalletInstance: Get<u8>;
+		/// Convert an account into the location used as XCM origin
+		type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
+		/// Convert a location into the account which owns transferred NFTs
+		type LocationToAccountId: ConvertLocation<Self::AccountId>;
+		/// Convert the currency paying the XCM fee into its location
+		type CurrencyIdConvert: Convert<FungibleTokenId, Option<MultiLocation>>;
+		/// Provides the reserve location of the fee asset
+		type ReserveProvider: Reserve;
+		/// XCM executor running the transfer on this chain
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+		/// Means of measuring the weight of the transfer message
+		type Weigher: WeightBounds<<Self as frame_system::Config>::RuntimeCall>;
+		/// The pallet account holding NFTs which are away on other chains
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Origin allowed to map foreign NFT classes into local derivative classes
+		type ForeignClassOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Weight implementation for xnft extrinsics
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_class)]
+	/// Local derivative class of each registered foreign NFT class location.
+	pub type ForeignClasses<T: Config> = StorageMap<_, Blake2_128Concat, MultiLocation, ClassId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_class_location)]
+	/// Foreign NFT class location of each local derivative class.
+	pub type ForeignClassLocations<T: Config> = StorageMap<_, Twox64Concat, ClassId, MultiLocation, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn outbound_nft)]
+	/// Local NFTs locked on this chain and the chain holding their derivative.
+	pub type OutboundNfts<T: Config> = StorageMap<_, Twox64Concat, (ClassId, TokenId), MultiLocation, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Foreign NFT class is mapped into a local derivative class
+		ForeignClassRegistered { location: MultiLocation, class_id: ClassId },
+		/// NFT is transferred to another chain
+		NftTransferred {
+			sender: T::AccountId,
+			class_id: ClassId,
+			token_id: TokenId,
+			dest: MultiLocation,
+		},
+		/// Local NFT is locked while its derivative lives on another chain
+		NftLocked {
+			class_id: ClassId,
+			token_id: TokenId,
+			chain: MultiLocation,
+		},
+		/// Local NFT is restored to an owner after returning from another chain
+		NftRestored {
+			class_id: ClassId,
+			token_id: TokenId,
+			owner: T::AccountId,
+		},
+		/// Derivative of a foreign NFT is minted
+		DerivativeMinted {
+			class_id: ClassId,
+			token_id: TokenId,
+			owner: T::AccountId,
+		},
+		/// Derivative of a foreign NFT is burned
+		DerivativeBurned {
+			class_id: ClassId,
+			token_id: TokenId,
+			owner: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The version of the location is not supported
+		BadVersion,
+		/// Destination is not a chain and a beneficiary
+		InvalidDest,
+		/// Foreign class location is not a foreign chain
+		InvalidForeignClass,
+		/// Foreign class is already registered
+		ForeignClassAlreadyRegistered,
+		/// No permission
+		NoPermission,
+		/// Fee amount must be above zero
+		ZeroFee,
+		/// Fee currency can not be transferred over XCM
+		NotCrossChainTransferableCurrency,
+		/// Fee asset must have the same reserve as the NFT
+		InvalidFeeAsset,
+		/// Derivatives can only be transferred back to the reserve of their class
+		NotNftReserve,
+		/// Asset could not be reanchored to the destination
+		CannotReanchor,
+		/// Transfer message could not be weighed
+		UnweighableMessage,
+		/// Transfer message failed to execute
+		XcmExecutionFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Maps a foreign NFT class into a new local derivative class
+		///
+		/// The dispatch origin for this call must be `ForeignClassOrigin`.
+		/// - `location`: the location of the foreign class as seen from this chain
+		/// - `metadata`: the metadata of the derivative class
+		///
+		/// Emits `ForeignClassRegistered` if successful.
+		#[pallet::weight(T::WeightInfo::register_foreign_class())]
+		#[transactional]
+		pub fn register_foreign_class(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			metadata: NftMetadata,
+		) -> DispatchResult {
+			T::ForeignClassOrigin::ensure_origin(origin)?;
+
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(
+				location
+					.chain_part()
+					.map_or(false, |chain| chain != T::SelfLocation::get()),
+				Error::<T>::InvalidForeignClass
+			);
+			ensure!(
+				!ForeignClasses::<T>::contains_key(location),
+				Error::<T>::ForeignClassAlreadyRegistered
+			);
+
+			let class_id = T::NFTHandler::create_token_class(
+				&Self::account_id(),
+				metadata,
+				Attributes::new(),
+				0,
+				TokenType::Transferable,
+				CollectionType::Collectable,
+				Perbill::zero(),
+				None,
+			)?;
+			ForeignClasses::<T>::insert(location, class_id);
+			ForeignClassLocations::<T>::insert(class_id, location);
+
+			Self::deposit_event(Event::<T>::ForeignClassRegistered { location, class_id });
+
+			Ok(())
+		}
+
+		/// Transfers an NFT to another chain
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		/// Local NFTs are sent as reserve transfers and stay locked on this chain. Derivatives of
+		/// foreign NFTs can only be sent back to the reserve chain of their class.
+		/// - `nft`: the NFT to transfer
+		/// - `fee_currency_id`: the currency paying the execution on the destination, its reserve
+		///   must be the reserve of the NFT
+		/// - `fee_amount`: the amount of fee currency sent along the NFT
+		/// - `dest`: the destination chain and beneficiary
+		/// - `dest_weight_limit`: the weight limit of the execution on the destination
+		///
+		/// Emits `NftTransferred` if successful.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		#[transactional]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			nft: (ClassId, TokenId),
+			fee_currency_id: FungibleTokenId,
+			fee_amount: Balance,
+			dest: Box<VersionedMultiLocation>,
+			dest_weight_limit: WeightLimit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let chain = dest.chain_part().ok_or(Error::<T>::InvalidDest)?;
+			let beneficiary = dest.non_chain_part().ok_or(Error::<T>::InvalidDest)?;
+			ensure!(!fee_amount.is_zero(), Error::<T>::ZeroFee);
+			ensure!(T::NFTHandler::check_ownership(&who, &nft)?, Error::<T>::NoPermission);
+
+			let fee_location =
+				T::CurrencyIdConvert::convert(fee_currency_id).ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+			let fee: MultiAsset = (fee_location, fee_amount).into();
+			let fee_reserve = T::ReserveProvider::reserve(&fee).ok_or(Error::<T>::InvalidFeeAsset)?;
+			let remote_fee = fee
+				.clone()
+				.reanchored(&chain, T::UniversalLocation::get())
+				.map_err(|_| Error::<T>::CannotReanchor)?;
+			let remote_xcm = Xcm(vec![
+				BuyExecution {
+					fees: remote_fee,
+					weight_limit: dest_weight_limit,
+				},
+				DepositAsset {
+					assets: Wild(AllCounted(2)),
+					beneficiary,
+				},
+			]);
+
+			let (nft_asset, transfer_instruction) = match Self::foreign_class_location(nft.0) {
+				Some(class_location) => {
+					ensure!(class_location.chain_part() == Some(chain), Error::<T>::NotNftReserve);
+					ensure!(fee_reserve == chain, Error::<T>::InvalidFeeAsset);
+					(
+						Self::nft_asset(class_location, nft.1),
+						InitiateReserveWithdraw {
+							assets: Wild(AllCounted(2)),
+							reserve: chain,
+							xcm: remote_xcm,
+						},
+					)
+				}
+				None => {
+					ensure!(fee_reserve == T::SelfLocation::get(), Error::<T>::InvalidFeeAsset);
+					(
+						Self::nft_asset(Self::local_class_location(nft.0), nft.1),
+						DepositReserveAsset {
+							assets: Wild(AllCounted(2)),
+							dest: chain,
+							xcm: remote_xcm,
+						},
+					)
+				}
+			};
+
+			let mut message = Xcm(vec![WithdrawAsset(vec![fee, nft_asset].into()), transfer_instruction]);
+			let weight = T::Weigher::weight(&mut message).map_err(|()| Error::<T>::UnweighableMessage)?;
+			let hash = message.using_encoded(sp_io::hashing::blake2_256);
+			T::XcmExecutor::execute_xcm_in_credit(
+				T::AccountIdToMultiLocation::convert(who.clone()),
+				message,
+				hash,
+				weight,
+				weight,
+			)
+			.ensure_complete()
+			.map_err(|error| {
+				log::error!("Failed to execute NFT transfer: {:?}", error);
+				Error::<T>::XcmExecutionFailed
+			})?;
+
+			Self::deposit_event(Event::<T>::NftTransferred {
+				sender: who,
+				class_id: nft.0,
+				token_id: nft.1,
+				dest,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account ID holding NFTs which are away on other chains.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Location of a local NFT class as seen from this chain
+	pub fn local_class_location(class_id: ClassId) -> MultiLocation {
+		MultiLocation::new(
+			0,
+			X2(
+				PalletInstance(T::NftPalletInstance::get()),
+				GeneralIndex(class_id.into()),
+			),
+		)
+	}
+
+	fn nft_asset(class_location: MultiLocation, token_id: TokenId) -> MultiAsset {
+		(class_location, AssetInstance::Index(token_id.into())).into()
+	}
+
+	/// Matches an XCM asset with an NFT of a local class or a derivative of a registered foreign
+	/// class. The flag is set for derivatives.
+	fn match_nft(asset: &MultiAsset) -> Option<((ClassId, TokenId), bool)> {
+		let (location, token_id) = match asset {
+			MultiAsset {
+				id: Concrete(location),
+				fun: NonFungible(AssetInstance::Index(index)),
+			} => (location, TokenId::try_from(*index).ok()?),
+			_ => return None,
+		};
+
+		if let Some(class_id) = Self::foreign_class(location) {
+			return Some(((class_id, token_id), true));
+		}
+
+		// Local classes might be expressed from the relay chain point of view
+		let (pallet_instance, class_index) = match (location.parents, &location.interior) {
+			(0, X2(PalletInstance(pallet_instance), GeneralIndex(class_index))) => (*pallet_instance, *class_index),
+			(1, X3(Parachain(para_id), PalletInstance(pallet_instance), GeneralIndex(class_index)))
+				if MultiLocation::new(1, X1(Parachain(*para_id))) == T::SelfLocation::get() =>
+			{
+				(*pallet_instance, *class_index)
+			}
+			_ => return None,
+		};
+		if pallet_instance != T::NftPalletInstance::get() {
+			return None;
+		}
+		let class_id = ClassId::try_from(class_index).ok()?;
+		if ForeignClassLocations::<T>::contains_key(class_id) {
+			return None;
+		}
+
+		Some(((class_id, token_id), false))
+	}
+
+	/// Whether the location is a chain rather than an account
+	fn is_chain(location: &MultiLocation) -> bool {
+		matches!(
+			(location.parents, &location.interior),
+			(1, Here) | (1, X1(Parachain(_)))
+		)
+	}
+
+	fn location_to_account(location: &MultiLocation) -> Result<T::AccountId, XcmError> {
+		T::LocationToAccountId::convert_location(location)
+			.ok_or(XcmError::FailedToTransactAsset("AccountIdConversionFailed"))
+	}
+
+	#[transactional]
+	fn do_mint_derivative(owner: &T::AccountId, nft: (ClassId, TokenId)) -> DispatchResult {
+		let vault = Self::account_id();
+		T::NFTHandler::mint_token_with_id(&vault, nft.0, nft.1, Vec::new(), Attributes::new())?;
+		T::NFTHandler::transfer_nft(&vault, owner, &nft)?;
+
+		Self::deposit_event(Event::<T>::DerivativeMinted {
+			class_id: nft.0,
+			token_id: nft.1,
+			owner: owner.clone(),
+		});
+
+		Ok(())
+	}
+
+	#[transactional]
+	fn do_burn_derivative(owner: &T::AccountId, nft: (ClassId, TokenId)) -> DispatchResult {
+		T::NFTHandler::burn_nft(owner, &nft)?;
+
+		Self::deposit_event(Event::<T>::DerivativeBurned {
+			class_id: nft.0,
+			token_id: nft.1,
+			owner: owner.clone(),
+		});
+
+		Ok(())
+	}
+
+	fn do_lock_outbound(nft: (ClassId, TokenId), chain: &MultiLocation) -> DispatchResult {
+		ensure!(
+			T::NFTHandler::check_ownership(&Self::account_id(), &nft)?,
+			Error::<T>::NoPermission
+		);
+		OutboundNfts::<T>::insert(nft, chain);
+
+		Self::deposit_event(Event::<T>::NftLocked {
+			class_id: nft.0,
+			token_id: nft.1,
+			chain: *chain,
+		});
+
+		Ok(())
+	}
+
+	fn do_restore(owner: &T::AccountId, nft: (ClassId, TokenId)) -> DispatchResult {
+		ensure!(!OutboundNfts::<T>::contains_key(nft), Error::<T>::NoPermission);
+		T::NFTHandler::transfer_nft(&Self::account_id(), owner, &nft)?;
+
+		Self::deposit_event(Event::<T>::NftRestored {
+			class_id: nft.0,
+			token_id: nft.1,
+			owner: owner.clone(),
+		});
+
+		Ok(())
+	}
+}
+
+impl<T: Config> TransactAsset for Pallet<T> {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, _context: &XcmContext) -> XcmResult {
+		let (nft, is_derivative) = Self::match_nft(what).ok_or(XcmError::AssetNotFound)?;
+
+		let result = match (is_derivative, Self::is_chain(who)) {
+			// Derivatives only travel back to their reserve
+			(true, true) => Err(Error::<T>::NotNftReserve.into()),
+			(true, false) => Self::do_mint_derivative(&Self::location_to_account(who)?, nft),
+			// The NFT stays locked here while it is on the other chain
+			(false, true) => Self::do_lock_outbound(nft, who),
+			(false, false) => Self::do_restore(&Self::location_to_account(who)?, nft),
+		};
+
+		result.map_err(|error| {
+			log::error!("Failed to deposit NFT {:?}: {:?}", nft, error);
+			XcmError::FailedToTransactAsset(error.into())
+		})
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		_maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		let (nft, is_derivative) = Self::match_nft(what).ok_or(XcmError::AssetNotFound)?;
+
+		let result = if Self::is_chain(who) {
+			// Only the chain the NFT was sent to can send it back
+			if !is_derivative && Self::outbound_nft(nft) == Some(*who) {
+				OutboundNfts::<T>::remove(nft);
+				Ok(())
+			} else {
+				Err(Error::<T>::NoPermission.into())
+			}
+		} else {
+			let owner = Self::location_to_account(who)?;
+			if is_derivative {
+				Self::do_burn_derivative(&owner, nft)
+			} else {
+				T::NFTHandler::transfer_nft(&owner, &Self::account_id(), &nft)
+			}
+		};
+
+		result.map_err(|error: DispatchError| {
+			log::error!("Failed to withdraw NFT {:?}: {:?}", nft, error);
+			XcmError::FailedToTransactAsset(error.into())
+		})?;
+
+		Ok(what.clone().into())
+	}
+}
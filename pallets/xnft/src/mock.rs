@@ -0,0 +1,353 @@
+#![cfg(test)]
+
+use frame_support::storage::unhashed;
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
+use frame_system::EnsureSignedBy;
+use orml_traits::location::AbsoluteReserveProvider;
+use sp_core::H256;
+use sp_runtime::traits::IdentityLookup;
+use sp_runtime::{BuildStorage, DispatchError};
+use xcm_builder::FixedWeightBounds;
+
+use core_primitives::NftClassData;
+use primitives::GroupCollectionId;
+
+use crate as xnft;
+
+use super::*;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const PARA_ID: u32 = 2096;
+pub const SIBLING_PARA_ID: u32 = 3096;
+pub const STATEMINE_PARA_ID: u32 = 1000;
+pub const NFT_PALLET_INSTANCE: u8 = 61;
+
+pub const LOCAL_CLASS_ID: ClassId = 0;
+pub const ALICE_TOKEN: (ClassId, TokenId) = (LOCAL_CLASS_ID, 0);
+pub const DERIVATIVE_CLASS_ID: ClassId = 10;
+
+pub const NATIVE: FungibleTokenId = FungibleTokenId::NativeToken(0);
+pub const KSM: FungibleTokenId = FungibleTokenId::NativeToken(1);
+
+ord_parameter_types! {
+	pub const Admin: AccountId = ALICE;
+}
+
+// Configure a mock runtime to test the pallet.
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+/// NFT handler keeping the owner of each NFT in storage, so failed calls are rolled back
+pub struct MockNFTHandler;
+
+impl MockNFTHandler {
+	fn owner_key(nft: &(ClassId, TokenId)) -> Vec<u8> {
+		(b"xnft/owner", nft).encode()
+	}
+
+	pub fn owner(nft: &(ClassId, TokenId)) -> Option<AccountId> {
+		unhashed::get(&Self::owner_key(nft))
+	}
+
+	pub fn set_owner(nft: &(ClassId, TokenId), owner: AccountId) {
+		unhashed::put(&Self::owner_key(nft), &owner)
+	}
+}
+
+impl NFTTrait<AccountId, Balance> for MockNFTHandler {
+	type TokenId = TokenId;
+	type ClassId = ClassId;
+
+	fn check_ownership(who: &AccountId, asset_id: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(Self::owner(asset_id) == Some(*who))
+	}
+
+	fn get_nft_detail(_asset_id: (Self::ClassId, Self::TokenId)) -> Result<NftClassData<Balance>, DispatchError> {
+		Err(DispatchError::Other("Not supported"))
+	}
+
+	fn get_nft_group_collection(_nft_collection: &Self::ClassId) -> Result<GroupCollectionId, DispatchError> {
+		Ok(0)
+	}
+
+	fn is_stackable(_asset_id: (Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn check_collection_and_class(
+		_collection_id: GroupCollectionId,
+		_class_id: Self::ClassId,
+	) -> Result<bool, DispatchError> {
+		Ok(true)
+	}
+
+	fn create_token_class(
+		_sender: &AccountId,
+		_metadata: NftMetadata,
+		_attributes: Attributes,
+		_collection_id: GroupCollectionId,
+		_token_type: TokenType,
+		_collection_type: CollectionType,
+		_royalty_fee: Perbill,
+		_mint_limit: Option<u32>,
+	) -> Result<ClassId, DispatchError> {
+		let key = b"xnft/next_class_id".to_vec();
+		let class_id = unhashed::get_or(&key, DERIVATIVE_CLASS_ID);
+		unhashed::put(&key, &(class_id + 1));
+		Ok(class_id)
+	}
+
+	fn mint_token(
+		_sender: &AccountId,
+		_class_id: Self::ClassId,
+		_metadata: NftMetadata,
+		_attributes: Attributes,
+	) -> Result<Self::TokenId, DispatchError> {
+		Err(DispatchError::Other("Not supported"))
+	}
+
+	fn mint_token_with_id(
+		sender: &AccountId,
+		class_id: Self::ClassId,
+		token_id: Self::TokenId,
+		_metadata: NftMetadata,
+		_attributes: Attributes,
+	) -> Result<Self::TokenId, DispatchError> {
+		ensure!(
+			Self::owner(&(class_id, token_id)).is_none(),
+			DispatchError::Other("TokenAlreadyExists")
+		);
+		Self::set_owner(&(class_id, token_id), *sender);
+		Ok(token_id)
+	}
+
+	fn burn_nft(account: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
+		ensure!(Self::owner(nft) == Some(*account), DispatchError::Other("NoPermission"));
+		unhashed::kill(&Self::owner_key(nft));
+		Ok(())
+	}
+
+	fn check_item_on_listing(_class_id: Self::ClassId, _token_id: Self::TokenId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn transfer_nft(sender: &AccountId, to: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
+		ensure!(Self::owner(nft) == Some(*sender), DispatchError::Other("NoPermission"));
+		Self::set_owner(nft, *to);
+		Ok(())
+	}
+
+	fn is_transferable(_nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(true)
+	}
+
+	fn get_class_fund(_class_id: &Self::ClassId) -> AccountId {
+		0
+	}
+
+	fn set_lock_collection(_class_id: Self::ClassId, _is_locked: bool) -> DispatchResult {
+		Ok(())
+	}
+
+	fn set_lock_nft(_token_id: (Self::ClassId, Self::TokenId), _is_locked: bool) -> DispatchResult {
+		Ok(())
+	}
+
+	fn get_nft_class_detail(_class_id: Self::ClassId) -> Result<NftClassData<Balance>, DispatchError> {
+		Err(DispatchError::Other("Not supported"))
+	}
+
+	fn get_total_issuance(_class_id: Self::ClassId) -> Result<Self::TokenId, DispatchError> {
+		Ok(0)
+	}
+
+	fn get_asset_owner(asset_id: &(Self::ClassId, Self::TokenId)) -> Result<AccountId, DispatchError> {
+		Self::owner(asset_id).ok_or(DispatchError::Other("AssetInfoNotFound"))
+	}
+
+	fn get_free_stackable_nft_balance(_who: &AccountId, _asset_id: &(Self::ClassId, Self::TokenId)) -> Balance {
+		0
+	}
+
+	fn reserve_stackable_nft_balance(
+		_who: &AccountId,
+		_asset_id: &(Self::ClassId, Self::TokenId),
+		_amount: Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn unreserve_stackable_nft_balance(
+		_who: &AccountId,
+		_asset_id: &(Self::ClassId, Self::TokenId),
+		_amount: Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn transfer_stackable_nft(
+		_sender: &AccountId,
+		_to: &AccountId,
+		_nft: &(Self::ClassId, Self::TokenId),
+		_amount: Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn get_royalty_payouts(
+		_class_id: &Self::ClassId,
+		_sale_price: Balance,
+	) -> Result<Vec<(AccountId, Balance)>, DispatchError> {
+		Ok(vec![])
+	}
+}
+
+pub fn account_location(account: AccountId) -> MultiLocation {
+	X1(AccountIndex64 {
+		network: None,
+		index: account as u64,
+	})
+	.into()
+}
+
+pub fn chain_location(para_id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(para_id)))
+}
+
+pub struct AccountIdToMultiLocation;
+
+impl Convert<AccountId, MultiLocation> for AccountIdToMultiLocation {
+	fn convert(account: AccountId) -> MultiLocation {
+		account_location(account)
+	}
+}
+
+/// Accounts are `AccountIndex64` junctions, sibling chains get their para id offset by 1000000
+pub struct LocationToAccountId;
+
+impl ConvertLocation<AccountId> for LocationToAccountId {
+	fn convert_location(location: &MultiLocation) -> Option<AccountId> {
+		match (location.parents, &location.interior) {
+			(0, X1(AccountIndex64 { index, .. })) => Some(*index as AccountId),
+			(1, X1(Parachain(para_id))) => Some(1_000_000 + *para_id as AccountId),
+			_ => None,
+		}
+	}
+}
+
+pub struct CurrencyIdConvert;
+
+impl Convert<FungibleTokenId, Option<MultiLocation>> for CurrencyIdConvert {
+	fn convert(currency_id: FungibleTokenId) -> Option<MultiLocation> {
+		match currency_id {
+			NATIVE => Some(MultiLocation::new(1, X2(Parachain(PARA_ID), GeneralIndex(0)))),
+			KSM => Some(MultiLocation::parent()),
+			_ => None,
+		}
+	}
+}
+
+parameter_types! {
+	pub SelfLocation: MultiLocation = chain_location(PARA_ID);
+	pub UniversalLocation: InteriorMultiLocation = X2(GlobalConsensus(NetworkId::Kusama), Parachain(PARA_ID));
+	pub const NftPalletInstance: u8 = NFT_PALLET_INSTANCE;
+	pub const UnitWeightCost: Weight = Weight::from_parts(10, 10);
+	pub const MaxInstructions: u32 = 100;
+	pub const XnftPalletId: PalletId = PalletId(*b"bit/xnft");
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type NFTHandler = MockNFTHandler;
+	type SelfLocation = SelfLocation;
+	type UniversalLocation = UniversalLocation;
+	type NftPalletInstance = NftPalletInstance;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type LocationToAccountId = LocationToAccountId;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type ReserveProvider = AbsoluteReserveProvider;
+	type XcmExecutor = ();
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type PalletId = XnftPalletId;
+	type ForeignClassOrigin = EnsureSignedBy<Admin, AccountId>;
+	type WeightInfo = ();
+}
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Xnft: xnft::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+pub type XnftModule = Pallet<Runtime>;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			MockNFTHandler::set_owner(&ALICE_TOKEN, ALICE);
+		});
+		ext
+	}
+}
+
+pub fn last_event() -> RuntimeEvent {
+	frame_system::Pallet::<Runtime>::events()
+		.pop()
+		.expect("Event expected")
+		.event
+}
@@ -0,0 +1,367 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+use mock::{RuntimeEvent, *};
+
+use super::*;
+
+fn statemine_class_location() -> MultiLocation {
+	MultiLocation::new(1, X3(Parachain(STATEMINE_PARA_ID), PalletInstance(50), GeneralIndex(7)))
+}
+
+fn register_statemine_class() {
+	assert_ok!(XnftModule::register_foreign_class(
+		RuntimeOrigin::signed(ALICE),
+		Box::new(statemine_class_location().into()),
+		vec![1]
+	));
+}
+
+fn local_nft_asset(nft: (ClassId, TokenId)) -> MultiAsset {
+	(
+		XnftModule::local_class_location(nft.0),
+		AssetInstance::Index(nft.1.into()),
+	)
+		.into()
+}
+
+fn context() -> XcmContext {
+	XcmContext::with_message_id([0; 32])
+}
+
+fn beneficiary(para_id: u32, account: AccountId) -> Box<VersionedMultiLocation> {
+	Box::new(
+		MultiLocation::new(
+			1,
+			X2(
+				Parachain(para_id),
+				AccountIndex64 {
+					network: None,
+					index: account as u64,
+				},
+			),
+		)
+		.into(),
+	)
+}
+
+#[test]
+fn register_foreign_class_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		register_statemine_class();
+
+		assert_eq!(
+			XnftModule::foreign_class(statemine_class_location()),
+			Some(DERIVATIVE_CLASS_ID)
+		);
+		assert_eq!(
+			XnftModule::foreign_class_location(DERIVATIVE_CLASS_ID),
+			Some(statemine_class_location())
+		);
+		let event = RuntimeEvent::Xnft(crate::Event::ForeignClassRegistered {
+			location: statemine_class_location(),
+			class_id: DERIVATIVE_CLASS_ID,
+		});
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn register_foreign_class_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XnftModule::register_foreign_class(
+				RuntimeOrigin::signed(BOB),
+				Box::new(statemine_class_location().into()),
+				vec![1]
+			),
+			BadOrigin
+		);
+		// Local classes can not be registered as foreign classes
+		assert_noop!(
+			XnftModule::register_foreign_class(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(XnftModule::local_class_location(LOCAL_CLASS_ID).into()),
+				vec![1]
+			),
+			Error::<Runtime>::InvalidForeignClass
+		);
+		assert_noop!(
+			XnftModule::register_foreign_class(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(
+					MultiLocation::new(
+						1,
+						X3(Parachain(PARA_ID), PalletInstance(NFT_PALLET_INSTANCE), GeneralIndex(0))
+					)
+					.into()
+				),
+				vec![1]
+			),
+			Error::<Runtime>::InvalidForeignClass
+		);
+		register_statemine_class();
+		assert_noop!(
+			XnftModule::register_foreign_class(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(statemine_class_location().into()),
+				vec![1]
+			),
+			Error::<Runtime>::ForeignClassAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn local_nft_locked_and_restored_on_return_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset = local_nft_asset(ALICE_TOKEN);
+		let sibling = chain_location(SIBLING_PARA_ID);
+
+		// Outbound: withdrawn from Alice and deposited to the sibling chain
+		assert_ok!(XnftModule::withdraw_asset(
+			&asset,
+			&account_location(ALICE),
+			Some(&context())
+		));
+		assert_eq!(MockNFTHandler::owner(&ALICE_TOKEN), Some(XnftModule::account_id()));
+		assert_ok!(XnftModule::deposit_asset(&asset, &sibling, &context()));
+		assert_eq!(XnftModule::outbound_nft(ALICE_TOKEN), Some(sibling));
+		let event = RuntimeEvent::Xnft(crate::Event::NftLocked {
+			class_id: LOCAL_CLASS_ID,
+			token_id: 0,
+			chain: sibling,
+		});
+		assert_eq!(last_event(), event);
+
+		// Inbound: the sibling sends it back with the asset expressed from the relay chain
+		let returned_asset: MultiAsset = (
+			MultiLocation::new(
+				1,
+				X3(
+					Parachain(PARA_ID),
+					PalletInstance(NFT_PALLET_INSTANCE),
+					GeneralIndex(LOCAL_CLASS_ID.into()),
+				),
+			),
+			AssetInstance::Index(0),
+		)
+			.into();
+		assert_ok!(XnftModule::withdraw_asset(&returned_asset, &sibling, Some(&context())));
+		assert_eq!(XnftModule::outbound_nft(ALICE_TOKEN), None);
+		assert_ok!(XnftModule::deposit_asset(
+			&returned_asset,
+			&account_location(BOB),
+			&context()
+		));
+		assert_eq!(MockNFTHandler::owner(&ALICE_TOKEN), Some(BOB));
+		let event = RuntimeEvent::Xnft(crate::Event::NftRestored {
+			class_id: LOCAL_CLASS_ID,
+			token_id: 0,
+			owner: BOB,
+		});
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn local_nft_withdraw_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset = local_nft_asset(ALICE_TOKEN);
+		let sibling = chain_location(SIBLING_PARA_ID);
+
+		// Bob does not own the NFT
+		assert!(XnftModule::withdraw_asset(&asset, &account_location(BOB), Some(&context())).is_err());
+
+		assert_ok!(XnftModule::withdraw_asset(
+			&asset,
+			&account_location(ALICE),
+			Some(&context())
+		));
+		assert_ok!(XnftModule::deposit_asset(&asset, &sibling, &context()));
+
+		// Only the chain holding the derivative can send it back
+		assert!(XnftModule::withdraw_asset(&asset, &chain_location(STATEMINE_PARA_ID), Some(&context())).is_err());
+		// The NFT can not be released while it is away
+		assert!(XnftModule::deposit_asset(&asset, &account_location(BOB), &context()).is_err());
+		assert_eq!(XnftModule::outbound_nft(ALICE_TOKEN), Some(sibling));
+	});
+}
+
+#[test]
+fn foreign_nft_derivative_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		register_statemine_class();
+		let asset: MultiAsset = (statemine_class_location(), AssetInstance::Index(5)).into();
+		let derivative = (DERIVATIVE_CLASS_ID, 5);
+
+		assert_ok!(XnftModule::deposit_asset(&asset, &account_location(BOB), &context()));
+		assert_eq!(MockNFTHandler::owner(&derivative), Some(BOB));
+		let event = RuntimeEvent::Xnft(crate::Event::DerivativeMinted {
+			class_id: DERIVATIVE_CLASS_ID,
+			token_id: 5,
+			owner: BOB,
+		});
+		assert_eq!(last_event(), event);
+
+		// Derivatives can not be deposited to another chain
+		assert!(XnftModule::deposit_asset(&asset, &chain_location(SIBLING_PARA_ID), &context()).is_err());
+
+		assert_ok!(XnftModule::withdraw_asset(
+			&asset,
+			&account_location(BOB),
+			Some(&context())
+		));
+		assert_eq!(MockNFTHandler::owner(&derivative), None);
+		let event = RuntimeEvent::Xnft(crate::Event::DerivativeBurned {
+			class_id: DERIVATIVE_CLASS_ID,
+			token_id: 5,
+			owner: BOB,
+		});
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn unknown_assets_are_not_found() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Fungible assets are left to the fungible asset transactor
+		let fungible: MultiAsset = (MultiLocation::parent(), 100).into();
+		assert_eq!(
+			XnftModule::deposit_asset(&fungible, &account_location(BOB), &context()),
+			Err(XcmError::AssetNotFound)
+		);
+		// Foreign classes must be registered first
+		let foreign: MultiAsset = (statemine_class_location(), AssetInstance::Index(5)).into();
+		assert_eq!(
+			XnftModule::deposit_asset(&foreign, &account_location(BOB), &context()),
+			Err(XcmError::AssetNotFound)
+		);
+		// Local classes of another pallet are not NFTs
+		let other_pallet: MultiAsset = (
+			MultiLocation::new(0, X2(PalletInstance(1), GeneralIndex(0))),
+			AssetInstance::Index(0),
+		)
+			.into();
+		assert!(XnftModule::withdraw_asset(&other_pallet, &account_location(ALICE), None).is_err());
+	});
+}
+
+#[test]
+fn transfer_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		register_statemine_class();
+		MockNFTHandler::set_owner(&(DERIVATIVE_CLASS_ID, 5), ALICE);
+
+		assert_noop!(
+			XnftModule::transfer(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_TOKEN,
+				NATIVE,
+				100,
+				Box::new(chain_location(SIBLING_PARA_ID).into()),
+				WeightLimit::Unlimited
+			),
+			Error::<Runtime>::InvalidDest
+		);
+		assert_noop!(
+			XnftModule::transfer(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_TOKEN,
+				NATIVE,
+				0,
+				beneficiary(SIBLING_PARA_ID, BOB),
+				WeightLimit::Unlimited
+			),
+			Error::<Runtime>::ZeroFee
+		);
+		assert_noop!(
+			XnftModule::transfer(
+				RuntimeOrigin::signed(BOB),
+				ALICE_TOKEN,
+				NATIVE,
+				100,
+				beneficiary(SIBLING_PARA_ID, BOB),
+				WeightLimit::Unlimited
+			),
+			Error::<Runtime>::NoPermission
+		);
+		// Local NFTs must be paid with an asset reserved on this chain
+		assert_noop!(
+			XnftModule::transfer(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_TOKEN,
+				KSM,
+				100,
+				beneficiary(SIBLING_PARA_ID, BOB),
+				WeightLimit::Unlimited
+			),
+			Error::<Runtime>::InvalidFeeAsset
+		);
+		assert_noop!(
+			XnftModule::transfer(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_TOKEN,
+				FungibleTokenId::MiningResource(0),
+				100,
+				beneficiary(SIBLING_PARA_ID, BOB),
+				WeightLimit::Unlimited
+			),
+			Error::<Runtime>::NotCrossChainTransferableCurrency
+		);
+		// Derivatives only go back to their reserve
+		assert_noop!(
+			XnftModule::transfer(
+				RuntimeOrigin::signed(ALICE),
+				(DERIVATIVE_CLASS_ID, 5),
+				NATIVE,
+				100,
+				beneficiary(SIBLING_PARA_ID, BOB),
+				WeightLimit::Unlimited
+			),
+			Error::<Runtime>::NotNftReserve
+		);
+		assert_noop!(
+			XnftModule::transfer(
+				RuntimeOrigin::signed(ALICE),
+				(DERIVATIVE_CLASS_ID, 5),
+				NATIVE,
+				100,
+				beneficiary(STATEMINE_PARA_ID, BOB),
+				WeightLimit::Unlimited
+			),
+			Error::<Runtime>::InvalidFeeAsset
+		);
+		// The mock runtime has no XCM executor
+		assert_noop!(
+			XnftModule::transfer(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_TOKEN,
+				NATIVE,
+				100,
+				beneficiary(SIBLING_PARA_ID, BOB),
+				WeightLimit::Unlimited
+			),
+			Error::<Runtime>::XcmExecutionFailed
+		);
+	});
+}
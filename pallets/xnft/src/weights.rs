@@ -0,0 +1,71 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for xnft, estimated from storage access until benchmarks are added
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for xnft.
+pub trait WeightInfo {
+	fn register_foreign_class() -> Weight;
+	fn transfer() -> Weight;
+}
+
+/// Weights for xnft using the for collator node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Xnft ForeignClasses (r:1 w:1)
+	// Storage: Nft Classes (r:1 w:1)
+	// Storage: OrmlNft NextClassId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Xnft ForeignClassLocations (r:0 w:1)
+	fn register_foreign_class() -> Weight {
+		Weight::from_parts(45_000_000, 8000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: Xnft ForeignClassLocations (r:1 w:0)
+	// Storage: OrmlNft Tokens (r:1 w:1)
+	// Storage: OrmlNft TokensByOwner (r:0 w:2)
+	// Storage: Xnft OutboundNfts (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	fn transfer() -> Weight {
+		Weight::from_parts(120_000_000, 12000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn register_foreign_class() -> Weight {
+		Weight::from_parts(45_000_000, 8000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	fn transfer() -> Weight {
+		Weight::from_parts(120_000_000, 12000)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+}
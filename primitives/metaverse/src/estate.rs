@@ -6,7 +6,7 @@ use sp_runtime::DispatchError;
 use sp_runtime::RuntimeDebug;
 
 use crate::UndeployedLandBlockId;
-use crate::{EstateId, MetaverseId};
+use crate::{ClassId, EstateId, MetaverseId, TokenId, ZoneId};
 
 pub trait Estate<AccountId> {
 	fn transfer_estate(estate_id: EstateId, from: &AccountId, to: &AccountId) -> Result<EstateId, DispatchError>;
@@ -41,6 +41,10 @@ pub trait Estate<AccountId> {
 	fn is_estate_leasor(leasor: AccountId, estate_id: EstateId) -> Result<bool, DispatchError>;
 
 	fn is_estate_leased(estate_id: EstateId) -> Result<bool, DispatchError>;
+
+	fn get_estate_token(estate_id: EstateId) -> Option<(ClassId, TokenId)>;
+
+	fn get_land_unit_token(metaverse_id: MetaverseId, coordinate: (i32, i32)) -> Option<(ClassId, TokenId)>;
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -58,6 +62,85 @@ pub enum OwnerId<AccountId, ClassId, TokenId> {
 	Token(ClassId, TokenId),
 }
 
+/// Land and estate operation applied as a step of a batch
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum EstateOperation<AccountId> {
+	/// Create an estate from land units [Metaverse Id, Coordinates]
+	CreateEstate(MetaverseId, Vec<(i32, i32)>),
+	/// Add land units to an estate [Estate Id, Coordinates]
+	AddLandUnits(EstateId, Vec<(i32, i32)>),
+	/// Remove land units from an estate [Estate Id, Coordinates]
+	RemoveLandUnits(EstateId, Vec<(i32, i32)>),
+	/// Transfer a land unit [To Account Id, Metaverse Id, Coordinate]
+	TransferLand(AccountId, MetaverseId, (i32, i32)),
+	/// Transfer an estate [To Account Id, Estate Id]
+	TransferEstate(AccountId, EstateId),
+	/// Deploy an undeployed land block [Undeployed Land Block Id, Metaverse Id, Land Block
+	/// Coordinate, Coordinates]
+	DeployLandBlock(UndeployedLandBlockId, MetaverseId, (i32, i32), Vec<(i32, i32)>),
+}
+
+/// Rule defining which land units of an estate are considered adjacent
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum EstateConnectivity {
+	/// Land units sharing an edge are adjacent
+	FourConnected,
+	/// Land units sharing an edge or a corner are adjacent
+	EightConnected,
+}
+
+impl EstateConnectivity {
+	/// Offsets of the land units adjacent to a land unit
+	pub fn neighbour_offsets(&self) -> &'static [(i32, i32)] {
+		match self {
+			EstateConnectivity::FourConnected => &[(0, 1), (1, 0), (0, -1), (-1, 0)],
+			EstateConnectivity::EightConnected => {
+				&[(0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0), (-1, 1)]
+			}
+		}
+	}
+}
+
+/// Land unit or estate that metadata and build permissions apply to
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum LandAsset {
+	/// Land unit [Metaverse Id, Coordinate]
+	LandUnit(MetaverseId, (i32, i32)),
+	/// Estate [Estate Id]
+	Estate(EstateId),
+}
+
+/// Metadata of a land unit or an estate
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub struct LandMetadata<Hash> {
+	/// Zoning type defined by the metaverse owner
+	pub zone: Option<ZoneId>,
+	/// Content hash of the scene built on the land
+	pub scene_hash: Option<Hash>,
+}
+
+/// Role granted to an account building on a land unit or an estate
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum BuilderRole {
+	/// Can update the scene
+	Builder,
+	/// Can update the scene and grant or revoke the builder role
+	Moderator,
+}
+
+/// Build permission granted on a land unit or an estate
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct BuildPermission<AccountId> {
+	/// Granted role
+	pub role: BuilderRole,
+	/// Account which granted the role, the role is valid while it controls the land
+	pub granted_by: AccountId,
+}
+
 #[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum LandUnitStatus<AccountId> {
 	NonExisting,
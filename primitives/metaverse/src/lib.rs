@@ -25,7 +25,7 @@ use sp_runtime::RuntimeDebug;
 use sp_runtime::{
 	generic,
 	traits::{BlakeTwo256, IdentifyAccount, Verify},
-	MultiSignature,
+	DispatchError, MultiSignature,
 };
 use sp_runtime::{FixedU128, OpaqueExtrinsic as UncheckedExtrinsic};
 use sp_std::collections::btree_map::BTreeMap;
@@ -92,6 +92,12 @@ pub type ClassId = u32;
 pub type NftId = u64;
 /// AuctionId
 pub type AuctionId = u64;
+/// Drop ID
+pub type DropId = u64;
+/// Swap ID
+pub type SwapId = u64;
+/// Crafting recipe ID
+pub type CraftingRecipeId = u64;
 /// SpotId
 pub type SpotId = u64;
 /// MapSpotId
@@ -104,6 +110,8 @@ pub type ReferendumId = u64;
 pub type LandId = u64;
 /// EstateId
 pub type EstateId = u64;
+/// Land zoning type Id
+pub type ZoneId = u32;
 /// Number of era on relaychain
 pub type EraIndex = u32;
 /// Social Token Id type
@@ -128,6 +136,8 @@ pub type NftMetadata = Vec<u8>;
 pub type Attributes = BTreeMap<Vec<u8>, Vec<u8>>;
 /// Weight ratio
 pub type Ratio = FixedU128;
+/// Price of one smallest unit of a currency in a reference currency
+pub type Price = FixedU128;
 /// Trie index
 pub type TrieIndex = u32;
 /// Campaign index
@@ -155,6 +165,8 @@ pub enum ItemId<Balance> {
 	LandUnit((i32, i32), MetaverseId),
 	Bundle(Vec<(ClassId, TokenId, Balance)>),
 	UndeployedLandBlock(UndeployedLandBlockId),
+	/// Bundle of any items with the listing price share of each item
+	MixedBundle(Vec<(ItemId<Balance>, Balance)>),
 }
 
 impl<Balance: AtLeast32Bit + Copy> ItemId<Balance> {
@@ -239,6 +251,17 @@ pub struct NftOffer<Balance, BlockNumber> {
 	pub end_block: BlockNumber,
 }
 
+/// NFT equipped into a slot of another NFT, with the NFTs equipped into it
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct EquippedNft<ClassId, TokenId> {
+	/// Slot of the parent NFT
+	pub slot: Vec<u8>,
+	/// The equipped NFT
+	pub asset: (ClassId, TokenId),
+	/// NFTs equipped into the equipped NFT
+	pub children: Vec<EquippedNft<ClassId, TokenId>>,
+}
+
 /// App-specific crypto used for reporting equivocation/misbehavior in BABE and
 /// GRANDPA. Any rewards for misbehavior reporting will be paid out to this
 /// account.
@@ -409,6 +432,13 @@ pub trait ForeignAssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata> {
 	/// Returns the CurrencyId associated with a given MultiLocation.
 	fn get_currency_id(multi_location: MultiLocation) -> Option<FungibleTokenId>;
 }
+
+/// Registry for fungible tokens issued on this chain.
+pub trait FungibleTokenRegistry<Balance> {
+	/// Registers the metadata of a new fungible token and returns its currency id.
+	fn register_fungible_token(metadata: &AssetMetadata<Balance>) -> Result<FungibleTokenId, DispatchError>;
+}
+
 #[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum RewardType<FungibleTokenId, Balance, ClassId, TokenId> {
 	FungibleTokens(FungibleTokenId, Balance),
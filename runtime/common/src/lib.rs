@@ -16,13 +16,15 @@
 // limitations under the License.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	traits::Get,
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 use orml_traits::currency::MutationHooks;
 
-use sp_runtime::{FixedPointNumber, FixedU128};
+use scale_info::TypeInfo;
+use sp_runtime::{FixedPointNumber, FixedU128, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 use xcm::latest::prelude::*;
 use xcm_builder::TakeRevenue;
@@ -175,3 +177,24 @@ where
 	type OnNewTokenAccount = ();
 	type OnKilledTokenAccount = ();
 }
+
+/// The kinds of calls executable NFTs are allowed to dispatch, set per executable collection.
+/// Each runtime filters its own calls by implementing `InstanceFilter` for this type. Calls are
+/// filtered once the placeholder account of the collection call is replaced by the NFT holder.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum ExecutableNftCallType {
+	/// Transfer native or fungible tokens
+	Transfer,
+	/// Grant undeployed land blocks
+	LandBlockGrant,
+	/// Credit power
+	PowerCredit,
+	/// Mint or transfer reward NFTs
+	RewardMint,
+}
+
+impl Default for ExecutableNftCallType {
+	fn default() -> Self {
+		Self::Transfer
+	}
+}
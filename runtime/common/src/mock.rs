@@ -9,6 +9,7 @@ use frame_support::{
 	weights::Weight,
 	PalletId,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use frame_system::Call as SystemCall;
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
@@ -368,6 +369,24 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn sealed_bid_commit_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_commitment: [u8; 32],
+		_deposit: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn sealed_bid_reveal_handler(
+		_from: AccountId,
+		_id: AuctionId,
+		_value: Self::Balance,
+		_salt: Vec<u8>,
+	) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
 	fn local_auction_bid_handler(
 		_: BlockNumber,
 		_: AuctionId,
@@ -432,11 +451,27 @@ parameter_types! {
 	pub ClassMintingFee: Balance = 1;
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
+	pub MaxRoyaltyRecipients: u32 = 3;
+	pub MaxEquipmentDepth: u32 = 3;
+	pub MaxEquippedItems: u32 = 10;
+	pub MaxCraftingIngredients: u32 = 5;
+	pub MaxAttributeHistory: u32 = 3;
+	pub MaxTokenAttributes: u32 = 4;
 	pub MaxNftMetadata: u32 = 1024;
 	pub NftPalletId: PalletId = PalletId(*b"bit/bNFT");
 	pub const MetaverseNetworkTreasuryPalletId: PalletId = PalletId(*b"bit/trsy");
 }
 
+pub struct MockRandomness;
+impl frame_support::traits::Randomness<H256, BlockNumberFor<Runtime>> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumberFor<Runtime>) {
+		(
+			<BlakeTwo256 as sp_runtime::traits::Hash>::hash(subject),
+			frame_system::Pallet::<Runtime>::block_number(),
+		)
+	}
+}
+
 impl nft_pallet::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -454,6 +489,14 @@ impl nft_pallet::Config for Runtime {
 	type StorageDepositFee = StorageDepositFee;
 	type OffchainSignature = Signature;
 	type OffchainPublic = AccountPublic;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxEquipmentDepth = MaxEquipmentDepth;
+	type MaxEquippedItems = MaxEquippedItems;
+	type MaxCraftingIngredients = MaxCraftingIngredients;
+	type Randomness = MockRandomness;
+	type MaxAttributeHistory = MaxAttributeHistory;
+	type MaxTokenAttributes = MaxTokenAttributes;
+	type ExecutableCallFilter = ProxyType;
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
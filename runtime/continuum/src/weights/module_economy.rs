@@ -56,4 +56,9 @@ impl<T: frame_system::Config> economy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	fn transfer_power() -> Weight {
+		Weight::from_parts(21_480_000, 5160)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -20,3 +20,6 @@ mod estate;
 
 #[cfg(feature = "with-pioneer-runtime")]
 mod evm;
+
+#[cfg(feature = "with-pioneer-runtime")]
+mod nft_xcm;
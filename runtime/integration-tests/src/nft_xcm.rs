@@ -0,0 +1,173 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use sp_runtime::Perbill;
+use xcm::latest::{Junction, Junction::*, Junctions::*, MultiLocation, WeightLimit};
+use xcm_emulator::TestExt;
+
+use core_primitives::{CollectionType, NFTTrait, TokenType};
+use core_traits::{AssetMetadata, ClassId, FungibleTokenId, TokenId};
+use pioneer_runtime::{AssetManager, Xnft};
+
+use crate::relaychain::kusama_test_net::*;
+use crate::setup::*;
+
+fn account_on(para_id: u32, account: [u8; 32]) -> Box<xcm::VersionedMultiLocation> {
+	Box::new(
+		MultiLocation::new(
+			1,
+			X2(
+				Parachain(para_id),
+				Junction::AccountId32 {
+					network: None,
+					id: account,
+				},
+			),
+		)
+		.into(),
+	)
+}
+
+fn development_class_location(class_id: ClassId) -> MultiLocation {
+	MultiLocation::new(
+		1,
+		X3(
+			Parachain(PARA_ID_DEVELOPMENT),
+			PalletInstance(<Nft as frame_support::traits::PalletInfoAccess>::index() as u8),
+			GeneralIndex(class_id.into()),
+		),
+	)
+}
+
+/// Registers the Development native token on the sibling so it can pay for the return trip
+fn register_development_native_token() -> FungibleTokenId {
+	let token_id = AssetManager::next_foreign_asset_id();
+	assert_ok!(AssetManager::register_foreign_asset(
+		Origin::root(),
+		Box::new(
+			MultiLocation::new(
+				1,
+				X2(
+					Parachain(PARA_ID_DEVELOPMENT),
+					Junction::from(
+						frame_support::BoundedVec::try_from(FungibleTokenId::NativeToken(0).encode()).unwrap()
+					),
+				),
+			)
+			.into()
+		),
+		Box::new(AssetMetadata {
+			name: b"Development Token".to_vec(),
+			symbol: b"NEER".to_vec(),
+			decimals: 18,
+			minimal_balance: 0,
+		})
+	));
+	FungibleTokenId::FungibleToken(token_id)
+}
+
+fn mint_development_nft() -> (ClassId, TokenId) {
+	let class_id = OrmlNFT::next_class_id();
+	assert_ok!(Nft::create_group(RawOrigin::Root.into(), vec![1], vec![1]));
+	assert_ok!(Nft::create_class(
+		Origin::signed(ALICE.into()),
+		vec![1],
+		test_attributes(1),
+		0u32.into(),
+		TokenType::Transferable,
+		CollectionType::Collectable,
+		Perbill::from_percent(0u32),
+		None,
+	));
+	assert_ok!(Nft::mint(
+		Origin::signed(ALICE.into()),
+		class_id,
+		vec![2],
+		test_attributes(2),
+		1,
+	));
+	(class_id, 0)
+}
+
+#[test]
+fn transfer_nft_to_sibling_and_back() {
+	TestNet::reset();
+
+	let mut nft = (0, 0);
+	let mut derivative_class_id = 0;
+	let mut development_token = FungibleTokenId::NativeToken(0);
+
+	Development::execute_with(|| {
+		nft = mint_development_nft();
+	});
+
+	Sibling::execute_with(|| {
+		derivative_class_id = OrmlNFT::next_class_id();
+		assert_ok!(Xnft::register_foreign_class(
+			RawOrigin::Root.into(),
+			Box::new(development_class_location(nft.0).into()),
+			vec![1],
+		));
+		development_token = register_development_native_token();
+		assert_ok!(Currencies::deposit(development_token, &BOB.into(), native_amount(1)));
+	});
+
+	Development::execute_with(|| {
+		assert_ok!(Xnft::transfer(
+			Origin::signed(ALICE.into()),
+			nft,
+			FungibleTokenId::NativeToken(0),
+			native_amount(1),
+			account_on(PARA_ID_SIBLING, BOB),
+			WeightLimit::Unlimited,
+		));
+
+		// The NFT stays in the xnft vault while the sibling holds the derivative
+		assert_eq!(Nft::check_ownership(&Xnft::account_id(), &nft), Ok(true));
+		assert_eq!(
+			Xnft::outbound_nft(nft),
+			Some(MultiLocation::new(1, X1(Parachain(PARA_ID_SIBLING))))
+		);
+	});
+
+	Sibling::execute_with(|| {
+		assert_eq!(
+			Nft::check_ownership(&BOB.into(), &(derivative_class_id, nft.1)),
+			Ok(true)
+		);
+
+		assert_ok!(Xnft::transfer(
+			Origin::signed(BOB.into()),
+			(derivative_class_id, nft.1),
+			development_token,
+			native_amount(1),
+			account_on(PARA_ID_DEVELOPMENT, BOB),
+			WeightLimit::Unlimited,
+		));
+
+		// The derivative is burned on the way back
+		assert!(OrmlNFT::tokens(derivative_class_id, nft.1).is_none());
+	});
+
+	Development::execute_with(|| {
+		assert_eq!(Xnft::outbound_nft(nft), None);
+		assert_eq!(Nft::check_ownership(&BOB.into(), &nft), Ok(true));
+	});
+}
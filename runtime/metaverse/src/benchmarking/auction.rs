@@ -1,7 +1,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{account, whitelisted_caller};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, BoundedVec};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
 use frame_system::RawOrigin;
 use orml_benchmarking::runtime_benchmarks;
@@ -10,14 +10,16 @@ use sp_runtime::Perbill;
 use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec};
 
 use auction::Config;
-use auction_manager::{CheckAuctionItemHandler, ListingLevel};
+use auction_manager::{CheckAuctionItemHandler, ListingLevel, SealedBidSettlement};
 use core_primitives::{Attributes, CollectionType, MetaverseInfo, MetaverseTrait, NftMetadata, TokenType};
 use primitives::{
-	AccountId, FungibleTokenId, ItemId, UndeployedLandBlock, UndeployedLandBlockId, UndeployedLandBlockType,
+	AccountId, Balance, BlockNumber, FungibleTokenId, ItemId, UndeployedLandBlock, UndeployedLandBlockId,
+	UndeployedLandBlockType,
 };
 
 use crate::{
-	Auction, Balances, Currencies, Metaverse, MinimumAuctionDuration, Nft, Runtime, RuntimeCall, RuntimeEvent, System,
+	Auction, Balances, Currencies, MaxSealedBidsPerAuction, MaxSwapItems, Metaverse, MinimumAuctionDuration, Nft,
+	Runtime, RuntimeCall, RuntimeEvent, SealedBidRevealPeriod, System,
 };
 
 use super::utils::{
@@ -46,6 +48,38 @@ pub fn run_to_block(n: u32) {
 	}
 }
 
+// Create a sealed-bid auction of an NFT of the caller, returning the block its reveal phase starts
+fn create_sealed_bid_auction(caller: &AccountId) -> BlockNumber {
+	create_nft_group();
+	mint_NFT(caller, 0u32);
+	set_metaverse_treasury_initial_balance();
+	assert_ok!(Metaverse::create_metaverse(
+		RawOrigin::Signed(caller.clone()).into(),
+		vec![1u8]
+	));
+	let reveal_start = System::block_number() + MinimumAuctionDuration::get();
+	assert_ok!(Auction::create_new_sealed_bid_auction(
+		RawOrigin::Signed(caller.clone()).into(),
+		ItemId::NFT(0, 0),
+		dollar(1),
+		reveal_start,
+		ListingLevel::Local(METAVERSE_ID),
+		CURRENCY_ID,
+		SealedBidSettlement::SecondPrice
+	));
+	reveal_start
+}
+
+// Mint n NFTs of separate classes to be offered in a swap
+fn swap_nfts(owner: &AccountId, n: u32) -> BoundedVec<ItemId<Balance>, MaxSwapItems> {
+	let mut items = Vec::new();
+	for i in 0..n {
+		mint_NFT(owner, i);
+		items.push(ItemId::NFT(i, 0));
+	}
+	items.try_into().unwrap()
+}
+
 runtime_benchmarks! {
 	{ Runtime, auction }
 
@@ -196,6 +230,106 @@ runtime_benchmarks! {
 		Auction::on_finalize(System::block_number() + MinimumAuctionDuration::get());
 	}
 
+	// propose_swap offering n NFTs for an amount of native token
+	propose_swap {
+		let n in 1 .. MaxSwapItems::get();
+		System::set_block_number(1u32.into());
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		create_nft_group();
+		let offered_items = swap_nfts(&caller, n);
+	}: _(RawOrigin::Signed(caller.clone()), offered_items, Default::default(), Default::default(), vec![(CURRENCY_ID, dollar(1))].try_into().unwrap(), None, 100u32.into())
+
+	// accept_swap of n NFTs with a royalty fee for an amount of native token
+	accept_swap {
+		let n in 1 .. MaxSwapItems::get();
+		System::set_block_number(1u32.into());
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let acceptor: AccountId = account("acceptor", 0, SEED);
+		set_balance(CURRENCY_ID, &acceptor, dollar(1000));
+		let royalty_recipient: AccountId = account("royalty_recipient", 0, SEED);
+		create_nft_group();
+		let offered_items = swap_nfts(&caller, n);
+		for class_id in 0..n {
+			assert_ok!(Nft::force_update_royalty_fee(RawOrigin::Root.into(), class_id, Perbill::from_percent(10u32)));
+			assert_ok!(Nft::set_class_royalty_recipients(RawOrigin::Signed(caller.clone()).into(), class_id, vec![(royalty_recipient.clone(), Perbill::from_percent(5u32))]));
+		}
+		assert_ok!(Auction::propose_swap(RawOrigin::Signed(caller.clone()).into(), offered_items, Default::default(), Default::default(), vec![(CURRENCY_ID, dollar(1))].try_into().unwrap(), None, 100u32.into()));
+	}: _(RawOrigin::Signed(acceptor.clone()), 0u32.into())
+
+	// cancel_swap offering n NFTs
+	cancel_swap {
+		let n in 1 .. MaxSwapItems::get();
+		System::set_block_number(1u32.into());
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		create_nft_group();
+		let offered_items = swap_nfts(&caller, n);
+		assert_ok!(Auction::propose_swap(RawOrigin::Signed(caller.clone()).into(), offered_items, Default::default(), Default::default(), vec![(CURRENCY_ID, dollar(1))].try_into().unwrap(), None, 100u32.into()));
+	}: _(RawOrigin::Signed(caller.clone()), 0u32.into())
+
+	// create_new_sealed_bid_auction
+	create_new_sealed_bid_auction{
+		System::set_block_number(1u32.into());
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		create_nft_group();
+		mint_NFT(&caller, 0u32);
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+	}: _(RawOrigin::Signed(caller.clone()), ItemId::NFT(0,0), dollar(1), System::block_number() + MinimumAuctionDuration::get(), ListingLevel::Local(METAVERSE_ID), CURRENCY_ID, SealedBidSettlement::SecondPrice)
+
+	// commit_bid
+	commit_bid{
+		System::set_block_number(1u32.into());
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let bidder: AccountId = account("bidder", 0, SEED);
+		set_balance(CURRENCY_ID, &bidder, dollar(1000));
+		create_sealed_bid_auction(&caller);
+		let commitment = Auction::sealed_bid_commitment(0, &bidder, dollar(2), &[1]);
+	}: _(RawOrigin::Signed(bidder.clone()), 0u32.into(), commitment, dollar(2))
+
+	// reveal_bid
+	reveal_bid{
+		System::set_block_number(1u32.into());
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let bidder: AccountId = account("bidder", 0, SEED);
+		set_balance(CURRENCY_ID, &bidder, dollar(1000));
+		let reveal_start = create_sealed_bid_auction(&caller);
+		let commitment = Auction::sealed_bid_commitment(0, &bidder, dollar(2), &[1]);
+		assert_ok!(Auction::commit_bid(RawOrigin::Signed(bidder.clone()).into(), 0u32.into(), commitment, dollar(2)));
+		System::set_block_number(reveal_start);
+	}: _(RawOrigin::Signed(bidder.clone()), 0u32.into(), dollar(2), vec![1])
+
+	// settle_sealed_bids of b revealed bids when the auction ends
+	settle_sealed_bids{
+		let b in 1 .. MaxSealedBidsPerAuction::get();
+		System::set_block_number(1u32.into());
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let reveal_start = create_sealed_bid_auction(&caller);
+		let bidders: Vec<AccountId> = (0..b).map(|i| account("bidder", i, SEED)).collect();
+		for (i, bidder) in bidders.iter().enumerate() {
+			let value = dollar(2 + i as u32);
+			set_balance(CURRENCY_ID, bidder, dollar(1000));
+			let commitment = Auction::sealed_bid_commitment(0, bidder, value, &[1]);
+			assert_ok!(Auction::commit_bid(RawOrigin::Signed(bidder.clone()).into(), 0u32.into(), commitment, value));
+		}
+		System::set_block_number(reveal_start);
+		for (i, bidder) in bidders.iter().enumerate() {
+			assert_ok!(Auction::reveal_bid(RawOrigin::Signed(bidder.clone()).into(), 0u32.into(), dollar(2 + i as u32), vec![1]));
+		}
+		let auction_end = reveal_start + SealedBidRevealPeriod::get();
+		System::set_block_number(auction_end);
+	}: {
+		Auction::on_initialize(auction_end);
+	}
+	verify {
+		assert_eq!(Auction::sealed_bid_count(0), 0);
+	}
 }
 
 #[cfg(test)]
@@ -215,6 +215,15 @@ runtime_benchmarks! {
 		run_to_block(100);
 	}: _(RawOrigin::Signed(caller.clone()))
 
+	// transfer power
+	transfer_power{
+		let caller: AccountId = whitelisted_caller();
+		let target: AccountId = account("target", 0, SEED);
+		economy::PowerBalance::<Runtime>::insert(&caller, 1000);
+	}: _(RawOrigin::Signed(caller.clone()), target.clone(), 100)
+	verify {
+		assert_eq!(Economy::get_power_balance(target), 100);
+	}
 }
 
 #[cfg(test)]
@@ -7,7 +7,7 @@ use orml_benchmarking::runtime_benchmarks;
 use sp_runtime::traits::{AccountIdConversion, Lookup, StaticLookup, UniqueSaturatedInto};
 
 use estate::{MintingRateConfig, MintingRateInfo, Round};
-use primitives::estate::{EstateInfo, OwnerId};
+use primitives::estate::{BuilderRole, EstateInfo, LandAsset, OwnerId};
 use primitives::staking::RoundInfo;
 use primitives::UndeployedLandBlockType;
 use primitives::{AccountId, Balance, FungibleTokenId, TokenId};
@@ -16,7 +16,10 @@ use super::utils::{
 	create_nft_group, dollar, issue_new_undeployed_land_block, mint_NFT, set_balance,
 	set_metaverse_treasury_initial_balance,
 };
-use crate::{Currencies, Estate, Metaverse, MinimumStake, Runtime, RuntimeCall, RuntimeEvent, System};
+use crate::{
+	Currencies, Estate, LocalMetaverseFundPalletId, MaxReshapeLandUnits, Metaverse, MinimumStake, Runtime, RuntimeCall,
+	RuntimeEvent, System,
+};
 
 //pub type AccountId = u128;
 pub type LandId = u64;
@@ -181,6 +184,136 @@ runtime_benchmarks! {
 		assert_eq!(Estate::get_estate_owner(0), Some(OwnerId::Token(1,0)));
 	}
 
+	// merge_estates
+	merge_estates {
+		let u in 4 .. MaxReshapeLandUnits::get();
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		let land_units: Vec<(i32, i32)> = (0..u as i32).map(|x| (x, 0)).collect();
+		Estate::mint_lands(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, land_units.clone());
+		Estate::create_estate(RawOrigin::Signed(caller.clone()).into(), METAVERSE_ID, land_units[..(u / 2) as usize].to_vec());
+		Estate::create_estate(RawOrigin::Signed(caller.clone()).into(), METAVERSE_ID, land_units[(u / 2) as usize..].to_vec());
+	}: _(RawOrigin::Signed(caller.clone()), 0, 1)
+	verify {
+		assert_eq!(Estate::get_estates(0).map(|estate_info| estate_info.land_units.len() as u32), Some(u));
+		assert_eq!(Estate::get_estates(1), None);
+	}
+
+	// split_estate
+	split_estate {
+		let u in 4 .. MaxReshapeLandUnits::get();
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		let land_units: Vec<(i32, i32)> = (0..u as i32).map(|x| (x, 0)).collect();
+		Estate::mint_lands(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, land_units.clone());
+		Estate::create_estate(RawOrigin::Signed(caller.clone()).into(), METAVERSE_ID, land_units.clone());
+	}: _(RawOrigin::Signed(caller.clone()), 0, land_units[(u / 2) as usize..].to_vec())
+	verify {
+		assert_eq!(Estate::get_estates(0).map(|estate_info| estate_info.land_units.len() as u32), Some(u / 2));
+		assert!(Estate::get_estates(1).is_some());
+	}
+
+	// define_zone
+	define_zone {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+	}: _(RawOrigin::Signed(caller.clone()), METAVERSE_ID, 1, vec![1u8; 32])
+	verify {
+		assert!(Estate::zones(METAVERSE_ID, 1).is_some());
+	}
+
+	// remove_zone
+	remove_zone {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::define_zone(RawOrigin::Signed(caller.clone()).into(), METAVERSE_ID, 1, vec![1u8; 32]);
+	}: _(RawOrigin::Signed(caller.clone()), METAVERSE_ID, 1)
+	verify {
+		assert!(Estate::zones(METAVERSE_ID, 1).is_none());
+	}
+
+	// set_land_zone
+	set_land_zone {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2]);
+		Estate::define_zone(RawOrigin::Signed(caller.clone()).into(), METAVERSE_ID, 1, vec![1u8; 32]);
+	}: _(RawOrigin::Signed(caller.clone()), LandAsset::Estate(ESTATE_ID), Some(1))
+	verify {
+		assert_eq!(Estate::land_metadata(LandAsset::Estate(ESTATE_ID)).and_then(|metadata| metadata.zone), Some(1));
+	}
+
+	// set_land_scene
+	set_land_scene {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let builder: AccountId = account("builder", 0, SEED);
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2]);
+		Estate::grant_builder_role(RawOrigin::Signed(caller.clone()).into(), LandAsset::Estate(ESTATE_ID), builder.clone(), BuilderRole::Builder);
+	}: _(RawOrigin::Signed(builder.clone()), LandAsset::Estate(ESTATE_ID), Some(Default::default()))
+	verify {
+		assert!(Estate::land_metadata(LandAsset::Estate(ESTATE_ID)).is_some());
+	}
+
+	// grant_builder_role
+	grant_builder_role {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let moderator: AccountId = account("moderator", 0, SEED);
+		let builder: AccountId = account("builder", 0, SEED);
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2]);
+		Estate::grant_builder_role(RawOrigin::Signed(caller.clone()).into(), LandAsset::Estate(ESTATE_ID), moderator.clone(), BuilderRole::Moderator);
+	}: _(RawOrigin::Signed(moderator.clone()), LandAsset::Estate(ESTATE_ID), builder.clone(), BuilderRole::Builder)
+	verify {
+		assert!(Estate::builders(LandAsset::Estate(ESTATE_ID), builder).is_some());
+	}
+
+	// revoke_builder_role
+	revoke_builder_role {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let moderator: AccountId = account("moderator", 0, SEED);
+		let builder: AccountId = account("builder", 0, SEED);
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2]);
+		Estate::grant_builder_role(RawOrigin::Signed(caller.clone()).into(), LandAsset::Estate(ESTATE_ID), moderator.clone(), BuilderRole::Moderator);
+		Estate::grant_builder_role(RawOrigin::Signed(caller.clone()).into(), LandAsset::Estate(ESTATE_ID), builder.clone(), BuilderRole::Builder);
+	}: _(RawOrigin::Signed(moderator.clone()), LandAsset::Estate(ESTATE_ID), builder.clone())
+	verify {
+		assert!(Estate::builders(LandAsset::Estate(ESTATE_ID), builder).is_none());
+	}
+
 	// transfer_estate
 	transfer_estate {
 		let caller: AccountId = whitelisted_caller();
@@ -350,6 +483,25 @@ runtime_benchmarks! {
 		}
 	}
 
+	// distribute_undeployed_land_blocks
+	distribute_undeployed_land_blocks {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		let target: AccountId = account("target", 0, SEED);
+		set_balance(CURRENCY_ID, &target, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		let metaverse_treasury: AccountId = LocalMetaverseFundPalletId::get().into_sub_account_truncating(METAVERSE_ID);
+		set_balance(CURRENCY_ID, &metaverse_treasury, dollar(1000));
+		Estate::issue_undeployed_land_blocks(RawOrigin::Root.into(), metaverse_treasury, 1, 100, UndeployedLandBlockType::Transferable);
+	}: _(RawOrigin::Signed(caller.clone()), METAVERSE_ID, target.clone(), Default::default())
+	verify {
+		assert_eq!(Estate::get_undeployed_land_block(0).map(|a| a.owner), Some(target));
+	}
+
 	// deploy_land_block
 	deploy_land_block {
 		let caller: AccountId = whitelisted_caller();
@@ -9,9 +9,10 @@ use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::assert_ok;
 use frame_support::traits::{Currency, Get};
 use frame_system::RawOrigin;
+use metaverse::BondingCurve;
 use orml_benchmarking::runtime_benchmarks;
-use primitives::{AccountId, Balance, ClassId, FungibleTokenId, GroupCollectionId, MetaverseId};
-use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
+use primitives::{AccountId, AssetMetadata, Balance, ClassId, FungibleTokenId, GroupCollectionId, MetaverseId, Ratio};
+use sp_runtime::traits::{AccountIdConversion, One, StaticLookup, UniqueSaturatedInto, Zero};
 use sp_runtime::Perbill;
 use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec};
 
@@ -28,6 +29,59 @@ fn get_metaverse_fund(metaverse_id: MetaverseId) -> AccountId {
 	LocalMetaverseFundPalletId::get().into_sub_account_truncating(metaverse_id)
 }
 
+fn social_token_metadata() -> Box<AssetMetadata<Balance>> {
+	Box::new(AssetMetadata {
+		name: b"Social Token".to_vec(),
+		symbol: b"SOCIAL".to_vec(),
+		decimals: 18,
+		minimal_balance: 0,
+	})
+}
+
+fn social_token_curve() -> BondingCurve<Balance> {
+	BondingCurve {
+		initial_price: Ratio::one(),
+		slope: Ratio::zero(),
+		max_supply: dollar(1000),
+	}
+}
+
+// Create a metaverse of the caller and launch its social token
+fn launch_social_token(caller: &AccountId) {
+	create_nft_group();
+	set_metaverse_treasury_initial_balance();
+	assert_ok!(Metaverse::create_metaverse(
+		RawOrigin::Signed(caller.clone()).into(),
+		vec![1]
+	));
+	assert_ok!(Metaverse::launch_social_token(
+		RawOrigin::Signed(caller.clone()).into(),
+		0,
+		social_token_metadata(),
+		social_token_curve(),
+		dollar(1)
+	));
+}
+
+// Create a metaverse of the caller and a credential class issued by it
+fn create_credential_class(caller: &AccountId) -> ClassId {
+	create_nft_group();
+	set_metaverse_treasury_initial_balance();
+	assert_ok!(Metaverse::create_metaverse(
+		RawOrigin::Signed(caller.clone()).into(),
+		vec![1]
+	));
+	set_balance(CURRENCY_ID, &get_metaverse_fund(0), dollar(1000));
+	let class_id = orml_nft::Pallet::<Runtime>::next_class_id();
+	assert_ok!(Metaverse::create_credential_class(
+		RawOrigin::Signed(caller.clone()).into(),
+		0,
+		vec![1],
+		BTreeMap::new()
+	));
+	class_id
+}
+
 runtime_benchmarks! {
 	{ Runtime, metaverse }
 
@@ -171,6 +225,80 @@ runtime_benchmarks! {
 		assert_eq!(Balances::free_balance(&metaverse_fund), 1u32.into());
 	}
 
+	launch_social_token{
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+	}: _(RawOrigin::Signed(caller.clone()), 0, social_token_metadata(), social_token_curve(), dollar(1))
+	verify {
+		assert!(Metaverse::social_token_launch(0).is_some());
+	}
+
+	buy_social_token{
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		launch_social_token(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), 0, dollar(10), dollar(10))
+	verify {
+		assert_eq!(Metaverse::social_token_launch(0).map(|launch| launch.supply), Some(dollar(10)));
+	}
+
+	sell_social_token{
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		launch_social_token(&caller);
+		assert_ok!(Metaverse::buy_social_token(RawOrigin::Signed(caller.clone()).into(), 0, dollar(10), dollar(10)));
+	}: _(RawOrigin::Signed(caller.clone()), 0, dollar(5), 0)
+	verify {
+		assert_eq!(Metaverse::social_token_launch(0).map(|launch| launch.supply), Some(dollar(5)));
+	}
+
+	graduate_social_token{
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		launch_social_token(&caller);
+		assert_ok!(Metaverse::buy_social_token(RawOrigin::Signed(caller.clone()).into(), 0, dollar(10), dollar(10)));
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_eq!(Metaverse::social_token_launch(0).map(|launch| launch.graduated), Some(true));
+	}
+
+	create_credential_class{
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+		set_balance(CURRENCY_ID, &get_metaverse_fund(0), dollar(1000));
+		let class_id = orml_nft::Pallet::<Runtime>::next_class_id();
+	}: _(RawOrigin::Signed(caller.clone()), 0, vec![1], BTreeMap::new())
+	verify {
+		assert_eq!(Metaverse::credential_issuer(class_id), Some(0));
+	}
+
+	issue_credential{
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let holder: AccountId = account("holder", 0, SEED);
+		let class_id = create_credential_class(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), class_id, holder.clone(), vec![1], None)
+	verify {
+		assert!(Metaverse::credentials(class_id, holder).is_some());
+	}
+
+	revoke_credential{
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let holder: AccountId = account("holder", 0, SEED);
+		let class_id = create_credential_class(&caller);
+		assert_ok!(Metaverse::issue_credential(RawOrigin::Signed(caller.clone()).into(), class_id, holder.clone(), vec![1], None));
+	}: _(RawOrigin::Signed(caller.clone()), class_id, holder.clone())
+	verify {
+		assert!(Metaverse::credentials(class_id, holder).is_none());
+	}
+
 }
 
 #[cfg(test)]
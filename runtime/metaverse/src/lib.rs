@@ -92,12 +92,14 @@ use core_primitives::{NftAssetData, NftClassData};
 use currencies::BasicCurrencyAdapter;
 pub use estate::{MintingRateInfo, Range as MintingRange};
 use evm_mapping::EvmAddressMapping;
-use metaverse_runtime_common::{precompiles::MetaverseNetworkPrecompiles, CurrencyHooks};
+use metaverse_runtime_common::{precompiles::MetaverseNetworkPrecompiles, CurrencyHooks, ExecutableNftCallType};
 use primitives::evm::{
 	CurrencyIdType, Erc20Mapping, EvmAddress, H160_POSITION_CURRENCY_ID_TYPE, H160_POSITION_TOKEN,
 	H160_POSITION_TOKEN_NFT, H160_POSITION_TOKEN_NFT_CLASS_ID_END,
 };
-use primitives::{Amount, Balance, BlockNumber, ClassId, FungibleTokenId, Moment, NftId, PoolId, RoundIndex, TokenId};
+use primitives::{
+	Amount, Balance, BlockNumber, ClassId, FungibleTokenId, Moment, NftId, PoolId, Price, RoundIndex, TokenId,
+};
 
 // primitives imports
 use crate::opaque::SessionKeys;
@@ -582,10 +584,45 @@ parameter_types! {
 	pub ClassMintingFee: Balance = 10 * DOLLARS;
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
+	pub MaxRoyaltyRecipients: u32 = 10;
+	pub MaxEquipmentDepth: u32 = 5;
+	pub MaxEquippedItems: u32 = 20;
+	pub MaxCraftingIngredients: u32 = 10;
+	pub MaxAttributeHistory: u32 = 20;
+	pub MaxTokenAttributes: u32 = 32;
 	pub MaxNftMetadata: u32 = 1024;
 	pub const StorageDepositFee: Balance = BASE_STORAGE_FEE;
 }
 
+impl InstanceFilter<RuntimeCall> for ExecutableNftCallType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ExecutableNftCallType::Transfer => matches!(
+				c,
+				RuntimeCall::Balances(pallet_balances::Call::transfer { .. })
+					| RuntimeCall::Currencies(currencies::Call::transfer { .. })
+			),
+			ExecutableNftCallType::LandBlockGrant => matches!(
+				c,
+				RuntimeCall::Estate(estate::Call::transfer_undeployed_land_blocks { .. })
+			),
+			ExecutableNftCallType::PowerCredit => {
+				matches!(c, RuntimeCall::Economy(economy::Call::transfer_power { .. }))
+			}
+			ExecutableNftCallType::RewardMint => matches!(
+				c,
+				RuntimeCall::Nft(nft::Call::mint { .. })
+					| RuntimeCall::Nft(nft::Call::mint_stackable_nft { .. })
+					| RuntimeCall::Nft(nft::Call::transfer { .. })
+					| RuntimeCall::Nft(nft::Call::transfer_stackable_nft { .. })
+			),
+		}
+	}
+	fn is_superset(&self, o: &Self) -> bool {
+		self == o
+	}
+}
+
 impl nft::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -603,6 +640,14 @@ impl nft::Config for Runtime {
 	type StorageDepositFee = StorageDepositFee;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as Verify>::Signer;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxEquipmentDepth = MaxEquipmentDepth;
+	type MaxEquippedItems = MaxEquippedItems;
+	type MaxCraftingIngredients = MaxCraftingIngredients;
+	type Randomness = RandomnessCollectiveFlip;
+	type MaxAttributeHistory = MaxAttributeHistory;
+	type MaxTokenAttributes = MaxTokenAttributes;
+	type ExecutableCallFilter = ExecutableNftCallType;
 }
 
 parameter_types! {
@@ -625,6 +670,7 @@ parameter_types! {
 	pub MinContribution: Balance = 50 * DOLLARS;
 	pub MaxNumberOfStakerPerMetaverse: u32 = 512;
 	pub MetaverseStorageFee: Balance = 2 * BASE_STORAGE_FEE;
+	pub const SocialTokenReserveShare: Perbill = Perbill::from_percent(10);
 }
 
 impl metaverse::Config for Runtime {
@@ -635,6 +681,7 @@ impl metaverse::Config for Runtime {
 	type MaxMetaverseMetadata = MaxMetaverseMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureRootOrHalfCouncilCollective;
+	type MetaverseGovernanceOrigin = metaverse::EnsureMetaverse;
 	type WeightInfo = weights::module_metaverse::WeightInfo<Runtime>;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinContribution;
@@ -642,6 +689,9 @@ impl metaverse::Config for Runtime {
 	type MultiCurrency = Currencies;
 	type NFTHandler = Nft;
 	type StorageDepositFee = MetaverseStorageFee;
+	type AssetRegistry = AssetManager;
+	type SwapHandler = Dex;
+	type SocialTokenReserveShare = SocialTokenReserveShare;
 }
 
 parameter_types! {
@@ -653,6 +703,16 @@ parameter_types! {
 	pub const DefaultMaxBound: (i32,i32) = (-1000,1000);
 	pub const NetworkFee: Balance = 10 * DOLLARS; // Network fee
 	pub const MaxOffersPerEstate: u32 = 100;
+	pub const MaxBatchEstateOperations: u32 = 50;
+	pub const EstateChunkSize: u32 = 16;
+	pub const MaxSpatialQueryChunks: u32 = 1024;
+	pub const MaxReshapeLandUnits: u32 = 1000;
+	pub const MaxZoneNameLength: u32 = 32;
+	pub const MaxBuildersPerAsset: u32 = 50;
+	pub const LandUnitsPerIssuedLandBlock: u32 = 100;
+	pub const MaxIssuanceRecipients: u32 = 50;
+	pub const MinAnnualLandIssuance: u64 = 100_000;
+	pub const BoundLandBlockVestingPeriod: BlockNumber = 180 * DAYS;
 	pub const MinLeasePricePerBlock: Balance = 1 * CENTS;
 	pub const MaxLeasePeriod: u32 = 1000000;
 	pub const LeaseOfferExpiryPeriod: u32 = 10000;
@@ -664,6 +724,7 @@ impl estate::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type LandTreasury = LandTreasuryPalletId;
 	type MetaverseInfoSource = Metaverse;
+	type MetaverseGovernanceOrigin = metaverse::EnsureMetaverse;
 	type Currency = Balances;
 	type MinimumLandPrice = MinimumLandPrice;
 	type CouncilOrigin = pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
@@ -681,6 +742,35 @@ impl estate::Config for Runtime {
 	type LeaseOfferExpiryPeriod = LeaseOfferExpiryPeriod;
 	type BlockNumberToBalance = ConvertInto;
 	type StorageDepositFee = EstateStorageFee;
+	type MaxBatchOperations = MaxBatchEstateOperations;
+	type EstateChunkSize = EstateChunkSize;
+	type MaxSpatialQueryChunks = MaxSpatialQueryChunks;
+	type MaxReshapeLandUnits = MaxReshapeLandUnits;
+	type MaxZoneNameLength = MaxZoneNameLength;
+	type MaxBuildersPerAsset = MaxBuildersPerAsset;
+	type EstateStakeSource = Economy;
+	type LandUnitsPerIssuedLandBlock = LandUnitsPerIssuedLandBlock;
+	type MaxIssuanceRecipients = MaxIssuanceRecipients;
+	type MinAnnualLandIssuance = MinAnnualLandIssuance;
+	type BoundLandBlockVestingPeriod = BoundLandBlockVestingPeriod;
+}
+
+parameter_types! {
+	pub RecoveryDeposit: Balance = 10 * DOLLARS;
+	pub const MaxGuardians: u32 = 9;
+	pub const MinimumRecoveryDelay: BlockNumber = 1 * DAYS;
+	pub const MaxRecoveredAssets: u32 = 20;
+}
+
+impl asset_recovery::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RecoveryDeposit = RecoveryDeposit;
+	type MaxGuardians = MaxGuardians;
+	type MinimumRecoveryDelay = MinimumRecoveryDelay;
+	type MaxRecoveredAssets = MaxRecoveredAssets;
+	type AssetsMigration = (Nft, Estate, Metaverse, EvmMapping);
+	type WeightInfo = asset_recovery::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -695,7 +785,17 @@ parameter_types! {
 	pub const OfferDuration: BlockNumber = 100800; // Default 100800 Blocks
 	pub const MinimumListingPrice: Balance = DOLLARS;
 	pub const AntiSnipeDuration: BlockNumber = 50; // Minimum anti snipe duration is 50 blocks
+	pub const SealedBidRevealPeriod: BlockNumber = 1 * DAYS;
+	pub const MaxSealedBidsPerAuction: u32 = 100;
+	pub const MaxDropPhases: u32 = 5;
+	pub const MaxDropAllowlistSize: u32 = 500;
+	pub const MaxDropRecordRemovals: u32 = 100;
 	pub const AuctionStorageFee: Balance = 3 * BASE_STORAGE_FEE;
+	pub const MaxSwapDuration: BlockNumber = 7 * DAYS;
+	pub const MaxSwapItems: u32 = 20;
+	pub const MaxSwapRoyaltyFees: u32 = 20;
+	pub const SwapDeposit: Balance = 1 * DOLLARS;
+	pub const SignedOrderNonceWindow: u32 = 64;
 }
 
 impl auction::Config for Runtime {
@@ -706,6 +806,7 @@ impl auction::Config for Runtime {
 	type ContinuumHandler = Continuum;
 	type FungibleTokenCurrency = Tokens;
 	type MetaverseInfoSource = Metaverse;
+	type MetaverseGovernanceOrigin = metaverse::EnsureMetaverse;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = Estate;
 	type MaxFinality = MaxFinality;
@@ -716,8 +817,22 @@ impl auction::Config for Runtime {
 	type WeightInfo = weights::module_auction::WeightInfo<Runtime>;
 	type OfferDuration = OfferDuration;
 	type MinimumListingPrice = MinimumListingPrice;
+	type PriceProvider = OraclePriceProvider;
 	type AntiSnipeDuration = AntiSnipeDuration;
 	type StorageDepositFee = AuctionStorageFee;
+	type SealedBidRevealPeriod = SealedBidRevealPeriod;
+	type MaxSealedBidsPerAuction = MaxSealedBidsPerAuction;
+	type MaxDropPhases = MaxDropPhases;
+	type MaxDropAllowlistSize = MaxDropAllowlistSize;
+	type MaxDropRecordRemovals = MaxDropRecordRemovals;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type Credentials = Metaverse;
+	type MaxSwapDuration = MaxSwapDuration;
+	type MaxSwapItems = MaxSwapItems;
+	type MaxSwapRoyaltyFees = MaxSwapRoyaltyFees;
+	type SwapDeposit = SwapDeposit;
+	type SignedOrderNonceWindow = SignedOrderNonceWindow;
 }
 
 parameter_types! {
@@ -883,6 +998,8 @@ parameter_types! {
 	pub const DefaultEnactmentPeriod: u32 = 10;
 	pub const DefaultProposalLaunchPeriod: u32 = 15;
 	pub const DefaultMaxProposalsPerMetaverse: u8 = 20;
+	pub const PreimageExpiryPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxLifecycleOperationsPerBlock: u32 = 20;
 }
 
 parameter_types! {
@@ -1054,7 +1171,12 @@ impl InstanceFilter<RuntimeCall> for ProposalType {
 	fn filter(&self, c: &RuntimeCall) -> bool {
 		match self {
 			ProposalType::Any => true,
-			ProposalType::JustMetaverse => matches!(c, RuntimeCall::Metaverse(..)),
+			ProposalType::JustMetaverse => {
+				matches!(
+					c,
+					RuntimeCall::Metaverse(..) | RuntimeCall::Estate(..) | RuntimeCall::Auction(..)
+				)
+			}
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -1077,6 +1199,7 @@ impl governance::Config for Runtime {
 	type DefaultMaxProposalsPerMetaverse = DefaultMaxProposalsPerMetaverse;
 	type OneBlock = OneBlock;
 	type Currency = Balances;
+	type FungibleTokenCurrency = Currencies;
 	type Slash = ();
 	type MetaverseInfo = Metaverse;
 	type PalletsOrigin = OriginCaller;
@@ -1087,6 +1210,9 @@ impl governance::Config for Runtime {
 	type ProposalType = ProposalType;
 	type NetworkTreasury = TreasuryModuleAccount;
 	type StorageDepositFee = GovernanceStorageFee;
+	type PreimageExpiryPeriod = PreimageExpiryPeriod;
+	type MaxLifecycleOperationsPerBlock = MaxLifecycleOperationsPerBlock;
+	type Credentials = Metaverse;
 }
 
 impl crowdloan::Config for Runtime {
@@ -1165,6 +1291,37 @@ impl orml_oracle::Config<MiningRewardDataProvider> for Runtime {
 	type WeightInfo = ();
 }
 
+type PriceDataProvider = orml_oracle::Instance2;
+
+impl orml_oracle::Config<PriceDataProvider> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OnNewData = ();
+	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, PriceDataProvider>;
+	type Time = Timestamp;
+	type OracleKey = FungibleTokenId;
+	type OracleValue = Price;
+	type RootOperatorAccountId = RootOperatorAccountId;
+	type Members = OracleMembership;
+	type MaxHasDispatchedSize = MaxHasDispatchedSize;
+	type MaxFeedValues = MaxFeedValues;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const PriceReferenceCurrencyId: FungibleTokenId = FungibleTokenId::NativeToken(0);
+	pub const MaxPriceAge: Moment = 1000 * 60 * 60; // 1 hour
+	pub const MinimumPriceFeeds: u32 = 3;
+}
+
+/// Median of the fresh prices fed by the oracle members, in native token
+pub type OraclePriceProvider = module_prices::MedianPriceProvider<
+	module_prices::OracleRawPrices<Runtime, PriceDataProvider>,
+	Timestamp,
+	PriceReferenceCurrencyId,
+	MaxPriceAge,
+	MinimumPriceFeeds,
+>;
+
 parameter_types! {
 	// Tells `pallet_base_fee` whether to calculate a new BaseFee `on_finalize` or not.
 	pub DefaultBaseFeePerGas: U256 = (10 * CENTS).into();
@@ -1467,6 +1624,7 @@ impl modules_bridge::Config for Runtime {
 	type NFTHandler = Nft;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type PalletId = BridgeSovereignPalletId;
+	type PriceProvider = OraclePriceProvider;
 }
 
 impl pallet_evm_chain_id::Config for Runtime {}
@@ -1499,6 +1657,7 @@ impl spp::Config for Runtime {
 	type WeightInfo = weights::module_spp::WeightInfo<Runtime>;
 	type MinimumStake = MinimumStake;
 	type NetworkFee = NetworkFee;
+	type PriceProvider = OraclePriceProvider;
 	type StorageDepositFee = StorageDepositFee;
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type PoolAccount = PoolAccountPalletId;
@@ -1509,6 +1668,23 @@ impl spp::Config for Runtime {
 	type GovernanceOrigin = EnsureRootOrTwoThirdsCouncilCollective;
 }
 
+parameter_types! {
+	pub SwapFee: Perbill = Perbill::from_rational(3u32, 1000u32);
+	pub const TradingPathLimit: u32 = 4;
+	pub const TwapPeriod: BlockNumber = 1 * HOURS;
+}
+
+impl dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MultiCurrency = Currencies;
+	type SwapFee = SwapFee;
+	type TradingPathLimit = TradingPathLimit;
+	type TwapPeriod = TwapPeriod;
+	type PalletId = SwapPalletId;
+	type GovernanceOrigin = EnsureRootOrTwoThirdsCouncilCollective;
+	type WeightInfo = dex::weights::SubstrateWeight<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime where
@@ -1541,7 +1717,7 @@ construct_runtime!(
 		OrmlNFT: orml_nft::{Pallet, Storage},
 		Nft: nft::{Pallet, Call, Storage, Event<T>},
 		Auction: auction::{Pallet, Call ,Storage, Event<T>},
-		Metaverse: metaverse::{Pallet, Call, Storage, Event<T>},
+		Metaverse: metaverse::{Pallet, Call, Storage, Event<T>, Origin},
 		Continuum: continuum::{Pallet, Call, Storage, Event<T>},
 		Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>},
 		Mining: mining::{Pallet, Call, Storage ,Event<T>},
@@ -1551,6 +1727,7 @@ construct_runtime!(
 		Emergency: emergency::{Pallet, Call, Storage, Event<T>},
 		RewardOracle: orml_oracle::<Instance1>::{Pallet, Storage, Call, Event<T>},
 		OracleMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
+		PriceOracle: orml_oracle::<Instance2>::{Pallet, Storage, Call, Event<T>},
 
 		// Governance
 		Governance: governance::{Pallet, Call ,Storage, Event<T>},
@@ -1568,6 +1745,9 @@ construct_runtime!(
 		EvmMapping: evm_mapping::{Pallet, Call, Storage, Config<T>, Event<T>},
 		EvmChainId: pallet_evm_chain_id::{Pallet, Storage, Config<T>},
 
+		// Social recovery of land, estates and NFTs
+		AssetRecovery: asset_recovery::{Pallet, Call, Storage, Event<T>},
+
 		// ink! Smart Contracts.
 		Contracts: pallet_contracts::{Pallet, Call, Storage, Event<T>},
 
@@ -1586,7 +1766,10 @@ construct_runtime!(
 
 		// Spp
 		Spp: spp::{Pallet, Call, Storage, Event<T>},
-		Rewards: orml_rewards::{Pallet, Storage}
+		Rewards: orml_rewards::{Pallet, Storage},
+
+		// DEX
+		Dex: dex::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -2072,6 +2255,39 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl estate_runtime_api::EstateApi<Block, AccountId> for Runtime {
+		fn estates_in_bounding_box(
+			metaverse_id: primitives::MetaverseId,
+			bottom_left: (i32, i32),
+			top_right: (i32, i32),
+		) -> Result<Vec<primitives::EstateId>, sp_runtime::DispatchError> {
+			Estate::estates_in_bounding_box(metaverse_id, bottom_left, top_right)
+		}
+
+		fn estate_neighbours(estate_id: primitives::EstateId) -> Vec<primitives::EstateId> {
+			Estate::estate_neighbours(estate_id)
+		}
+
+		fn can_build(who: AccountId, asset: primitives::estate::LandAsset) -> bool {
+			Estate::can_build(&who, &asset)
+		}
+
+		fn validate_estate_operations(
+			who: AccountId,
+			operations: Vec<primitives::estate::EstateOperation<AccountId>>,
+		) -> Result<(), (u32, sp_runtime::DispatchError)> {
+			Estate::validate_estate_operations(&who, operations)
+		}
+	}
+
+	impl nft_runtime_api::NftApi<Block> for Runtime {
+		fn equipped_tree(
+			asset_id: (primitives::ClassId, primitives::TokenId),
+		) -> Vec<primitives::EquippedNft<primitives::ClassId, primitives::TokenId>> {
+			Nft::equipped_tree(&asset_id)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -165,4 +165,55 @@ impl<T: frame_system::Config> estate::WeightInfo for WeightInfo<T>  {
 	fn on_initialize() -> Weight {
 		Weight::from_parts(191_000, 0)
 	}
+	fn distribute_undeployed_land_blocks() -> Weight {
+		Weight::from_parts(44_512_000, 17210)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn merge_estates(u: u32) -> Weight {
+		Weight::from_parts(112_410_000, 70412)
+			.saturating_add(Weight::from_parts(4_120_000, 2520).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(12))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
+	}
+	fn split_estate(u: u32) -> Weight {
+		Weight::from_parts(128_530_000, 66058)
+			.saturating_add(Weight::from_parts(3_870_000, 2520).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
+	}
+	fn define_zone() -> Weight {
+		Weight::from_parts(18_240_000, 3618)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn remove_zone() -> Weight {
+		Weight::from_parts(20_110_000, 7236)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_land_zone() -> Weight {
+		Weight::from_parts(27_630_000, 14472)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_land_scene() -> Weight {
+		Weight::from_parts(34_870_000, 21708)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn grant_builder_role() -> Weight {
+		Weight::from_parts(41_520_000, 28944)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn revoke_builder_role() -> Weight {
+		Weight::from_parts(38_960_000, 25326)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
\ No newline at end of file
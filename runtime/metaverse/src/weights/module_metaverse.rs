@@ -81,4 +81,39 @@ impl<T: frame_system::Config> metaverse::WeightInfo for WeightInfo<T>{
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	fn launch_social_token() -> Weight {
+		Weight::from_parts(62_000_000, 14000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn buy_social_token() -> Weight {
+		Weight::from_parts(78_000_000, 12500)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	fn sell_social_token() -> Weight {
+		Weight::from_parts(72_000_000, 11000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn graduate_social_token() -> Weight {
+		Weight::from_parts(135_000_000, 21000)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	fn create_credential_class() -> Weight {
+		Weight::from_parts(52_000_000, 7890)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	fn issue_credential() -> Weight {
+		Weight::from_parts(98_000_000, 21840)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	fn revoke_credential() -> Weight {
+		Weight::from_parts(46_000_000, 11260)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
\ No newline at end of file
@@ -82,8 +82,8 @@ pub use constants::{currency::*, time::*};
 use core_primitives::{NftAssetData, NftClassData};
 // External imports
 use currencies::BasicCurrencyAdapter;
-use metaverse_runtime_common::{CurrencyHooks, FixedRateOfAsset};
-use primitives::{Amount, ClassId, ForeignAssetIdMapping, FungibleTokenId, Moment, NftId, RoundIndex};
+use metaverse_runtime_common::{CurrencyHooks, ExecutableNftCallType, FixedRateOfAsset};
+use primitives::{Amount, ClassId, ForeignAssetIdMapping, FungibleTokenId, Moment, NftId, Price, RoundIndex};
 
 // XCM Imports
 use crate::constants::parachains;
@@ -763,6 +763,28 @@ impl orml_xtokens::Config for Runtime {
 	type ReserveProvider = AbsoluteReserveProvider;
 }
 
+parameter_types! {
+	pub NftPalletInstance: u8 = <Nft as frame_support::traits::PalletInfoAccess>::index() as u8;
+	pub const XnftPalletId: PalletId = PalletId(*b"bit/xnft");
+}
+
+impl xnft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type NFTHandler = Nft;
+	type SelfLocation = SelfLocation;
+	type UniversalLocation = UniversalLocation;
+	type NftPalletInstance = NftPalletInstance;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type LocationToAccountId = LocationToAccountId;
+	type CurrencyIdConvert = FungibleTokenIdConvert;
+	type ReserveProvider = AbsoluteReserveProvider;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type PalletId = XnftPalletId;
+	type ForeignClassOrigin = EnsureRootOrTwoThirdsCouncilCollective;
+	type WeightInfo = xnft::weights::SubstrateWeight<Runtime>;
+}
+
 impl orml_unknown_tokens::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
@@ -1172,7 +1194,8 @@ impl xcm_executor::Config for XcmConfig {
 	type AssetTrap = PolkadotXcm;
 	type AssetClaims = PolkadotXcm;
 	// How to withdraw and deposit an asset.
-	type AssetTransactor = LocalAssetTransactor;
+	// NFTs are handled by xnft, anything else falls through to the fungible transactor
+	type AssetTransactor = (Xnft, LocalAssetTransactor);
 	type Barrier = Barrier;
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
@@ -1457,10 +1480,45 @@ parameter_types! {
 	pub ClassMintingFee: Balance = 10 * DOLLARS;
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
+	pub MaxRoyaltyRecipients: u32 = 10;
+	pub MaxEquipmentDepth: u32 = 5;
+	pub MaxEquippedItems: u32 = 20;
+	pub MaxCraftingIngredients: u32 = 10;
+	pub MaxAttributeHistory: u32 = 20;
+	pub MaxTokenAttributes: u32 = 32;
 	pub MaxNftMetadata: u32 = 1024;
 	pub StorageDepositFee: Balance =  BASE_STORAGE_FEE;
 }
 
+impl InstanceFilter<RuntimeCall> for ExecutableNftCallType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ExecutableNftCallType::Transfer => matches!(
+				c,
+				RuntimeCall::Balances(pallet_balances::Call::transfer { .. })
+					| RuntimeCall::Currencies(currencies::Call::transfer { .. })
+			),
+			ExecutableNftCallType::LandBlockGrant => matches!(
+				c,
+				RuntimeCall::Estate(estate::Call::transfer_undeployed_land_blocks { .. })
+			),
+			ExecutableNftCallType::PowerCredit => {
+				matches!(c, RuntimeCall::Economy(economy::Call::transfer_power { .. }))
+			}
+			ExecutableNftCallType::RewardMint => matches!(
+				c,
+				RuntimeCall::Nft(nft::Call::mint { .. })
+					| RuntimeCall::Nft(nft::Call::mint_stackable_nft { .. })
+					| RuntimeCall::Nft(nft::Call::transfer { .. })
+					| RuntimeCall::Nft(nft::Call::transfer_stackable_nft { .. })
+			),
+		}
+	}
+	fn is_superset(&self, o: &Self) -> bool {
+		self == o
+	}
+}
+
 impl nft::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -1478,6 +1536,14 @@ impl nft::Config for Runtime {
 	type StorageDepositFee = StorageDepositFee;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as Verify>::Signer;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxEquipmentDepth = MaxEquipmentDepth;
+	type MaxEquippedItems = MaxEquippedItems;
+	type MaxCraftingIngredients = MaxCraftingIngredients;
+	type Randomness = RandomnessCollectiveFlip;
+	type MaxAttributeHistory = MaxAttributeHistory;
+	type MaxTokenAttributes = MaxTokenAttributes;
+	type ExecutableCallFilter = ExecutableNftCallType;
 }
 
 parameter_types! {
@@ -1500,6 +1566,7 @@ parameter_types! {
 	pub MinContribution: Balance = 50 * DOLLARS;
 	pub MaxNumberOfStakersPerMetaverse: u32 = 512;
 	pub MetaverseStorageFee: Balance = 2 * BASE_STORAGE_FEE;
+	pub const SocialTokenReserveShare: Perbill = Perbill::from_percent(10);
 }
 
 impl metaverse::Config for Runtime {
@@ -1510,6 +1577,7 @@ impl metaverse::Config for Runtime {
 	type MaxMetaverseMetadata = MaxMetaverseMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureRootOrHalfCouncilCollective;
+	type MetaverseGovernanceOrigin = metaverse::EnsureMetaverse;
 	type WeightInfo = weights::module_metaverse::WeightInfo<Runtime>;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinContribution;
@@ -1517,6 +1585,9 @@ impl metaverse::Config for Runtime {
 	type MultiCurrency = Currencies;
 	type NFTHandler = Nft;
 	type StorageDepositFee = MetaverseStorageFee;
+	type AssetRegistry = AssetManager;
+	type SwapHandler = Dex;
+	type SocialTokenReserveShare = SocialTokenReserveShare;
 }
 
 parameter_types! {
@@ -1528,6 +1599,16 @@ parameter_types! {
 	pub const DefaultMaxBound: (i32,i32) = (-1000,1000);
 	pub const NetworkFee: Balance = 10 * DOLLARS; // Network fee
 	pub const MaxOffersPerEstate: u32 = 100;
+	pub const MaxBatchEstateOperations: u32 = 50;
+	pub const EstateChunkSize: u32 = 16;
+	pub const MaxSpatialQueryChunks: u32 = 1024;
+	pub const MaxReshapeLandUnits: u32 = 1000;
+	pub const MaxZoneNameLength: u32 = 32;
+	pub const MaxBuildersPerAsset: u32 = 50;
+	pub const LandUnitsPerIssuedLandBlock: u32 = 100;
+	pub const MaxIssuanceRecipients: u32 = 50;
+	pub const MinAnnualLandIssuance: u64 = 100_000;
+	pub const BoundLandBlockVestingPeriod: BlockNumber = 180 * DAYS;
 	pub const MinLeasePricePerBlock: Balance = 1 * CENTS;
 	pub const MaxLeasePeriod: u32 = 1000000;
 	pub const LeaseOfferExpiryPeriod: u32 = 10000;
@@ -1538,6 +1619,7 @@ impl estate::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type LandTreasury = LandTreasuryPalletId;
 	type MetaverseInfoSource = Metaverse;
+	type MetaverseGovernanceOrigin = metaverse::EnsureMetaverse;
 	type Currency = Balances;
 	type MinimumLandPrice = MinimumLandPrice;
 	type CouncilOrigin = EnsureRoot<AccountId>;
@@ -1555,6 +1637,35 @@ impl estate::Config for Runtime {
 	type LeaseOfferExpiryPeriod = LeaseOfferExpiryPeriod;
 	type BlockNumberToBalance = ConvertInto;
 	type StorageDepositFee = EstateStorageFee;
+	type MaxBatchOperations = MaxBatchEstateOperations;
+	type EstateChunkSize = EstateChunkSize;
+	type MaxSpatialQueryChunks = MaxSpatialQueryChunks;
+	type MaxReshapeLandUnits = MaxReshapeLandUnits;
+	type MaxZoneNameLength = MaxZoneNameLength;
+	type MaxBuildersPerAsset = MaxBuildersPerAsset;
+	type EstateStakeSource = Economy;
+	type LandUnitsPerIssuedLandBlock = LandUnitsPerIssuedLandBlock;
+	type MaxIssuanceRecipients = MaxIssuanceRecipients;
+	type MinAnnualLandIssuance = MinAnnualLandIssuance;
+	type BoundLandBlockVestingPeriod = BoundLandBlockVestingPeriod;
+}
+
+parameter_types! {
+	pub RecoveryDeposit: Balance = 10 * DOLLARS;
+	pub const MaxGuardians: u32 = 9;
+	pub const MinimumRecoveryDelay: BlockNumber = 1 * DAYS;
+	pub const MaxRecoveredAssets: u32 = 20;
+}
+
+impl asset_recovery::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RecoveryDeposit = RecoveryDeposit;
+	type MaxGuardians = MaxGuardians;
+	type MinimumRecoveryDelay = MinimumRecoveryDelay;
+	type MaxRecoveredAssets = MaxRecoveredAssets;
+	type AssetsMigration = (Nft, Estate, Metaverse);
+	type WeightInfo = asset_recovery::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -1569,7 +1680,17 @@ parameter_types! {
 	pub const OfferDuration: BlockNumber = 100800; // Default 100800 Blocks
 	pub const MinimumListingPrice: Balance = DOLLARS;
 	pub const AntiSnipeDuration: BlockNumber = 50; // Minimum anti snipe duration is 50 blocks
+	pub const SealedBidRevealPeriod: BlockNumber = 1 * DAYS;
+	pub const MaxSealedBidsPerAuction: u32 = 100;
+	pub const MaxDropPhases: u32 = 5;
+	pub const MaxDropAllowlistSize: u32 = 500;
+	pub const MaxDropRecordRemovals: u32 = 100;
 	pub const AuctionStorageFee: Balance = 3 * BASE_STORAGE_FEE;
+	pub const MaxSwapDuration: BlockNumber = 7 * DAYS;
+	pub const MaxSwapItems: u32 = 20;
+	pub const MaxSwapRoyaltyFees: u32 = 20;
+	pub const SwapDeposit: Balance = 1 * DOLLARS;
+	pub const SignedOrderNonceWindow: u32 = 64;
 }
 
 impl auction::Config for Runtime {
@@ -1580,6 +1701,7 @@ impl auction::Config for Runtime {
 	type ContinuumHandler = Continuum;
 	type FungibleTokenCurrency = Tokens;
 	type MetaverseInfoSource = Metaverse;
+	type MetaverseGovernanceOrigin = metaverse::EnsureMetaverse;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = Estate;
 	type MaxFinality = MaxFinality;
@@ -1590,8 +1712,22 @@ impl auction::Config for Runtime {
 	type WeightInfo = weights::module_auction::WeightInfo<Runtime>;
 	type OfferDuration = OfferDuration;
 	type MinimumListingPrice = MinimumListingPrice;
+	type PriceProvider = OraclePriceProvider;
 	type AntiSnipeDuration = AntiSnipeDuration;
 	type StorageDepositFee = AuctionStorageFee;
+	type SealedBidRevealPeriod = SealedBidRevealPeriod;
+	type MaxSealedBidsPerAuction = MaxSealedBidsPerAuction;
+	type MaxDropPhases = MaxDropPhases;
+	type MaxDropAllowlistSize = MaxDropAllowlistSize;
+	type MaxDropRecordRemovals = MaxDropRecordRemovals;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type Credentials = Metaverse;
+	type MaxSwapDuration = MaxSwapDuration;
+	type MaxSwapItems = MaxSwapItems;
+	type MaxSwapRoyaltyFees = MaxSwapRoyaltyFees;
+	type SwapDeposit = SwapDeposit;
+	type SignedOrderNonceWindow = SignedOrderNonceWindow;
 }
 
 parameter_types! {
@@ -1689,6 +1825,37 @@ impl orml_oracle::Config<MiningRewardDataProvider> for Runtime {
 	type WeightInfo = ();
 }
 
+type PriceDataProvider = orml_oracle::Instance2;
+
+impl orml_oracle::Config<PriceDataProvider> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OnNewData = ();
+	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, PriceDataProvider>;
+	type Time = Timestamp;
+	type OracleKey = FungibleTokenId;
+	type OracleValue = Price;
+	type RootOperatorAccountId = RootOperatorAccountId;
+	type Members = OracleMembership;
+	type MaxHasDispatchedSize = MaxHasDispatchedSize;
+	type MaxFeedValues = MaxFeedValues;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const PriceReferenceCurrencyId: FungibleTokenId = FungibleTokenId::NativeToken(0);
+	pub const MaxPriceAge: Moment = 1000 * 60 * 60; // 1 hour
+	pub const MinimumPriceFeeds: u32 = 3;
+}
+
+/// Median of the fresh prices fed by the oracle members, in native token
+pub type OraclePriceProvider = module_prices::MedianPriceProvider<
+	module_prices::OracleRawPrices<Runtime, PriceDataProvider>,
+	Timestamp,
+	PriceReferenceCurrencyId,
+	MaxPriceAge,
+	MinimumPriceFeeds,
+>;
+
 impl asset_manager::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -1807,6 +1974,24 @@ impl modules_bridge::Config for Runtime {
 	type NFTHandler = Nft;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type PalletId = BridgeSovereignPalletId;
+	type PriceProvider = OraclePriceProvider;
+}
+
+parameter_types! {
+	pub SwapFee: Perbill = Perbill::from_rational(3u32, 1000u32);
+	pub const TradingPathLimit: u32 = 4;
+	pub const TwapPeriod: BlockNumber = 1 * HOURS;
+}
+
+impl dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MultiCurrency = Currencies;
+	type SwapFee = SwapFee;
+	type TradingPathLimit = TradingPathLimit;
+	type TwapPeriod = TwapPeriod;
+	type PalletId = SwapPalletId;
+	type GovernanceOrigin = EnsureRootOrTwoThirdsCouncilCollective;
+	type WeightInfo = dex::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -1870,13 +2055,14 @@ construct_runtime!(
 
 		// Pioneer pallets
 		// Metaverse & Related
-		Metaverse: metaverse::{Pallet, Call ,Storage, Event<T>} = 50,
+		Metaverse: metaverse::{Pallet, Call ,Storage, Event<T>, Origin} = 50,
 
 		Vesting: pallet_vesting::{Pallet, Call ,Storage, Event<T>} = 53,
 		Mining: mining:: {Pallet, Call ,Storage ,Event<T>} = 54,
 		Emergency: emergency::{Pallet, Call, Storage, Event<T>} = 55,
 		RewardOracle: orml_oracle::<Instance1>::{Pallet, Storage, Call, Event<T>} = 56,
 		OracleMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 57,
+		PriceOracle: orml_oracle::<Instance2>::{Pallet, Storage, Call, Event<T>} = 58,
 
 		OrmlNFT: orml_nft::{Pallet, Storage} = 60,
 		Nft: nft::{Call, Pallet, Storage, Event<T>} = 61,
@@ -1894,6 +2080,12 @@ construct_runtime!(
 		Crowdloan: crowdloan::{Pallet, Call, Storage, Event<T>} = 70,
 		// Bridge
 		BridgeSupport: modules_bridge::{Pallet, Call, Storage, Event<T>} = 71,
+		// Cross-chain NFT transfers
+		Xnft: xnft::{Pallet, Call, Storage, Event<T>} = 72,
+		// Social recovery of land, estates and NFTs
+		AssetRecovery: asset_recovery::{Pallet, Call, Storage, Event<T>} = 73,
+		// DEX
+		Dex: dex::{Pallet, Call, Storage, Event<T>} = 74,
 	}
 );
 
@@ -1991,6 +2183,39 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl estate_runtime_api::EstateApi<Block, AccountId> for Runtime {
+		fn estates_in_bounding_box(
+			metaverse_id: primitives::MetaverseId,
+			bottom_left: (i32, i32),
+			top_right: (i32, i32),
+		) -> Result<Vec<primitives::EstateId>, sp_runtime::DispatchError> {
+			Estate::estates_in_bounding_box(metaverse_id, bottom_left, top_right)
+		}
+
+		fn estate_neighbours(estate_id: primitives::EstateId) -> Vec<primitives::EstateId> {
+			Estate::estate_neighbours(estate_id)
+		}
+
+		fn can_build(who: AccountId, asset: primitives::estate::LandAsset) -> bool {
+			Estate::can_build(&who, &asset)
+		}
+
+		fn validate_estate_operations(
+			who: AccountId,
+			operations: Vec<primitives::estate::EstateOperation<AccountId>>,
+		) -> Result<(), (u32, sp_runtime::DispatchError)> {
+			Estate::validate_estate_operations(&who, operations)
+		}
+	}
+
+	impl nft_runtime_api::NftApi<Block> for Runtime {
+		fn equipped_tree(
+			asset_id: (primitives::ClassId, primitives::TokenId),
+		) -> Vec<primitives::EquippedNft<primitives::ClassId, primitives::TokenId>> {
+			Nft::equipped_tree(&asset_id)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
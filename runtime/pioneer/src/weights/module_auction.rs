@@ -57,11 +57,61 @@ impl<T: frame_system::Config> auction::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	fn accept_offer() -> Weight {
-		Weight::from_parts(72_454_000, 31537)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(6))
+		Weight::from_parts(81_630_000, 37745)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	fn on_finalize() -> Weight {
 		Weight::from_parts(7_467_000, 0)
 	}
+	fn propose_swap(n: u32) -> Weight {
+		Weight::from_parts(45_000_000, 9000)
+			.saturating_add(Weight::from_parts(20_000_000, 3000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	fn accept_swap(n: u32, r: u32) -> Weight {
+		Weight::from_parts(60_000_000, 12000)
+			.saturating_add(Weight::from_parts(35_000_000, 5000).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(45_000_000, 8000).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+	}
+	fn cancel_swap(n: u32) -> Weight {
+		Weight::from_parts(35_000_000, 6000)
+			.saturating_add(Weight::from_parts(15_000_000, 2500).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	fn create_new_sealed_bid_auction() -> Weight {
+		Weight::from_parts(63_214_000, 57373)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	fn commit_bid() -> Weight {
+		Weight::from_parts(52_310_000, 21630)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn reveal_bid() -> Weight {
+		Weight::from_parts(41_872_000, 17458)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn settle_sealed_bids(b: u32) -> Weight {
+		Weight::from_parts(4_318_000, 1485)
+			.saturating_add(Weight::from_parts(21_540_000, 2603).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
 }
@@ -71,4 +71,63 @@ impl<T: frame_system::Config> nft::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	fn set_executable_call_type() -> Weight {
+		Weight::from_parts(15_000_000, 2757)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn grant_attribute_updater() -> Weight {
+		Weight::from_parts(17_000_000, 3722)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn revoke_attribute_updater() -> Weight {
+		Weight::from_parts(18_000_000, 3774)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn freeze_attribute() -> Weight {
+		Weight::from_parts(16_000_000, 3722)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_class_royalty_recipients() -> Weight {
+		Weight::from_parts(19_000_000, 3722)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn equip(n: u32) -> Weight {
+		Weight::from_parts(52_000_000, 30240)
+			.saturating_add(Weight::from_parts(5_000_000, 2600).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn unequip() -> Weight {
+		Weight::from_parts(38_000_000, 21370)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn transfer_equipped_items(n: u32) -> Weight {
+		Weight::from_parts(3_000_000, 1190)
+			.saturating_add(Weight::from_parts(24_000_000, 5080).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	fn create_crafting_recipe() -> Weight {
+		Weight::from_parts(420_000_000, 362710)
+			.saturating_add(T::DbWeight::get().reads(123))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn remove_crafting_recipe() -> Weight {
+		Weight::from_parts(18_000_000, 3904)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn craft() -> Weight {
+		Weight::from_parts(1_660_000_000, 346420)
+			.saturating_add(T::DbWeight::get().reads(183))
+			.saturating_add(T::DbWeight::get().writes(192))
+	}
 }
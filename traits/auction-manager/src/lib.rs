@@ -5,7 +5,7 @@
 
 use codec::FullCodec;
 use codec::{Decode, Encode};
-use frame_support::dispatch::DispatchResult;
+use frame_support::{dispatch::DispatchResult, traits::Get, BoundedVec};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -16,7 +16,7 @@ use sp_std::{
 	vec::Vec,
 };
 
-use primitives::{AuctionId, ClassId, FungibleTokenId, ItemId, MetaverseId, TokenId};
+use primitives::{AuctionId, ClassId, FungibleTokenId, ItemId, MetaverseId, Ratio, TokenId};
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub enum Change<Value> {
@@ -31,6 +31,18 @@ pub enum Change<Value> {
 pub enum AuctionType {
 	Auction,
 	BuyNow,
+	/// Sealed-bid auction where bids are committed as hashes and revealed after bidding closes
+	SealedBid(SealedBidSettlement),
+}
+
+/// How the price of a sealed-bid auction is settled
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SealedBidSettlement {
+	/// Winner pays its own bid
+	FirstPrice,
+	/// Winner pays the second highest revealed bid (Vickrey auction)
+	SecondPrice,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -104,6 +116,117 @@ pub struct AuctionInfo<AccountId, Balance, BlockNumber> {
 	pub end: Option<BlockNumber>,
 }
 
+/// Sealed-bid auction info.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct SealedBidAuctionInfo<Balance, BlockNumber> {
+	/// Settlement of the winning price.
+	pub settlement: SealedBidSettlement,
+	/// Define which block bids stop being committed and start being revealed.
+	pub reveal_start: BlockNumber,
+	/// Second highest revealed bid price.
+	pub second_price: Option<Balance>,
+}
+
+/// Committed sealed bid of a bidder.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct SealedBid<Balance> {
+	/// Hash of the auction id, bidder, bid price and salt.
+	pub commitment: [u8; 32],
+	/// Deposit reserved to cover the bid price.
+	pub deposit: Balance,
+	/// Bid price once the bid is revealed.
+	pub revealed: Option<Balance>,
+}
+
+/// Accounts allowed to purchase during a drop phase.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DropEligibility<AccountId> {
+	/// Any account can purchase.
+	Public,
+	/// Only holders of an NFT of the class can purchase.
+	ClassHolders(ClassId),
+	/// Only holders of a valid metaverse credential of the class can purchase.
+	CredentialHolders(ClassId),
+	/// Only the listed accounts can purchase.
+	Accounts(Vec<AccountId>),
+}
+
+/// Phase of a drop, running until the start of the next phase or the end of the drop.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DropPhase<AccountId, Balance, BlockNumber> {
+	/// Define which block this phase will be started.
+	pub start: BlockNumber,
+	/// Price of a single unit.
+	pub price: Balance,
+	/// Max number of units an account can purchase during this phase.
+	pub per_account_limit: Balance,
+	/// Accounts allowed to purchase during this phase.
+	pub eligibility: DropEligibility<AccountId>,
+}
+
+/// Scheduled drop of a stackable NFT inventory.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct DropInfo<AccountId, Balance, BlockNumber> {
+	/// The creator of the drop.
+	pub creator: AccountId,
+	/// The stackable NFT sold in the drop.
+	pub asset: (ClassId, TokenId),
+	/// Number of units left in the inventory.
+	pub remaining: Balance,
+	/// Define which block this drop will be ended.
+	pub end: BlockNumber,
+	/// Phases of the drop, ordered by start block.
+	pub phases: Vec<DropPhase<AccountId, Balance, BlockNumber>>,
+	/// Listing level of the drop.
+	pub listing_level: ListingLevel<AccountId>,
+	/// Currency used to purchase.
+	pub currency_id: FungibleTokenId,
+	/// Listing fee of the drop.
+	pub listing_fee: Perbill,
+}
+
+/// Order signed off-chain by the owner of an item and fulfilled on-chain by the buyer. Orders
+/// signed with the same nonce are invalidated together.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SignedOrder<Balance, BlockNumber> {
+	/// The item sold by the order.
+	pub item_id: ItemId<Balance>,
+	/// The price of the item.
+	pub price: Balance,
+	/// Currency used to purchase.
+	pub currency_id: FungibleTokenId,
+	/// A deadline for the signature.
+	pub expired: BlockNumber,
+	/// Nonce of the signer when the order was signed.
+	pub nonce: u64,
+}
+
+/// Peer-to-peer swap proposal of items and fungible amounts, settled atomically on acceptance.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(MaxItems))]
+pub struct SwapProposal<AccountId, Balance, BlockNumber, MaxItems: Get<u32>> {
+	/// The account proposing the swap.
+	pub proposer: AccountId,
+	/// The only account allowed to accept the swap, any account if none.
+	pub counterparty: Option<AccountId>,
+	/// Items offered by the proposer, locked while the proposal is open.
+	pub offered_items: BoundedVec<ItemId<Balance>, MaxItems>,
+	/// Fungible amounts offered by the proposer, reserved while the proposal is open.
+	pub offered_tokens: BoundedVec<(FungibleTokenId, Balance), MaxItems>,
+	/// Items requested from the counterparty.
+	pub requested_items: BoundedVec<ItemId<Balance>, MaxItems>,
+	/// Fungible amounts requested from the counterparty.
+	pub requested_tokens: BoundedVec<(FungibleTokenId, Balance), MaxItems>,
+	/// Deposit reserved from the proposer while the proposal is open.
+	pub deposit: Balance,
+	/// Define which block this proposal will be expired.
+	pub expiry: BlockNumber,
+}
+
 /// Abstraction over a simple auction system.
 pub trait Auction<AccountId, BlockNumber> {
 	/// The price to bid.
@@ -149,6 +272,22 @@ pub trait Auction<AccountId, BlockNumber> {
 
 	fn buy_now_handler(from: AccountId, auction_id: AuctionId, value: Self::Balance) -> DispatchResult;
 
+	/// Commit a sealed bid hash with a deposit which covers the hidden bid
+	fn sealed_bid_commit_handler(
+		from: AccountId,
+		id: AuctionId,
+		commitment: [u8; 32],
+		deposit: Self::Balance,
+	) -> DispatchResult;
+
+	/// Reveal a committed sealed bid, return true if it is the leading bid
+	fn sealed_bid_reveal_handler(
+		from: AccountId,
+		id: AuctionId,
+		value: Self::Balance,
+		salt: Vec<u8>,
+	) -> Result<bool, DispatchError>;
+
 	fn local_auction_bid_handler(
 		_now: BlockNumber,
 		id: AuctionId,
@@ -202,4 +341,41 @@ pub trait SwapManager<AccountId, CurrencyId, Balance> {
 		max_amount_a: Balance,
 		max_amount_b: Balance,
 	) -> DispatchResult;
+	/// Enable the trading pair if it is not enabled yet so liquidity can be added
+	fn enable_trading_pair(token_id_a: FungibleTokenId, token_id_b: FungibleTokenId) -> DispatchResult;
+}
+
+impl<AccountId, CurrencyId, Balance> SwapManager<AccountId, CurrencyId, Balance> for () {
+	fn add_liquidity(
+		_who: &AccountId,
+		_token_id_a: FungibleTokenId,
+		_token_id_b: FungibleTokenId,
+		_max_amount_a: Balance,
+		_max_amount_b: Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Swap is not supported"))
+	}
+
+	fn enable_trading_pair(_token_id_a: FungibleTokenId, _token_id_b: FungibleTokenId) -> DispatchResult {
+		Err(DispatchError::Other("Swap is not supported"))
+	}
+}
+
+/// Price of currencies derived from the liquidity pools
+pub trait DEXPriceProvider<CurrencyId> {
+	/// Get the current price of `base` currency quoted in `quote` currency from the pool reserves
+	fn get_spot_price(base: CurrencyId, quote: CurrencyId) -> Option<Ratio>;
+	/// Get the time weighted average price of `base` currency quoted in `quote` currency, none
+	/// until the pool has been priced for a full averaging period
+	fn get_twap_price(base: CurrencyId, quote: CurrencyId) -> Option<Ratio>;
+}
+
+impl<CurrencyId> DEXPriceProvider<CurrencyId> for () {
+	fn get_spot_price(_base: CurrencyId, _quote: CurrencyId) -> Option<Ratio> {
+		None
+	}
+
+	fn get_twap_price(_base: CurrencyId, _quote: CurrencyId) -> Option<Ratio> {
+		None
+	}
 }
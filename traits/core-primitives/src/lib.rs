@@ -1,14 +1,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use impl_trait_for_tuples::impl_for_tuples;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
-use sp_runtime::{DispatchError, DispatchResult, Perbill, RuntimeDebug};
+use sp_runtime::{
+	traits::{CheckedDiv, One},
+	DispatchError, DispatchResult, FixedPointNumber, Perbill, RuntimeDebug,
+};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec::Vec};
 
 use primitives::staking::RoundInfo;
 use primitives::{
-	ClassId, FungibleTokenId, GroupCollectionId, MetaverseId, UndeployedLandBlockId, UndeployedLandBlockType,
+	Balance, ClassId, EstateId, FungibleTokenId, GroupCollectionId, MetaverseId, Price, TokenId, UndeployedLandBlockId,
+	UndeployedLandBlockType,
 };
 
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
@@ -173,6 +178,83 @@ pub struct PreSignedMint<ClassId, TokenId, AccountId, BlockNumber, Balance> {
 	pub mint_price: Option<Balance>,
 }
 
+/// Non-transferable credential issued by a metaverse to an account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CredentialInfo<BlockNumber> {
+	/// The credential token held by the account.
+	pub token_id: TokenId,
+	/// The block the credential was issued.
+	pub issued_at: BlockNumber,
+	/// The block the credential expires, `None` if it never expires.
+	pub expiry: Option<BlockNumber>,
+}
+
+/// Change of a token attribute recorded in its update history.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AttributeChange<AccountId, BlockNumber> {
+	/// The attribute key.
+	pub key: Vec<u8>,
+	/// The value before the update, `None` if the attribute was not set.
+	pub old_value: Option<Vec<u8>>,
+	/// The value after the update, `None` if the attribute was removed.
+	pub new_value: Option<Vec<u8>>,
+	/// The account that made the update.
+	pub updated_by: AccountId,
+	/// The block of the update.
+	pub block_number: BlockNumber,
+}
+
+/// Item produced by a crafting recipe.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum CraftingOutput<ClassId, TokenId, Balance> {
+	/// Mint a new NFT of the class to the crafter.
+	Nft {
+		class_id: ClassId,
+		metadata: NftMetadata,
+		attributes: Attributes,
+	},
+	/// Transfer units of a stackable NFT held by the recipe owner to the crafter.
+	StackableNft {
+		asset_id: (ClassId, TokenId),
+		amount: Balance,
+	},
+}
+
+/// Possible outcome of a crafting recipe.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CraftingOutcome<ClassId, TokenId, Balance> {
+	/// Relative chance of the outcome among the outcomes of the recipe.
+	pub weight: u32,
+	/// Items produced by the outcome.
+	pub outputs: Vec<CraftingOutput<ClassId, TokenId, Balance>>,
+}
+
+/// Crafting recipe registered by a class owner.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CraftingRecipe<AccountId, ClassId, TokenId, Balance, BlockNumber> {
+	/// The owner of the recipe and of the classes of its outputs.
+	pub owner: AccountId,
+	/// Stackable NFT amounts paid by the crafter to the recipe owner. Stackable balances can not
+	/// be burned, so they are not removed from the supply and the owner can reuse them as outputs.
+	pub stackable_inputs: Vec<((ClassId, TokenId), Balance)>,
+	/// Classes of the unique NFTs consumed, one token of each class is burned.
+	pub nft_inputs: Vec<ClassId>,
+	/// Fungible fees paid to the recipe owner.
+	pub fees: Vec<(FungibleTokenId, Balance)>,
+	/// Possible outcomes, one is picked randomly by weight.
+	pub outcomes: Vec<CraftingOutcome<ClassId, TokenId, Balance>>,
+	/// Max number of crafts of the recipe.
+	pub max_crafts: Option<u32>,
+	/// Max number of crafts of the recipe per account.
+	pub max_crafts_per_account: Option<u32>,
+	/// The block the recipe can be crafted from.
+	pub start: Option<BlockNumber>,
+	/// The block the recipe can be crafted until.
+	pub end: Option<BlockNumber>,
+	/// Number of crafts of the recipe.
+	pub total_crafted: u32,
+}
+
 pub trait MetaverseTrait<AccountId> {
 	/// Create metaverse
 	fn create_metaverse(who: &AccountId, metadata: MetaverseMetadata) -> MetaverseId;
@@ -326,6 +408,11 @@ pub trait NFTTrait<AccountId, Balance> {
 		nft: &(Self::ClassId, Self::TokenId),
 		amount: Balance,
 	) -> DispatchResult;
+	/// Get the royalty payouts of a sale, the rest of the royalty is paid to the class fund
+	fn get_royalty_payouts(
+		class_id: &Self::ClassId,
+		sale_price: Balance,
+	) -> Result<Vec<(AccountId, Balance)>, DispatchError>;
 }
 
 pub trait RoundTrait<BlockNumber> {
@@ -401,3 +488,84 @@ pub trait CurrencyIdManagement {
 	fn convert_to_rcurrency(currency_id: FungibleTokenId) -> Result<FungibleTokenId, ()>;
 	fn convert_to_currency(currency_id: FungibleTokenId) -> Result<FungibleTokenId, ()>;
 }
+
+/// Prices of fungible tokens expressed in a reference currency
+pub trait PriceProvider {
+	/// Price of `currency_id` in the reference currency, `None` if unknown or stale
+	fn get_price(currency_id: FungibleTokenId) -> Option<Price>;
+
+	/// Price of `base` expressed in `quote`
+	fn get_relative_price(base: FungibleTokenId, quote: FungibleTokenId) -> Option<Price> {
+		if base == quote {
+			return Some(Price::one());
+		}
+		Self::get_price(base)?.checked_div(&Self::get_price(quote)?)
+	}
+
+	/// Converts an `amount` of `from` into the equivalent amount of `to`
+	fn convert(from: FungibleTokenId, to: FungibleTokenId, amount: Balance) -> Option<Balance> {
+		Self::get_relative_price(from, to)?.checked_mul_int(amount)
+	}
+
+	/// Converts an `amount` of the reference currency into the equivalent amount of `currency_id`
+	fn from_reference(currency_id: FungibleTokenId, amount: Balance) -> Option<Balance> {
+		Price::one()
+			.checked_div(&Self::get_price(currency_id)?)?
+			.checked_mul_int(amount)
+	}
+}
+
+impl PriceProvider for () {
+	fn get_price(_currency_id: FungibleTokenId) -> Option<Price> {
+		None
+	}
+}
+
+/// Moves the assets owned by an account to another account, used by account recovery
+pub trait AccountAssetsMigration<AccountId> {
+	/// Moves at most `limit` assets of `from` to `to` and returns the number of assets moved.
+	/// Fewer than `limit` assets moved means `from` has no asset left to move.
+	fn migrate_account_assets(from: &AccountId, to: &AccountId, limit: u32) -> Result<u32, DispatchError>;
+}
+
+#[impl_for_tuples(8)]
+impl<AccountId> AccountAssetsMigration<AccountId> for Tuple {
+	fn migrate_account_assets(from: &AccountId, to: &AccountId, limit: u32) -> Result<u32, DispatchError> {
+		let mut migrated: u32 = 0;
+		for_tuples!( #(
+			migrated = migrated.saturating_add(Tuple::migrate_account_assets(from, to, limit.saturating_sub(migrated))?);
+		)* );
+		Ok(migrated)
+	}
+}
+
+/// Source of the stake bonded on estates, used to share land block issuance between metaverses
+pub trait EstateStakeProvider<Balance> {
+	/// Bonded stake of at most `limit` estates in storage order, starting after the estate
+	/// `start_after`, and the estate to continue from when more stakes remain
+	fn estate_stakes(start_after: Option<EstateId>, limit: u32) -> (Vec<(EstateId, Balance)>, Option<EstateId>);
+}
+
+impl<Balance> EstateStakeProvider<Balance> for () {
+	fn estate_stakes(_start_after: Option<EstateId>, _limit: u32) -> (Vec<(EstateId, Balance)>, Option<EstateId>) {
+		(Vec::new(), None)
+	}
+}
+
+/// Credentials issued by metaverses, used to gate actions of other pallets
+pub trait CredentialTrait<AccountId> {
+	/// Get the metaverse issuing the credentials of a class
+	fn get_credential_issuer(class_id: ClassId) -> Option<MetaverseId>;
+	/// Check if an account holds an unexpired and unrevoked credential of a class
+	fn has_valid_credential(who: &AccountId, class_id: ClassId) -> bool;
+}
+
+impl<AccountId> CredentialTrait<AccountId> for () {
+	fn get_credential_issuer(_class_id: ClassId) -> Option<MetaverseId> {
+		None
+	}
+
+	fn has_valid_credential(_who: &AccountId, _class_id: ClassId) -> bool {
+		false
+	}
+}